@@ -2,204 +2,272 @@
 // Copyright © 2025 DateTime (DTT) library. All rights reserved.
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-//! Enhanced benchmarks for the DTT (`DateTime`) library.
+//! Benchmark suite for the DTT (`DateTime`) library.
 //!
-//! This module contains benchmarks for various operations provided by the DTT library,
-//! including creation, parsing, formatting, arithmetic operations, comparisons, and timezone conversions.
+//! Benchmarks are grouped by operation so that results can be compared
+//! against a saved baseline:
+//!
+//! - `creation`: building `DateTime` values from the clock, components,
+//!   and timestamps.
+//! - `parsing`: RFC 3339, ISO 8601, HTTP dates, and custom formats.
+//! - `formatting`: the preset formats, custom formats, and `Display`.
+//! - `arithmetic`: calendar and duration arithmetic and comparisons.
+//! - `conversion`: timezone and offset conversions.
+//!
+//! Inputs are fixed values rather than the current time, so runs are
+//! comparable. To record and compare against a baseline:
+//!
+//! ```text
+//! cargo bench --bench benchmark -- --save-baseline main
+//! cargo bench --bench benchmark -- --baseline main
+//! ```
 
 use criterion::{
     black_box, criterion_group, criterion_main, Criterion,
 };
 use dtt::datetime::DateTime;
-use time::Duration;
+use dtt::format::CompiledFormat;
+use dtt::timezone::ZoneResolver;
+use time::{Duration, UtcOffset};
 
-/// Benchmark the creation of a new `DateTime` instance.
-fn bench_new_datetime(c: &mut Criterion) {
-    let _ =
-        c.bench_function("new `DateTime`", |b| b.iter(DateTime::new));
+/// A fixed input shared by the benchmarks.
+const SAMPLE: &str = "2024-02-29T12:34:56.789+01:00";
+
+/// Parses [`SAMPLE`], or `None` if parsing is broken.
+fn sample() -> Option<DateTime> {
+    DateTime::parse(SAMPLE).ok()
 }
 
-/// Benchmark accessing the day of a `DateTime` instance.
-fn bench_get_day(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let _ = c.bench_function("get day", |b| {
+/// Benchmarks building `DateTime` values.
+fn bench_creation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("creation");
+
+    let _ = group.bench_function("now", |b| b.iter(DateTime::new));
+    let _ = group.bench_function("now_with_tz", |b| {
+        b.iter(|| DateTime::new_with_tz(black_box("CET")));
+    });
+    let _ = group.bench_function("from_components", |b| {
         b.iter(|| {
-            let _ = date.day();
+            DateTime::from_components(
+                black_box(2024),
+                black_box(2),
+                black_box(29),
+                black_box(12),
+                black_box(34),
+                black_box(56),
+                UtcOffset::UTC,
+            )
         });
     });
-}
-
-/// Benchmark accessing the hour of a `DateTime` instance.
-fn bench_get_hour(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let _ = c.bench_function("get hour", |b| {
+    let _ = group.bench_function("from_components_extremes", |b| {
         b.iter(|| {
-            let _ = date.hour();
+            let past = DateTime::from_components(
+                black_box(1),
+                1,
+                1,
+                0,
+                0,
+                0,
+                UtcOffset::UTC,
+            );
+            let future = DateTime::from_components(
+                black_box(9999),
+                12,
+                31,
+                23,
+                59,
+                59,
+                UtcOffset::UTC,
+            );
+            (past, future)
         });
     });
-}
-
-/// Benchmark formatting a `DateTime` instance to ISO 8601.
-fn bench_format_iso_8601(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let _ = c.bench_function("format ISO 8601", |b| {
+    let _ = group.bench_function("from_unix_timestamp_nanos", |b| {
         b.iter(|| {
-            if let Ok(output) = date.format_iso8601() {
-                let _ = output;
-            }
+            DateTime::from_unix_timestamp_nanos(black_box(
+                1_709_206_496_789_000_000,
+            ))
         });
     });
+
+    group.finish();
 }
 
-/// Benchmark parsing a datetime string in ISO 8601 format.
-fn bench_parse_iso_8601(c: &mut Criterion) {
-    let date_str = black_box("2023-09-01T12:00:00Z");
-    let _ = c.bench_function("parse ISO 8601", |b| {
+/// Benchmarks parsing strings into `DateTime` values.
+fn bench_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parsing");
+
+    let _ = group.bench_function("rfc3339", |b| {
+        b.iter(|| DateTime::parse(black_box(SAMPLE)));
+    });
+    let _ = group.bench_function("iso8601_date", |b| {
+        b.iter(|| DateTime::parse(black_box("2024-02-29")));
+    });
+    let _ = group.bench_function("http_date", |b| {
         b.iter(|| {
-            if let Ok(date) = DateTime::parse(date_str) {
-                let _ = date;
-            }
+            DateTime::parse_http_date(black_box(
+                "Thu, 29 Feb 2024 11:34:56 GMT",
+            ))
         });
     });
-}
-
-/// Benchmark parsing a datetime string with a custom format.
-fn bench_parse_custom_format(c: &mut Criterion) {
-    let date_str = black_box("01/09/2023 12:00:00");
-    let format =
-        black_box("[day]/[month]/[year] [hour]:[minute]:[second]");
-    let _ = c.bench_function("parse custom format", |b| {
+    let _ = group.bench_function("custom_format", |b| {
         b.iter(|| {
-            if let Ok(date) =
-                DateTime::parse_custom_format(date_str, format)
-            {
-                let _ = date;
-            }
+            DateTime::parse_custom_format(
+                black_box("29/02/2024 12:34:56"),
+                black_box(
+                    "[day]/[month]/[year] [hour]:[minute]:[second]",
+                ),
+            )
         });
     });
+    let _ = group.bench_function("invalid", |b| {
+        b.iter(|| DateTime::parse(black_box("2024-02-30T12:34:56Z")));
+    });
+
+    group.finish();
 }
 
-/// Benchmark adding days to a `DateTime` instance.
-fn bench_add_days(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let _ = c.bench_function("add days", |b| {
+/// Benchmarks rendering `DateTime` values as strings.
+fn bench_formatting(c: &mut Criterion) {
+    let date = match sample() {
+        Some(date) => date,
+        None => return,
+    };
+    let mut group = c.benchmark_group("formatting");
+
+    let _ = group.bench_function("rfc3339", |b| {
+        b.iter(|| black_box(&date).format_rfc3339());
+    });
+    let _ = group.bench_function("rfc3339_reuse", |b| {
+        let mut output = String::new();
+        b.iter(|| black_box(&date).format_rfc3339_reuse(&mut output));
+    });
+    let _ = group.bench_function("rfc3339_into", |b| {
+        let mut output = String::new();
         b.iter(|| {
-            if let Ok(new_date) = date.add_days(30) {
-                let _ = new_date;
-            }
+            output.clear();
+            black_box(&date).write_rfc3339_into(&mut output)
         });
     });
-}
-
-/// Benchmark subtracting days from a `DateTime` instance.
-fn bench_sub_days(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let _ = c.bench_function("subtract days", |b| {
+    let _ = group.bench_function("iso8601", |b| {
+        b.iter(|| black_box(&date).format_iso8601());
+    });
+    let _ = group.bench_function("http_date", |b| {
+        b.iter(|| black_box(&date).format_http_date());
+    });
+    let _ = group.bench_function("custom_format", |b| {
         b.iter(|| {
-            if let Ok(new_date) = date.add_days(-30) {
-                let _ = new_date;
-            }
+            black_box(&date).format(black_box(
+                "[year]-[month]-[day] [hour]:[minute]",
+            ))
         });
     });
-}
-
-/// Benchmark adding a `Duration` to a `DateTime` instance.
-fn bench_add_duration(c: &mut Criterion) {
-    let date = black_box(DateTime::new());
-    let duration = black_box(Duration::hours(48));
-    let _ = c.bench_function("add duration", |b| {
+    let _ = group.bench_function("compiled_format", |b| {
+        let compiled =
+            CompiledFormat::new("[year]-[month]-[day] [hour]:[minute]");
         b.iter(|| {
-            if let Ok(new_date) = date + duration {
-                let _ = new_date;
-            }
+            compiled
+                .as_ref()
+                .map(|compiled| black_box(&date).format_with(compiled))
         });
     });
+    let _ = group.bench_function("display", |b| {
+        b.iter(|| black_box(&date).to_string());
+    });
+
+    group.finish();
 }
 
-/// Benchmark comparing two `DateTime` instances.
-fn bench_compare_datetimes(c: &mut Criterion) {
-    let date1 = black_box(DateTime::new());
-    let date2 = match date1.add_days(1) {
-        Ok(date) => date,
+/// Benchmarks calendar and duration arithmetic.
+fn bench_arithmetic(c: &mut Criterion) {
+    let date = match sample() {
+        Some(date) => date,
+        None => return,
+    };
+    let later = match date.add_days(1) {
+        Ok(later) => later,
         Err(_) => return,
     };
-    let _ = c.bench_function("compare datetimes", |b| {
-        b.iter(|| {
-            let _ = date1.cmp(&date2);
-        });
-    });
-}
+    let mut group = c.benchmark_group("arithmetic");
 
-/// Benchmark creating a `DateTime` for an extreme date (far in the future).
-fn bench_extreme_date_future(c: &mut Criterion) {
-    let _ = c.bench_function("create extreme future date", |b| {
-        b.iter(|| {
-            if let Ok(date) = DateTime::from_components(
-                9999,
-                12,
-                31,
-                23,
-                59,
-                59,
-                time::UtcOffset::UTC,
-            ) {
-                let _ = date;
-            }
-        });
+    let _ = group.bench_function("add_days", |b| {
+        b.iter(|| black_box(&date).add_days(black_box(30)));
     });
-}
-
-/// Benchmark creating a `DateTime` for an extreme date (far in the past).
-fn bench_extreme_date_past(c: &mut Criterion) {
-    let _ = c.bench_function("create extreme past date", |b| {
+    let _ = group.bench_function("sub_days", |b| {
+        b.iter(|| black_box(&date).add_days(black_box(-30)));
+    });
+    let _ = group.bench_function("add_months", |b| {
+        b.iter(|| black_box(&date).add_months(black_box(13)));
+    });
+    let _ = group.bench_function("add_years", |b| {
+        b.iter(|| black_box(&date).add_years(black_box(1)));
+    });
+    let _ = group.bench_function("bump_day", |b| {
+        let mut current = date;
         b.iter(|| {
-            if let Ok(date) = DateTime::from_components(
-                1,
-                1,
-                1,
-                0,
-                0,
-                0,
-                time::UtcOffset::UTC,
-            ) {
-                let _ = date;
+            if current.bump_day().is_err() {
+                current = date;
             }
         });
     });
+    let _ = group.bench_function("add_duration", |b| {
+        b.iter(|| *black_box(&date) + black_box(Duration::hours(48)));
+    });
+    let _ = group.bench_function("duration_since", |b| {
+        b.iter(|| black_box(&later).duration_since(black_box(&date)));
+    });
+    let _ = group.bench_function("compare", |b| {
+        b.iter(|| black_box(&date).cmp(black_box(&later)));
+    });
+
+    group.finish();
 }
 
-/// Benchmark converting a `DateTime` to a different timezone.
-fn bench_convert_timezone(c: &mut Criterion) {
-    let date = match DateTime::new_with_tz("UTC") {
-        Ok(date) => date,
+/// Benchmarks converting between timezones and offsets.
+fn bench_conversion(c: &mut Criterion) {
+    let date = match sample() {
+        Some(date) => date,
+        None => return,
+    };
+    let offset = match UtcOffset::from_hms(-5, 0, 0) {
+        Ok(offset) => offset,
+        Err(_) => return,
+    };
+    let mut group = c.benchmark_group("conversion");
+
+    let _ = group.bench_function("convert_to_tz", |b| {
+        b.iter(|| black_box(&date).convert_to_tz(black_box("EST")));
+    });
+    let resolver = match ZoneResolver::new("EST") {
+        Ok(resolver) => resolver,
         Err(_) => return,
     };
-    let _ = c.bench_function("convert timezone", |b| {
+    let _ = group.bench_function("zone_resolver_convert", |b| {
+        b.iter(|| resolver.convert(black_box(&date)));
+    });
+    let _ = group.bench_function("saturating_convert_to_offset", |b| {
         b.iter(|| {
-            if let Ok(new_date) = date.convert_to_tz("EST") {
-                let _ = new_date;
-            }
+            black_box(&date)
+                .saturating_convert_to_offset(black_box(offset))
         });
     });
+    let _ = group.bench_function("unix_timestamp", |b| {
+        b.iter(|| black_box(&date).unix_timestamp());
+    });
+    let _ = group.bench_function("to_offset_datetime", |b| {
+        b.iter(|| black_box(&date).to_offset_datetime());
+    });
+
+    group.finish();
 }
 
-// Group all benchmarks.
 criterion_group!(
     benches,
-    bench_new_datetime,
-    bench_get_day,
-    bench_get_hour,
-    bench_format_iso_8601,
-    bench_parse_iso_8601,
-    bench_parse_custom_format,
-    bench_add_days,
-    bench_sub_days,
-    bench_add_duration,
-    bench_compare_datetimes,
-    bench_extreme_date_future,
-    bench_extreme_date_past,
-    bench_convert_timezone,
+    bench_creation,
+    bench_parsing,
+    bench_formatting,
+    bench_arithmetic,
+    bench_conversion,
 );
 
-// Entry point for running the benchmarks.
 criterion_main!(benches);
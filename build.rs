@@ -21,9 +21,9 @@ use std::process;
 /// # Returns
 ///
 /// * `Some(true)` - If the current Rustc version is at least the minimum
-///    required version.
+///   required version.
 /// * `Some(false)` - If the current Rustc version is less than the minimum
-///    required version.
+///   required version.
 /// * `None` - If the current Rustc version cannot be determined.
 ///
 /// # Errors
@@ -34,7 +34,7 @@ use std::process;
 /// # Examples
 ///
 /// ```rust
-/// let min_version = "1.56";
+/// let min_version = "1.67.1";
 ///
 /// match version_check::is_min_version(min_version) {
 ///     Some(true) => println!("Rustc version is at least {}", min_version),
@@ -49,7 +49,7 @@ use std::process;
 /// }
 /// ```
 fn main() {
-    let min_version = "1.56";
+    let min_version = "1.67.1";
 
     if version_check::is_min_version(min_version) == Some(true) {
     } else {
@@ -3,17 +3,17 @@
 
 //! # DTT Library Usage Examples
 //!
-//! This program demonstrates the comprehensive usage of the `DateTime` (DTT) library, covering basic and advanced `DateTime` operations, macro usage, date component handling, error handling scenarios, serialization, and performance considerations. Each function provides examples with explanatory output.
+//! This program demonstrates the comprehensive usage of the `DateTime` (DTT) library, covering basic and advanced `DateTime` operations, macro usage, date component handling, error handling scenarios, and serialization. Performance is measured by the criterion suite in `benches/` (`cargo bench`). Each function provides examples with explanatory output.
 
 #![allow(missing_docs)]
 
 use dtt::datetime::DateTime;
 use dtt::error::AppError;
+use dtt::validation::{validate_date, validate_time};
 use dtt::{
     dtt_add_days, dtt_assert, dtt_join, dtt_map, dtt_max, dtt_min,
     dtt_now, dtt_parse, dtt_print_vec, dtt_sub_days, dtt_vec,
 };
-use std::time::Instant;
 
 /// Entry point for the DTT library usage examples.
 ///
@@ -31,7 +31,6 @@ fn main() -> Result<(), AppError> {
     date_component_examples();
     error_handling_examples()?;
     serialization_examples()?;
-    performance_examples()?;
     locale_specific_examples()?;
 
     println!("\n🎉 All examples completed successfully!");
@@ -236,41 +235,48 @@ fn date_component_examples() {
     println!("Offset: {:?}", date.offset());
 
     println!("\n✅ Date Validation Examples:");
-    println!("Is 31 a valid day? {}", DateTime::is_valid_day("31"));
-    println!("Is 13 a valid month? {}", DateTime::is_valid_month("13"));
-    println!("Is 25 a valid hour? {}", DateTime::is_valid_hour("25"));
+    println!("Is 31 a valid day? {}", DateTime::is_valid_day_u8(31));
+    println!(
+        "Is 13 a valid month? {}",
+        DateTime::is_valid_month_u8(13)
+    );
+    println!("Is 25 a valid hour? {}", DateTime::is_valid_hour_u8(25));
     println!(
         "Is 2023-13-32 a valid ISO8601 date? {}",
         DateTime::is_valid_iso_8601("2023-13-32")
     );
     println!(
         "Is 61 a valid minute? {}",
-        DateTime::is_valid_minute("61")
+        DateTime::is_valid_minute_u8(61)
     );
     println!(
         "Is 60 a valid second? {}",
-        DateTime::is_valid_second("60")
-    );
-    println!(
-        "Is 2023 a valid year? {}",
-        DateTime::is_valid_year("2023")
+        DateTime::is_valid_second_u8(60)
     );
     println!(
         "Is 1000000 a valid microsecond? {}",
-        DateTime::is_valid_microsecond("1000000")
+        DateTime::is_valid_microsecond_u32(1_000_000)
     );
     println!(
         "Is 366 a valid ordinal day? {}",
-        DateTime::is_valid_ordinal("366")
+        DateTime::is_valid_ordinal_u16(366)
     );
     println!(
         "Is 53 a valid ISO week? {}",
-        DateTime::is_valid_iso_week("53")
+        DateTime::is_valid_iso_week_u8(53)
     );
     println!(
         "Is 12:34:56 a valid time? {}",
-        DateTime::is_valid_time("12:34:56")
+        validate_time(12, 34, 56, 0).is_ok()
     );
+    match validate_date(2023, 2, 29) {
+        Ok(date) => println!("2023-02-29 is valid: {}", date),
+        Err(errors) => {
+            for error in errors {
+                println!("2023-02-29 is invalid: {}", error);
+            }
+        }
+    }
 }
 
 /// Demonstrates error handling using the `DateTime` struct and associated methods.
@@ -378,56 +384,6 @@ fn serialization_examples() -> Result<(), AppError> {
     Ok(())
 }
 
-/// Demonstrates performance considerations when using the `DateTime` library.
-///
-/// This function includes examples of measuring the performance of various `DateTime` operations.
-///
-/// # Errors
-///
-/// Returns an `AppError` if any performance measurement fails.
-fn performance_examples() -> Result<(), AppError> {
-    println!("\n🦀 Performance Examples 🦀");
-
-    // Measure creation performance
-    let start = Instant::now();
-    for _ in 0..10000 {
-        let _ = DateTime::new();
-    }
-    let duration = start.elapsed();
-    println!("Time to create 10,000 DateTime objects: {:?}", duration);
-
-    // Measure parsing performance
-    let start = Instant::now();
-    for _ in 0..10000 {
-        let _ = DateTime::parse("2023-09-01T12:00:00Z")?;
-    }
-    let duration = start.elapsed();
-    println!("Time to parse 10,000 ISO8601 strings: {:?}", duration);
-
-    // Measure formatting performance
-    let dt = DateTime::new();
-    let start = Instant::now();
-    for _ in 0..10000 {
-        let _ = dt.format_iso8601()?;
-    }
-    let duration = start.elapsed();
-    println!(
-        "Time to format 10,000 DateTime objects to ISO8601: {:?}",
-        duration
-    );
-
-    // Measure arithmetic performance
-    let dt = DateTime::new();
-    let start = Instant::now();
-    for i in 0..10000 {
-        let _ = dt.add_days(i)?;
-    }
-    let duration = start.elapsed();
-    println!("Time to perform 10,000 date additions: {:?}", duration);
-
-    Ok(())
-}
-
 /// Demonstrates locale-specific formatting capabilities of the library.
 ///
 /// This function provides information about the library's support (or lack thereof)
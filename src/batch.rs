@@ -0,0 +1,158 @@
+// batch.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Parsing and formatting many timestamps at once.
+//!
+//! [`parse_many`] and [`format_many`] convert whole slices, keeping one
+//! result per input in the original order so failures can be matched back
+//! to their rows. [`parse_many_with_spec`] parses a custom format compiled
+//! once into a [`FormatSpec`]. With the `rayon` feature enabled, the
+//! `_parallel` variants do the same work across all cores and return
+//! identical results.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::batch::{format_many, parse_many};
+//! use dtt::format::Format;
+//!
+//! let parsed = parse_many(&["2024-01-01T00:00:00Z", "not a date"]);
+//! assert!(parsed[0].is_ok());
+//! assert!(parsed[1].is_err());
+//!
+//! let dates: Vec<_> = parsed.into_iter().flatten().collect();
+//! let formatted = format_many(&dates, Format::CalendarDate);
+//! assert_eq!(formatted[0].as_deref(), Ok("2024-01-01"));
+//! ```
+
+use crate::{
+    datetime::DateTime,
+    error::DateTimeError,
+    format::{Format, FormatSpec, FormatableDateTime},
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Parses each input with [`DateTime::parse`].
+#[must_use]
+pub fn parse_many<S: AsRef<str>>(
+    inputs: &[S],
+) -> Vec<Result<DateTime, DateTimeError>> {
+    inputs
+        .iter()
+        .map(|input| DateTime::parse(input.as_ref()))
+        .collect()
+}
+
+/// Parses each input with [`DateTime::parse_with_spec`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::batch::parse_many_with_spec;
+/// use dtt::format::FormatSpec;
+///
+/// let spec = FormatSpec::new("[day].[month].[year] [hour]:[minute]").unwrap();
+/// let parsed =
+///     parse_many_with_spec(&["01.02.2024 08:00", "31.02.2024 08:00"], &spec);
+/// assert_eq!(parsed[0].unwrap().to_string(), "2024-02-01T08:00:00Z");
+/// assert!(parsed[1].is_err());
+/// ```
+#[must_use]
+pub fn parse_many_with_spec<S: AsRef<str>>(
+    inputs: &[S],
+    spec: &FormatSpec,
+) -> Vec<Result<DateTime, DateTimeError>> {
+    inputs
+        .iter()
+        .map(|input| DateTime::parse_with_spec(input.as_ref(), spec))
+        .collect()
+}
+
+/// Formats each value with a [`Format`] preset.
+#[must_use]
+pub fn format_many(
+    values: &[DateTime],
+    format: Format,
+) -> Vec<Result<String, DateTimeError>> {
+    values
+        .iter()
+        .map(|value| value.format_preset(format))
+        .collect()
+}
+
+/// Parses each input with [`DateTime::parse`], in parallel.
+///
+/// Results are in input order, exactly as from [`parse_many`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::batch::{parse_many, parse_many_parallel};
+///
+/// let inputs: Vec<String> = (1..=28)
+///     .map(|day| format!("2024-02-{:02}T12:00:00Z", day))
+///     .collect();
+/// assert_eq!(parse_many_parallel(&inputs), parse_many(&inputs));
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[must_use]
+pub fn parse_many_parallel<S: AsRef<str> + Sync>(
+    inputs: &[S],
+) -> Vec<Result<DateTime, DateTimeError>> {
+    inputs
+        .par_iter()
+        .map(|input| DateTime::parse(input.as_ref()))
+        .collect()
+}
+
+/// Parses each input with [`DateTime::parse_with_spec`], in parallel.
+///
+/// Results are in input order, exactly as from [`parse_many_with_spec`].
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[must_use]
+pub fn parse_many_with_spec_parallel<S: AsRef<str> + Sync>(
+    inputs: &[S],
+    spec: &FormatSpec,
+) -> Vec<Result<DateTime, DateTimeError>> {
+    inputs
+        .par_iter()
+        .map(|input| DateTime::parse_with_spec(input.as_ref(), spec))
+        .collect()
+}
+
+/// Formats each value with a [`Format`] preset, in parallel.
+///
+/// Results are in input order, exactly as from [`format_many`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::batch::{format_many, format_many_parallel, parse_many};
+/// use dtt::format::Format;
+///
+/// let dates: Vec<_> = parse_many(&["2024-01-01", "2024-06-30"])
+///     .into_iter()
+///     .flatten()
+///     .collect();
+/// assert_eq!(
+///     format_many_parallel(&dates, Format::OrdinalDate),
+///     format_many(&dates, Format::OrdinalDate),
+/// );
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[must_use]
+pub fn format_many_parallel(
+    values: &[DateTime],
+    format: Format,
+) -> Vec<Result<String, DateTimeError>> {
+    values
+        .par_iter()
+        .map(|value| value.format_preset(format))
+        .collect()
+}
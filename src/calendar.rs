@@ -0,0 +1,231 @@
+// calendar.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Text calendars in the style of the Unix `cal` command.
+//!
+//! [`DateTime::render_month_calendar`] renders the month containing a
+//! date and [`DateTime::render_year_calendar`] renders its whole year,
+//! three months across. [`CalendarOptions`] sets the first day of the
+//! week and a date to highlight. The highlighted day is shown in reverse
+//! video using ANSI escape codes, so the grid keeps its alignment on a
+//! terminal.
+//!
+//! Each month is 20 columns wide. Lines carry no trailing whitespace.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::calendar::CalendarOptions;
+//! use dtt::datetime::DateTime;
+//!
+//! let dt = DateTime::parse("2024-02-15T10:00:00Z").unwrap();
+//! let grid = dt.render_month_calendar(&CalendarOptions::new());
+//!
+//! assert_eq!(
+//!     grid,
+//!     "   February 2024\n\
+//!      Mo Tu We Th Fr Sa Su\n\
+//!      \x20         1  2  3  4\n\
+//!      \x205  6  7  8  9 10 11\n\
+//!      12 13 14 15 16 17 18\n\
+//!      19 20 21 22 23 24 25\n\
+//!      26 27 28 29"
+//! );
+//! ```
+//!
+//! [`DateTime::render_month_calendar`]: crate::datetime::DateTime::render_month_calendar
+//! [`DateTime::render_year_calendar`]: crate::datetime::DateTime::render_year_calendar
+
+use crate::plain;
+use time::{Date, Month, Weekday};
+
+/// Width of one month in columns.
+const MONTH_WIDTH: usize = 20;
+
+/// Columns between months in a year calendar.
+const MONTH_GAP: &str = "  ";
+
+/// Months per row in a year calendar.
+const MONTHS_PER_ROW: usize = 3;
+
+/// Week rows per month in a year calendar, so months line up.
+const WEEK_ROWS: usize = 6;
+
+/// Two-letter weekday names, Monday first.
+const WEEKDAY_NAMES: [&str; 7] =
+    ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Starts reverse video.
+const HIGHLIGHT_START: &str = "\x1b[7m";
+
+/// Resets text attributes.
+const HIGHLIGHT_END: &str = "\x1b[0m";
+
+/// Options for the calendar renderers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CalendarOptions {
+    /// The weekday shown in the first column.
+    pub first_weekday: Weekday,
+    /// A date to highlight, if it is shown.
+    pub highlight: Option<plain::Date>,
+}
+
+impl Default for CalendarOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarOptions {
+    /// Creates options with weeks starting on Monday and no highlight.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            first_weekday: Weekday::Monday,
+            highlight: None,
+        }
+    }
+
+    /// Sets the weekday shown in the first column.
+    #[must_use]
+    pub const fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Highlights `date` wherever it appears.
+    #[must_use]
+    pub const fn highlight(mut self, date: plain::Date) -> Self {
+        self.highlight = Some(date);
+        self
+    }
+}
+
+/// Renders the month containing `date`, titled with the month and year.
+pub(crate) fn render_month(
+    date: Date,
+    options: CalendarOptions,
+) -> String {
+    let title = format!("{} {}", date.month(), date.year());
+    join(&month_lines(date, &title, options))
+}
+
+/// Renders every month of the year containing `date`.
+pub(crate) fn render_year(
+    date: Date,
+    options: CalendarOptions,
+) -> String {
+    let year = date.year();
+    let jan_1 = weekday_before(date.weekday(), date.ordinal() - 1);
+
+    let mut months = Vec::with_capacity(12);
+    let mut first = jan_1;
+    let mut month = Month::January;
+    for _ in 0..12 {
+        months.push(month_grid(year, month, first, options, true));
+        first = first.nth_next(month.length(year));
+        month = month.next();
+    }
+
+    let width = MONTH_WIDTH * MONTHS_PER_ROW
+        + MONTH_GAP.len() * (MONTHS_PER_ROW - 1);
+    let mut lines =
+        vec![center(&year.to_string(), width), String::new()];
+    for row in months.chunks(MONTHS_PER_ROW) {
+        for line in 0..row[0].len() {
+            let parts: Vec<&str> =
+                row.iter().map(|month| month[line].as_str()).collect();
+            lines.push(parts.join(MONTH_GAP));
+        }
+        lines.push(String::new());
+    }
+    let _ = lines.pop();
+    join(&lines)
+}
+
+/// Returns the title, header, and week lines for the month of `date`.
+fn month_lines(
+    date: Date,
+    title: &str,
+    options: CalendarOptions,
+) -> Vec<String> {
+    let first =
+        weekday_before(date.weekday(), u16::from(date.day()) - 1);
+    let mut lines =
+        month_grid(date.year(), date.month(), first, options, false);
+    lines[0] = center(title, MONTH_WIDTH);
+    lines
+}
+
+/// Returns the lines of one month, each padded to [`MONTH_WIDTH`]
+/// visible columns. `first` is the weekday of the 1st.
+fn month_grid(
+    year: i32,
+    month: Month,
+    first: Weekday,
+    options: CalendarOptions,
+    fixed_rows: bool,
+) -> Vec<String> {
+    let start =
+        usize::from(options.first_weekday.number_days_from_monday());
+    let header: Vec<&str> = (0..7)
+        .map(|column| WEEKDAY_NAMES[(start + column) % 7])
+        .collect();
+    let title = center(&month.to_string(), MONTH_WIDTH);
+    let mut lines = vec![
+        format!("{:<width$}", title, width = MONTH_WIDTH),
+        header.join(" "),
+    ];
+
+    let lead =
+        (7 + usize::from(first.number_days_from_monday()) - start) % 7;
+    let mut cells = vec![String::from("  "); lead];
+    for day in 1..=month.length(year) {
+        let highlighted = options.highlight.map_or(false, |date| {
+            date.year() == year
+                && date.month() == month
+                && date.day() == day
+        });
+        if highlighted {
+            cells.push(format!(
+                "{HIGHLIGHT_START}{day:>2}{HIGHLIGHT_END}"
+            ));
+        } else {
+            cells.push(format!("{day:>2}"));
+        }
+    }
+    let rows = if fixed_rows {
+        WEEK_ROWS
+    } else {
+        (cells.len() + 6) / 7
+    };
+    cells.resize(rows * 7, String::from("  "));
+
+    for week in cells.chunks(7) {
+        lines.push(week.join(" "));
+    }
+    lines
+}
+
+/// Returns the weekday `days` days before `weekday`.
+fn weekday_before(weekday: Weekday, days: u16) -> Weekday {
+    // Seven steps forward is a no-op, so step back by the complement.
+    weekday.nth_next(u8::try_from((7 - days % 7) % 7).unwrap_or(0))
+}
+
+/// Centers `text` in `width` columns, without trailing padding.
+fn center(text: &str, width: usize) -> String {
+    let pad = width.saturating_sub(text.chars().count()) / 2;
+    format!("{}{}", " ".repeat(pad), text)
+}
+
+/// Joins lines, dropping trailing whitespace from each.
+fn join(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
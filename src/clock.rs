@@ -0,0 +1,607 @@
+// clock.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Clock utilities built on top of `DateTime`.
+//!
+//! # Overview
+//!
+//! - [`Clock`]: a source of the current time, implemented by
+//!   [`SystemClock`], [`ServerClock`], and [`MonotonicStamp`].
+//! - [`TimeSource`]: a [`Clock`] that also reads a monotonic clock, for
+//!   measuring elapsed time.
+//! - [`FixedClock`] / [`MockClock`]: test doubles returning a fixed time
+//!   or a time the test sets and advances explicitly.
+//! - [`ServerClock`]: tracks the offset between the local clock and a remote
+//!   server clock, learned from HTTP `Date` headers.
+//! - [`MonotonicStamp`]: a generator returning the current UTC time while
+//!   guaranteeing strictly increasing values across calls, even when the
+//!   system clock stalls or steps backwards.
+//! - [`estimate_skew`]: the NTP four-timestamp offset/delay calculation, for
+//!   applications that synchronize against a server-provided time.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::clock::MonotonicStamp;
+//!
+//! let stamps = MonotonicStamp::new();
+//! let a = stamps.now();
+//! let b = stamps.now();
+//! assert!(b > a);
+//! ```
+
+use crate::{datetime::DateTime, error::DateTimeError};
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Mutex, PoisonError,
+    },
+    time::Instant,
+};
+use time::{Duration, OffsetDateTime};
+
+/// Process-wide generator backing `DateTime::strictly_monotonic_now`.
+static GLOBAL_STAMP: MonotonicStamp = MonotonicStamp::new();
+
+/// Process-wide server clock backing `ServerClock::global`.
+static GLOBAL_SERVER_CLOCK: ServerClock = ServerClock::new();
+
+/// The origin of `SystemClock`'s monotonic readings, set on first use.
+static MONOTONIC_ORIGIN: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// A source of the current time.
+///
+/// Implementing this trait lets code that needs "now" be written once and
+/// driven by the system clock, a server-synchronized clock, or a test
+/// double.
+pub trait Clock {
+    /// Returns the current time according to this clock.
+    fn now(&self) -> DateTime;
+}
+
+/// The system clock, returning the current UTC time.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime {
+        DateTime::now_utc()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> DateTime {
+        (**self).now()
+    }
+}
+
+/// A [`Clock`] that also reads a monotonic clock.
+///
+/// The wall-clock time from [`Clock::now`] can jump when the system clock
+/// is adjusted; [`TimeSource::monotonic`] never goes backwards, so
+/// differences between two readings measure elapsed time. Functions such
+/// as [`DateTime::new_with_tz_and_clock`] and
+/// [`DateTime::update_with_clock`] take a source instead of reading the
+/// system clock, so code built on them is deterministic under a
+/// [`MockClock`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::{MockClock, TimeSource};
+/// use dtt::datetime::DateTime;
+/// use time::Duration;
+///
+/// let clock = MockClock::new(DateTime::parse("2024-01-01T12:00:00Z").unwrap());
+/// let start = clock.monotonic();
+/// clock.advance(Duration::seconds(5)).unwrap();
+/// assert_eq!(clock.monotonic() - start, Duration::seconds(5));
+/// ```
+///
+/// [`DateTime::new_with_tz_and_clock`]: crate::datetime::DateTime::new_with_tz_and_clock
+/// [`DateTime::update_with_clock`]: crate::datetime::DateTime::update_with_clock
+pub trait TimeSource: Clock {
+    /// Returns the time elapsed since an arbitrary fixed point, which is
+    /// never less than an earlier reading from the same source.
+    fn monotonic(&self) -> Duration;
+}
+
+impl<S: TimeSource + ?Sized> TimeSource for &S {
+    fn monotonic(&self) -> Duration {
+        (**self).monotonic()
+    }
+}
+
+impl TimeSource for SystemClock {
+    /// Reads [`Instant`], measured from the first monotonic reading taken
+    /// in the process.
+    fn monotonic(&self) -> Duration {
+        let origin = *MONOTONIC_ORIGIN
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(Instant::now);
+        Duration::try_from(origin.elapsed()).unwrap_or(Duration::MAX)
+    }
+}
+
+/// A clock that always returns the same time.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::{Clock, FixedClock};
+/// use dtt::datetime::DateTime;
+///
+/// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// let clock = FixedClock::new(noon);
+/// assert_eq!(clock.now(), noon);
+/// assert_eq!(clock.now(), noon);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FixedClock(DateTime);
+
+impl FixedClock {
+    /// Creates a clock stopped at `time`.
+    #[must_use]
+    pub const fn new(time: DateTime) -> Self {
+        Self(time)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime {
+        self.0
+    }
+}
+
+impl TimeSource for FixedClock {
+    /// Always returns zero: no time passes on a fixed clock.
+    fn monotonic(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// A clock whose time is set and advanced explicitly, for tests of
+/// time-dependent logic such as expiry and retry schedules.
+///
+/// The time only changes through [`MockClock::set`] and
+/// [`MockClock::advance`]. Its [monotonic](TimeSource::monotonic) reading
+/// starts at zero and moves forward with `advance` only, as a real
+/// monotonic clock ignores wall-clock adjustments. The clock can be shared
+/// between threads.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::{Clock, MockClock};
+/// use dtt::datetime::DateTime;
+/// use time::Duration;
+///
+/// let start = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// let clock = MockClock::new(start);
+/// clock.advance(Duration::minutes(90)).unwrap();
+/// assert_eq!(clock.now().to_string(), "2024-01-01T13:30:00Z");
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    /// The time returned by [`Clock::now`] and the one returned by
+    /// [`TimeSource::monotonic`].
+    state: Mutex<(DateTime, Duration)>,
+}
+
+impl MockClock {
+    /// Creates a clock reading `start`.
+    #[must_use]
+    pub const fn new(start: DateTime) -> Self {
+        Self {
+            state: Mutex::new((start, Duration::ZERO)),
+        }
+    }
+
+    /// Sets the time returned by later calls to [`Clock::now`]. The
+    /// monotonic reading is unchanged.
+    pub fn set(&self, time: DateTime) {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).0 =
+            time;
+    }
+
+    /// Moves the clock by `duration`, which may be negative, and returns
+    /// the new time. A positive `duration` also advances the monotonic
+    /// reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the new time would fall
+    /// outside the supported date range. The clock is left unchanged in
+    /// that case.
+    pub fn advance(
+        &self,
+        duration: Duration,
+    ) -> Result<DateTime, DateTimeError> {
+        let mut state =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.0 = (state.0 + duration)?;
+        if duration.is_positive() {
+            state.1 = state.1.saturating_add(duration);
+        }
+        Ok(state.0)
+    }
+}
+
+impl Default for MockClock {
+    /// Creates a clock reading the Unix epoch.
+    fn default() -> Self {
+        Self::new(DateTime::from_offset_datetime(
+            OffsetDateTime::UNIX_EPOCH,
+        ))
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).0
+    }
+}
+
+impl TimeSource for MockClock {
+    fn monotonic(&self) -> Duration {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).1
+    }
+}
+
+/// A clock that estimates a remote server's time.
+///
+/// The clock stores the estimated offset between the local system clock and
+/// the server clock. Feed it HTTP `Date` headers (or any other observed
+/// server time) and read the estimated server time with
+/// [`ServerClock::now_server`]. This is useful for clients of APIs that
+/// validate request timestamps strictly against their own clock.
+///
+/// HTTP dates have a one-second resolution, so the estimate is only
+/// accurate to about a second.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::{Clock, ServerClock};
+/// use dtt::datetime::DateTime;
+///
+/// let clock = ServerClock::new();
+/// let local = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// clock
+///     .observe_http_date_at("Mon, 01 Jan 2024 12:00:30 GMT", &local)
+///     .unwrap();
+/// assert_eq!(clock.offset().whole_seconds(), 30);
+///
+/// let server_now = clock.now();
+/// assert!(server_now > DateTime::now_utc());
+/// ```
+#[derive(Debug)]
+pub struct ServerClock {
+    /// Estimated `server - local` offset, in nanoseconds.
+    offset_nanos: AtomicI64,
+}
+
+impl ServerClock {
+    /// Creates a server clock with a zero offset.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset_nanos: AtomicI64::new(0),
+        }
+    }
+
+    /// Returns the process-wide server clock.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_SERVER_CLOCK
+    }
+
+    /// Returns the current estimated `server - local` offset.
+    #[must_use]
+    pub fn offset(&self) -> Duration {
+        Duration::nanoseconds(self.offset_nanos.load(Ordering::Acquire))
+    }
+
+    /// Sets the estimated `server - local` offset directly, e.g. from a
+    /// [`SkewEstimate`].
+    pub fn set_offset(&self, offset: Duration) {
+        let nanos = offset
+            .whole_nanoseconds()
+            .clamp(i128::from(i64::MIN), i128::from(i64::MAX));
+        self.offset_nanos.store(
+            i64::try_from(nanos).unwrap_or_default(),
+            Ordering::Release,
+        );
+    }
+
+    /// Records that the server reported `server_time` when the local clock
+    /// read `local_time`, and returns the new offset.
+    pub fn observe(
+        &self,
+        server_time: &DateTime,
+        local_time: &DateTime,
+    ) -> Duration {
+        let offset = server_time.duration_since(local_time);
+        self.set_offset(offset);
+        offset
+    }
+
+    /// Parses an HTTP `Date` header and records it against the current
+    /// local time, returning the new offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the header is not a valid
+    /// HTTP date. The stored offset is left unchanged in that case.
+    pub fn observe_http_date(
+        &self,
+        header: &str,
+    ) -> Result<Duration, DateTimeError> {
+        self.observe_http_date_with_clock(header, &SystemClock)
+    }
+
+    /// Parses an HTTP `Date` header and records it against the time on the
+    /// local `clock`, returning the new offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the header is not a valid
+    /// HTTP date. The stored offset is left unchanged in that case.
+    pub fn observe_http_date_with_clock<C: Clock + ?Sized>(
+        &self,
+        header: &str,
+        clock: &C,
+    ) -> Result<Duration, DateTimeError> {
+        self.observe_http_date_at(header, &clock.now())
+    }
+
+    /// Parses an HTTP `Date` header and records it against the given local
+    /// time (typically when the response was received), returning the new
+    /// offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the header is not a valid
+    /// HTTP date. The stored offset is left unchanged in that case.
+    pub fn observe_http_date_at(
+        &self,
+        header: &str,
+        local_time: &DateTime,
+    ) -> Result<Duration, DateTimeError> {
+        let server_time = DateTime::parse_http_date(header)?;
+        Ok(self.observe(&server_time, local_time))
+    }
+
+    /// Returns the estimated current server time, in UTC.
+    #[must_use]
+    pub fn now_server(&self) -> DateTime {
+        self.now_server_with_clock(&SystemClock)
+    }
+
+    /// Returns the estimated server time when the local `clock` reads its
+    /// current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::{FixedClock, ServerClock};
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let local = FixedClock::new(
+    ///     DateTime::parse("2015-10-21T07:27:00Z").unwrap(),
+    /// );
+    /// let server = ServerClock::new();
+    /// server
+    ///     .observe_http_date_with_clock("Wed, 21 Oct 2015 07:28:00 GMT", &local)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     server.now_server_with_clock(&local).to_string(),
+    ///     "2015-10-21T07:28:00Z"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn now_server_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> DateTime {
+        let now = clock.now();
+        (now + self.offset()).unwrap_or(now)
+    }
+}
+
+impl Default for ServerClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ServerClock {
+    fn now(&self) -> DateTime {
+        self.now_server()
+    }
+}
+
+/// A generator of strictly increasing UTC timestamps.
+///
+/// Each call to [`MonotonicStamp::now`] returns the current system time,
+/// unless that would not be strictly greater than the previously returned
+/// value (clock ties or regressions), in which case the previous value is
+/// bumped by one nanosecond instead. This makes the generated values
+/// suitable for event ordering and ID generation.
+///
+/// The generator is lock-free and can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::MonotonicStamp;
+///
+/// let stamps = MonotonicStamp::new();
+/// let mut previous = stamps.now();
+/// for _ in 0..1000 {
+///     let next = stamps.now();
+///     assert!(next > previous);
+///     previous = next;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MonotonicStamp {
+    /// Last issued value, in nanoseconds since the Unix epoch.
+    last: AtomicI64,
+}
+
+impl MonotonicStamp {
+    /// Creates a new generator that has not issued any value yet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last: AtomicI64::new(i64::MIN),
+        }
+    }
+
+    /// Returns the process-wide generator.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_STAMP
+    }
+
+    /// Returns the current UTC time, strictly greater than any value
+    /// previously returned by this generator.
+    #[must_use]
+    pub fn now(&self) -> DateTime {
+        self.now_with_clock(&SystemClock)
+    }
+
+    /// Returns the time on `clock` in UTC, strictly greater than any value
+    /// previously returned by this generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::{FixedClock, MonotonicStamp};
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let clock = FixedClock::new(noon);
+    /// let stamps = MonotonicStamp::new();
+    /// assert_eq!(stamps.now_with_clock(&clock), noon);
+    /// assert_eq!(
+    ///     stamps.now_with_clock(&clock).to_string(),
+    ///     "2024-01-01T12:00:00.000000001Z"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn now_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> DateTime {
+        let system = i64::try_from(clock.now().unix_timestamp_nanos())
+            .unwrap_or(i64::MAX);
+
+        let mut last = self.last.load(Ordering::Relaxed);
+        loop {
+            let candidate = if system > last {
+                system
+            } else {
+                last.saturating_add(1)
+            };
+            match self.last.compare_exchange_weak(
+                last,
+                candidate,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return from_unix_nanos(candidate),
+                Err(actual) => last = actual,
+            }
+        }
+    }
+}
+
+impl Default for MonotonicStamp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MonotonicStamp {
+    fn now(&self) -> DateTime {
+        Self::now(self)
+    }
+}
+
+/// Converts nanoseconds since the Unix epoch into a UTC `DateTime`.
+fn from_unix_nanos(nanos: i64) -> DateTime {
+    DateTime::from_offset_datetime(
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(nanos))
+            .unwrap_or_else(|_| OffsetDateTime::now_utc()),
+    )
+}
+
+/// The result of an NTP-style clock skew estimation.
+///
+/// Created by [`estimate_skew`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SkewEstimate {
+    /// Estimated offset of the server clock relative to the local clock.
+    ///
+    /// A positive value means the server clock is ahead of the local clock,
+    /// so `local_now + offset` approximates the server time.
+    pub offset: Duration,
+    /// Round-trip network delay, excluding server processing time.
+    pub round_trip_delay: Duration,
+}
+
+/// Estimates the offset between the local clock and a server clock using
+/// the standard NTP four-timestamp calculation.
+///
+/// # Arguments
+///
+/// * `local_send` - Local time at which the request was sent (T1)
+/// * `server_recv` - Server time at which the request was received (T2)
+/// * `server_send` - Server time at which the response was sent (T3)
+/// * `local_recv` - Local time at which the response was received (T4)
+///
+/// # Returns
+///
+/// A [`SkewEstimate`] where `offset = ((T2 - T1) + (T3 - T4)) / 2` and
+/// `round_trip_delay = (T4 - T1) - (T3 - T2)`. The offset is only accurate
+/// when the network delay is symmetric; its error is bounded by half the
+/// round-trip delay.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::clock::estimate_skew;
+/// use dtt::datetime::DateTime;
+/// use time::Duration;
+///
+/// let t1 = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// let t2 = (t1 + Duration::milliseconds(5050)).unwrap();
+/// let t3 = (t2 + Duration::milliseconds(10)).unwrap();
+/// let t4 = (t1 + Duration::milliseconds(110)).unwrap();
+///
+/// let estimate = estimate_skew(&t1, &t2, &t3, &t4);
+/// assert_eq!(estimate.round_trip_delay, Duration::milliseconds(100));
+/// assert_eq!(estimate.offset, Duration::milliseconds(5000));
+/// ```
+#[must_use]
+pub fn estimate_skew(
+    local_send: &DateTime,
+    server_recv: &DateTime,
+    server_send: &DateTime,
+    local_recv: &DateTime,
+) -> SkewEstimate {
+    let outbound = server_recv.duration_since(local_send);
+    let inbound = server_send.duration_since(local_recv);
+    let total = local_recv.duration_since(local_send);
+    let processing = server_send.duration_since(server_recv);
+
+    SkewEstimate {
+        offset: (outbound + inbound) / 2,
+        round_trip_delay: total - processing,
+    }
+}
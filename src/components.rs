@@ -0,0 +1,176 @@
+// components.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Broken-out views of the components of a `DateTime`.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//!
+//! let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+//! let components = dt.components();
+//! assert_eq!(components.ordinal, 46);
+//! assert_eq!(components.iso_week, 7);
+//! println!("{components}");
+//!
+//! // Destructure the raw fields in one go.
+//! let dtt::components::DateTimeParts { year, month, day, .. } =
+//!     dt.to_components();
+//! assert_eq!((year, month, day), (2024, 2, 15));
+//! ```
+
+use crate::{datetime::DateTime, error::DateTimeError};
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, fmt};
+use time::{UtcOffset, Weekday};
+
+/// Every component of a `DateTime`, broken out for introspection.
+///
+/// Created by [`DateTime::components`]. Serializes to a flat structure and
+/// renders as a two-column table via `Display`.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub struct DateTimeComponents {
+    /// Calendar year.
+    pub year: i32,
+    /// Month of the year (1-12).
+    pub month: u8,
+    /// Day of the month (1-31).
+    pub day: u8,
+    /// Hour of the day (0-23).
+    pub hour: u8,
+    /// Minute of the hour (0-59).
+    pub minute: u8,
+    /// Second of the minute (0-59).
+    pub second: u8,
+    /// Sub-second microseconds (0-999,999).
+    pub microsecond: u32,
+    /// Day of the week.
+    pub weekday: Weekday,
+    /// Day of the year (1-366).
+    pub ordinal: u16,
+    /// ISO 8601 week number (1-53).
+    pub iso_week: u8,
+    /// UTC offset, in seconds.
+    pub offset_seconds: i32,
+    /// Seconds since the Unix epoch.
+    pub unix_timestamp: i64,
+}
+
+impl From<&DateTime> for DateTimeComponents {
+    fn from(dt: &DateTime) -> Self {
+        Self {
+            year: dt.year(),
+            month: dt.month() as u8,
+            day: dt.day(),
+            hour: dt.hour(),
+            minute: dt.minute(),
+            second: dt.second(),
+            microsecond: dt.microsecond(),
+            weekday: dt.weekday(),
+            ordinal: dt.ordinal(),
+            iso_week: dt.iso_week(),
+            offset_seconds: dt.offset().whole_seconds(),
+            unix_timestamp: dt.unix_timestamp(),
+        }
+    }
+}
+
+impl fmt::Display for DateTimeComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<16}{}", "year", self.year)?;
+        writeln!(f, "{:<16}{}", "month", self.month)?;
+        writeln!(f, "{:<16}{}", "day", self.day)?;
+        writeln!(f, "{:<16}{}", "hour", self.hour)?;
+        writeln!(f, "{:<16}{}", "minute", self.minute)?;
+        writeln!(f, "{:<16}{}", "second", self.second)?;
+        writeln!(f, "{:<16}{}", "microsecond", self.microsecond)?;
+        writeln!(f, "{:<16}{}", "weekday", self.weekday)?;
+        writeln!(f, "{:<16}{}", "ordinal", self.ordinal)?;
+        writeln!(f, "{:<16}{}", "iso_week", self.iso_week)?;
+        writeln!(f, "{:<16}{}", "offset_seconds", self.offset_seconds)?;
+        write!(f, "{:<16}{}", "unix_timestamp", self.unix_timestamp)
+    }
+}
+
+/// The raw fields of a `DateTime`, as plain data.
+///
+/// Created by [`DateTime::to_components`] or `From<DateTime>`. Unlike
+/// [`DateTimeComponents`], it holds only the fields needed to rebuild the
+/// value, so it can be destructured and converted back with `TryFrom`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::components::DateTimeParts;
+/// use dtt::datetime::DateTime;
+/// use std::convert::TryFrom;
+///
+/// let dt = DateTime::parse("2024-06-01T09:30:00Z").unwrap();
+/// match DateTimeParts::from(dt) {
+///     DateTimeParts { month: 6..=8, hour, .. } => assert_eq!(hour, 9),
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(DateTime::try_from(dt.to_components()).unwrap(), dt);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DateTimeParts {
+    /// Calendar year.
+    pub year: i32,
+    /// Month of the year (1-12).
+    pub month: u8,
+    /// Day of the month (1-31).
+    pub day: u8,
+    /// Hour of the day (0-23).
+    pub hour: u8,
+    /// Minute of the hour (0-59).
+    pub minute: u8,
+    /// Second of the minute (0-59).
+    pub second: u8,
+    /// Sub-second nanoseconds (0-999,999,999).
+    pub nanos: u32,
+    /// UTC offset of the wall-clock fields.
+    pub offset: UtcOffset,
+}
+
+impl From<&DateTime> for DateTimeParts {
+    fn from(dt: &DateTime) -> Self {
+        Self {
+            year: dt.year(),
+            month: dt.month() as u8,
+            day: dt.day(),
+            hour: dt.hour(),
+            minute: dt.minute(),
+            second: dt.second(),
+            nanos: dt.nanosecond(),
+            offset: dt.offset(),
+        }
+    }
+}
+
+impl From<DateTime> for DateTimeParts {
+    fn from(dt: DateTime) -> Self {
+        Self::from(&dt)
+    }
+}
+
+impl TryFrom<DateTimeParts> for DateTime {
+    type Error = DateTimeError;
+
+    fn try_from(parts: DateTimeParts) -> Result<Self, Self::Error> {
+        Self::from_components_with_nanos(
+            parts.year,
+            parts.month,
+            parts.day,
+            parts.hour,
+            parts.minute,
+            parts.second,
+            parts.nanos,
+            parts.offset,
+        )
+    }
+}
@@ -0,0 +1,546 @@
+// compression.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compact encodings for sequences of `DateTime` values.
+//!
+//! # Overview
+//!
+//! - [`TimestampEncoder`] / [`TimestampDecoder`]: Gorilla-style
+//!   delta-of-delta compression into a bit-packed byte stream. Regularly
+//!   spaced timestamps (metrics scraped every N seconds, for example) cost a
+//!   single bit each.
+//! - [`compress`] / [`decompress`]: one-shot helpers over slices.
+//! - [`encode_deltas`] / [`decode_deltas`]: plain integer deltas, for
+//!   columnar stores that apply their own compression. [`pack_varints`]
+//!   and [`unpack_varints`] turn them into zigzag LEB128 bytes.
+//!
+//! Timestamps are stored as integer ticks since the Unix epoch at a chosen
+//! [`Precision`]. Sub-tick precision is truncated and offsets are not
+//! preserved: decoded values are always in UTC.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::compression::{compress, decompress, Precision};
+//! use dtt::datetime::DateTime;
+//!
+//! let start = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+//! let series: Vec<DateTime> = (0..1_000)
+//!     .map(|i| (start + time::Duration::seconds(i * 10)).unwrap())
+//!     .collect();
+//!
+//! let bytes = compress(&series, Precision::Seconds).unwrap();
+//! assert!(bytes.len() < 150);
+//! assert_eq!(decompress(&bytes).unwrap(), series);
+//! ```
+
+use crate::{datetime::DateTime, error::DateTimeError};
+use time::OffsetDateTime;
+
+/// Resolution at which timestamps are encoded.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Precision {
+    /// Whole seconds.
+    Seconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Nanoseconds.
+    Nanoseconds,
+}
+
+impl Precision {
+    /// Number of nanoseconds in one tick.
+    const fn nanos_per_tick(self) -> i128 {
+        match self {
+            Self::Seconds => 1_000_000_000,
+            Self::Milliseconds => 1_000_000,
+            Self::Microseconds => 1_000,
+            Self::Nanoseconds => 1,
+        }
+    }
+
+    /// Stable single-byte tag used in the stream header.
+    const fn tag(self) -> u8 {
+        match self {
+            Self::Seconds => 0,
+            Self::Milliseconds => 1,
+            Self::Microseconds => 2,
+            Self::Nanoseconds => 3,
+        }
+    }
+
+    /// Parses a stream header tag.
+    const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Seconds),
+            1 => Some(Self::Milliseconds),
+            2 => Some(Self::Microseconds),
+            3 => Some(Self::Nanoseconds),
+            _ => None,
+        }
+    }
+
+    /// Converts a `DateTime` into ticks since the Unix epoch, truncating
+    /// towards negative infinity.
+    fn to_ticks(self, dt: &DateTime) -> Result<i64, DateTimeError> {
+        let nanos = dt.to_offset_datetime().unix_timestamp_nanos();
+        i64::try_from(nanos.div_euclid(self.nanos_per_tick()))
+            .map_err(|_| DateTimeError::InvalidDate)
+    }
+
+    /// Converts ticks since the Unix epoch back into a UTC `DateTime`.
+    fn datetime_from_ticks(
+        self,
+        ticks: i64,
+    ) -> Result<DateTime, DateTimeError> {
+        let nanos = i128::from(ticks) * self.nanos_per_tick();
+        OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .map(DateTime::from_offset_datetime)
+            .map_err(|_| DateTimeError::InvalidDate)
+    }
+}
+
+/// Size of the stream header: precision tag plus a big-endian `u32` count.
+const HEADER_LEN: usize = 5;
+
+/// Delta-of-delta buckets: (prefix bit count, prefix bits, payload width).
+const BUCKETS: [(u32, u64, u32); 5] = [
+    (2, 0b10, 7),
+    (3, 0b110, 9),
+    (4, 0b1110, 12),
+    (5, 0b11110, 32),
+    (5, 0b11111, 64),
+];
+
+/// Streaming Gorilla-style delta-of-delta encoder.
+///
+/// Push timestamps in order with [`TimestampEncoder::push`] and call
+/// [`TimestampEncoder::finish`] to obtain the byte stream. Sorted, regularly
+/// spaced input compresses best, but any order is accepted.
+#[derive(Clone, Debug)]
+pub struct TimestampEncoder {
+    precision: Precision,
+    bits: BitWriter,
+    count: u32,
+    previous: i64,
+    previous_delta: i64,
+}
+
+impl TimestampEncoder {
+    /// Creates an encoder storing timestamps at the given precision.
+    #[must_use]
+    pub const fn new(precision: Precision) -> Self {
+        Self {
+            precision,
+            bits: BitWriter::new(),
+            count: 0,
+            previous: 0,
+            previous_delta: 0,
+        }
+    }
+
+    /// Returns the number of timestamps pushed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.count as usize
+    }
+
+    /// Returns `true` if no timestamp has been pushed yet.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Appends a timestamp to the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the timestamp cannot be
+    /// represented as 64-bit ticks at the chosen precision, or if more than
+    /// `u32::MAX` timestamps are pushed.
+    pub fn push(&mut self, dt: &DateTime) -> Result<(), DateTimeError> {
+        let ticks = self.precision.to_ticks(dt)?;
+        let count = self
+            .count
+            .checked_add(1)
+            .ok_or(DateTimeError::InvalidDate)?;
+
+        if self.count == 0 {
+            self.bits.write(to_bits(ticks), 64);
+            self.previous_delta = 0;
+        } else {
+            let delta = ticks.wrapping_sub(self.previous);
+            let dod = delta.wrapping_sub(self.previous_delta);
+            self.write_dod(dod);
+            self.previous_delta = delta;
+        }
+
+        self.previous = ticks;
+        self.count = count;
+        Ok(())
+    }
+
+    /// Writes a delta-of-delta value using the smallest fitting bucket.
+    fn write_dod(&mut self, dod: i64) {
+        if dod == 0 {
+            self.bits.write(0, 1);
+            return;
+        }
+        for &(prefix_len, prefix, width) in &BUCKETS {
+            if width == 64 || fits_signed(dod, width) {
+                self.bits.write(prefix, prefix_len);
+                self.bits.write(to_bits(dod), width);
+                return;
+            }
+        }
+    }
+
+    /// Consumes the encoder and returns the encoded byte stream.
+    #[must_use]
+    pub fn finish(self) -> Vec<u8> {
+        let mut out =
+            Vec::with_capacity(HEADER_LEN + self.bits.bytes.len());
+        out.push(self.precision.tag());
+        out.extend_from_slice(&self.count.to_be_bytes());
+        out.extend_from_slice(&self.bits.bytes);
+        out
+    }
+}
+
+/// Streaming decoder for byte streams produced by [`TimestampEncoder`].
+///
+/// Yields one `Result<DateTime, DateTimeError>` per encoded timestamp.
+#[derive(Clone, Debug)]
+pub struct TimestampDecoder<'a> {
+    precision: Precision,
+    bits: BitReader<'a>,
+    remaining: u32,
+    started: bool,
+    previous: i64,
+    previous_delta: i64,
+}
+
+impl<'a> TimestampDecoder<'a> {
+    /// Creates a decoder over an encoded byte stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the stream header is
+    /// missing or malformed.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, DateTimeError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let precision = Precision::from_tag(bytes[0])
+            .ok_or(DateTimeError::InvalidFormat)?;
+        let count = u32::from_be_bytes([
+            bytes[1], bytes[2], bytes[3], bytes[4],
+        ]);
+
+        Ok(Self {
+            precision,
+            bits: BitReader::new(&bytes[HEADER_LEN..]),
+            remaining: count,
+            started: false,
+            previous: 0,
+            previous_delta: 0,
+        })
+    }
+
+    /// Returns the precision the stream was encoded with.
+    #[must_use]
+    pub const fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Reads the next tick value from the bit stream.
+    fn next_ticks(&mut self) -> Option<i64> {
+        if !self.started {
+            self.started = true;
+            return self.bits.read(64).map(from_bits);
+        }
+
+        let dod = if self.bits.read(1)? == 0 {
+            0
+        } else {
+            let mut width = None;
+            for &(prefix_len, _, bucket_width) in &BUCKETS {
+                if prefix_len == 5 {
+                    width = Some(if self.bits.read(1)? == 0 {
+                        32
+                    } else {
+                        64
+                    });
+                    break;
+                }
+                if self.bits.read(1)? == 0 {
+                    width = Some(bucket_width);
+                    break;
+                }
+            }
+            let width = width?;
+            sign_extend(self.bits.read(width)?, width)
+        };
+
+        let delta = self.previous_delta.wrapping_add(dod);
+        self.previous_delta = delta;
+        Some(self.previous.wrapping_add(delta))
+    }
+}
+
+impl Iterator for TimestampDecoder<'_> {
+    type Item = Result<DateTime, DateTimeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        if let Some(ticks) = self.next_ticks() {
+            self.previous = ticks;
+            Some(self.precision.datetime_from_ticks(ticks))
+        } else {
+            self.remaining = 0;
+            Some(Err(DateTimeError::InvalidFormat))
+        }
+    }
+}
+
+/// Compresses a sequence of timestamps into a Gorilla-style byte stream.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidDate` if a timestamp cannot be
+/// represented at the chosen precision.
+pub fn compress(
+    timestamps: &[DateTime],
+    precision: Precision,
+) -> Result<Vec<u8>, DateTimeError> {
+    let mut encoder = TimestampEncoder::new(precision);
+    for dt in timestamps {
+        encoder.push(dt)?;
+    }
+    Ok(encoder.finish())
+}
+
+/// Decompresses a byte stream produced by [`compress`] or
+/// [`TimestampEncoder`].
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormat` if the stream is truncated or
+/// malformed.
+pub fn decompress(
+    bytes: &[u8],
+) -> Result<Vec<DateTime>, DateTimeError> {
+    TimestampDecoder::new(bytes)?.collect()
+}
+
+/// Encodes timestamps as ticks at `precision`: the first value is stored
+/// as ticks since the Unix epoch and each later value as the difference
+/// from its predecessor.
+///
+/// Sorted input yields small non-negative deltas, which pack tightly with
+/// [`pack_varints`].
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidDate` if a timestamp or a delta
+/// cannot be represented as 64-bit ticks at the chosen precision.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::compression::{encode_deltas, Precision};
+/// use dtt::datetime::DateTime;
+///
+/// let series = [
+///     DateTime::parse("1970-01-01T00:01:00Z").unwrap(),
+///     DateTime::parse("1970-01-01T00:01:15Z").unwrap(),
+///     DateTime::parse("1970-01-01T00:01:30Z").unwrap(),
+/// ];
+/// let deltas = encode_deltas(&series, Precision::Seconds).unwrap();
+/// assert_eq!(deltas, [60, 15, 15]);
+/// ```
+pub fn encode_deltas(
+    timestamps: &[DateTime],
+    precision: Precision,
+) -> Result<Vec<i64>, DateTimeError> {
+    let mut deltas = Vec::with_capacity(timestamps.len());
+    let mut previous = 0_i64;
+    for dt in timestamps {
+        let ticks = precision.to_ticks(dt)?;
+        deltas.push(
+            ticks
+                .checked_sub(previous)
+                .ok_or(DateTimeError::InvalidDate)?,
+        );
+        previous = ticks;
+    }
+    Ok(deltas)
+}
+
+/// Decodes values produced by [`encode_deltas`] at the same `precision`.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidDate` if the running total overflows
+/// or falls outside the supported date range.
+pub fn decode_deltas(
+    deltas: &[i64],
+    precision: Precision,
+) -> Result<Vec<DateTime>, DateTimeError> {
+    let mut ticks = 0_i64;
+    deltas
+        .iter()
+        .map(|&delta| {
+            ticks = ticks
+                .checked_add(delta)
+                .ok_or(DateTimeError::InvalidDate)?;
+            precision.datetime_from_ticks(ticks)
+        })
+        .collect()
+}
+
+/// Packs integers as zigzag-encoded LEB128 varints, so values close to
+/// zero take a single byte whatever their sign.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::compression::{pack_varints, unpack_varints};
+///
+/// let bytes = pack_varints(&[1_704_067_200, 15, 15, -3]);
+/// assert_eq!(bytes.len(), 8);
+/// assert_eq!(unpack_varints(&bytes).unwrap(), [1_704_067_200, 15, 15, -3]);
+/// ```
+#[must_use]
+pub fn pack_varints(values: &[i64]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(values.len());
+    for &value in values {
+        let mut zigzag = to_bits((value << 1) ^ (value >> 63));
+        while zigzag >= 0x80 {
+            bytes.push(zigzag.to_le_bytes()[0] | 0x80);
+            zigzag >>= 7;
+        }
+        bytes.push(zigzag.to_le_bytes()[0]);
+    }
+    bytes
+}
+
+/// Unpacks integers written by [`pack_varints`].
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormat` if the input ends inside a
+/// value or a value is longer than ten bytes.
+pub fn unpack_varints(bytes: &[u8]) -> Result<Vec<i64>, DateTimeError> {
+    let mut values = Vec::new();
+    let mut zigzag = 0_u64;
+    let mut shift = 0_u32;
+    for &byte in bytes {
+        if shift >= 64 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        zigzag |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            values
+                .push(from_bits(zigzag >> 1) ^ -from_bits(zigzag & 1));
+            zigzag = 0;
+            shift = 0;
+        } else {
+            shift += 7;
+        }
+    }
+    if shift == 0 {
+        Ok(values)
+    } else {
+        Err(DateTimeError::InvalidFormat)
+    }
+}
+
+/// Returns `true` if `value` fits in a two's complement integer of `width`
+/// bits.
+const fn fits_signed(value: i64, width: u32) -> bool {
+    let min = -(1_i64 << (width - 1));
+    let max = (1_i64 << (width - 1)) - 1;
+    value >= min && value <= max
+}
+
+/// Reinterprets the bits of a signed value as unsigned.
+const fn to_bits(value: i64) -> u64 {
+    u64::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Reinterprets the bits of an unsigned value as signed.
+const fn from_bits(value: u64) -> i64 {
+    i64::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Sign-extends the low `width` bits of `value`.
+const fn sign_extend(value: u64, width: u32) -> i64 {
+    let shift = 64 - width;
+    from_bits(value << shift) >> shift
+}
+
+/// Minimal MSB-first bit writer.
+#[derive(Clone, Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u32,
+}
+
+impl BitWriter {
+    const fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            used: 8,
+        }
+    }
+
+    /// Writes the low `width` bits of `value`, most significant first.
+    fn write(&mut self, value: u64, width: u32) {
+        for i in (0..width).rev() {
+            if self.used == 8 {
+                self.bytes.push(0);
+                self.used = 0;
+            }
+            if (value >> i) & 1 == 1 {
+                if let Some(last) = self.bytes.last_mut() {
+                    *last |= 0x80 >> self.used;
+                }
+            }
+            self.used += 1;
+        }
+    }
+}
+
+/// Minimal MSB-first bit reader.
+#[derive(Clone, Debug)]
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Reads `width` bits, most significant first.
+    fn read(&mut self, width: u32) -> Option<u64> {
+        let mut value = 0_u64;
+        for _ in 0..width {
+            let byte = self.bytes.get(self.position / 8)?;
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | u64::from(bit);
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
@@ -0,0 +1,619 @@
+// cron.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Crontab-style schedules and their human-readable descriptions.
+//!
+//! # Overview
+//!
+//! - [`CronSchedule::parse`]: parses a standard five-field cron expression
+//!   (`minute hour day-of-month month day-of-week`), supporting `*`, lists,
+//!   ranges, steps, and three-letter month and weekday names.
+//! - [`CronSchedule::describe`]: renders the schedule as an English
+//!   sentence, such as "At 12:00 on every weekday".
+//! - [`CronSchedule::from_description`]: the reverse, for simple phrases
+//!   such as "every weekday at 12:00" or "every 15 minutes".
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::cron::CronSchedule;
+//!
+//! let schedule = CronSchedule::parse("0 12 * * 1-5").unwrap();
+//! assert_eq!(schedule.describe(), "At 12:00 on every weekday");
+//!
+//! let parsed = CronSchedule::from_description("every weekday at 12:00").unwrap();
+//! assert_eq!(parsed, schedule);
+//! assert_eq!(parsed.to_string(), "0 12 * * 1-5");
+//! ```
+
+use crate::error::DateTimeError;
+use std::{fmt, fmt::Write as _, str::FromStr};
+
+/// English weekday names, indexed from Sunday (cron day 0).
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// English month names, indexed from January (cron month 1).
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The values matched by a single cron field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CronField {
+    /// `*`: every value.
+    Any,
+    /// `*/n`: every n-th value, starting from the field's minimum.
+    Every(u8),
+    /// An explicit set of values, sorted and without duplicates.
+    Values(Vec<u8>),
+}
+
+impl CronField {
+    /// Parses one field, accepting values in `min..=max` or the given
+    /// three-letter names (matched case-insensitively, starting at `min`).
+    fn parse(
+        text: &str,
+        min: u8,
+        max: u8,
+        names: &[&str],
+    ) -> Result<Self, DateTimeError> {
+        if text == "*" {
+            return Ok(Self::Any);
+        }
+        if let Some(step) =
+            text.strip_prefix("*/").filter(|step| !step.contains(','))
+        {
+            return match parse_number(step)? {
+                0 => Err(DateTimeError::InvalidFormat),
+                1 => Ok(Self::Any),
+                step => Ok(Self::Every(step)),
+            };
+        }
+
+        let value = |token: &str| -> Result<u8, DateTimeError> {
+            let value = names
+                .iter()
+                .position(|name| name[..3].eq_ignore_ascii_case(token))
+                .map_or_else(
+                    || parse_number(token),
+                    |index| {
+                        u8::try_from(index)
+                            .map(|index| min + index)
+                            .map_err(|_| DateTimeError::InvalidFormat)
+                    },
+                )?;
+            if (min..=max).contains(&value) {
+                Ok(value)
+            } else {
+                Err(DateTimeError::InvalidFormat)
+            }
+        };
+
+        let mut values = Vec::new();
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, parse_number(step)?),
+                None => (part, 1),
+            };
+            if step == 0 {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                None if range == "*" => (min, max),
+                None if part.contains('/') => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            };
+            if start > end {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            values.extend((start..=end).step_by(usize::from(step)));
+        }
+        values.sort_unstable();
+        values.dedup();
+        Ok(Self::Values(values))
+    }
+
+    /// Returns `true` if the field is exactly the given set of values.
+    fn is(&self, expected: &[u8]) -> bool {
+        matches!(self, Self::Values(values) if values == expected)
+    }
+}
+
+impl fmt::Display for CronField {
+    /// Formats the field in cron syntax, collapsing runs of three or more
+    /// consecutive values into ranges.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "*"),
+            Self::Every(step) => write!(f, "*/{step}"),
+            Self::Values(values) => {
+                let parts: Vec<String> = runs(values)
+                    .iter()
+                    .flat_map(|&(start, end)| {
+                        if end - start >= 2 {
+                            vec![format!("{start}-{end}")]
+                        } else {
+                            (start..=end)
+                                .map(|v| v.to_string())
+                                .collect()
+                        }
+                    })
+                    .collect();
+                write!(f, "{}", parts.join(","))
+            }
+        }
+    }
+}
+
+/// A five-field crontab schedule.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CronSchedule {
+    /// Minutes of the hour (0-59).
+    pub minute: CronField,
+    /// Hours of the day (0-23).
+    pub hour: CronField,
+    /// Days of the month (1-31).
+    pub day_of_month: CronField,
+    /// Months of the year (1-12).
+    pub month: CronField,
+    /// Days of the week (0-6, Sunday is 0).
+    pub day_of_week: CronField,
+}
+
+impl CronSchedule {
+    /// Parses a five-field cron expression.
+    ///
+    /// Day of week accepts both 0 and 7 for Sunday.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the expression does not
+    /// have exactly five fields or any field is malformed or out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::cron::{CronField, CronSchedule};
+    ///
+    /// let schedule = CronSchedule::parse("*/15 9-17 * jan,jul MON").unwrap();
+    /// assert_eq!(schedule.minute, CronField::Every(15));
+    /// assert_eq!(schedule.month, CronField::Values(vec![1, 7]));
+    /// assert_eq!(schedule.day_of_week, CronField::Values(vec![1]));
+    /// ```
+    pub fn parse(expression: &str) -> Result<Self, DateTimeError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let (minute, hour, day_of_month, month, day_of_week) =
+            match fields.as_slice() {
+                [minute, hour, day_of_month, month, day_of_week] => {
+                    (minute, hour, day_of_month, month, day_of_week)
+                }
+                _ => return Err(DateTimeError::InvalidFormat),
+            };
+
+        let day_of_week = match CronField::parse(
+            day_of_week,
+            0,
+            7,
+            &WEEKDAY_NAMES,
+        )? {
+            CronField::Values(values) => {
+                let mut values: Vec<u8> =
+                    values.into_iter().map(|day| day % 7).collect();
+                values.sort_unstable();
+                values.dedup();
+                CronField::Values(values)
+            }
+            field => field,
+        };
+
+        Ok(Self {
+            minute: CronField::parse(minute, 0, 59, &[])?,
+            hour: CronField::parse(hour, 0, 23, &[])?,
+            day_of_month: CronField::parse(day_of_month, 1, 31, &[])?,
+            month: CronField::parse(month, 1, 12, &MONTH_NAMES)?,
+            day_of_week,
+        })
+    }
+
+    /// Builds a schedule from a simple English phrase.
+    ///
+    /// Matching is case-insensitive. Supported phrases are:
+    ///
+    /// - `every minute`, `every N minutes`, `every hour`, `every N hours`
+    /// - `at HH:MM`, optionally followed by `every day`, `on every weekday`,
+    ///   `on weekends`, or `on every <weekday>`
+    /// - `every day at HH:MM`, `daily at HH:MM`, `every weekday at HH:MM`,
+    ///   `every weekend at HH:MM`, and `every <weekday> at HH:MM`
+    ///
+    /// Every sentence produced by [`CronSchedule::describe`] for these
+    /// schedules is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the phrase is not
+    /// recognized or names an invalid time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::cron::CronSchedule;
+    ///
+    /// let schedule = CronSchedule::from_description("Every 15 minutes").unwrap();
+    /// assert_eq!(schedule.to_string(), "*/15 * * * *");
+    ///
+    /// let schedule = CronSchedule::from_description("every Friday at 17:30").unwrap();
+    /// assert_eq!(schedule.to_string(), "30 17 * * 5");
+    /// ```
+    pub fn from_description(
+        phrase: &str,
+    ) -> Result<Self, DateTimeError> {
+        let phrase = phrase.trim().trim_end_matches('.').to_lowercase();
+        let phrase =
+            phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+        let every = |minute, hour| Self {
+            minute,
+            hour,
+            day_of_month: CronField::Any,
+            month: CronField::Any,
+            day_of_week: CronField::Any,
+        };
+
+        match phrase.as_str() {
+            "every minute" => {
+                return Ok(every(CronField::Any, CronField::Any))
+            }
+            "every hour" => {
+                return Ok(every(
+                    CronField::Values(vec![0]),
+                    CronField::Any,
+                ))
+            }
+            _ => {}
+        }
+
+        if let Some(rest) = phrase.strip_prefix("every ") {
+            if let Some((count, unit)) = rest.split_once(' ') {
+                if let Ok(step) = parse_number(count) {
+                    let field = if step == 1 {
+                        CronField::Any
+                    } else {
+                        CronField::Every(step)
+                    };
+                    match unit {
+                        "minutes" if step > 0 && step < 60 => {
+                            return Ok(every(field, CronField::Any));
+                        }
+                        "hours" if step > 0 && step < 24 => {
+                            return Ok(every(
+                                CronField::Values(vec![0]),
+                                field,
+                            ));
+                        }
+                        _ => return Err(DateTimeError::InvalidFormat),
+                    }
+                }
+            }
+        }
+
+        let (time, days) =
+            if let Some(rest) = phrase.strip_prefix("at ") {
+                rest.split_once(' ').unwrap_or((rest, ""))
+            } else {
+                let (days, time) = phrase
+                    .split_once(" at ")
+                    .ok_or(DateTimeError::InvalidFormat)?;
+                (time, days)
+            };
+
+        let (hour, minute) =
+            time.split_once(':').ok_or(DateTimeError::InvalidFormat)?;
+        let (hour, minute) =
+            (parse_number(hour)?, parse_number(minute)?);
+        if hour > 23 || minute > 59 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+
+        Ok(Self {
+            day_of_week: parse_days_phrase(days)?,
+            ..every(
+                CronField::Values(vec![minute]),
+                CronField::Values(vec![hour]),
+            )
+        })
+    }
+
+    /// Describes the schedule as an English sentence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::cron::CronSchedule;
+    ///
+    /// let describe = |expr| CronSchedule::parse(expr).unwrap().describe();
+    /// assert_eq!(describe("30 9 * * *"), "At 09:30 every day");
+    /// assert_eq!(describe("*/5 * * * *"), "Every 5 minutes");
+    /// assert_eq!(describe("0 0 1,15 * *"), "At 00:00 on days 1 and 15 of the month");
+    /// assert_eq!(describe("0 8 * 1-3 sat,sun"), "At 08:00 on weekends in January through March");
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut sentence = self.describe_time();
+        let dom = self.describe_day_of_month();
+        let dow = self.describe_day_of_week();
+
+        match (dom, dow) {
+            (Some(dom), Some(dow)) => {
+                let _ = write!(sentence, " on {dom} or {dow}");
+            }
+            (Some(days), None) | (None, Some(days)) => {
+                let _ = write!(sentence, " on {days}");
+            }
+            (None, None) => {
+                let at_times = matches!(
+                    (&self.minute, &self.hour),
+                    (CronField::Values(minutes), CronField::Values(_))
+                        if minutes.len() == 1
+                );
+                if at_times && self.month == CronField::Any {
+                    sentence.push_str(" every day");
+                }
+            }
+        }
+
+        match &self.month {
+            CronField::Any => {}
+            CronField::Every(step) => {
+                let _ = write!(sentence, " every {step} months");
+            }
+            CronField::Values(months) => {
+                sentence.push_str(" in ");
+                sentence.push_str(&describe_values(months, month_name));
+            }
+        }
+        sentence
+    }
+
+    /// Describes the minute and hour fields.
+    fn describe_time(&self) -> String {
+        match (&self.minute, &self.hour) {
+            (CronField::Values(minutes), CronField::Values(hours))
+                if minutes.len() == 1 =>
+            {
+                let times: Vec<String> = hours
+                    .iter()
+                    .map(|hour| format!("{hour:02}:{:02}", minutes[0]))
+                    .collect();
+                format!("At {}", join_list(&times))
+            }
+            (CronField::Values(minutes), CronField::Any)
+                if minutes == &[0] =>
+            {
+                "Every hour".to_string()
+            }
+            (CronField::Values(minutes), CronField::Every(step))
+                if minutes == &[0] =>
+            {
+                format!("Every {step} hours")
+            }
+            (minute, hour) => {
+                let mut text = match minute {
+                    CronField::Any => "Every minute".to_string(),
+                    CronField::Every(step) => {
+                        format!("Every {step} minutes")
+                    }
+                    CronField::Values(minutes) => format!(
+                        "At {} {}",
+                        plural("minute", minutes.len()),
+                        describe_values(minutes, |m| m.to_string())
+                    ),
+                };
+                match hour {
+                    CronField::Any => {}
+                    CronField::Every(step) => {
+                        let _ = write!(text, " every {step} hours");
+                    }
+                    CronField::Values(hours) => {
+                        let _ = write!(
+                            text,
+                            " past {} {}",
+                            plural("hour", hours.len()),
+                            describe_values(hours, |h| h.to_string())
+                        );
+                    }
+                }
+                text
+            }
+        }
+    }
+
+    /// Describes the day-of-month field, or `None` if it is unrestricted.
+    fn describe_day_of_month(&self) -> Option<String> {
+        match &self.day_of_month {
+            CronField::Any => None,
+            CronField::Every(step) => {
+                Some(format!("every {step} days of the month"))
+            }
+            CronField::Values(days) => Some(format!(
+                "{} {} of the month",
+                plural("day", days.len()),
+                describe_values(days, |d| d.to_string())
+            )),
+        }
+    }
+
+    /// Describes the day-of-week field, or `None` if it is unrestricted.
+    fn describe_day_of_week(&self) -> Option<String> {
+        match &self.day_of_week {
+            CronField::Any => None,
+            CronField::Every(step) => {
+                Some(format!("every {step} days of the week"))
+            }
+            field if field.is(&[1, 2, 3, 4, 5]) => {
+                Some("every weekday".to_string())
+            }
+            field if field.is(&[0, 6]) => Some("weekends".to_string()),
+            CronField::Values(days) if days.len() == 1 => {
+                Some(format!("every {}", weekday_name(days[0])))
+            }
+            CronField::Values(days) => {
+                Some(describe_values(days, weekday_name))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    /// Formats the schedule as a five-field cron expression.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.minute,
+            self.hour,
+            self.day_of_month,
+            self.month,
+            self.day_of_week
+        )
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses an unsigned decimal number of at most three digits.
+fn parse_number(text: &str) -> Result<u8, DateTimeError> {
+    if text.is_empty()
+        || text.len() > 3
+        || !text.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    text.parse().map_err(|_| DateTimeError::InvalidFormat)
+}
+
+/// Parses the day part of a phrase accepted by
+/// [`CronSchedule::from_description`] into a day-of-week field.
+fn parse_days_phrase(days: &str) -> Result<CronField, DateTimeError> {
+    let days = days.strip_prefix("on ").unwrap_or(days);
+    let days = days.strip_prefix("every ").unwrap_or(days);
+    match days {
+        "" | "day" | "daily" => Ok(CronField::Any),
+        "weekday" | "weekdays" => {
+            Ok(CronField::Values(vec![1, 2, 3, 4, 5]))
+        }
+        "weekend" | "weekends" => Ok(CronField::Values(vec![0, 6])),
+        day => {
+            let day = day.strip_suffix('s').unwrap_or(day);
+            WEEKDAY_NAMES
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(day))
+                .and_then(|index| u8::try_from(index).ok())
+                .map(|index| CronField::Values(vec![index]))
+                .ok_or(DateTimeError::InvalidFormat)
+        }
+    }
+}
+
+/// Returns the English name of cron month `month`, or "month N" for a
+/// value outside 1-12.
+fn month_name(month: u8) -> String {
+    month
+        .checked_sub(1)
+        .and_then(|index| MONTH_NAMES.get(usize::from(index)))
+        .map_or_else(
+            || format!("month {month}"),
+            |name| (*name).to_string(),
+        )
+}
+
+/// Returns the English name of cron weekday `day`, or "weekday N" for a
+/// value outside 0-6.
+fn weekday_name(day: u8) -> String {
+    WEEKDAY_NAMES.get(usize::from(day)).map_or_else(
+        || format!("weekday {day}"),
+        |name| (*name).to_string(),
+    )
+}
+
+/// Splits sorted values into runs of consecutive values.
+fn runs(values: &[u8]) -> Vec<(u8, u8)> {
+    let mut runs: Vec<(u8, u8)> = Vec::new();
+    for &value in values {
+        match runs.last_mut() {
+            Some((_, end)) if end.checked_add(1) == Some(value) => {
+                *end = value;
+            }
+            _ => runs.push((value, value)),
+        }
+    }
+    runs
+}
+
+/// Describes sorted values, rendering runs of three or more as
+/// "`first` through `last`".
+fn describe_values(
+    values: &[u8],
+    render: impl Fn(u8) -> String,
+) -> String {
+    let parts: Vec<String> = runs(values)
+        .iter()
+        .flat_map(|&(start, end)| {
+            if end - start >= 2 {
+                vec![format!(
+                    "{} through {}",
+                    render(start),
+                    render(end)
+                )]
+            } else {
+                (start..=end).map(&render).collect()
+            }
+        })
+        .collect();
+    join_list(&parts)
+}
+
+/// Joins items as an English list: "a", "a and b", or "a, b, and c".
+fn join_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [init @ .., last] => format!("{}, and {last}", init.join(", ")),
+    }
+}
+
+/// Returns `word`, pluralized when `count` is not one.
+fn plural(word: &str, count: usize) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        format!("{word}s")
+    }
+}
@@ -45,49 +45,190 @@
     clippy::clone_on_ref_ptr
 )]
 #![warn(clippy::pedantic, clippy::nursery, clippy::cargo)]
-
-use crate::error::DateTimeError;
+#![allow(clippy::manual_let_else)]
+
+use crate::{
+    batch,
+    calendar::{self, CalendarOptions},
+    clock::{Clock, MonotonicStamp, SystemClock},
+    components::{DateTimeComponents, DateTimeParts},
+    delta::DateDelta,
+    diff::DiffReport,
+    error::{
+        ComponentError, ComponentIssues, DateTimeError, InputError,
+        ValidationIssue, ValidationIssueKind,
+    },
+    flexible::{self, FlexibleOptions},
+    format::{
+        self, DisplayAs, DisplayStyle, FormatRegistry, FormatSpec,
+    },
+    holidays::{self, HolidayProvider},
+    limits::{
+        MAX_DAY, MAX_HOUR, MAX_ISO_WEEK, MAX_MICROSECOND, MAX_MINUTE,
+        MAX_MONTH, MAX_OFFSET_SECONDS, MAX_ORDINAL_DAY, MAX_SECOND,
+        MAX_YEAR, MIN_DAY, MIN_ISO_WEEK, MIN_MONTH, MIN_ORDINAL_DAY,
+        MIN_YEAR,
+    },
+    naive::NaiveDateTime,
+    offsets,
+    period::Period,
+    plain,
+    timezone::TimezoneRegistry,
+    validators,
+};
 use serde::{Deserialize, Serialize};
 use std::{
+    cell::RefCell,
     cmp::Ordering,
-    collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
-    ops::{Add, Sub},
+    ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
+    time::Duration as StdDuration,
 };
 use time::{
-    format_description, Date, Duration, Month, OffsetDateTime,
-    PrimitiveDateTime, Time, UtcOffset, Weekday,
+    error::ParseFromDescription,
+    format_description::{self, FormatItem},
+    formatting::Formattable,
+    macros::format_description,
+    parsing::Parsed,
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time,
+    UtcOffset, Weekday,
 };
 
-/// Maximum valid hour value (0-23)
-const MAX_HOUR: u8 = 23;
-
-/// Maximum valid minute/second value (0-59)
-const MAX_MIN_SEC: u8 = 59;
-
-/// Maximum valid day value (1-31)
-const MAX_DAY: u8 = 31;
+/// Seconds in a calendar day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Seconds in an hour.
+const SECONDS_PER_HOUR: i64 = 3_600;
+
+/// Seconds in a minute.
+const SECONDS_PER_MINUTE: i64 = 60;
+
+/// ISO 8601 ordinal date format (`YYYY-DDD`).
+const ORDINAL_DATE_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[ordinal]");
+
+/// Date and time to the second, followed by a zone in
+/// [`DateTime::format_zoned`].
+const ZONED_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day] [hour]:[minute]:[second]"
+);
+
+/// HTTP `Date` header format (IMF-fixdate, RFC 9110).
+const HTTP_DATE_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+);
+
+/// ISO 8601 date and time to the second.
+const ISO8601_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second]"
+);
+
+/// ISO 8601 date and time with a fractional second.
+const ISO8601_SUBSECOND_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"
+);
+
+/// Extended ISO 8601 written by `Display` for values RFC 3339 cannot
+/// express: negative years and offsets with seconds.
+const DISPLAY_EXTENDED_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+);
+
+/// [`DISPLAY_EXTENDED_FORMAT`] with a fractional second.
+const DISPLAY_EXTENDED_SUBSECOND_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:1+][offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+);
+
+thread_local! {
+    /// Scratch space for the formatting hot paths. Once it has grown to
+    /// the length of a timestamp, formatting no longer reallocates it.
+    static FORMAT_BUFFER: RefCell<Vec<u8>> =
+        const { RefCell::new(Vec::new()) };
+}
 
-/// Maximum valid month value (1-12)
-const MAX_MONTH: u8 = 12;
+/// Runs `write` on the thread's format buffer, then copies the result
+/// into `output`, replacing its contents.
+fn write_formatted<F>(
+    output: &mut String,
+    write: F,
+) -> Result<(), DateTimeError>
+where
+    F: FnOnce(&mut Vec<u8>) -> Result<usize, time::error::Format>,
+{
+    with_formatted(write, |text| {
+        output.clear();
+        output.push_str(text);
+        Ok(())
+    })
+}
 
-/// Maximum valid microsecond value (0-999_999)
-const MAX_MICROSECOND: u32 = 999_999;
+/// Runs `write` on the thread's format buffer, then appends the result
+/// to `output`.
+fn append_formatted<W, F>(
+    output: &mut W,
+    write: F,
+) -> Result<(), DateTimeError>
+where
+    W: fmt::Write + ?Sized,
+    F: FnOnce(&mut Vec<u8>) -> Result<usize, time::error::Format>,
+{
+    with_formatted(write, |text| {
+        output
+            .write_str(text)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    })
+}
 
-/// Maximum valid ISO week number (1-53)
-const MAX_ISO_WEEK: u8 = 53;
+/// Runs `write` on the thread's format buffer and hands the text to
+/// `then`.
+///
+/// The buffer is taken out of the thread-local for the duration of the
+/// call, so nested formatting and formatting during thread teardown fall
+/// back to a fresh buffer instead of panicking.
+fn with_formatted<F, T>(write: F, then: T) -> Result<(), DateTimeError>
+where
+    F: FnOnce(&mut Vec<u8>) -> Result<usize, time::error::Format>,
+    T: FnOnce(&str) -> Result<(), DateTimeError>,
+{
+    let mut buffer = FORMAT_BUFFER
+        .try_with(|cell| {
+            cell.try_borrow_mut()
+                .map(|mut buffer| std::mem::take(&mut *buffer))
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    buffer.clear();
+
+    let result = write(&mut buffer)
+        .map_err(|_| DateTimeError::InvalidFormat)
+        .and_then(|_| {
+            std::str::from_utf8(&buffer)
+                .map_err(|_| DateTimeError::InvalidFormat)
+        })
+        .and_then(then);
 
-/// Maximum valid ordinal day (1-366)
-const MAX_ORDINAL_DAY: u16 = 366;
+    let _ = FORMAT_BUFFER.try_with(|cell| {
+        if let Ok(mut slot) = cell.try_borrow_mut() {
+            *slot = buffer;
+        }
+    });
+    result
+}
 
 /// Represents a date and time with timezone offset support.
 ///
-/// This struct combines a UTC datetime with a timezone offset, allowing for
-/// timezone-aware datetime operations. While it supports fixed offsets,
+/// This struct combines a wall-clock datetime with a timezone offset, allowing
+/// for timezone-aware datetime operations. While it supports fixed offsets,
 /// it does **not** automatically handle DST transitions.
 ///
+/// The fields are private so that every value is created through a validating
+/// constructor. Use [`DateTime::from_primitive`] or
+/// [`DateTime::from_offset_datetime`] to build a value from `time` types, and
+/// [`DateTime::to_primitive`] or [`DateTime::to_offset_datetime`] to get them
+/// back.
+///
 /// # Examples
 ///
 /// ```
@@ -99,60 +240,134 @@ const MAX_ORDINAL_DAY: u16 = 366;
 ///     // ...
 /// }
 /// ```
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct DateTime {
-    /// The date and time in UTC (when offset = `UtcOffset::UTC`) or a
-    /// user-chosen offset if `offset != UtcOffset::UTC`.
-    pub datetime: PrimitiveDateTime,
+    /// The wall-clock date and time, always expressed in `offset`.
+    datetime: PrimitiveDateTime,
     /// The timezone offset from UTC.
-    pub offset: UtcOffset,
-}
-
-lazy_static::lazy_static! {
-    /// Static mapping of timezone abbreviations to their `UtcOffset`.
-    ///
-    /// # Note
-    ///
-    /// This is not an exhaustive list of timezones. It is a convenient subset
-    /// for demonstration purposes. Real-world usage might integrate a
-    /// more robust timezone library or database.
-    static ref TIMEZONE_OFFSETS: HashMap<&'static str, Result<UtcOffset, DateTimeError>> = {
-        let mut m = HashMap::new();
-        let _ = m.insert("UTC", Ok(UtcOffset::UTC));
-        let _ = m.insert("GMT", Ok(UtcOffset::UTC));
-
-        // North American time zones
-        let _ = m.insert("EST", UtcOffset::from_hms(-5, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("EDT", UtcOffset::from_hms(-4, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("CST", UtcOffset::from_hms(-6, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("CDT", UtcOffset::from_hms(-5, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("MST", UtcOffset::from_hms(-7, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("MDT", UtcOffset::from_hms(-6, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("PST", UtcOffset::from_hms(-8, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("PDT", UtcOffset::from_hms(-7, 0, 0).map_err(DateTimeError::from));
-
-        // European time zones
-        let _ = m.insert("CET", UtcOffset::from_hms(1, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("CEST", UtcOffset::from_hms(2, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("EET", UtcOffset::from_hms(2, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("EEST", UtcOffset::from_hms(3, 0, 0).map_err(DateTimeError::from));
-
-        // Asian time zones
-        let _ = m.insert("JST", UtcOffset::from_hms(9, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("IST", UtcOffset::from_hms(5, 30, 0).map_err(DateTimeError::from));
-        let _ = m.insert("HKT", UtcOffset::from_hms(8, 0, 0).map_err(DateTimeError::from));
-
-        // Australian time zones
-        let _ = m.insert("AEDT", UtcOffset::from_hms(11, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert("AEST", UtcOffset::from_hms(10, 0, 0).map_err(DateTimeError::from));
-        let _ = m.insert(
-            "WADT",
-            UtcOffset::from_hms(8, 45, 0)
-                .map_err(DateTimeError::from)
-        );
+    offset: UtcOffset,
+    /// The abbreviation the value was created or converted with, if
+    /// any. Operations that keep the offset keep it, and setting
+    /// a new offset clears it. It is presentation only: equality,
+    /// ordering, and hashing ignore it, and it is not serialized.
+    #[serde(skip)]
+    zone: Option<&'static str>,
+}
+
+/// Timezone abbreviations and their offsets, sorted by name so lookups
+/// can use a binary search. Defined with the constants in [`offsets`].
+///
+/// # Note
+///
+/// This is not an exhaustive list of timezones. It is a convenient subset
+/// for demonstration purposes. Real-world usage might integrate a
+/// more robust timezone library or database.
+const TIMEZONE_OFFSETS: &[(&str, UtcOffset)] = offsets::ALL;
+
+/// Resolves a timezone name through the global [`TimezoneRegistry`].
+fn timezone_offset(name: &str) -> Option<UtcOffset> {
+    TimezoneRegistry::global().lookup(name)
+}
+
+/// Returns the registered or built-in abbreviation equal to `name`, as
+/// the `'static` label a `DateTime` carries.
+pub(crate) fn zone_label(name: &str) -> Option<&'static str> {
+    TimezoneRegistry::global().label(name)
+}
+
+/// Returns the built-in abbreviation equal to `name`, as a `'static`
+/// label.
+pub(crate) fn builtin_zone_label(name: &str) -> Option<&'static str> {
+    TIMEZONE_OFFSETS
+        .binary_search_by(|(key, _)| (*key).cmp(name))
+        .ok()
+        .and_then(|index| TIMEZONE_OFFSETS.get(index))
+        .map(|(key, _)| *key)
+}
+
+/// Looks up the offset of a built-in timezone abbreviation, falling back
+/// to a numeric offset such as `UTC+05:30`, `+0530`, or `GMT-7`.
+pub(crate) fn builtin_timezone_offset(name: &str) -> Option<UtcOffset> {
+    TIMEZONE_OFFSETS
+        .binary_search_by(|(key, _)| (*key).cmp(name))
+        .ok()
+        .and_then(|index| TIMEZONE_OFFSETS.get(index))
+        .map(|(_, offset)| *offset)
+        .or_else(|| parse_offset_name(name))
+}
+
+/// Parses an offset written `±HH:MM:SS`, as `Display` does for offsets
+/// with seconds. Any offset `UtcOffset` can hold is accepted.
+fn parse_extended_offset(text: &str) -> Option<UtcOffset> {
+    let (sign, digits) = match text.as_bytes().first()? {
+        b'+' => (1, &text[1..]),
+        b'-' => (-1, &text[1..]),
+        _ => return None,
+    };
+    let fields = digits
+        .split(':')
+        .map(|field| {
+            if field.len() == 2
+                && field.bytes().all(|b| b.is_ascii_digit())
+            {
+                field.parse::<i8>().ok()
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<i8>>>()?;
+    match fields[..] {
+        [hours, minutes, seconds] => UtcOffset::from_hms(
+            sign * hours,
+            sign * minutes,
+            sign * seconds,
+        )
+        .ok(),
+        _ => None,
+    }
+}
 
-        m
+/// Parses a numeric offset: an optional `UTC` or `GMT` prefix, a sign,
+/// and one or two hour digits with optional minutes, written `HH:MM` or
+/// `HHMM`. Offsets beyond ±14:00 are rejected.
+pub(crate) fn parse_offset_name(name: &str) -> Option<UtcOffset> {
+    let rest = match name.get(..3) {
+        Some(prefix)
+            if prefix.eq_ignore_ascii_case("UTC")
+                || prefix.eq_ignore_ascii_case("GMT") =>
+        {
+            &name[3..]
+        }
+        _ => name,
     };
+    let (sign, digits) = match rest.as_bytes().first()? {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => {
+            (hours, minutes)
+        }
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, "00"),
+        Some(_) => return None,
+    };
+    let all_digits =
+        |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if !(1..=2).contains(&hours.len())
+        || !all_digits(hours)
+        || !all_digits(minutes)
+    {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    let seconds = sign * (hours * 3_600 + minutes * 60);
+    if minutes >= 60 || seconds.abs() > MAX_OFFSET_SECONDS {
+        return None;
+    }
+    UtcOffset::from_whole_seconds(seconds).ok()
 }
 
 // -----------------------------------------------------------------------------
@@ -205,6 +420,9 @@ pub struct DateTimeBuilder {
     minute: u8,
     /// Second of the minute (0-59).
     second: u8,
+    /// Nanosecond of the second (0-999,999,999).
+    #[serde(default)]
+    nanosecond: u32,
     /// The time zone offset from UTC.
     offset: UtcOffset,
 }
@@ -218,6 +436,7 @@ impl Default for DateTimeBuilder {
             hour: 0,
             minute: 0,
             second: 0,
+            nanosecond: 0,
             offset: UtcOffset::UTC,
         }
     }
@@ -235,6 +454,7 @@ impl DateTimeBuilder {
             hour: 0,
             minute: 0,
             second: 0,
+            nanosecond: 0,
             offset: UtcOffset::UTC,
         }
     }
@@ -281,6 +501,13 @@ impl DateTimeBuilder {
         self
     }
 
+    /// Sets the nanosecond component.
+    #[must_use]
+    pub const fn nanosecond(mut self, nanosecond: u32) -> Self {
+        self.nanosecond = nanosecond;
+        self
+    }
+
     /// Sets the time zone offset component.
     #[must_use]
     pub const fn offset(mut self, offset: UtcOffset) -> Self {
@@ -292,16 +519,29 @@ impl DateTimeBuilder {
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if any of the date components are invalid
-    /// (e.g., `month = 13` or `day = 32`).
+    /// Returns a `DateTimeError::InvalidComponents` listing every
+    /// out-of-range component (e.g., `month = 13`, `day = 32`, and
+    /// `nanosecond = 1_000_000_000` together).
     pub fn build(&self) -> Result<DateTime, DateTimeError> {
-        DateTime::from_components(
+        if let Some(issues) = ComponentIssues::check(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        ) {
+            return Err(DateTimeError::InvalidComponents(issues));
+        }
+        DateTime::from_components_with_nanos(
             self.year,
             self.month,
             self.day,
             self.hour,
             self.minute,
             self.second,
+            self.nanosecond,
             self.offset,
         )
     }
@@ -318,6 +558,9 @@ impl DateTime {
 
     /// Creates a new `DateTime` instance representing the current UTC time.
     ///
+    /// This is equivalent to [`DateTime::now_utc`], which states the
+    /// intent more explicitly.
+    ///
     /// # Examples
     ///
     /// ```
@@ -327,19 +570,117 @@ impl DateTime {
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        // Directly obtain the current UTC time.
-        let now = OffsetDateTime::now_utc();
-        Self {
-            datetime: PrimitiveDateTime::new(now.date(), now.time()),
-            offset: UtcOffset::UTC,
-        }
+        Self::now_utc()
+    }
+
+    /// Creates a new `DateTime` instance representing the current time in
+    /// UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
+    ///
+    /// let now = DateTime::now_utc();
+    /// assert_eq!(now.offset(), UtcOffset::UTC);
+    /// ```
+    #[must_use]
+    pub fn now_utc() -> Self {
+        Self::from_offset_datetime(OffsetDateTime::now_utc())
+    }
+
+    /// Creates a new `DateTime` instance representing the current time as
+    /// wall-clock time in the given fixed offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
+    ///
+    /// let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+    /// let tokyo = DateTime::now_fixed(offset);
+    /// assert_eq!(tokyo.offset(), offset);
+    /// ```
+    #[must_use]
+    pub fn now_fixed(offset: UtcOffset) -> Self {
+        Self::now_fixed_with_clock(offset, &SystemClock)
+    }
+
+    /// Returns the time on `clock` as wall-clock time in the given fixed
+    /// offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+    /// let tokyo = DateTime::now_fixed_with_clock(offset, &FixedClock::new(noon));
+    /// assert_eq!(tokyo.to_string(), "2024-01-01T21:00:00+09:00");
+    /// ```
+    #[must_use]
+    pub fn now_fixed_with_clock<C: Clock + ?Sized>(
+        offset: UtcOffset,
+        clock: &C,
+    ) -> Self {
+        clock.now().saturating_convert_to_offset(offset)
+    }
+
+    /// Returns the current UTC time, guaranteed to be strictly greater than
+    /// any value previously returned by this function in the same process.
+    ///
+    /// On clock ties or regressions the previous value is bumped by one
+    /// nanosecond. See [`MonotonicStamp`] for a per-instance generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let a = DateTime::strictly_monotonic_now();
+    /// let b = DateTime::strictly_monotonic_now();
+    /// assert!(b > a);
+    /// ```
+    #[must_use]
+    pub fn strictly_monotonic_now() -> Self {
+        MonotonicStamp::global().now()
+    }
+
+    /// Returns the current time according to `clock`.
+    ///
+    /// Code that takes a [`Clock`] instead of calling [`DateTime::now_utc`]
+    /// directly can be tested deterministically with a [`FixedClock`] or
+    /// [`MockClock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let clock = FixedClock::new(noon);
+    /// assert_eq!(DateTime::now_with_clock(&clock), noon);
+    /// ```
+    ///
+    /// [`FixedClock`]: crate::clock::FixedClock
+    /// [`MockClock`]: crate::clock::MockClock
+    #[must_use]
+    pub fn now_with_clock<C: Clock + ?Sized>(clock: &C) -> Self {
+        clock.now()
     }
 
     /// Creates a new `DateTime` instance with the current time in the specified timezone.
     ///
     /// # Arguments
     ///
-    /// * `tz` - A timezone abbreviation (e.g., "UTC", "EST", "PST")
+    /// * `tz` - A timezone abbreviation (e.g., "UTC", "EST", "PST") or a
+    ///   numeric offset (e.g., "UTC+05:30", "+0530", "GMT-7")
     ///
     /// # Returns
     ///
@@ -359,32 +700,58 @@ impl DateTime {
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the timezone is invalid.
+    /// Returns a `DateTimeError` if the timezone is invalid. Use
+    /// [`suggest_timezone`] to find the closest supported abbreviation.
     ///
     pub fn new_with_tz(tz: &str) -> Result<Self, DateTimeError> {
-        let offset = TIMEZONE_OFFSETS
-            .get(tz)
-            .ok_or(DateTimeError::InvalidTimezone)?
-            .as_ref()
-            .map_err(Clone::clone)?;
-
-        let now_utc = OffsetDateTime::now_utc();
-        let now_local = now_utc.to_offset(*offset);
+        Self::new_with_tz_and_clock(tz, &SystemClock)
+    }
 
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                now_local.date(),
-                now_local.time(),
-            ),
-            offset: *offset,
-        })
+    /// Creates a `DateTime` with the current time according to `clock`,
+    /// in the specified timezone.
+    ///
+    /// This is [`DateTime::new_with_tz`] with the time read from a
+    /// [`Clock`] or [`TimeSource`] instead of the system clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let clock = FixedClock::new(noon);
+    /// let tokyo = DateTime::new_with_tz_and_clock("JST", &clock).unwrap();
+    /// assert_eq!(tokyo.to_string(), "2024-01-01T21:00:00+09:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the timezone is
+    /// invalid.
+    ///
+    /// [`TimeSource`]: crate::clock::TimeSource
+    pub fn new_with_tz_and_clock<C: Clock + ?Sized>(
+        tz: &str,
+        clock: &C,
+    ) -> Result<Self, DateTimeError> {
+        let offset = timezone_offset(tz)
+            .ok_or(DateTimeError::InvalidTimezone)?;
+        clock
+            .now()
+            .try_convert_to_offset(offset)
+            .map(|dt| dt.with_zone_label(zone_label(tz)))
     }
 
     /// Creates a new `DateTime` instance with a custom UTC offset.
     ///
+    /// Offsets beyond ±14:00 never correspond to a real timezone and are
+    /// rejected; use [`DateTime::new_with_custom_offset_lenient`] to accept
+    /// them anyway.
+    ///
     /// # Arguments
     ///
-    /// * `hours` - Hour offset from UTC (-23 to +23)
+    /// * `hours` - Hour offset from UTC (-14 to +14)
     /// * `minutes` - Minute offset from UTC (-59 to +59)
     ///
     /// # Returns
@@ -406,11 +773,77 @@ impl DateTime {
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the timezone is invalid.
+    /// Returns a `DateTimeError::InvalidTimezone` if the offset is invalid
+    /// or outside the ±14:00 range.
     ///
     pub fn new_with_custom_offset(
         hours: i8,
         minutes: i8,
+    ) -> Result<Self, DateTimeError> {
+        let dt = Self::new_with_custom_offset_lenient(hours, minutes)?;
+        let _ = validate_offset(dt.offset)?;
+        Ok(dt)
+    }
+
+    /// Creates a new `DateTime` instance with a custom UTC offset of up to
+    /// ±23:59, without the ±14:00 real-world range check.
+    ///
+    /// # Arguments
+    ///
+    /// * `hours` - Hour offset from UTC (-23 to +23)
+    /// * `minutes` - Minute offset from UTC (-59 to +59)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// assert!(DateTime::new_with_custom_offset(20, 0).is_err());
+    /// let dt = DateTime::new_with_custom_offset_lenient(20, 0).unwrap();
+    /// assert_eq!(dt.offset().whole_hours(), 20);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the offset is invalid.
+    ///
+    pub fn new_with_custom_offset_lenient(
+        hours: i8,
+        minutes: i8,
+    ) -> Result<Self, DateTimeError> {
+        Self::new_with_custom_offset_lenient_and_clock(
+            hours,
+            minutes,
+            &SystemClock,
+        )
+    }
+
+    /// Like [`DateTime::new_with_custom_offset_lenient`], but reads the
+    /// current time from `clock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let clock = FixedClock::new(noon);
+    /// let dt =
+    ///     DateTime::new_with_custom_offset_lenient_and_clock(20, 0, &clock)
+    ///         .unwrap();
+    /// assert_eq!(dt.to_string(), "2024-01-02T08:00:00+20:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the offset is invalid.
+    pub fn new_with_custom_offset_lenient_and_clock<
+        C: Clock + ?Sized,
+    >(
+        hours: i8,
+        minutes: i8,
+        clock: &C,
     ) -> Result<Self, DateTimeError> {
         // Direct numeric checks (no casts needed)
         if hours.abs() > 23 || minutes.abs() > 59 {
@@ -420,16 +853,7 @@ impl DateTime {
         let offset = UtcOffset::from_hms(hours, minutes, 0)
             .map_err(|_| DateTimeError::InvalidTimezone)?;
 
-        let now_utc = OffsetDateTime::now_utc();
-        let now_local = now_utc.to_offset(offset);
-
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                now_local.date(),
-                now_local.time(),
-            ),
-            offset,
-        })
+        clock.now().try_convert_to_offset(offset)
     }
 
     /// Returns a new `DateTime` which is exactly one day earlier.
@@ -533,92 +957,274 @@ impl DateTime {
                 new_time,
             ),
             offset: self.offset,
+            zone: self.zone,
         })
     }
 
-    /// Subtracts a specified number of years from the `DateTime`.
-    ///
-    /// Handles leap year transitions appropriately (e.g., if subtracting a year from
-    /// Feb 29 results in Feb 28).
+    /// Sets the fractional second while preserving every other component.
     ///
     /// # Arguments
     ///
-    /// * `years` - Number of years to subtract
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the resulting date would be invalid.
+    /// * `nanosecond` - Nanosecond (0-999,999,999)
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::new();
-    /// let maybe_past = dt.sub_years(1);
-    /// assert!(maybe_past.is_ok());
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let dt = dt.set_nanosecond(500_000_000).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-01-01T12:00:00.5Z");
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the resulting date would be invalid.
+    /// Returns a `DateTimeError::InvalidTime` if `nanosecond` is out of
+    /// range.
     ///
-    pub fn sub_years(&self, years: i32) -> Result<Self, DateTimeError> {
-        self.add_years(-years)
+    pub fn set_nanosecond(
+        &self,
+        nanosecond: u32,
+    ) -> Result<Self, DateTimeError> {
+        let datetime = self
+            .datetime
+            .replace_nanosecond(nanosecond)
+            .map_err(|_| DateTimeError::InvalidTime)?;
+        Ok(Self {
+            datetime,
+            offset: self.offset,
+            zone: self.zone,
+        })
     }
 
-    /// Converts this `DateTime` to another timezone, then formats it
-    /// using the provided `format_str`.
+    /// Sets the time of day from components that may exceed their usual
+    /// range or be negative, carrying the excess into the date.
     ///
-    /// # Arguments
+    /// The components are combined into a number of seconds from midnight
+    /// of the current date, so 25:00:00 is 01:00:00 on the next day and
+    /// -1:00:00 is 23:00:00 on the previous one. The fractional second is
+    /// cleared, as with [`DateTime::set_time`]. The returned [`Carried`]
+    /// records how many days the date moved.
     ///
-    /// * `tz` - Target timezone abbreviation (e.g., "UTC", "EST", "PST").
-    /// * `format_str` - A format description (see the `time` crate documentation
-    ///   for the supported syntax).
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    /// Returns a `Result<String, DateTimeError>` containing either
-    /// the formatted datetime string or an error if conversion or
-    /// formatting fails.
+    /// let dt = DateTime::parse("2024-02-28T08:00:00Z").unwrap();
+    /// let carried = dt.set_time_carrying(49, 30, 0).unwrap();
+    /// assert_eq!(carried.datetime.to_string(), "2024-03-01T01:30:00Z");
+    /// assert_eq!(carried.days, 2);
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function will return a [`DateTimeError`] if:
-    /// - The specified timezone is not recognized or invalid.
-    /// - The formatting operation fails due to an invalid `format_str`.
+    /// Returns a `DateTimeError::InvalidDate` if the resulting date is out
+    /// of range.
+    ///
+    pub fn set_time_carrying(
+        &self,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<Carried, DateTimeError> {
+        let midnight = Self {
+            datetime: PrimitiveDateTime::new(
+                self.datetime.date(),
+                Time::MIDNIGHT,
+            ),
+            offset: self.offset,
+            zone: self.zone,
+        };
+        let seconds = hour
+            .checked_mul(SECONDS_PER_HOUR)
+            .zip(minute.checked_mul(SECONDS_PER_MINUTE))
+            .and_then(|(hours, minutes)| hours.checked_add(minutes))
+            .and_then(|seconds| seconds.checked_add(second))
+            .ok_or(DateTimeError::InvalidDate)?;
+        midnight.add_seconds(seconds)
+    }
+
+    /// Adds hours, reporting how many days the local date moved.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::new();
-    /// let result = dt.format_time_in_timezone("EST", "[hour]:[minute]:[second]");
-    /// if let Ok(formatted_str) = result {
-    ///     println!("Time in EST: {}", formatted_str);
-    /// }
+    /// let dt = DateTime::parse("2024-12-31T22:00:00+01:00").unwrap();
+    /// let carried = dt.add_hours(3).unwrap();
+    /// assert_eq!(
+    ///     carried.datetime.to_string(),
+    ///     "2025-01-01T01:00:00+01:00"
+    /// );
+    /// assert_eq!(carried.days, 1);
     /// ```
-    pub fn format_time_in_timezone(
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is out of
+    /// range.
+    ///
+    pub fn add_hours(
         &self,
-        tz: &str,
-        format_str: &str,
-    ) -> Result<String, DateTimeError> {
-        // 1. Convert this DateTime to the specified timezone
-        let dt_tz = self.convert_to_tz(tz)?;
-
-        // 2. Format the timezone-adjusted DateTime using the provided format string
-        dt_tz.format(format_str)
+        hours: i64,
+    ) -> Result<Carried, DateTimeError> {
+        hours
+            .checked_mul(SECONDS_PER_HOUR)
+            .ok_or(DateTimeError::InvalidDate)
+            .and_then(|seconds| self.add_seconds(seconds))
     }
 
-    /// Returns `true` if the input string is a valid ISO 8601 or RFC 3339–like datetime/date.
+    /// Adds minutes, reporting how many days the local date moved.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `input` - A string that might represent a date or datetime in ISO 8601/RFC 3339 format.
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    /// # Returns
+    /// let dt = DateTime::parse("2024-03-01T00:10:00Z").unwrap();
+    /// let carried = dt.add_minutes(-20).unwrap();
+    /// assert_eq!(carried.datetime.to_string(), "2024-02-29T23:50:00Z");
+    /// assert_eq!(carried.days, -1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is out of
+    /// range.
+    ///
+    pub fn add_minutes(
+        &self,
+        minutes: i64,
+    ) -> Result<Carried, DateTimeError> {
+        minutes
+            .checked_mul(SECONDS_PER_MINUTE)
+            .ok_or(DateTimeError::InvalidDate)
+            .and_then(|seconds| self.add_seconds(seconds))
+    }
+
+    /// Adds seconds, reporting how many days the local date moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let carried = dt.add_seconds(3_600).unwrap();
+    /// assert_eq!(carried.datetime.hour(), 13);
+    /// assert_eq!(carried.days, 0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is out of
+    /// range.
+    ///
+    pub fn add_seconds(
+        &self,
+        seconds: i64,
+    ) -> Result<Carried, DateTimeError> {
+        let datetime = self
+            .datetime
+            .checked_add(Duration::seconds(seconds))
+            .ok_or(DateTimeError::InvalidDate)?;
+        let days = i64::from(datetime.date().to_julian_day())
+            - i64::from(self.datetime.date().to_julian_day());
+        Ok(Carried {
+            datetime: Self {
+                datetime,
+                offset: self.offset,
+                zone: self.zone,
+            },
+            days,
+        })
+    }
+
+    /// Subtracts a specified number of years from the `DateTime`.
+    ///
+    /// Handles leap year transitions appropriately (e.g., if subtracting a year from
+    /// Feb 29 results in Feb 28).
+    ///
+    /// # Arguments
+    ///
+    /// * `years` - Number of years to subtract
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the resulting date would be invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let maybe_past = dt.sub_years(1);
+    /// assert!(maybe_past.is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the resulting date would be invalid.
+    ///
+    pub fn sub_years(&self, years: i32) -> Result<Self, DateTimeError> {
+        self.add_years(-years)
+    }
+
+    /// Converts this `DateTime` to another timezone, then formats it
+    /// using the provided `format_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tz` - Target timezone abbreviation (e.g., "UTC", "EST", "PST").
+    /// * `format_str` - A format description (see the `time` crate documentation
+    ///   for the supported syntax).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<String, DateTimeError>` containing either
+    /// the formatted datetime string or an error if conversion or
+    /// formatting fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`DateTimeError`] if:
+    /// - The specified timezone is not recognized or invalid.
+    /// - The formatting operation fails due to an invalid `format_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let result = dt.format_time_in_timezone("EST", "[hour]:[minute]:[second]");
+    /// if let Ok(formatted_str) = result {
+    ///     println!("Time in EST: {}", formatted_str);
+    /// }
+    /// ```
+    pub fn format_time_in_timezone(
+        &self,
+        tz: &str,
+        format_str: &str,
+    ) -> Result<String, DateTimeError> {
+        // 1. Convert this DateTime to the specified timezone
+        let dt_tz = self.convert_to_tz(tz)?;
+
+        // 2. Format the timezone-adjusted DateTime using the provided format string
+        dt_tz.format(format_str)
+    }
+
+    /// Returns `true` if the input string is a valid ISO 8601 or RFC 3339–like datetime/date.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A string that might represent a date or datetime in ISO 8601/RFC 3339 format.
+    ///
+    /// # Returns
     ///
     /// `true` if the string can be successfully parsed as either:
     ///   - RFC 3339 datetime (e.g., "2024-01-01T12:00:00Z"), or
@@ -711,6 +1317,135 @@ impl DateTime {
         Ok(Self {
             datetime: PrimitiveDateTime::new(date, time),
             offset,
+            zone: None,
+        })
+    }
+
+    /// Creates a `DateTime` from individual components, including the
+    /// fractional second as a number of nanoseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Calendar year
+    /// * `month` - Month (1-12)
+    /// * `day` - Day of month (1-31)
+    /// * `hour` - Hour (0-23)
+    /// * `minute` - Minute (0-59)
+    /// * `second` - Second (0-59)
+    /// * `nanosecond` - Nanosecond (0-999,999,999)
+    /// * `offset` - UTC offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
+    ///
+    /// let dt = DateTime::from_components_with_nanos(
+    ///     2024, 1, 1, 12, 0, 0, 123_456_789, UtcOffset::UTC,
+    /// ).unwrap();
+    /// assert_eq!(dt.microsecond(), 123_456);
+    /// assert_eq!(dt.to_string(), "2024-01-01T12:00:00.123456789Z");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if any component is invalid.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_components_with_nanos(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        Self::from_components(
+            year, month, day, hour, minute, second, offset,
+        )?
+        .set_nanosecond(nanosecond)
+    }
+
+    /// Creates a `DateTime` at midnight UTC from an ISO 8601 week date.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - ISO week-numbering year (may differ from the calendar year
+    ///   for dates in late December or early January)
+    /// * `week` - ISO week number (1-53, depending on the year)
+    /// * `weekday` - Day of the week
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::{Month, Weekday};
+    ///
+    /// // 2020-W01-1 is Monday, 30 December 2019.
+    /// let dt = DateTime::from_iso_week_date(2020, 1, Weekday::Monday).unwrap();
+    /// assert_eq!(dt.year(), 2019);
+    /// assert_eq!(dt.month(), Month::December);
+    /// assert_eq!(dt.day(), 30);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the week does not exist in
+    /// the given ISO year.
+    ///
+    pub fn from_iso_week_date(
+        year: i32,
+        week: u8,
+        weekday: Weekday,
+    ) -> Result<Self, DateTimeError> {
+        let date = Date::from_iso_week_date(year, week, weekday)
+            .map_err(|_| DateTimeError::InvalidDate)?;
+
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(date, Time::MIDNIGHT),
+            offset: UtcOffset::UTC,
+            zone: None,
+        })
+    }
+
+    /// Creates a `DateTime` at midnight UTC from a year and an ordinal day
+    /// (day of the year).
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Calendar year
+    /// * `ordinal` - Day of the year (1-365, or 1-366 in leap years)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Month;
+    ///
+    /// let dt = DateTime::from_ordinal_date(2024, 366).unwrap();
+    /// assert_eq!(dt.month(), Month::December);
+    /// assert_eq!(dt.day(), 31);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the ordinal day does not
+    /// exist in the given year.
+    ///
+    pub fn from_ordinal_date(
+        year: i32,
+        ordinal: u16,
+    ) -> Result<Self, DateTimeError> {
+        let date = Date::from_ordinal_date(year, ordinal)
+            .map_err(|_| DateTimeError::InvalidDate)?;
+
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(date, Time::MIDNIGHT),
+            offset: UtcOffset::UTC,
+            zone: None,
         })
     }
 
@@ -760,12 +1495,37 @@ impl DateTime {
         self.datetime.microsecond()
     }
 
+    /// Returns the nanosecond component of the `DateTime`.
+    #[must_use]
+    pub const fn nanosecond(&self) -> u32 {
+        self.datetime.nanosecond()
+    }
+
     /// Returns the ISO week component of the `DateTime`.
     #[must_use]
     pub const fn iso_week(&self) -> u8 {
         self.datetime.iso_week()
     }
 
+    /// Returns the ISO 8601 week date as `(iso_year, week, weekday)`.
+    ///
+    /// The ISO year may differ from [`DateTime::year`] for dates in late
+    /// December or early January.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Weekday;
+    ///
+    /// let dt = DateTime::parse("2021-01-01").unwrap();
+    /// assert_eq!(dt.to_iso_week_date(), (2020, 53, Weekday::Friday));
+    /// ```
+    #[must_use]
+    pub const fn to_iso_week_date(&self) -> (i32, u8, Weekday) {
+        self.datetime.date().to_iso_week_date()
+    }
+
     /// Returns the ordinal day (day of year) component of the `DateTime`.
     #[must_use]
     pub const fn ordinal(&self) -> u16 {
@@ -778,473 +1538,857 @@ impl DateTime {
         self.offset
     }
 
-    /// Returns the weekday of the `DateTime`.
-    #[must_use]
-    pub const fn weekday(&self) -> Weekday {
-        self.datetime.date().weekday()
-    }
-
-    // -------------------------------------------------------------------------
-    // Parsing Methods
-    // -------------------------------------------------------------------------
-
-    /// Parses a string representation of a date and time.
+    /// Returns the timezone abbreviation the `DateTime` was created or
+    /// converted with, such as `"EST"`, or `None` if it only has a
+    /// numeric offset.
     ///
-    /// Supports both RFC 3339 and ISO 8601 formats.
+    /// [`DateTime::new_with_tz`] and [`DateTime::convert_to_tz`] record
+    /// the abbreviation when given a built-in or registered one. Arithmetic
+    /// and field changes keep it; anything that sets a new offset, such as
+    /// [`DateTime::try_convert_to_offset`], drops it. It is not part of
+    /// equality and is not serialized.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `input` - A string slice containing the date/time to parse
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    /// # Returns
+    /// let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// assert_eq!(utc.zone_abbreviation(), None);
     ///
-    /// Returns a `Result` containing either the parsed `DateTime` or a `DateTimeError`
-    /// if parsing fails.
+    /// let tokyo = utc.convert_to_tz("JST").unwrap();
+    /// assert_eq!(tokyo.zone_abbreviation(), Some("JST"));
+    /// assert_eq!(tokyo.add_days(1).unwrap().zone_abbreviation(), Some("JST"));
+    /// assert_eq!(utc.convert_to_tz("+09:00").unwrap().zone_abbreviation(), None);
+    /// ```
+    #[must_use]
+    pub const fn zone_abbreviation(&self) -> Option<&'static str> {
+        self.zone
+    }
+
+    /// Returns the value with `zone` as its abbreviation.
+    pub(crate) const fn with_zone_label(
+        mut self,
+        zone: Option<&'static str>,
+    ) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Returns the offset as `+HH:MM`, e.g. `+05:30` or `-08:00`, rather
+    /// than the `+05:30:00` of `UtcOffset`'s `Display`. Seconds are added
+    /// as `+HH:MM:SS` only when the offset has them. UTC is `+00:00`.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// // Parse RFC 3339 format
-    /// let dt1 = DateTime::parse("2024-01-01T12:00:00Z");
+    /// let dt = DateTime::parse("2024-01-01T09:00:00+05:30").unwrap();
+    /// assert_eq!(dt.offset_string(), "+05:30");
+    /// assert_eq!(dt.offset().to_string(), "+05:30:00");
     ///
-    /// // Parse ISO 8601 date
-    /// let dt2 = DateTime::parse("2024-01-01");
-    /// assert!(dt1.is_ok());
-    /// assert!(dt2.is_ok());
+    /// let dt = DateTime::parse("2024-01-01T09:00:00-00:30").unwrap();
+    /// assert_eq!(dt.offset_string(), "-00:30");
     /// ```
+    #[must_use]
+    pub fn offset_string(&self) -> String {
+        let (hours, minutes, seconds) = self.offset.as_hms();
+        let sign = if self.offset.is_negative() { '-' } else { '+' };
+        let (hours, minutes, seconds) = (
+            hours.unsigned_abs(),
+            minutes.unsigned_abs(),
+            seconds.unsigned_abs(),
+        );
+        if seconds == 0 {
+            format!("{sign}{hours:02}:{minutes:02}")
+        } else {
+            format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+        }
+    }
+
+    /// Returns the offset from UTC in whole seconds, negative west of
+    /// Greenwich.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns a `DateTimeError` if the input string is not a valid date/time.
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    pub fn parse(input: &str) -> Result<Self, DateTimeError> {
-        // Try RFC 3339 format first
-        if let Ok(dt) = PrimitiveDateTime::parse(
-            input,
-            &format_description::well_known::Rfc3339,
-        ) {
-            return Ok(Self {
-                datetime: dt,
-                offset: UtcOffset::UTC,
-            });
-        }
+    /// let dt = DateTime::parse("2024-01-01T09:00:00-03:30").unwrap();
+    /// assert_eq!(dt.offset_seconds(), -12_600);
+    /// assert_eq!(dt.offset_minutes(), -210);
+    /// ```
+    #[must_use]
+    pub const fn offset_seconds(&self) -> i32 {
+        self.offset.whole_seconds()
+    }
 
-        // Fall back to ISO 8601 date format
-        if let Ok(date) = Date::parse(
-            input,
-            &format_description::well_known::Iso8601::DATE,
-        ) {
-            return Ok(Self {
-                datetime: PrimitiveDateTime::new(date, Time::MIDNIGHT),
-                offset: UtcOffset::UTC,
-            });
-        }
+    /// Returns the offset from UTC in whole minutes, truncated towards
+    /// zero, negative west of Greenwich.
+    #[must_use]
+    pub const fn offset_minutes(&self) -> i16 {
+        self.offset.whole_minutes()
+    }
 
-        Err(DateTimeError::InvalidFormat)
+    /// Returns the weekday of the `DateTime`.
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.datetime.date().weekday()
     }
 
-    /// Parses a date/time string using a custom format specification.
-    ///
-    /// # Arguments
-    ///
-    /// * `input` - The date/time string to parse
-    /// * `format` - Format specification string (see `time` crate documentation)
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the parsed `DateTime` or a `DateTimeError`
-    /// if parsing fails.
+    /// Returns the calendar quarter of the `DateTime` (1-4).
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::parse_custom_format(
-    ///     "2024-01-01 12:00:00",
-    ///     "[year]-[month]-[day] [hour]:[minute]:[second]"
-    /// );
-    /// assert!(dt.is_ok());
+    /// let dt = DateTime::parse("2024-05-16T12:00:00Z").unwrap();
+    /// assert_eq!(dt.quarter(), 2);
     /// ```
+    #[must_use]
+    pub const fn quarter(&self) -> u8 {
+        (self.datetime.month() as u8 - 1) / 3 + 1
+    }
+
+    /// Returns every component of the `DateTime` broken out into a single
+    /// struct, suitable for serialization or table-style display.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns a `DateTimeError` if the input string is not a valid date/time.
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    pub fn parse_custom_format(
-        input: &str,
-        format: &str,
-    ) -> Result<Self, DateTimeError> {
-        let format_desc = format_description::parse(format)
-            .map_err(|_| DateTimeError::InvalidFormat)?;
-        let datetime = PrimitiveDateTime::parse(input, &format_desc)
-            .map_err(|_| DateTimeError::InvalidFormat)?;
-
-        Ok(Self {
-            datetime,
-            offset: UtcOffset::UTC,
-        })
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let components = dt.components();
+    /// assert_eq!(components.year, 2024);
+    /// assert_eq!(components.unix_timestamp, 1_704_110_400);
+    /// ```
+    #[must_use]
+    pub fn components(&self) -> DateTimeComponents {
+        DateTimeComponents::from(self)
     }
 
-    // -------------------------------------------------------------------------
-    // Formatting Methods
-    // -------------------------------------------------------------------------
-
-    /// Formats the `DateTime` according to the specified format string.
+    /// Returns the raw fields of the `DateTime` as a plain struct.
     ///
-    /// # Arguments
+    /// Convenient for destructuring or matching on several fields at once
+    /// instead of calling each accessor.
     ///
-    /// * `format_str` - Format specification string (see `time` crate documentation)
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use dtt::components::DateTimeParts;
+    /// use dtt::datetime::DateTime;
     ///
-    /// Returns a `Result` containing either the formatted string or a `DateTimeError`
-    /// if formatting fails.
+    /// let dt = DateTime::parse("2024-01-01T12:00:00+05:30").unwrap();
+    /// let DateTimeParts { hour, offset, .. } = dt.to_components();
+    /// assert_eq!(hour, 12);
+    /// assert_eq!(offset.whole_seconds(), 19_800);
+    /// ```
+    #[must_use]
+    pub fn to_components(&self) -> DateTimeParts {
+        DateTimeParts::from(self)
+    }
+
+    /// Returns the wall-clock date, discarding the time and offset.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::new();
-    /// let formatted = dt.format("[year]-[month]-[day]");
-    /// assert!(formatted.is_ok());
+    /// let dt = DateTime::parse("2024-01-15T23:30:00-05:00").unwrap();
+    /// assert_eq!(dt.date_only().to_string(), "2024-01-15");
     /// ```
+    #[must_use]
+    pub const fn date_only(&self) -> plain::Date {
+        plain::Date::new(self.datetime.date())
+    }
+
+    /// Returns the wall-clock time of day, discarding the date and offset.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns a `DateTimeError` if the format string is invalid.
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    pub fn format(
-        &self,
-        format_str: &str,
-    ) -> Result<String, DateTimeError> {
-        let format_desc = format_description::parse(format_str)
-            .map_err(|_| DateTimeError::InvalidFormat)?;
-        self.datetime
-            .format(&format_desc)
-            .map_err(|_| DateTimeError::InvalidFormat)
+    /// let dt = DateTime::parse("2024-01-15T23:30:00-05:00").unwrap();
+    /// assert_eq!(dt.time_only().to_string(), "23:30:00");
+    /// ```
+    #[must_use]
+    pub const fn time_only(&self) -> plain::Time {
+        plain::Time::new(self.datetime.time())
     }
 
-    /// Formats the `DateTime` as an RFC 3339 string.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the formatted RFC 3339 string
-    /// or a `DateTimeError` if formatting fails.
+    // -------------------------------------------------------------------------
+    // Conversion Methods
+    // -------------------------------------------------------------------------
+
+    /// Creates a `DateTime` from a wall-clock `PrimitiveDateTime` expressed
+    /// in the given offset.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use time::macros::datetime;
+    /// use time::UtcOffset;
     ///
-    /// let dt = DateTime::new();
-    /// let maybe_rfc3339 = dt.format_rfc3339();
-    /// assert!(maybe_rfc3339.is_ok());
+    /// let dt = DateTime::from_primitive(datetime!(2024-01-01 12:00), UtcOffset::UTC);
+    /// assert_eq!(dt.to_string(), "2024-01-01T12:00:00Z");
     /// ```
+    #[must_use]
+    pub const fn from_primitive(
+        datetime: PrimitiveDateTime,
+        offset: UtcOffset,
+    ) -> Self {
+        Self {
+            datetime,
+            offset,
+            zone: None,
+        }
+    }
+
+    /// Creates a `DateTime` from an `OffsetDateTime`, keeping its offset.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns a `DateTimeError` if formatting fails.
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::macros::datetime;
     ///
-    pub fn format_rfc3339(&self) -> Result<String, DateTimeError> {
+    /// let dt = DateTime::from_offset_datetime(datetime!(2024-01-01 12:00 +1));
+    /// assert_eq!(dt.hour(), 12);
+    /// assert_eq!(dt.offset().whole_hours(), 1);
+    /// ```
+    #[must_use]
+    pub const fn from_offset_datetime(
+        datetime: OffsetDateTime,
+    ) -> Self {
+        Self {
+            datetime: PrimitiveDateTime::new(
+                datetime.date(),
+                datetime.time(),
+            ),
+            offset: datetime.offset(),
+            zone: None,
+        }
+    }
+
+    /// Returns the wall-clock date and time, without the offset.
+    #[must_use]
+    pub const fn to_primitive(&self) -> PrimitiveDateTime {
         self.datetime
-            .assume_offset(self.offset)
-            .format(&format_description::well_known::Rfc3339)
-            .map_err(|_| DateTimeError::InvalidFormat)
     }
 
-    /// Formats the `DateTime` as an ISO 8601 string (YYYY-MM-DDTHH:MM:SS).
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the formatted ISO 8601 string
-    /// or a `DateTimeError` if formatting fails.
+    /// Returns the wall-clock date and time in this value's offset, without
+    /// the offset.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::new();
-    /// let maybe_iso8601 = dt.format_iso8601();
-    /// assert!(maybe_iso8601.is_ok());
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let jst = dt.convert_to_tz("JST").unwrap();
+    /// assert_eq!(jst.to_naive().to_string(), "2024-01-01T21:00:00");
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns a `DateTimeError` if formatting fails.
-    ///
-    pub fn format_iso8601(&self) -> Result<String, DateTimeError> {
-        self.format("[year]-[month]-[day]T[hour]:[minute]:[second]")
+    #[must_use]
+    pub const fn to_naive(&self) -> NaiveDateTime {
+        NaiveDateTime::new(self.datetime)
     }
 
-    /// Updates the `DateTime` to the current time while preserving the timezone offset.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the updated `DateTime` or a `DateTimeError`
-    /// if the update fails.
+    /// Returns the wall-clock date and time in UTC, without the offset.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
-    /// use std::thread::sleep;
-    /// use std::time::Duration;
     ///
-    /// let dt = DateTime::new();
-    /// sleep(Duration::from_secs(1));
-    /// let updated_dt = dt.update();
-    /// assert!(updated_dt.is_ok());
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let jst = dt.convert_to_tz("JST").unwrap();
+    /// assert_eq!(jst.to_naive_utc().unwrap().to_string(), "2024-01-01T12:00:00");
+    ///
+    /// let last = DateTime::parse("9999-12-31T23:00:00-05:00").unwrap();
+    /// assert!(last.to_naive_utc().is_err());
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the update fails.
-    ///
-    pub fn update(&self) -> Result<Self, DateTimeError> {
-        let now = OffsetDateTime::now_utc().to_offset(self.offset);
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(now.date(), now.time()),
-            offset: self.offset,
-        })
+    /// Returns a `DateTimeError::ConversionOverflow` if the time in UTC
+    /// is out of range.
+    pub fn to_naive_utc(&self) -> Result<NaiveDateTime, DateTimeError> {
+        self.to_utc().map(|utc| utc.to_naive())
+    }
+
+    /// Returns the date and time as an `OffsetDateTime`.
+    #[must_use]
+    pub const fn to_offset_datetime(&self) -> OffsetDateTime {
+        self.datetime.assume_offset(self.offset)
     }
 
     // -------------------------------------------------------------------------
-    // Timezone Conversion Method
+    // Parsing Methods
     // -------------------------------------------------------------------------
 
-    /// Converts the current `DateTime` to another timezone.
+    /// Parses a string representation of a date and time.
+    ///
+    /// Supports both RFC 3339 and ISO 8601 formats. The offset in an
+    /// RFC 3339 input is preserved: the result keeps the local wall-clock
+    /// time and offset of the input rather than being converted to UTC.
+    /// Offsets beyond ±14:00 are rejected. Date-only inputs are read as
+    /// midnight UTC.
     ///
     /// # Arguments
     ///
-    /// * `new_tz` - Target timezone abbreviation (e.g., "UTC", "EST", "PST")
+    /// * `input` - A string slice containing the date/time to parse
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing either the `DateTime` in the new timezone
-    /// or a `DateTimeError` if the conversion fails.
+    /// Returns a `Result` containing either the parsed `DateTime` or a `DateTimeError`
+    /// if parsing fails.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let utc = DateTime::new();
-    /// let maybe_est = utc.convert_to_tz("EST");
-    /// assert!(maybe_est.is_ok());
+    /// // Parse RFC 3339 format
+    /// let dt1 = DateTime::parse("2024-01-01T12:00:00Z");
+    ///
+    /// // Parse ISO 8601 date
+    /// let dt2 = DateTime::parse("2024-01-01");
+    /// assert!(dt1.is_ok());
+    /// assert!(dt2.is_ok());
+    ///
+    /// // Offsets are preserved
+    /// let ist = DateTime::parse("2024-01-01T12:00:00+05:30").unwrap();
+    /// assert_eq!(ist.hour(), 12);
+    /// assert_eq!(ist.offset().whole_minutes(), 330);
+    /// assert_eq!(ist.to_string(), "2024-01-01T12:00:00+05:30");
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the timezone is invalid.
+    /// Returns a `DateTimeError` if the input string is not a valid date/time.
     ///
-    pub fn convert_to_tz(
-        &self,
-        new_tz: &str,
-    ) -> Result<Self, DateTimeError> {
-        let new_offset = TIMEZONE_OFFSETS
-            .get(new_tz)
-            .ok_or(DateTimeError::InvalidTimezone)?
-            .as_ref()
-            .map_err(Clone::clone)?;
-
-        let datetime_with_offset =
-            self.datetime.assume_offset(self.offset);
-        let new_datetime = datetime_with_offset.to_offset(*new_offset);
-
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                new_datetime.date(),
-                new_datetime.time(),
-            ),
-            offset: *new_offset,
-        })
+    pub fn parse(input: &str) -> Result<Self, DateTimeError> {
+        Self::parse_with_options(input, &ParseOptions::new())
+            .map(|outcome| outcome.datetime)
     }
 
-    // -------------------------------------------------------------------------
-    // Additional Utilities
-    // -------------------------------------------------------------------------
-
-    /// Gets the Unix timestamp (seconds since Unix epoch).
+    /// Parses any text written by `Display`, keeping its offset, so
+    /// `DateTime::parse_display(&dt.to_string())` always returns `dt`.
     ///
-    /// # Returns
+    /// Accepts everything [`DateTime::parse`] does, offsets beyond ±14:00
+    /// that [`DateTime::from_components`] can build, and the extended
+    /// ISO 8601 that `Display` falls back to for negative years and
+    /// offsets with seconds. `FromStr` uses this function.
     ///
-    /// Returns the number of seconds from the Unix epoch (1970-01-01T00:00:00Z).
+    /// # Errors
+    ///
+    /// Returns the error from [`DateTime::parse`] if neither form matches.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
     ///
-    /// let dt = DateTime::new();
-    /// let ts = dt.unix_timestamp();
+    /// let lmt = UtcOffset::from_hms(0, 9, 21).unwrap();
+    /// let dt = DateTime::parse("1900-01-01T12:00:00Z")
+    ///     .unwrap()
+    ///     .try_convert_to_offset(lmt)
+    ///     .unwrap();
+    /// assert_eq!(dt.to_string(), "1900-01-01T12:09:21+00:09:21");
+    /// assert_eq!(DateTime::parse_display(&dt.to_string()), Ok(dt));
     /// ```
-    #[must_use]
-    pub const fn unix_timestamp(&self) -> i64 {
-        self.datetime.assume_offset(self.offset).unix_timestamp()
+    pub fn parse_display(input: &str) -> Result<Self, DateTimeError> {
+        Self::parse_with_options(
+            input,
+            &ParseOptions::new().allow_extreme_offsets(true),
+        )
+        .map(|outcome| outcome.datetime)
+        .or_else(|error| {
+            // `time` only parses offset hours up to 23, so the offset is
+            // split off and read separately.
+            let split = input
+                .len()
+                .checked_sub("+00:00:00".len())
+                .filter(|&index| input.is_char_boundary(index))
+                .ok_or(error)?;
+            let (local, offset) = input.split_at(split);
+            let offset = parse_extended_offset(offset).ok_or(error)?;
+            [ISO8601_FORMAT, ISO8601_SUBSECOND_FORMAT]
+                .iter()
+                .find_map(|description| {
+                    PrimitiveDateTime::parse(local, description).ok()
+                })
+                .map(|datetime| Self {
+                    datetime,
+                    offset,
+                    zone: None,
+                })
+                .ok_or(error)
+        })
     }
 
-    /// Calculates the duration between this `DateTime` and another.
+    /// Parses like [`DateTime::parse`], but on failure returns an
+    /// [`InputError`] carrying the input and, when it can be found, the
+    /// byte position and name of the first offending component.
     ///
-    /// The result can be negative if `other` is later than `self`.
+    /// # Examples
     ///
-    /// # Arguments
+    /// ```
+    /// use dtt::datetime::DateTime;
     ///
-    /// * `other` - The `DateTime` to compare with
+    /// let error = DateTime::parse_detailed("2024-13-01").unwrap_err();
+    /// assert_eq!(error.position(), Some(5));
+    /// assert_eq!(error.expected(), Some("month"));
+    /// ```
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a `Duration` representing the time difference.
+    /// Returns an `InputError` wrapping the error from
+    /// [`DateTime::parse`].
+    pub fn parse_detailed(input: &str) -> Result<Self, InputError> {
+        Self::parse(input).map_err(|error| {
+            let detailed = error.with_input(input);
+            if detailed.position().is_some() {
+                return detailed;
+            }
+            match Self::validate(input) {
+                Err(issues) => match issues.first() {
+                    Some(issue) => detailed
+                        .located(issue.position, issue.component),
+                    None => detailed,
+                },
+                Ok(()) => detailed,
+            }
+        })
+    }
+
+    /// Parses a human-written date such as `01/02/2024`, `Jan 2 2024`, or
+    /// `next monday`.
+    ///
+    /// This is a convenience wrapper around
+    /// [`flexible::parse_flexible`](crate::flexible::parse_flexible); see
+    /// the [`flexible`](crate::flexible) module for the accepted formats.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use dtt::flexible::{DateOrder, FlexibleOptions};
     ///
-    /// let dt1 = DateTime::new();
-    /// let dt2 = dt1.add_days(1).unwrap_or(dt1);
-    /// let duration = dt1.duration_since(&dt2);
-    /// // duration could be negative if dt2 > dt1
+    /// let options = FlexibleOptions::new().order(DateOrder::DayFirst);
+    /// let dt = DateTime::parse_flexible("15.03.24", &options).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-03-15T00:00:00Z");
     /// ```
-    #[must_use]
-    pub fn duration_since(&self, other: &Self) -> Duration {
-        let self_offset = self.datetime.assume_offset(self.offset);
-        let other_offset = other.datetime.assume_offset(other.offset);
-
-        let seconds_diff = self_offset.unix_timestamp()
-            - other_offset.unix_timestamp();
-        let nanos_diff = i64::from(self_offset.nanosecond())
-            - i64::from(other_offset.nanosecond());
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the input is not a
+    /// recognized format, or a `DateTimeError::InvalidDate` if it names a
+    /// date that does not exist.
+    ///
+    pub fn parse_flexible(
+        input: &str,
+        options: &FlexibleOptions,
+    ) -> Result<Self, DateTimeError> {
+        flexible::parse_flexible(input, options)
+    }
 
-        Duration::seconds(seconds_diff)
-            + Duration::nanoseconds(nanos_diff)
-    }
+    /// Parses an input that contains no leap second.
+    fn parse_without_leap_second(
+        input: &str,
+        options: ParseOptions,
+    ) -> Result<Self, DateTimeError> {
+        // Try RFC 3339 format first, keeping the offset from the input
+        if let Ok(dt) = OffsetDateTime::parse(
+            input,
+            &format_description::well_known::Rfc3339,
+        ) {
+            debug_event!(branch = "rfc3339", "parsed");
+            if !options.allow_extreme_offsets {
+                let _ = validate_offset(dt.offset())?;
+            }
+            return Ok(Self::from_offset_datetime(dt));
+        }
 
-    // -------------------------------------------------------------------------
-    // Date Arithmetic Methods
-    // -------------------------------------------------------------------------
+        // Fall back to ISO 8601 date format
+        if let Ok(date) = Date::parse(
+            input,
+            &format_description::well_known::Iso8601::DATE,
+        ) {
+            debug_event!(branch = "iso8601_date", "parsed");
+            return Ok(Self {
+                datetime: PrimitiveDateTime::new(date, Time::MIDNIGHT),
+                offset: UtcOffset::UTC,
+                zone: None,
+            });
+        }
 
-    /// Adds a specified number of days to the `DateTime`.
+        // Fall back to ISO 8601 ordinal date format
+        if let Ok(dt) = Self::parse_ordinal_date(input) {
+            debug_event!(branch = "ordinal_date", "parsed");
+            return Ok(dt);
+        }
+
+        debug_event!("no parser branch matched");
+        Err(DateTimeError::InvalidFormat)
+    }
+
+    /// Parses a date/time string like [`DateTime::parse`], with lenient
+    /// behaviour controlled by `options`.
+    ///
+    /// Leap-second timestamps (`23:59:60` in UTC) are rejected by `parse`.
+    /// With [`LeapSecondPolicy::Clamp`] they are read as `23:59:59.999999999`;
+    /// with [`LeapSecondPolicy::Carry`] they roll over into the first second
+    /// of the next day. The returned [`ParseOutcome`] records whether a
+    /// leap second was encountered.
     ///
     /// # Arguments
     ///
-    /// * `days` - Number of days to add (can be negative for subtraction)
+    /// * `input` - The string to parse
+    /// * `options` - Leniency options
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the operation would result in an invalid date.
+    /// ```
+    /// use dtt::datetime::{DateTime, LeapSecondPolicy, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().leap_seconds(LeapSecondPolicy::Carry);
+    /// let outcome =
+    ///     DateTime::parse_with_options("2016-12-31T23:59:60Z", &options).unwrap();
+    /// assert!(outcome.leap_second);
+    /// assert_eq!(outcome.datetime.to_string(), "2017-01-01T00:00:00Z");
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function returns a [`DateTimeError::InvalidDate`] if adding `days` results
-    /// in a date overflow or otherwise invalid date.
+    /// Returns a `DateTimeError::InvalidTime` if the input contains a leap
+    /// second and the policy is [`LeapSecondPolicy::Reject`], or if the
+    /// leap second does not fall on the last minute of a UTC day. Returns
+    /// a `DateTimeError::InvalidTimezone` if the input's offset is beyond
+    /// ±14:00 and [`ParseOptions::allow_extreme_offsets`] is not set. Any
+    /// other error from [`DateTime::parse`] is returned unchanged.
+    ///
+    pub fn parse_with_options(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<ParseOutcome, DateTimeError> {
+        instrument!("dtt::parse", input_len = input.len());
+        let result = Self::parse_outcome(input, *options);
+        trace_failure!(result, "parse");
+        result
+    }
+
+    /// Parses `input`, reading a leap second as `options` ask.
+    fn parse_outcome(
+        input: &str,
+        options: ParseOptions,
+    ) -> Result<ParseOutcome, DateTimeError> {
+        let index = match leap_second_index(input) {
+            Some(index) => index,
+            None => {
+                return Self::parse_without_leap_second(input, options)
+                    .map(|datetime| ParseOutcome {
+                        datetime,
+                        leap_second: false,
+                    })
+            }
+        };
+        debug_event!(index, "leap second found");
+        if options.leap_seconds == LeapSecondPolicy::Reject {
+            return Err(DateTimeError::InvalidTime);
+        }
+
+        let mut adjusted = input.to_string();
+        adjusted.replace_range(index..index + 2, "59");
+        let datetime =
+            Self::parse_without_leap_second(&adjusted, options)?;
+
+        let utc = OffsetDateTime::parse(
+            &adjusted,
+            &format_description::well_known::Rfc3339,
+        )
+        .map_err(|_| DateTimeError::InvalidFormat)?
+        .checked_to_offset(UtcOffset::UTC)
+        .ok_or(DateTimeError::InvalidTime)?;
+        if (utc.hour(), utc.minute()) != (23, 59) {
+            return Err(DateTimeError::InvalidTime);
+        }
+
+        let datetime =
+            if options.leap_seconds == LeapSecondPolicy::Carry {
+                (datetime + Duration::SECOND)?
+            } else {
+                datetime.set_nanosecond(999_999_999)?
+            };
+        Ok(ParseOutcome {
+            datetime,
+            leap_second: true,
+        })
+    }
+
+    /// Parses an ISO 8601 ordinal date (`YYYY-DDD`, e.g. `2024-046`) into a
+    /// `DateTime` at midnight UTC.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use time::Month;
     ///
-    /// let dt = DateTime::new();
-    /// let future = dt.add_days(7);
-    /// assert!(future.is_ok());
+    /// let dt = DateTime::parse_ordinal_date("2024-046").unwrap();
+    /// assert_eq!(dt.month(), Month::February);
+    /// assert_eq!(dt.day(), 15);
     /// ```
-    pub fn add_days(&self, days: i64) -> Result<Self, DateTimeError> {
-        let new_datetime = self
-            .datetime
-            .checked_add(Duration::days(days))
-            .ok_or(DateTimeError::InvalidDate)?;
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the input is not an
+    /// ordinal date, or if the day does not exist in the given year.
+    ///
+    pub fn parse_ordinal_date(
+        input: &str,
+    ) -> Result<Self, DateTimeError> {
+        let date = Date::parse(input, ORDINAL_DATE_FORMAT)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
 
         Ok(Self {
-            datetime: new_datetime,
-            offset: self.offset,
+            datetime: PrimitiveDateTime::new(date, Time::MIDNIGHT),
+            offset: UtcOffset::UTC,
+            zone: None,
         })
     }
 
-    /// Adds a specified number of months to the `DateTime`.
+    /// Parses an HTTP `Date` header value (IMF-fixdate, e.g.
+    /// `Sun, 06 Nov 1994 08:49:37 GMT`) into a UTC `DateTime`.
     ///
-    /// Handles month-end dates and leap years appropriately.
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+    /// assert_eq!(dt.unix_timestamp(), 784_111_777);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the input is not a valid
+    /// HTTP date or its UTC time is out of range.
+    ///
+    pub fn parse_http_date(input: &str) -> Result<Self, DateTimeError> {
+        OffsetDateTime::parse(
+            input.trim(),
+            &format_description::well_known::Rfc2822,
+        )
+        .ok()
+        .and_then(|parsed| parsed.checked_to_offset(UtcOffset::UTC))
+        .map(Self::from_offset_datetime)
+        .ok_or(DateTimeError::InvalidFormat)
+    }
+
+    /// Parses a date/time string using a custom format specification.
     ///
     /// # Arguments
     ///
-    /// * `months` - Number of months to add (can be negative for subtraction)
+    /// * `input` - The date/time string to parse
+    /// * `format` - Format specification string (see `time` crate documentation)
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the operation would result in an invalid date.
+    /// Returns a `Result` containing either the parsed `DateTime` or a `DateTimeError`
+    /// if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse_custom_format(
+    ///     "2024-01-01 12:00:00",
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second]"
+    /// );
+    /// assert!(dt.is_ok());
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function returns a [`DateTimeError`] if:
-    /// - The calculated year, month, or day is invalid (e.g., out of range).
-    /// - The underlying date library fails to construct a valid date.
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or the input does not describe a valid date/time, or a
+    /// `DateTimeError::InvalidFormatAt` locating the first part of the
+    /// input that does not match the format.
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::DateTimeError;
+    ///
+    /// let result = DateTime::parse_custom_format(
+    ///     "2024-1x-01 12:00:00",
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second]",
+    /// );
+    /// assert_eq!(
+    ///     result,
+    ///     Err(DateTimeError::InvalidFormatAt { index: 5, expected: "month" })
+    /// );
+    /// ```
+    ///
+    pub fn parse_custom_format(
+        input: &str,
+        format: &str,
+    ) -> Result<Self, DateTimeError> {
+        instrument!(
+            "dtt::parse_custom_format",
+            input_len = input.len(),
+            format_len = format.len()
+        );
+        let result = format::cached_spec(format)
+            .and_then(|spec| Self::parse_spec(input, &spec));
+        trace_failure!(result, "parse_custom_format");
+        result
+    }
+
+    /// Parses `input` with a pre-compiled [`FormatSpec`], as
+    /// [`parse_custom_format`](Self::parse_custom_format) does with a
+    /// format string. The result is in UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormatAt` naming the first
+    /// component that does not match, or a `DateTimeError::InvalidFormat`
+    /// if the values themselves are invalid.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use dtt::format::FormatSpec;
     ///
-    /// let dt = DateTime::new();
-    /// let future = dt.add_months(3);
-    /// assert!(future.is_ok());
+    /// let spec = FormatSpec::new("[year][month][day]T[hour][minute]").unwrap();
+    /// for input in ["20240101T0900", "20240229T1730"] {
+    ///     assert!(DateTime::parse_with_spec(input, &spec).is_ok());
+    /// }
+    /// assert!(DateTime::parse_with_spec("20240230T0900", &spec).is_err());
     /// ```
-    pub fn add_months(
-        &self,
-        months: i32,
+    pub fn parse_with_spec(
+        input: &str,
+        spec: &FormatSpec,
     ) -> Result<Self, DateTimeError> {
-        let current_date = self.datetime.date();
-        let total_months =
-            current_date.year() * 12 + current_date.month() as i32 - 1
-                + months;
+        instrument!(
+            "dtt::parse_with_spec",
+            input_len = input.len(),
+            format = spec.as_str()
+        );
+        let result = Self::parse_spec(input, spec);
+        trace_failure!(result, "parse_with_spec");
+        result
+    }
 
-        let target_year = total_months / 12;
-        let target_month = u8::try_from((total_months % 12) + 1);
+    /// Parses `input` with `spec`, locating the mismatch on failure.
+    fn parse_spec(
+        input: &str,
+        spec: &FormatSpec,
+    ) -> Result<Self, DateTimeError> {
+        PrimitiveDateTime::parse(input, spec.items())
+            .map(|datetime| Self {
+                datetime,
+                offset: UtcOffset::UTC,
+                zone: None,
+            })
+            .map_err(|_| {
+                // Failures are the slow path: parse the borrowed form of
+                // the description again to find where the input diverges.
+                format_description::parse(spec.as_str())
+                    .map_or(DateTimeError::InvalidFormat, |items| {
+                        locate_format_error(input, &items)
+                    })
+            })
+    }
 
-        let target_month =
-            target_month.map_err(|_| DateTimeError::InvalidDate)?;
-        let days_in_target_month =
-            days_in_month(target_year, target_month)?;
-        let target_day = current_date.day().min(days_in_target_month);
+    /// Parses each input with [`DateTime::parse`], keeping one result per
+    /// input in order. See [`batch`](crate::batch) for formatting and
+    /// parallel variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let results = DateTime::parse_many(&["2024-01-01", "nope"]);
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// ```
+    #[must_use]
+    pub fn parse_many<S: AsRef<str>>(
+        inputs: &[S],
+    ) -> Vec<Result<Self, DateTimeError>> {
+        batch::parse_many(inputs)
+    }
 
-        let new_month = Month::try_from(target_month)
-            .map_err(|_| DateTimeError::InvalidDate)?;
-        let new_date = Date::from_calendar_date(
-            target_year,
-            new_month,
-            target_day,
-        )
-        .map_err(|_| DateTimeError::InvalidDate)?;
+    /// Parses each input with [`DateTime::parse`], in parallel. Results
+    /// are in input order, exactly as from
+    /// [`parse_many`](Self::parse_many).
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[must_use]
+    pub fn parse_many_parallel<S: AsRef<str> + Sync>(
+        inputs: &[S],
+    ) -> Vec<Result<Self, DateTimeError>> {
+        batch::parse_many_parallel(inputs)
+    }
 
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                new_date,
-                self.datetime.time(),
-            ),
-            offset: self.offset,
-        })
+    /// Parses `input` with the format registered as `name` in the
+    /// [global format registry](FormatRegistry::global).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if no format is registered
+    /// under `name`, and otherwise the errors of
+    /// [`parse_custom_format`](Self::parse_custom_format).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::format::FormatRegistry;
+    ///
+    /// FormatRegistry::global()
+    ///     .register("log", "[day]/[month]/[year] [hour]:[minute]:[second]")
+    ///     .unwrap();
+    ///
+    /// let dt = DateTime::parse_named("15/02/2024 10:30:00", "log").unwrap();
+    /// assert_eq!(dt.to_string(), "2024-02-15T10:30:00Z");
+    /// assert!(DateTime::parse_named("15/02/2024", "unknown").is_err());
+    /// ```
+    pub fn parse_named(
+        input: &str,
+        name: &str,
+    ) -> Result<Self, DateTimeError> {
+        let format = FormatRegistry::global()
+            .lookup(name)
+            .ok_or(DateTimeError::InvalidFormat)?;
+        Self::parse_custom_format(input, &format)
     }
 
-    /// Subtracts a specified number of months from the `DateTime`.
+    // -------------------------------------------------------------------------
+    // Formatting Methods
+    // -------------------------------------------------------------------------
+
+    /// Formats the `DateTime` according to the specified format string.
     ///
     /// # Arguments
     ///
-    /// * `months` - Number of months to subtract
+    /// * `format_str` - Format specification string (see `time` crate documentation)
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the operation would result in an invalid date.
-    ///
-    /// # Errors
-    ///
-    /// This function returns a [`DateTimeError::InvalidDate`] if:
-    /// - The resulting date is out of valid range.
-    /// - The underlying date library fails to construct a valid `DateTime`.
+    /// Returns a `Result` containing either the formatted string or a `DateTimeError`
+    /// if formatting fails.
     ///
     /// # Examples
     ///
@@ -1252,220 +2396,2199 @@ impl DateTime {
     /// use dtt::datetime::DateTime;
     ///
     /// let dt = DateTime::new();
-    /// let past = dt.sub_months(3);
-    /// assert!(past.is_ok());
+    /// let formatted = dt.format("[year]-[month]-[day]");
+    /// assert!(formatted.is_ok());
     /// ```
-    pub fn sub_months(
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the format string is invalid.
+    ///
+    pub fn format(
         &self,
-        months: i32,
-    ) -> Result<Self, DateTimeError> {
-        self.add_months(-months)
+        format_str: &str,
+    ) -> Result<String, DateTimeError> {
+        instrument!("dtt::format", format_len = format_str.len());
+        let result = format::cached_spec(format_str)
+            .and_then(|spec| self.format_spec(&spec));
+        trace_failure!(result, "format");
+        result
     }
 
-    /// Adds a specified number of years to the `DateTime`.
+    /// Formats the `DateTime` with a pre-compiled format, as
+    /// [`format`](Self::format) does with a format string.
     ///
-    /// Handles leap-year transitions appropriately.
+    /// # Errors
     ///
-    /// # Arguments
+    /// Returns a `DateTimeError::InvalidFormat` if the format asks for a
+    /// component the value cannot provide.
     ///
-    /// * `years` - Number of years to add (can be negative for subtraction)
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::format::CompiledFormat;
+    ///
+    /// let compiled = CompiledFormat::new("[hour]:[minute]").unwrap();
+    /// let times: Vec<String> = ["09:15", "17:40"]
+    ///     .iter()
+    ///     .map(|t| DateTime::parse(&format!("2024-01-01T{t}:00Z")).unwrap())
+    ///     .map(|dt| dt.format_with(&compiled).unwrap())
+    ///     .collect();
+    /// assert_eq!(times, ["09:15", "17:40"]);
+    /// ```
+    pub fn format_with(
+        &self,
+        format: &FormatSpec,
+    ) -> Result<String, DateTimeError> {
+        instrument!("dtt::format_with", format = format.as_str());
+        let result = self.format_spec(format);
+        trace_failure!(result, "format_with");
+        result
+    }
+
+    /// Appends the `DateTime`, formatted with a pre-compiled format, to
+    /// any [`fmt::Write`] sink.
     ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the operation would result in an invalid date.
+    /// This is [`format_with`](Self::format_with) without the `String`
+    /// per call: once the thread's scratch buffer has grown to fit the
+    /// output, formatting does not allocate.
     ///
     /// # Errors
     ///
-    /// This function returns a [`DateTimeError::InvalidDate`] if:
-    /// - The resulting year is out of valid range.
-    /// - A non-leap year cannot accommodate February 29th.
-    /// - Any other invalid date scenario occurs during calculation.
+    /// Returns a `DateTimeError::InvalidFormat` if the format asks for a
+    /// component the value cannot provide, in which case nothing is
+    /// written, or if the sink reports an error.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
-    ///
-    /// let dt = DateTime::new();
-    /// let future = dt.add_years(5);
-    /// assert!(future.is_ok());
+    /// use dtt::format::CompiledFormat;
+    /// use std::fmt::Write;
+    ///
+    /// let compiled = CompiledFormat::new("[hour]:[minute]").unwrap();
+    /// let mut out = String::new();
+    /// for input in ["2024-01-01T09:15:00Z", "2024-01-01T17:40:00Z"] {
+    ///     let dt = DateTime::parse(input).unwrap();
+    ///     dt.format_into(&mut out, &compiled).unwrap();
+    ///     out.write_char(';').unwrap();
+    /// }
+    /// assert_eq!(out, "09:15;17:40;");
     /// ```
-    pub fn add_years(&self, years: i32) -> Result<Self, DateTimeError> {
-        let current_date = self.datetime.date();
-        let target_year = current_date
-            .year()
-            .checked_add(years)
-            .ok_or(DateTimeError::InvalidDate)?;
+    pub fn format_into<W: fmt::Write + ?Sized>(
+        &self,
+        output: &mut W,
+        format: &FormatSpec,
+    ) -> Result<(), DateTimeError> {
+        append_formatted(output, |buffer| {
+            self.datetime.format_into(buffer, format.items())
+        })
+    }
 
-        // Handle February 29th in leap years
-        let new_day = if current_date.month() == Month::February
-            && current_date.day() == 29
-            && !is_leap_year(target_year)
-        {
-            28
-        } else {
-            current_date.day()
-        };
-
-        let new_date = Date::from_calendar_date(
-            target_year,
-            current_date.month(),
-            new_day,
-        )
-        .map_err(|_| DateTimeError::InvalidDate)?;
-
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                new_date,
-                self.datetime.time(),
-            ),
-            offset: self.offset,
+    /// Formats with `spec`.
+    fn format_spec(
+        &self,
+        spec: &FormatSpec,
+    ) -> Result<String, DateTimeError> {
+        self.datetime.format(spec.items()).map_err(|_| {
+            debug_event!(cause = "component not available");
+            DateTimeError::InvalidFormat
         })
     }
 
-    // -------------------------------------------------------------------------
-    // Range / Boundary Helper Methods
-    // -------------------------------------------------------------------------
+    /// Formats the `DateTime` with the format registered as `name` in the
+    /// [global format registry](FormatRegistry::global).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if no format is registered
+    /// under `name`, or if formatting fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::format::FormatRegistry;
+    ///
+    /// FormatRegistry::global()
+    ///     .register("compact", "[year][month][day]")
+    ///     .unwrap();
+    ///
+    /// let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.format_named("compact").unwrap(), "20240215");
+    /// ```
+    pub fn format_named(
+        &self,
+        name: &str,
+    ) -> Result<String, DateTimeError> {
+        let format = FormatRegistry::global()
+            .lookup(name)
+            .ok_or(DateTimeError::InvalidFormat)?;
+        self.format(&format)
+    }
 
-    /// Returns a new `DateTime` for the start of the current week (Monday).
+    /// Formats the `DateTime` as an RFC 3339 string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the formatted RFC 3339 string
+    /// or a `DateTimeError` if formatting fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let maybe_rfc3339 = dt.format_rfc3339();
+    /// assert!(maybe_rfc3339.is_ok());
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if an overflow or
-    /// invalid date calculation occurs during date arithmetic.
-    pub fn start_of_week(&self) -> Result<Self, DateTimeError> {
-        let days_since_monday = i64::from(
-            self.datetime.weekday().number_days_from_monday(),
-        );
-        self.add_days(-days_since_monday)
+    /// Returns a `DateTimeError` if formatting fails.
+    ///
+    pub fn format_rfc3339(&self) -> Result<String, DateTimeError> {
+        let mut output = String::new();
+        self.format_rfc3339_reuse(&mut output)?;
+        Ok(output)
     }
 
-    /// Returns a new `DateTime` for the end of the current week (Sunday).
+    /// Formats the `DateTime` as RFC 3339 into `output`, replacing its
+    /// contents.
+    ///
+    /// Reusing one `String` across calls, for example one per log line,
+    /// avoids allocating once `output` has grown to fit a timestamp.
+    /// On error, `output` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let mut line = String::new();
+    /// for input in ["2024-01-01T00:00:00Z", "2024-01-02T12:30:00+01:00"] {
+    ///     let dt = DateTime::parse(input).unwrap();
+    ///     dt.format_rfc3339_reuse(&mut line).unwrap();
+    ///     assert_eq!(line, input);
+    /// }
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if an overflow or
-    /// invalid date calculation occurs during date arithmetic.
-    pub fn end_of_week(&self) -> Result<Self, DateTimeError> {
-        let days_until_sunday = 6 - i64::from(
-            self.datetime.weekday().number_days_from_monday(),
-        );
-        self.add_days(days_until_sunday)
+    /// Returns a `DateTimeError::InvalidFormat` if the value cannot be
+    /// expressed in RFC 3339, such as a year outside 0 to 9999.
+    pub fn format_rfc3339_reuse(
+        &self,
+        output: &mut String,
+    ) -> Result<(), DateTimeError> {
+        let datetime = self.datetime.assume_offset(self.offset);
+        write_formatted(output, |buffer| {
+            datetime.format_into(
+                buffer,
+                &format_description::well_known::Rfc3339,
+            )
+        })
     }
 
-    /// Returns a new `DateTime` for the start of the current month.
+    /// Appends the `DateTime` as RFC 3339 to any [`fmt::Write`] sink,
+    /// such as a `String` or a `fmt::Formatter`, without allocating once
+    /// the thread's scratch buffer has grown to fit a timestamp.
+    ///
+    /// Unlike [`format_rfc3339_reuse`](Self::format_rfc3339_reuse), the
+    /// output is appended rather than replaced, so several fields can be
+    /// written into one log line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-01-02T12:30:00+01:00").unwrap();
+    /// let mut line = String::from("ts=");
+    /// dt.write_rfc3339_into(&mut line).unwrap();
+    /// line.push_str(" level=info");
+    /// assert_eq!(line, "ts=2024-01-02T12:30:00+01:00 level=info");
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if the date cannot be
-    /// constructed (e.g., due to an invalid year or month).
-    pub fn start_of_month(&self) -> Result<Self, DateTimeError> {
-        self.set_date(
-            self.datetime.year(),
-            self.datetime.month() as u8,
-            1,
+    /// Returns a `DateTimeError::InvalidFormat` if the value cannot be
+    /// expressed in RFC 3339 or the sink reports an error. Nothing is
+    /// written in the first case.
+    pub fn write_rfc3339_into<W: fmt::Write + ?Sized>(
+        &self,
+        output: &mut W,
+    ) -> Result<(), DateTimeError> {
+        self.write_described(
+            output,
+            &format_description::well_known::Rfc3339,
         )
     }
 
-    /// Returns a new `DateTime` for the end of the current month.
+    /// Appends the value formatted with `description`, which may use
+    /// offset components, to `output`.
+    pub(crate) fn write_described<W, D>(
+        &self,
+        output: &mut W,
+        description: &D,
+    ) -> Result<(), DateTimeError>
+    where
+        W: fmt::Write + ?Sized,
+        D: Formattable + ?Sized,
+    {
+        let datetime = self.to_offset_datetime();
+        append_formatted(output, |buffer| {
+            datetime.format_into(buffer, description)
+        })
+    }
+
+    /// Returns an adapter that displays the value in `style` instead of
+    /// the RFC 3339 used by `Display`, without building a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::format::{CompiledFormat, DisplayStyle};
+    ///
+    /// let dt = DateTime::parse("2024-02-15T10:30:05+01:00").unwrap();
+    /// assert_eq!(
+    ///     format!("{}", dt.display_as(DisplayStyle::Iso8601Basic)),
+    ///     "20240215T103005+0100"
+    /// );
+    /// assert_eq!(
+    ///     format!("due {}", dt.display_as(DisplayStyle::DateOnly)),
+    ///     "due 2024-02-15"
+    /// );
+    ///
+    /// let compiled = CompiledFormat::new("[day]/[month]").unwrap();
+    /// assert_eq!(
+    ///     dt.display_as(DisplayStyle::Custom(&compiled)).to_string(),
+    ///     "15/02"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn display_as<'a>(
+        &'a self,
+        style: DisplayStyle<'a>,
+    ) -> DisplayAs<'a> {
+        DisplayAs::new(self, style)
+    }
+
+    /// Formats the `DateTime` as an ISO 8601 string (YYYY-MM-DDTHH:MM:SS).
+    ///
+    /// A fractional second is appended, with trailing zeros removed, when
+    /// the value is not a whole second.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the formatted ISO 8601 string
+    /// or a `DateTimeError` if formatting fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let maybe_iso8601 = dt.format_iso8601();
+    /// assert!(maybe_iso8601.is_ok());
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if the date cannot be
-    /// constructed (e.g., `days_in_month` fails to provide a valid day).
-    pub fn end_of_month(&self) -> Result<Self, DateTimeError> {
-        let year = self.datetime.year();
-        let month = self.datetime.month() as u8;
-        let last_day = days_in_month(year, month)?;
-        self.set_date(year, month, last_day)
+    /// Returns a `DateTimeError` if formatting fails.
+    ///
+    pub fn format_iso8601(&self) -> Result<String, DateTimeError> {
+        let mut output = String::new();
+        self.format_iso8601_reuse(&mut output)?;
+        Ok(output)
     }
 
-    /// Returns a new `DateTime` for the start of the current year.
+    /// Formats the `DateTime` as ISO 8601 into `output`, replacing its
+    /// contents.
+    ///
+    /// Like [`format_rfc3339_reuse`](Self::format_rfc3339_reuse), this
+    /// lets a caller reuse one `String` across calls. On error, `output`
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let mut output = String::with_capacity(32);
+    /// let dt = DateTime::parse("2024-01-01T08:00:00.25Z").unwrap();
+    /// dt.format_iso8601_reuse(&mut output).unwrap();
+    /// assert_eq!(output, "2024-01-01T08:00:00.25");
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if the date cannot
-    /// be constructed (e.g., invalid year).
-    pub fn start_of_year(&self) -> Result<Self, DateTimeError> {
-        self.set_date(self.datetime.year(), 1, 1)
+    /// Returns a `DateTimeError::InvalidFormat` if formatting fails.
+    pub fn format_iso8601_reuse(
+        &self,
+        output: &mut String,
+    ) -> Result<(), DateTimeError> {
+        let description = if self.datetime.nanosecond() == 0 {
+            ISO8601_FORMAT
+        } else {
+            ISO8601_SUBSECOND_FORMAT
+        };
+        write_formatted(output, |buffer| {
+            self.datetime.format_into(buffer, description)
+        })
     }
 
-    /// Returns a new `DateTime` for the end of the current year.
+    /// Formats the `DateTime` as an HTTP `Date` header value (IMF-fixdate),
+    /// converting it to GMT first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("1994-11-06T08:49:37Z").unwrap();
+    /// assert_eq!(dt.format_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
     ///
     /// # Errors
     ///
-    /// This function can return a [`DateTimeError`] if the date cannot
-    /// be constructed (e.g., invalid year).
-    pub fn end_of_year(&self) -> Result<Self, DateTimeError> {
-        self.set_date(self.datetime.year(), 12, 31)
+    /// Returns a `DateTimeError::ConversionOverflow` if the time in GMT
+    /// is out of range, or `DateTimeError::InvalidFormat` if formatting
+    /// fails.
+    ///
+    pub fn format_http_date(&self) -> Result<String, DateTimeError> {
+        self.try_convert_to_offset(UtcOffset::UTC)?
+            .to_offset_datetime()
+            .format(HTTP_DATE_FORMAT)
+            .map_err(|_| DateTimeError::InvalidFormat)
     }
 
-    // -------------------------------------------------------------------------
-    // Range Validation
-    // -------------------------------------------------------------------------
+    /// Formats the `DateTime` as a date and time followed by its timezone
+    /// abbreviation, as log lines usually show it. Values without an
+    /// abbreviation show their numeric offset instead; see
+    /// [`DateTime::zone_abbreviation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let est = utc.convert_to_tz("EST").unwrap();
+    /// assert_eq!(est.format_zoned().unwrap(), "2024-01-01 07:00:00 EST");
+    /// assert_eq!(utc.format_zoned().unwrap(), "2024-01-01 12:00:00 +00:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if formatting fails.
+    ///
+    pub fn format_zoned(&self) -> Result<String, DateTimeError> {
+        let datetime = self
+            .datetime
+            .format(ZONED_FORMAT)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        let zone = self
+            .zone
+            .map_or_else(|| self.offset_string(), str::to_string);
+        Ok(format!("{datetime} {zone}"))
+    }
 
-    /// Checks if the current `DateTime` falls within a specific date range (inclusive).
+    /// Formats the date as an ISO 8601 ordinal date (`YYYY-DDD`).
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `start` - Start of the date range (inclusive)
-    /// * `end` - End of the date range (inclusive)
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-02-15").unwrap();
+    /// assert_eq!(dt.format_ordinal_date().unwrap(), "2024-046");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if formatting fails.
+    ///
+    pub fn format_ordinal_date(&self) -> Result<String, DateTimeError> {
+        self.datetime
+            .format(ORDINAL_DATE_FORMAT)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+
+    /// Updates the `DateTime` to the current time while preserving the timezone offset.
     ///
     /// # Returns
     ///
-    /// Returns `true` if the current `DateTime` falls within the range, `false` otherwise.
+    /// Returns a `Result` containing either the updated `DateTime` or a `DateTimeError`
+    /// if the update fails.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
+    /// use std::thread::sleep;
+    /// use std::time::Duration;
     ///
     /// let dt = DateTime::new();
-    /// let start = dt.add_days(-1).unwrap_or(dt);
-    /// let end = dt.add_days(1).unwrap_or(dt);
+    /// sleep(Duration::from_secs(1));
+    /// let updated_dt = dt.update();
+    /// assert!(updated_dt.is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the update fails.
+    ///
+    pub fn update(&self) -> Result<Self, DateTimeError> {
+        self.update_with_clock(&SystemClock)
+    }
+
+    /// Returns the current time according to `clock`, in this value's
+    /// offset.
+    ///
+    /// This is [`DateTime::update`] with the time read from a [`Clock`] or
+    /// [`TimeSource`] instead of the system clock.
+    ///
+    /// # Examples
     ///
-    /// assert!(dt.is_within_range(&start, &end));
     /// ```
-    #[must_use]
-    pub fn is_within_range(&self, start: &Self, end: &Self) -> bool {
-        self >= start && self <= end
+    /// use dtt::clock::MockClock;
+    /// use dtt::datetime::DateTime;
+    /// use time::Duration;
+    ///
+    /// let start = DateTime::parse("2024-01-01T12:00:00+01:00").unwrap();
+    /// let clock = MockClock::new(start);
+    /// clock.advance(Duration::hours(1)).unwrap();
+    /// let updated = start.update_with_clock(&clock).unwrap();
+    /// assert_eq!(updated.to_string(), "2024-01-01T13:00:00+01:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::ConversionOverflow` if the clock's time
+    /// cannot be represented in this offset.
+    ///
+    /// [`TimeSource`]: crate::clock::TimeSource
+    pub fn update_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> Result<Self, DateTimeError> {
+        clock.now().try_convert_to_offset(self.offset)
     }
 
     // -------------------------------------------------------------------------
-    // Mutation Helpers
+    // Timezone Conversion Method
     // -------------------------------------------------------------------------
 
-    /// Sets the date components while maintaining the current time.
+    /// Converts the current `DateTime` to another timezone.
     ///
     /// # Arguments
     ///
-    /// * `year` - Calendar year
-    /// * `month` - Month (1-12)
-    /// * `day` - Day of month (1-31)
+    /// * `new_tz` - Target timezone abbreviation (e.g., "UTC", "EST", "PST") or a
+    ///   numeric offset (e.g., "UTC+05:30", "+0530", "GMT-7")
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
-    /// if the date is invalid.
+    /// Returns a `Result` containing either the `DateTime` in the new timezone
+    /// or a `DateTimeError` if the conversion fails.
     ///
     /// # Examples
     ///
     /// ```
     /// use dtt::datetime::DateTime;
     ///
-    /// let dt = DateTime::new();
-    /// let new_dt = dt.set_date(2024, 1, 1);
-    /// assert!(new_dt.is_ok());
+    /// let utc = DateTime::new();
+    /// let maybe_est = utc.convert_to_tz("EST");
+    /// assert!(maybe_est.is_ok());
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `DateTimeError` if the resulting date would be invalid.
+    /// Returns a `DateTimeError` if the timezone is invalid. Use
+    /// [`suggest_timezone`] to find the closest supported abbreviation.
     ///
-    pub fn set_date(
+    pub fn convert_to_tz(
         &self,
-        year: i32,
+        new_tz: &str,
+    ) -> Result<Self, DateTimeError> {
+        instrument!("dtt::convert_to_tz", timezone = new_tz);
+        let result = timezone_offset(new_tz)
+            .ok_or(DateTimeError::InvalidTimezone)
+            .and_then(|new_offset| {
+                self.try_convert_to_offset(new_offset)
+            })
+            .map(|dt| dt.with_zone_label(zone_label(new_tz)));
+        trace_failure!(result, "convert_to_tz");
+        result
+    }
+
+    /// Converts the `DateTime` to the given offset, keeping the same
+    /// instant.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The target UTC offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::DateTimeError;
+    /// use time::UtcOffset;
+    ///
+    /// let offset = UtcOffset::from_hms(10, 0, 0).unwrap();
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// assert_eq!(dt.try_convert_to_offset(offset).unwrap().hour(), 22);
+    ///
+    /// let last = DateTime::parse("9999-12-31T23:00:00Z").unwrap();
+    /// assert!(matches!(
+    ///     last.try_convert_to_offset(offset),
+    ///     Err(DateTimeError::ConversionOverflow { .. })
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateTimeError::ConversionOverflow`] carrying the instant
+    /// and target offset if the converted wall-clock time falls outside
+    /// the supported date range.
+    ///
+    pub fn try_convert_to_offset(
+        &self,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        let result = self
+            .to_offset_datetime()
+            .checked_to_offset(offset)
+            .map(Self::from_offset_datetime)
+            .ok_or(DateTimeError::ConversionOverflow {
+                instant: *self,
+                target: offset,
+            });
+        trace_failure!(result, "convert_to_offset");
+        result
+    }
+
+    /// Converts the `DateTime` to the given offset, clamping to the first
+    /// or last representable wall-clock time in that offset on overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The target UTC offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::UtcOffset;
+    ///
+    /// let offset = UtcOffset::from_hms(10, 0, 0).unwrap();
+    /// let last = DateTime::parse("9999-12-31T23:00:00Z").unwrap();
+    /// let clamped = last.saturating_convert_to_offset(offset);
+    /// assert_eq!(clamped.to_string(), "9999-12-31T23:59:59.999999999+10:00");
+    /// ```
+    #[must_use]
+    pub fn saturating_convert_to_offset(
+        &self,
+        offset: UtcOffset,
+    ) -> Self {
+        self.try_convert_to_offset(offset).unwrap_or_else(|_| {
+            let limit = if offset.whole_seconds()
+                > self.offset.whole_seconds()
+            {
+                PrimitiveDateTime::MAX
+            } else {
+                PrimitiveDateTime::MIN
+            };
+            Self::from_primitive(limit, offset)
+        })
+    }
+
+    /// Converts the `DateTime` to UTC, keeping the same instant.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateTimeError::ConversionOverflow`] if the UTC
+    /// wall-clock time falls outside the supported date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-01-01T09:00:00+05:30").unwrap();
+    /// assert_eq!(dt.to_utc().unwrap().to_string(), "2024-01-01T03:30:00Z");
+    /// ```
+    pub fn to_utc(&self) -> Result<Self, DateTimeError> {
+        self.try_convert_to_offset(UtcOffset::UTC)
+    }
+
+    /// Replaces the offset, keeping the wall-clock time. The result is a
+    /// different instant unless the offsets are equal; use
+    /// [`with_offset_same_instant`](Self::with_offset_same_instant) to
+    /// keep the instant instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::offsets;
+    ///
+    /// let dt = DateTime::parse("2024-01-01T09:00:00Z").unwrap();
+    /// let tokyo = dt.with_offset(offsets::JST);
+    /// assert_eq!(tokyo.to_string(), "2024-01-01T09:00:00+09:00");
+    /// assert!(!tokyo.same_instant_as(&dt));
+    /// ```
+    #[must_use]
+    pub const fn with_offset(&self, offset: UtcOffset) -> Self {
+        Self {
+            datetime: self.datetime,
+            offset,
+            zone: None,
+        }
+    }
+
+    /// Changes the offset, keeping the same instant, as
+    /// [`try_convert_to_offset`](Self::try_convert_to_offset) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateTimeError::ConversionOverflow`] if the converted
+    /// wall-clock time falls outside the supported date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::offsets;
+    ///
+    /// let dt = DateTime::parse("2024-01-01T09:00:00Z").unwrap();
+    /// let tokyo = dt.with_offset_same_instant(offsets::JST).unwrap();
+    /// assert_eq!(tokyo.to_string(), "2024-01-01T18:00:00+09:00");
+    /// assert!(tokyo.same_instant_as(&dt));
+    /// ```
+    pub fn with_offset_same_instant(
+        &self,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        self.try_convert_to_offset(offset)
+    }
+
+    // -------------------------------------------------------------------------
+    // Additional Utilities
+    // -------------------------------------------------------------------------
+
+    /// Gets the Unix timestamp (seconds since Unix epoch).
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of seconds from the Unix epoch (1970-01-01T00:00:00Z).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let ts = dt.unix_timestamp();
+    /// ```
+    #[must_use]
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.datetime.assume_offset(self.offset).unix_timestamp()
+    }
+
+    /// Gets the Unix timestamp in nanoseconds.
+    ///
+    /// Uses `i128`, which holds every representable `DateTime` exactly;
+    /// an `i64` count of nanoseconds overflows in the year 2262.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2300-01-01T00:00:00.000000001Z").unwrap();
+    /// assert_eq!(dt.unix_timestamp_nanos(), 10_413_792_000_000_000_001);
+    /// ```
+    #[must_use]
+    pub const fn unix_timestamp_nanos(&self) -> i128 {
+        self.to_offset_datetime().unix_timestamp_nanos()
+    }
+
+    /// Returns a hash of the instant and offset that does not depend on
+    /// the platform, the Rust release, or the `time` crate's internals,
+    /// so it can be persisted or compared across machines.
+    ///
+    /// The hash is 64-bit FNV-1a over the little-endian bytes of
+    /// [`unix_timestamp_nanos`](Self::unix_timestamp_nanos) (16 bytes)
+    /// followed by those of the offset in whole seconds (4 bytes). It will
+    /// not change in future releases. Equal values have equal hashes; the
+    /// same instant at different offsets does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let epoch = DateTime::parse("1970-01-01T00:00:00Z").unwrap();
+    /// assert_eq!(epoch.stable_hash_u64(), 0xee85_fafd_354b_0935);
+    ///
+    /// let paris = DateTime::parse("1970-01-01T01:00:00+01:00").unwrap();
+    /// assert_ne!(paris.stable_hash_u64(), epoch.stable_hash_u64());
+    /// ```
+    #[must_use]
+    pub const fn stable_hash_u64(&self) -> u64 {
+        let hash = fnv1a(
+            FNV_OFFSET_BASIS,
+            &self.unix_timestamp_nanos().to_le_bytes(),
+        );
+        fnv1a(hash, &self.offset.whole_seconds().to_le_bytes())
+    }
+
+    /// Creates a UTC `DateTime` from a Unix timestamp in nanoseconds.
+    ///
+    /// Round-trips exactly with [`DateTime::unix_timestamp_nanos`] for
+    /// every instant whose UTC wall clock falls in the years -9999 to
+    /// 9999. Values near the ends of the range in a large offset, such as
+    /// 9999-12-31 23:00 at -14:00, are only representable in that offset:
+    /// use [`DateTime::from_unix_timestamp_nanos_in`] for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::from_unix_timestamp_nanos(-1).unwrap();
+    /// assert_eq!(dt.to_string(), "1969-12-31T23:59:59.999999999Z");
+    /// assert_eq!(dt.unix_timestamp_nanos(), -1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::ComponentRange` if the timestamp is outside
+    /// the supported range of years -9999 to 9999.
+    ///
+    pub fn from_unix_timestamp_nanos(
+        nanos: i128,
+    ) -> Result<Self, DateTimeError> {
+        OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .map(Self::from_offset_datetime)
+            .map_err(DateTimeError::ComponentRange)
+    }
+
+    /// Creates a `DateTime` in `offset` from a Unix timestamp in
+    /// nanoseconds.
+    ///
+    /// Round-trips exactly with [`DateTime::unix_timestamp_nanos`] for
+    /// every `DateTime`, given the value's own offset, including instants
+    /// whose UTC wall clock is outside the supported years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::macros::{datetime, offset};
+    ///
+    /// let last = DateTime::from_primitive(
+    ///     datetime!(9999-12-31 23:59:59.999_999_999),
+    ///     offset!(-14:00),
+    /// );
+    /// let nanos = last.unix_timestamp_nanos();
+    /// assert!(DateTime::from_unix_timestamp_nanos(nanos).is_err());
+    /// assert_eq!(
+    ///     DateTime::from_unix_timestamp_nanos_in(nanos, offset!(-14:00)).unwrap(),
+    ///     last
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::ComponentRange` if the wall clock in
+    /// `offset` is outside the supported range of years -9999 to 9999.
+    pub fn from_unix_timestamp_nanos_in(
+        nanos: i128,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        let local = nanos.saturating_add(
+            i128::from(offset.whole_seconds()) * 1_000_000_000,
+        );
+        OffsetDateTime::from_unix_timestamp_nanos(local)
+            .map(|wall| {
+                Self::from_primitive(
+                    PrimitiveDateTime::new(wall.date(), wall.time()),
+                    offset,
+                )
+            })
+            .map_err(DateTimeError::ComponentRange)
+    }
+
+    /// Returns `true` if both values name the same instant, whatever
+    /// their offsets. `==` also compares the offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let tokyo = DateTime::parse("2024-01-01T21:00:00+09:00").unwrap();
+    /// assert!(utc.same_instant_as(&tokyo));
+    /// assert_ne!(utc, tokyo);
+    /// ```
+    #[must_use]
+    pub const fn same_instant_as(&self, other: &Self) -> bool {
+        self.unix_timestamp_nanos() == other.unix_timestamp_nanos()
+    }
+
+    /// Compares the instants of two values, whatever their offsets.
+    ///
+    /// `Ord` for `DateTime` compares wall-clock times, ignoring offsets;
+    /// use this, or wrap values in [`InstantOrdered`], to order by
+    /// absolute time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use std::cmp::Ordering;
+    ///
+    /// let paris = DateTime::parse("2024-01-01T12:30:00+01:00").unwrap();
+    /// let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// assert_eq!(paris.cmp_instant(&utc), Ordering::Less);
+    /// assert_eq!(paris.cmp(&utc), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn cmp_instant(&self, other: &Self) -> Ordering {
+        self.unix_timestamp_nanos()
+            .cmp(&other.unix_timestamp_nanos())
+    }
+
+    /// Calculates the duration between this `DateTime` and another.
+    ///
+    /// The result can be negative if `other` is later than `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The `DateTime` to compare with
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Duration` representing the time difference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt1 = DateTime::new();
+    /// let dt2 = dt1.add_days(1).unwrap_or(dt1);
+    /// let duration = dt1.duration_since(&dt2);
+    /// // duration could be negative if dt2 > dt1
+    /// ```
+    #[must_use]
+    pub fn duration_since(&self, other: &Self) -> Duration {
+        let self_offset = self.datetime.assume_offset(self.offset);
+        let other_offset = other.datetime.assume_offset(other.offset);
+
+        let seconds_diff = self_offset.unix_timestamp()
+            - other_offset.unix_timestamp();
+        let nanos_diff = i64::from(self_offset.nanosecond())
+            - i64::from(other_offset.nanosecond());
+
+        Duration::seconds(seconds_diff)
+            + Duration::nanoseconds(nanos_diff)
+    }
+
+    /// Counts the whole units from `other` to `self`, truncated towards
+    /// zero.
+    ///
+    /// Seconds, minutes, hours, days, and weeks are exact lengths of time,
+    /// so offsets do not matter. Months and years are calendar units
+    /// counted as by [`difference`](Self::difference). The count is
+    /// negative if `other` is later than `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the count does not fit in
+    /// an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::{DateTime, Unit};
+    ///
+    /// let start = DateTime::parse("2024-01-31T08:00:00Z").unwrap();
+    /// let end = DateTime::parse("2024-03-15T07:30:00+01:00").unwrap();
+    /// assert_eq!(end.diff_in(&start, Unit::Day), Ok(43));
+    /// assert_eq!(end.diff_in(&start, Unit::Week), Ok(6));
+    /// assert_eq!(end.diff_in(&start, Unit::Month), Ok(1));
+    /// assert_eq!(start.diff_in(&end, Unit::Hour), Ok(-1054));
+    /// ```
+    pub fn diff_in(
+        &self,
+        other: &Self,
+        unit: Unit,
+    ) -> Result<i64, DateTimeError> {
+        let elapsed = self.duration_since(other);
+        let count = match unit {
+            Unit::Second => Some(elapsed.whole_seconds()),
+            Unit::Minute => Some(elapsed.whole_minutes()),
+            Unit::Hour => Some(elapsed.whole_hours()),
+            Unit::Day => Some(elapsed.whole_days()),
+            Unit::Week => Some(elapsed.whole_weeks()),
+            Unit::Month => {
+                let delta = self.difference(other);
+                i64::from(delta.years).checked_mul(12).and_then(
+                    |months| {
+                        months.checked_add(i64::from(delta.months))
+                    },
+                )
+            }
+            Unit::Year => Some(i64::from(self.difference(other).years)),
+        };
+        count.ok_or(DateTimeError::InvalidDate)
+    }
+
+    /// Calculates the calendar-aware difference between this `DateTime` and
+    /// another, broken down into years, months, days, hours, minutes, and
+    /// seconds.
+    ///
+    /// The fields of the result are positive if `self` is later than
+    /// `other`, and negative otherwise. Month lengths and leap years are
+    /// taken into account: one month after January 31 is the last day of
+    /// February.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let birth = DateTime::parse("2020-02-29T00:00:00Z").unwrap();
+    /// let today = DateTime::parse("2024-03-15T06:00:00Z").unwrap();
+    ///
+    /// let delta = today.difference(&birth);
+    /// assert_eq!((delta.years, delta.months, delta.days, delta.hours), (4, 0, 15, 6));
+    /// assert_eq!(birth.difference(&today).years, -4);
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> DateDelta {
+        DateDelta::between(other, self)
+    }
+
+    /// Returns the age, in whole years, of someone born at `self` on the
+    /// day `today`.
+    ///
+    /// Ages count calendar days, not elapsed time: each value is read on
+    /// its own wall clock, so someone born on March 10 in Tokyo turns a
+    /// year older when `today` falls on March 10 in its own offset,
+    /// whatever the time of day. A February 29 birthday falls on February
+    /// 28 in common years, as with [`add_months`](Self::add_months).
+    ///
+    /// The result is negative if `today` is before the birth date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let birth = DateTime::parse("2000-02-29T23:00:00+09:00").unwrap();
+    /// let before = DateTime::parse("2023-02-27T12:00:00Z").unwrap();
+    /// let birthday = DateTime::parse("2023-02-28T00:00:00-05:00").unwrap();
+    ///
+    /// assert_eq!(birth.age_in_years(&before), 22);
+    /// assert_eq!(birth.age_in_years(&birthday), 23);
+    /// ```
+    #[must_use]
+    pub fn age_in_years(&self, today: &Self) -> i32 {
+        self.age_in_months(today) / 12
+    }
+
+    /// Returns the age, in whole months, of someone born at `self` on the
+    /// day `today`.
+    ///
+    /// Calendar days are compared as in
+    /// [`age_in_years`](Self::age_in_years); a birth day that does not
+    /// exist in a shorter month falls on the last day of that month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let birth = DateTime::parse("2024-01-31T08:00:00Z").unwrap();
+    /// let today = DateTime::parse("2024-02-29T00:00:00Z").unwrap();
+    /// assert_eq!(birth.age_in_months(&today), 1);
+    /// assert_eq!(today.age_in_months(&birth), -1);
+    /// ```
+    #[must_use]
+    pub fn age_in_months(&self, today: &Self) -> i32 {
+        let birth = self.datetime.date();
+        let today = today.datetime.date();
+        if birth <= today {
+            whole_months_between(birth, today)
+        } else {
+            -whole_months_between(today, birth)
+        }
+    }
+
+    /// Returns the number of whole years from `self` until `other`,
+    /// counting the time of day.
+    ///
+    /// Unlike [`age_in_years`](Self::age_in_years), this compares instants:
+    /// `other` is converted to the offset of `self` first, and a year is
+    /// only complete once the same time of day is reached. The result is
+    /// negative if `other` is earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let start = DateTime::parse("2020-06-01T12:00:00Z").unwrap();
+    /// let early = DateTime::parse("2024-06-01T11:59:59Z").unwrap();
+    /// let later = DateTime::parse("2024-06-01T14:00:00+02:00").unwrap();
+    ///
+    /// assert_eq!(start.years_until(&early), 3);
+    /// assert_eq!(start.years_until(&later), 4);
+    /// assert_eq!(later.years_until(&start), -4);
+    /// ```
+    #[must_use]
+    pub fn years_until(&self, other: &Self) -> i32 {
+        DateDelta::between(self, other).years
+    }
+
+    /// Renders the month containing `self` as a text grid, like the
+    /// Unix `cal` command.
+    ///
+    /// See the [`calendar`](crate::calendar) module for the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::calendar::CalendarOptions;
+    /// use dtt::datetime::DateTime;
+    /// use time::Weekday;
+    ///
+    /// let dt = DateTime::parse("2024-09-10T08:00:00Z").unwrap();
+    /// let options = CalendarOptions::new().first_weekday(Weekday::Sunday);
+    /// let grid = dt.render_month_calendar(&options);
+    ///
+    /// let lines: Vec<&str> = grid.lines().collect();
+    /// assert_eq!(lines[0], "   September 2024");
+    /// assert_eq!(lines[1], "Su Mo Tu We Th Fr Sa");
+    /// assert_eq!(lines[2], " 1  2  3  4  5  6  7");
+    /// ```
+    #[must_use]
+    pub fn render_month_calendar(
+        &self,
+        options: &CalendarOptions,
+    ) -> String {
+        calendar::render_month(self.datetime.date(), *options)
+    }
+
+    /// Renders the year containing `self` as a text grid, three months
+    /// across, like `cal -y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::calendar::CalendarOptions;
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-09-10T08:00:00Z").unwrap();
+    /// let grid = dt.render_year_calendar(&CalendarOptions::new());
+    ///
+    /// let lines: Vec<&str> = grid.lines().collect();
+    /// assert_eq!(lines[0].trim(), "2024");
+    /// assert_eq!(lines.len(), 2 + 4 * 8 + 3);
+    /// ```
+    #[must_use]
+    pub fn render_year_calendar(
+        &self,
+        options: &CalendarOptions,
+    ) -> String {
+        calendar::render_year(self.datetime.date(), *options)
+    }
+
+    /// Returns `true` if the calendar date of `self` is a holiday in
+    /// `provider`'s calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::holidays::UkBankHolidays;
+    ///
+    /// let dt = DateTime::parse("2024-12-26T10:00:00Z").unwrap();
+    /// assert!(dt.is_holiday(&UkBankHolidays));
+    /// ```
+    #[must_use]
+    pub fn is_holiday<P>(&self, provider: &P) -> bool
+    where
+        P: HolidayProvider + ?Sized,
+    {
+        provider.is_holiday(self.date_only())
+    }
+
+    /// Returns `true` if the calendar date of `self` is neither a weekend
+    /// day nor a holiday in `provider`'s calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::holidays::UsFederal;
+    ///
+    /// let dt = DateTime::parse("2024-11-28T10:00:00-05:00").unwrap();
+    /// assert!(!dt.is_business_day(&UsFederal));
+    /// ```
+    #[must_use]
+    pub fn is_business_day<P>(&self, provider: &P) -> bool
+    where
+        P: HolidayProvider + ?Sized,
+    {
+        holidays::is_business_day(self.date_only(), provider)
+    }
+
+    /// Moves `days` business days forward, or backward if negative,
+    /// keeping the time of day and offset.
+    ///
+    /// See [`holidays::add_business_days`] for the counting rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result falls outside
+    /// the supported date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::holidays::Target2;
+    ///
+    /// let dt = DateTime::parse("2024-12-24T15:00:00+01:00").unwrap();
+    /// let next = dt.add_business_days(1, &Target2).unwrap();
+    /// assert_eq!(next.to_string(), "2024-12-27T15:00:00+01:00");
+    /// ```
+    pub fn add_business_days<P>(
+        &self,
+        days: i64,
+        provider: &P,
+    ) -> Result<Self, DateTimeError>
+    where
+        P: HolidayProvider + ?Sized,
+    {
+        let date = holidays::add_business_days(
+            self.date_only(),
+            days,
+            provider,
+        )?;
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(
+                date.to_date(),
+                self.datetime.time(),
+            ),
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+
+    /// Builds a human-readable report of how this `DateTime` differs from
+    /// another.
+    ///
+    /// The report lists every differing component and the offset-normalized
+    /// delta (`other - self`). Its `Display` output is designed for use in
+    /// assertion messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let a = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let b = a.add_days(1).unwrap();
+    ///
+    /// let report = a.diff_report(&b);
+    /// assert_eq!(report.differences().len(), 1);
+    /// assert_eq!(report.delta().whole_days(), 1);
+    /// ```
+    #[must_use]
+    pub fn diff_report(&self, other: &Self) -> DiffReport {
+        DiffReport::new(self, other)
+    }
+
+    // -------------------------------------------------------------------------
+    // Date Arithmetic Methods
+    // -------------------------------------------------------------------------
+
+    /// Adds a specified number of days to the `DateTime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Number of days to add (can be negative for subtraction)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the operation would result in an invalid date.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a [`DateTimeError::InvalidDate`] if adding `days` results
+    /// in a date overflow or otherwise invalid date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let future = dt.add_days(7);
+    /// assert!(future.is_ok());
+    /// ```
+    pub fn add_days(&self, days: i64) -> Result<Self, DateTimeError> {
+        let new_datetime = days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|seconds| {
+                self.datetime.checked_add(Duration::seconds(seconds))
+            })
+            .ok_or(DateTimeError::InvalidDate)?;
+
+        Ok(Self {
+            datetime: new_datetime,
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+
+    /// Adds a specified number of months to the `DateTime`.
+    ///
+    /// Handles month-end dates and leap years appropriately.
+    ///
+    /// # Arguments
+    ///
+    /// * `months` - Number of months to add (can be negative for subtraction)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the operation would result in an invalid date.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a [`DateTimeError`] if:
+    /// - The calculated year, month, or day is invalid (e.g., out of range).
+    /// - The underlying date library fails to construct a valid date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let future = dt.add_months(3);
+    /// assert!(future.is_ok());
+    /// ```
+    pub fn add_months(
+        &self,
+        months: i32,
+    ) -> Result<Self, DateTimeError> {
+        let current_date = self.datetime.date();
+        let total_months = i64::from(current_date.year()) * 12
+            + i64::from(current_date.month() as u8 - 1)
+            + i64::from(months);
+
+        let target_year = i32::try_from(total_months.div_euclid(12))
+            .map_err(|_| DateTimeError::InvalidDate)?;
+        let target_month =
+            u8::try_from(total_months.rem_euclid(12) + 1)
+                .map_err(|_| DateTimeError::InvalidDate)?;
+        let days_in_target_month =
+            days_in_month(target_year, target_month)?;
+        let target_day = current_date.day().min(days_in_target_month);
+
+        let new_month = Month::January.nth_next(target_month - 1);
+        let new_date = Date::from_calendar_date(
+            target_year,
+            new_month,
+            target_day,
+        )
+        .map_err(|_| DateTimeError::InvalidDate)?;
+
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(
+                new_date,
+                self.datetime.time(),
+            ),
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+
+    /// Adds a calendar [`Period`]: first its years and months, clamping
+    /// the day to the end of a shorter month as
+    /// [`add_months`](Self::add_months) does, and then its days. The time
+    /// of day and offset are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is outside the
+    /// supported date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::period::Period;
+    ///
+    /// let dt = DateTime::parse("2024-02-29T12:00:00Z").unwrap();
+    /// let next = dt.add_period(&Period::new(1, 0, 1)).unwrap();
+    /// assert_eq!(next.to_string(), "2025-03-01T12:00:00Z");
+    /// ```
+    pub fn add_period(
+        &self,
+        period: &Period,
+    ) -> Result<Self, DateTimeError> {
+        let months =
+            period.total_months().ok_or(DateTimeError::InvalidDate)?;
+        self.add_months(months)?.add_days(i64::from(period.days))
+    }
+
+    /// Subtracts a specified number of months from the `DateTime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `months` - Number of months to subtract
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the operation would result in an invalid date.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a [`DateTimeError::InvalidDate`] if:
+    /// - The resulting date is out of valid range.
+    /// - The underlying date library fails to construct a valid `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let past = dt.sub_months(3);
+    /// assert!(past.is_ok());
+    /// ```
+    pub fn sub_months(
+        &self,
+        months: i32,
+    ) -> Result<Self, DateTimeError> {
+        self.add_months(-months)
+    }
+
+    /// Adds a specified number of years to the `DateTime`.
+    ///
+    /// Handles leap-year transitions appropriately.
+    ///
+    /// # Arguments
+    ///
+    /// * `years` - Number of years to add (can be negative for subtraction)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the operation would result in an invalid date.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a [`DateTimeError::InvalidDate`] if:
+    /// - The resulting year is out of valid range.
+    /// - A non-leap year cannot accommodate February 29th.
+    /// - Any other invalid date scenario occurs during calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let future = dt.add_years(5);
+    /// assert!(future.is_ok());
+    /// ```
+    pub fn add_years(&self, years: i32) -> Result<Self, DateTimeError> {
+        let current_date = self.datetime.date();
+        let target_year = current_date
+            .year()
+            .checked_add(years)
+            .ok_or(DateTimeError::InvalidDate)?;
+
+        // Handle February 29th in leap years
+        let new_day = if current_date.month() == Month::February
+            && current_date.day() == 29
+            && !is_leap_year(target_year)
+        {
+            28
+        } else {
+            current_date.day()
+        };
+
+        let new_date = Date::from_calendar_date(
+            target_year,
+            current_date.month(),
+            new_day,
+        )
+        .map_err(|_| DateTimeError::InvalidDate)?;
+
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(
+                new_date,
+                self.datetime.time(),
+            ),
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+
+    /// Adds a specified number of quarters to the `DateTime`.
+    ///
+    /// A quarter is three months, so month-end clamping follows
+    /// [`DateTime::add_months`].
+    ///
+    /// # Arguments
+    ///
+    /// * `quarters` - Number of quarters to add (can be negative for
+    ///   subtraction)
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateTimeError::InvalidDate`] if the number of months
+    /// overflows or the resulting date is out of the valid range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2023-11-30T00:00:00Z").unwrap();
+    /// let next = dt.add_quarters(1).unwrap();
+    /// assert_eq!(next.to_string(), "2024-02-29T00:00:00Z");
+    /// ```
+    pub fn add_quarters(
+        &self,
+        quarters: i32,
+    ) -> Result<Self, DateTimeError> {
+        let months = quarters
+            .checked_mul(3)
+            .ok_or(DateTimeError::InvalidDate)?;
+        self.add_months(months)
+    }
+
+    // -------------------------------------------------------------------------
+    // Range / Boundary Helper Methods
+    // -------------------------------------------------------------------------
+
+    /// Returns a new `DateTime` for the start of the current week (Monday).
+    ///
+    /// # Errors
+    ///
+    /// This function can return a [`DateTimeError`] if an overflow or
+    /// invalid date calculation occurs during date arithmetic.
+    pub fn start_of_week(&self) -> Result<Self, DateTimeError> {
+        let days_since_monday = i64::from(
+            self.datetime.weekday().number_days_from_monday(),
+        );
+        self.add_days(-days_since_monday)
+    }
+
+    /// Returns a new `DateTime` for the end of the current week (Sunday).
+    ///
+    /// # Errors
+    ///
+    /// This function can return a [`DateTimeError`] if an overflow or
+    /// invalid date calculation occurs during date arithmetic.
+    pub fn end_of_week(&self) -> Result<Self, DateTimeError> {
+        let days_until_sunday = 6 - i64::from(
+            self.datetime.weekday().number_days_from_monday(),
+        );
+        self.add_days(days_until_sunday)
+    }
+
+    /// Returns a new `DateTime` for the start of the current month.
+    ///
+    /// Prefer [`first_day_of_month`](Self::first_day_of_month), which
+    /// cannot fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn start_of_month(&self) -> Result<Self, DateTimeError> {
+        Ok(self.first_day_of_month())
+    }
+
+    /// Returns a new `DateTime` for the end of the current month.
+    ///
+    /// Prefer [`last_day_of_month`](Self::last_day_of_month), which cannot
+    /// fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn end_of_month(&self) -> Result<Self, DateTimeError> {
+        Ok(self.last_day_of_month())
+    }
+
+    /// Returns a new `DateTime` for the start of the current quarter.
+    ///
+    /// Prefer [`first_day_of_quarter`](Self::first_day_of_quarter), which
+    /// cannot fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn start_of_quarter(
+        &self,
+    ) -> Result<Self, DateTimeError> {
+        Ok(self.first_day_of_quarter())
+    }
+
+    /// Returns a new `DateTime` for the end of the current quarter.
+    ///
+    /// Prefer [`last_day_of_quarter`](Self::last_day_of_quarter), which
+    /// cannot fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn end_of_quarter(&self) -> Result<Self, DateTimeError> {
+        Ok(self.last_day_of_quarter())
+    }
+
+    /// Returns a new `DateTime` for the start of the current year.
+    ///
+    /// Prefer [`first_day_of_year`](Self::first_day_of_year), which cannot
+    /// fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn start_of_year(&self) -> Result<Self, DateTimeError> {
+        Ok(self.first_day_of_year())
+    }
+
+    /// Returns a new `DateTime` for the end of the current year.
+    ///
+    /// Prefer [`last_day_of_year`](Self::last_day_of_year), which cannot
+    /// fail.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` is kept for compatibility.
+    pub const fn end_of_year(&self) -> Result<Self, DateTimeError> {
+        Ok(self.last_day_of_year())
+    }
+
+    /// Returns the first day of the current month, keeping the time of day
+    /// and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.first_day_of_month().to_string(), "2024-02-01T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn first_day_of_month(&self) -> Self {
+        self.with_day_of_month(1)
+    }
+
+    /// Returns the last day of the current month, keeping the time of day
+    /// and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.last_day_of_month().to_string(), "2024-02-29T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn last_day_of_month(&self) -> Self {
+        let date = self.datetime.date();
+        self.with_day_of_month(date.month().length(date.year()))
+    }
+
+    /// Returns the first day of the current quarter, keeping the time of
+    /// day and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-05-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.first_day_of_quarter().to_string(), "2024-04-01T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn first_day_of_quarter(&self) -> Self {
+        let year = self.datetime.year();
+        let month = Month::January.nth_next((self.quarter() - 1) * 3);
+        self.with_date(Date::from_calendar_date(year, month, 1))
+    }
+
+    /// Returns the last day of the current quarter, keeping the time of
+    /// day and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-05-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.last_day_of_quarter().to_string(), "2024-06-30T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn last_day_of_quarter(&self) -> Self {
+        let year = self.datetime.year();
+        let month = Month::January.nth_next(self.quarter() * 3 - 1);
+        self.with_date(Date::from_calendar_date(
+            year,
+            month,
+            month.length(year),
+        ))
+    }
+
+    /// Returns January 1 of the current year, keeping the time of day and
+    /// offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-05-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.first_day_of_year().to_string(), "2024-01-01T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn first_day_of_year(&self) -> Self {
+        self.with_date(Date::from_ordinal_date(self.datetime.year(), 1))
+    }
+
+    /// Returns December 31 of the current year, keeping the time of day
+    /// and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::parse("2024-05-15T10:30:00Z").unwrap();
+    /// assert_eq!(dt.last_day_of_year().to_string(), "2024-12-31T10:30:00Z");
+    /// ```
+    #[must_use]
+    pub const fn last_day_of_year(&self) -> Self {
+        self.with_date(Date::from_calendar_date(
+            self.datetime.year(),
+            Month::December,
+            31,
+        ))
+    }
+
+    /// Returns the `n`th `weekday` of the current month, counting from 1,
+    /// keeping the time of day and offset. Returns `None` if `n` is 0 or
+    /// the month has fewer than `n` such days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Weekday;
+    ///
+    /// // Thanksgiving: the fourth Thursday of November.
+    /// let dt = DateTime::parse("2024-11-01T09:00:00Z").unwrap();
+    /// let thanksgiving = dt.nth_weekday_of_month(4, Weekday::Thursday).unwrap();
+    /// assert_eq!(thanksgiving.to_string(), "2024-11-28T09:00:00Z");
+    /// assert!(dt.nth_weekday_of_month(5, Weekday::Monday).is_none());
+    /// ```
+    #[must_use]
+    pub const fn nth_weekday_of_month(
+        &self,
+        n: u8,
+        weekday: Weekday,
+    ) -> Option<Self> {
+        let first = self.first_day_of_month();
+        let weeks = match n.checked_sub(1) {
+            Some(weeks) => weeks,
+            None => return None,
+        };
+        let day = match weeks.checked_mul(7) {
+            Some(days) => days
+                .checked_add(1 + days_until(first.weekday(), weekday)),
+            None => None,
+        };
+        match day {
+            Some(day) if day <= self.last_day_of_month().day() => {
+                Some(self.with_day_of_month(day))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the last `weekday` of the current month, keeping the time
+    /// of day and offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Weekday;
+    ///
+    /// // Memorial Day: the last Monday of May.
+    /// let dt = DateTime::parse("2024-05-10T00:00:00Z").unwrap();
+    /// let memorial = dt.last_weekday_of_month(Weekday::Monday);
+    /// assert_eq!(memorial.to_string(), "2024-05-27T00:00:00Z");
+    /// ```
+    #[must_use]
+    pub const fn last_weekday_of_month(
+        &self,
+        weekday: Weekday,
+    ) -> Self {
+        let last = self.last_day_of_month();
+        last.with_day_of_month(
+            last.day() - days_until(weekday, last.weekday()),
+        )
+    }
+
+    /// Returns the next `weekday` strictly after the current date, keeping
+    /// the time of day and offset. On that weekday itself, this is a week
+    /// later.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateTimeError`] if the result is past the supported
+    /// date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Weekday;
+    ///
+    /// let friday = DateTime::parse("2024-03-15T17:00:00Z").unwrap();
+    /// let monday = friday.next_occurrence_of(Weekday::Monday).unwrap();
+    /// assert_eq!(monday.to_string(), "2024-03-18T17:00:00Z");
+    ///
+    /// let next_friday = friday.next_occurrence_of(Weekday::Friday).unwrap();
+    /// assert_eq!(next_friday.to_string(), "2024-03-22T17:00:00Z");
+    /// ```
+    pub fn next_occurrence_of(
+        &self,
+        weekday: Weekday,
+    ) -> Result<Self, DateTimeError> {
+        let days = match days_until(self.weekday(), weekday) {
+            0 => 7,
+            days => days,
+        };
+        self.add_days(i64::from(days))
+    }
+
+    /// Moves to another day of the current month, which must exist.
+    const fn with_day_of_month(&self, day: u8) -> Self {
+        self.with_date(self.datetime.date().replace_day(day))
+    }
+
+    /// Replaces the date, keeping the time of day and offset.
+    ///
+    /// Callers only pass dates in the same year as `self`, which always
+    /// exist; an error leaves the value unchanged.
+    const fn with_date(
+        &self,
+        date: Result<Date, time::error::ComponentRange>,
+    ) -> Self {
+        match date {
+            Ok(date) => Self {
+                datetime: PrimitiveDateTime::new(
+                    date,
+                    self.datetime.time(),
+                ),
+                offset: self.offset,
+                zone: self.zone,
+            },
+            Err(_) => *self,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // In-place Mutation Methods
+    // -------------------------------------------------------------------------
+
+    /// Advances the `DateTime` by one second, in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn bump_second(&mut self) -> Result<(), DateTimeError> {
+        self.bump_by(Duration::SECOND)
+    }
+
+    /// Advances the `DateTime` by one minute, in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn bump_minute(&mut self) -> Result<(), DateTimeError> {
+        self.bump_by(Duration::MINUTE)
+    }
+
+    /// Advances the `DateTime` by one hour, in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn bump_hour(&mut self) -> Result<(), DateTimeError> {
+        self.bump_by(Duration::HOUR)
+    }
+
+    /// Advances the `DateTime` by one calendar day, in place, keeping the
+    /// time of day and offset.
+    ///
+    /// This is the mutating counterpart of [`next_day`](Self::next_day),
+    /// for loops that step through many days without creating a new value
+    /// each time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let mut dt = DateTime::parse("2024-02-28T09:00:00+01:00").unwrap();
+    /// dt.bump_day().unwrap();
+    /// assert_eq!(dt.to_string(), "2024-02-29T09:00:00+01:00");
+    /// dt.bump_day().unwrap();
+    /// assert_eq!(dt.to_string(), "2024-03-01T09:00:00+01:00");
+    /// ```
+    pub fn bump_day(&mut self) -> Result<(), DateTimeError> {
+        let date = self
+            .datetime
+            .date()
+            .next_day()
+            .ok_or(DateTimeError::InvalidDate)?;
+        self.datetime = self.datetime.replace_date(date);
+        Ok(())
+    }
+
+    /// Sets the time of day to midnight, in place.
+    pub fn make_start_of_day(&mut self) {
+        self.datetime = self.datetime.replace_time(Time::MIDNIGHT);
+    }
+
+    /// Moves to the first day of the current month, in place, keeping the
+    /// time of day and offset like
+    /// [`first_day_of_month`](Self::first_day_of_month).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let mut dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+    /// dt.make_start_of_month();
+    /// assert_eq!(dt.to_string(), "2024-02-01T10:30:00Z");
+    /// dt.make_start_of_day();
+    /// assert_eq!(dt.to_string(), "2024-02-01T00:00:00Z");
+    /// ```
+    pub fn make_start_of_month(&mut self) {
+        *self = self.first_day_of_month();
+    }
+
+    /// Adds `duration`, which may be negative, in place.
+    ///
+    /// Unlike `+=`, which saturates, this reports overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn advance(
+        &mut self,
+        duration: Duration,
+    ) -> Result<(), DateTimeError> {
+        self.bump_by(duration)
+    }
+
+    /// Adds `days` calendar days, which may be negative, in place.
+    ///
+    /// This is the mutating counterpart of [`add_days`](Self::add_days).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let mut dt = DateTime::parse("2024-02-27T09:00:00Z").unwrap();
+    /// dt.advance_days(3).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-03-01T09:00:00Z");
+    /// assert!(dt.advance_days(i64::MAX / 2).is_err());
+    /// assert_eq!(dt.to_string(), "2024-03-01T09:00:00Z");
+    /// ```
+    pub fn advance_days(
+        &mut self,
+        days: i64,
+    ) -> Result<(), DateTimeError> {
+        *self = self.add_days(days)?;
+        Ok(())
+    }
+
+    /// Adds `months` months, which may be negative, in place, clamping
+    /// the day to the end of the month as
+    /// [`add_months`](Self::add_months) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn advance_months(
+        &mut self,
+        months: i32,
+    ) -> Result<(), DateTimeError> {
+        *self = self.add_months(months)?;
+        Ok(())
+    }
+
+    /// Adds `years` years, which may be negative, in place, as
+    /// [`add_years`](Self::add_years) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result would fall
+    /// outside the supported date range, leaving the value unchanged.
+    pub fn advance_years(
+        &mut self,
+        years: i32,
+    ) -> Result<(), DateTimeError> {
+        *self = self.add_years(years)?;
+        Ok(())
+    }
+
+    /// Advances the wall clock by `duration`, in place.
+    fn bump_by(
+        &mut self,
+        duration: Duration,
+    ) -> Result<(), DateTimeError> {
+        self.datetime = self
+            .datetime
+            .checked_add(duration)
+            .ok_or(DateTimeError::InvalidDate)?;
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Rounding Methods
+    // -------------------------------------------------------------------------
+
+    /// Truncates the `DateTime` down to the start of the given unit.
+    ///
+    /// Truncation is applied to the wall-clock time in the current offset,
+    /// which is kept unchanged. Weeks start on Monday.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The calendar unit to truncate to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::{DateTime, Unit};
+    ///
+    /// let dt = DateTime::parse("2024-05-16T12:34:56Z").unwrap();
+    /// let hour = dt.truncate_to(Unit::Hour).unwrap();
+    /// assert_eq!(hour.to_string(), "2024-05-16T12:00:00Z");
+    /// let week = dt.truncate_to(Unit::Week).unwrap();
+    /// assert_eq!(week.to_string(), "2024-05-13T00:00:00Z");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the truncated value cannot be
+    /// represented (e.g., the start of the week falls before the minimum
+    /// supported date).
+    pub fn truncate_to(
+        &self,
+        unit: Unit,
+    ) -> Result<Self, DateTimeError> {
+        let date = self.datetime.date();
+        let time = self.datetime.time();
+        let (date, time) = match unit {
+            Unit::Second => (
+                date,
+                Time::from_hms(
+                    time.hour(),
+                    time.minute(),
+                    time.second(),
+                )?,
+            ),
+            Unit::Minute => {
+                (date, Time::from_hms(time.hour(), time.minute(), 0)?)
+            }
+            Unit::Hour => (date, Time::from_hms(time.hour(), 0, 0)?),
+            Unit::Day => (date, Time::MIDNIGHT),
+            Unit::Week => {
+                let days_since_monday =
+                    i64::from(date.weekday().number_days_from_monday());
+                let monday = date
+                    .checked_sub(Duration::days(days_since_monday))
+                    .ok_or(DateTimeError::InvalidDate)?;
+                (monday, Time::MIDNIGHT)
+            }
+            Unit::Month => (date.replace_day(1)?, Time::MIDNIGHT),
+            Unit::Year => (
+                Date::from_calendar_date(
+                    date.year(),
+                    Month::January,
+                    1,
+                )?,
+                Time::MIDNIGHT,
+            ),
+        };
+
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(date, time),
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+
+    /// Rounds the `DateTime` to the nearest boundary of the given unit.
+    ///
+    /// Values exactly halfway between two boundaries are rounded up. For
+    /// months and years, "nearest" is measured in elapsed time, so the
+    /// halfway point depends on the length of the month or year. Rounding
+    /// is applied to the wall-clock time in the current offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The calendar unit to round to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::{DateTime, Unit};
+    ///
+    /// let dt = DateTime::parse("2024-05-16T12:34:56Z").unwrap();
+    /// let hour = dt.round_to(Unit::Hour).unwrap();
+    /// assert_eq!(hour.to_string(), "2024-05-16T13:00:00Z");
+    /// let day = dt.round_to(Unit::Day).unwrap();
+    /// assert_eq!(day.to_string(), "2024-05-17T00:00:00Z");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the rounded value cannot be represented
+    /// (e.g., rounding up past the maximum supported date).
+    pub fn round_to(&self, unit: Unit) -> Result<Self, DateTimeError> {
+        let floor = self.truncate_to(unit)?;
+        if floor == *self {
+            return Ok(floor);
+        }
+
+        let ceil = match unit {
+            Unit::Month => floor.add_months(1)?,
+            Unit::Year => floor.add_years(1)?,
+            _ => (floor + unit.fixed_duration())?,
+        };
+
+        if self.duration_since(&floor) >= ceil.duration_since(self) {
+            Ok(ceil)
+        } else {
+            Ok(floor)
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Range Validation
+    // -------------------------------------------------------------------------
+
+    /// Checks if the current `DateTime` falls within a specific date range (inclusive).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start of the date range (inclusive)
+    /// * `end` - End of the date range (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the current `DateTime` falls within the range, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let start = dt.add_days(-1).unwrap_or(dt);
+    /// let end = dt.add_days(1).unwrap_or(dt);
+    ///
+    /// assert!(dt.is_within_range(&start, &end));
+    /// ```
+    #[must_use]
+    pub fn is_within_range(&self, start: &Self, end: &Self) -> bool {
+        self >= start && self <= end
+    }
+
+    // -------------------------------------------------------------------------
+    // Mutation Helpers
+    // -------------------------------------------------------------------------
+
+    /// Sets the date components while maintaining the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Calendar year
+    /// * `month` - Month (1-12)
+    /// * `day` - Day of month (1-31)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`
+    /// if the date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// let dt = DateTime::new();
+    /// let new_dt = dt.set_date(2024, 1, 1);
+    /// assert!(new_dt.is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the resulting date would be invalid.
+    ///
+    pub fn set_date(
+        &self,
+        year: i32,
         month: u8,
         day: u8,
     ) -> Result<Self, DateTimeError> {
@@ -1474,264 +4597,1502 @@ impl DateTime {
         let new_date = Date::from_calendar_date(year, month, day)
             .map_err(|_| DateTimeError::InvalidDate)?;
 
-        Ok(Self {
-            datetime: PrimitiveDateTime::new(
-                new_date,
-                self.datetime.time(),
-            ),
-            offset: self.offset,
-        })
+        Ok(Self {
+            datetime: PrimitiveDateTime::new(
+                new_date,
+                self.datetime.time(),
+            ),
+            offset: self.offset,
+            zone: self.zone,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Validation Methods
+// -----------------------------------------------------------------------------
+
+impl DateTime {
+    /// Validates every date and time component at once.
+    ///
+    /// Unlike [`DateTime::from_components`], which stops at the first
+    /// invalid component, this reports every problem so that, for example,
+    /// a form backend can show all errors together. The valid day range
+    /// depends on the year and month when both are valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Calendar year (-9999 to 9999)
+    /// * `month` - Month (1-12)
+    /// * `day` - Day of month (1 to the length of the month)
+    /// * `hour` - Hour (0-23)
+    /// * `minute` - Minute (0-59)
+    /// * `second` - Second (0-59)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// assert!(DateTime::validate_components(2024, 2, 29, 23, 59, 59).is_ok());
+    ///
+    /// let errors = DateTime::validate_components(2024, 13, 32, 0, 60, 0)
+    ///     .unwrap_err();
+    /// let names: Vec<&str> = errors.iter().map(|e| e.component).collect();
+    /// assert_eq!(names, ["month", "day", "minute"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns every out-of-range component, in order from year to second.
+    ///
+    pub fn validate_components(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<(), Vec<ComponentError>> {
+        let max_day = days_in_month(year, month)
+            .ok()
+            .filter(|_| (MIN_YEAR..=MAX_YEAR).contains(&year))
+            .unwrap_or(MAX_DAY);
+
+        let checks = [
+            (
+                "year",
+                i64::from(year),
+                i64::from(MIN_YEAR),
+                i64::from(MAX_YEAR),
+            ),
+            (
+                "month",
+                i64::from(month),
+                i64::from(MIN_MONTH),
+                i64::from(MAX_MONTH),
+            ),
+            (
+                "day",
+                i64::from(day),
+                i64::from(MIN_DAY),
+                i64::from(max_day),
+            ),
+            ("hour", i64::from(hour), 0, i64::from(MAX_HOUR)),
+            ("minute", i64::from(minute), 0, i64::from(MAX_MINUTE)),
+            ("second", i64::from(second), 0, i64::from(MAX_SECOND)),
+        ];
+
+        let errors: Vec<ComponentError> = checks
+            .iter()
+            .filter(|(_, value, minimum, maximum)| {
+                !(*minimum..=*maximum).contains(value)
+            })
+            .map(|&(component, value, minimum, maximum)| {
+                ComponentError {
+                    component,
+                    value,
+                    minimum,
+                    maximum,
+                }
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns `true` if the whole input matches a `time` format
+    /// description.
+    ///
+    /// The input may describe a date, a time, or a date and time, with or
+    /// without an offset, as long as the format string covers all of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    ///
+    /// assert!(DateTime::matches_format("2024-01-15", "[year]-[month]-[day]"));
+    /// assert!(DateTime::matches_format("09:30", "[hour]:[minute]"));
+    /// assert!(!DateTime::matches_format("2024-02-30", "[year]-[month]-[day]"));
+    /// assert!(!DateTime::matches_format("2024-01-15 ", "[year]-[month]-[day]"));
+    /// ```
+    #[must_use]
+    pub fn matches_format(input: &str, format: &str) -> bool {
+        format_description::parse(format).map_or(false, |desc| {
+            OffsetDateTime::parse(input, &desc).is_ok()
+                || PrimitiveDateTime::parse(input, &desc).is_ok()
+                || Date::parse(input, &desc).is_ok()
+                || Time::parse(input, &desc).is_ok()
+        })
+    }
+
+    /// Validates an RFC 3339 datetime or ISO 8601 calendar date string,
+    /// reporting every problem found.
+    ///
+    /// Accepts the same shapes as [`DateTime::parse`]:
+    /// `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)`. Each
+    /// issue names the component, its byte position, and why it failed.
+    /// Scanning stops at the first missing component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::ValidationIssueKind;
+    ///
+    /// assert!(DateTime::validate("2024-02-29T23:59:59.5+05:30").is_ok());
+    ///
+    /// let issues = DateTime::validate("2024-13-01T12:60").unwrap_err();
+    /// let names: Vec<&str> = issues.iter().map(|i| i.component).collect();
+    /// assert_eq!(names, ["month", "minute", "time separator"]);
+    /// assert_eq!(issues[2].kind, ValidationIssueKind::Missing);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns every issue found, in input order.
+    ///
+    pub fn validate(input: &str) -> Result<(), Vec<ValidationIssue>> {
+        let mut scanner = IssueScanner::new(input);
+
+        let year = scanner.number("year", 4);
+        scanner.separator("date separator", b"-");
+        let month = scanner.number("month", 2);
+        scanner.separator("date separator", b"-");
+        let day = scanner.number("day", 2);
+
+        scanner.range(month, MIN_MONTH.into(), MAX_MONTH.into());
+        let max_day = match (year, month) {
+            (Some((_, _, year)), Some((_, _, month))) => {
+                i32::try_from(year)
+                    .ok()
+                    .zip(u8::try_from(month).ok())
+                    .and_then(|(y, m)| days_in_month(y, m).ok())
+                    .unwrap_or(MAX_DAY)
+            }
+            _ => MAX_DAY,
+        };
+        scanner.range(day, MIN_DAY.into(), max_day.into());
+
+        if !scanner.at_end() {
+            scanner.separator("date-time separator", b"Tt ");
+            let hour = scanner.number("hour", 2);
+            scanner.separator("time separator", b":");
+            let minute = scanner.number("minute", 2);
+            scanner.separator("time separator", b":");
+            let second = scanner.number("second", 2);
+            scanner.range(hour, 0, MAX_HOUR.into());
+            scanner.range(minute, 0, MAX_MINUTE.into());
+            scanner.range(second, 0, MAX_SECOND.into());
+            scanner.fraction();
+            scanner.offset();
+            scanner.trailing();
+        }
+
+        scanner.finish()
+    }
+
+    /// Validates whether a number is a valid day of the month (1-31).
+    #[must_use]
+    pub const fn is_valid_day_u8(day: u8) -> bool {
+        day >= MIN_DAY && day <= MAX_DAY
+    }
+
+    /// Validates whether a number is a valid hour (0-23).
+    #[must_use]
+    pub const fn is_valid_hour_u8(hour: u8) -> bool {
+        hour <= MAX_HOUR
+    }
+
+    /// Validates whether a number is a valid minute (0-59).
+    #[must_use]
+    pub const fn is_valid_minute_u8(minute: u8) -> bool {
+        minute <= MAX_MINUTE
+    }
+
+    /// Validates whether a number is a valid second (0-59).
+    #[must_use]
+    pub const fn is_valid_second_u8(second: u8) -> bool {
+        second <= MAX_SECOND
+    }
+
+    /// Validates whether a number is a valid month (1-12).
+    #[must_use]
+    pub const fn is_valid_month_u8(month: u8) -> bool {
+        month >= MIN_MONTH && month <= MAX_MONTH
+    }
+
+    /// Validates whether a number is a valid microsecond (0-999,999).
+    #[must_use]
+    pub const fn is_valid_microsecond_u32(microsecond: u32) -> bool {
+        microsecond <= MAX_MICROSECOND
+    }
+
+    /// Validates whether a number is a valid ordinal day of the year
+    /// (1-366).
+    #[must_use]
+    pub const fn is_valid_ordinal_u16(ordinal: u16) -> bool {
+        ordinal >= MIN_ORDINAL_DAY && ordinal <= MAX_ORDINAL_DAY
+    }
+
+    /// Validates whether a number is a valid ISO week number (1-53).
+    #[must_use]
+    pub const fn is_valid_iso_week_u8(week: u8) -> bool {
+        week >= MIN_ISO_WEEK && week <= MAX_ISO_WEEK
+    }
+
+    /// Validates whether a string represents a valid day of the month.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_date` or `is_valid_day_u8`"
+    )]
+    #[must_use]
+    pub fn is_valid_day(day: &str) -> bool {
+        validators::is_valid_day(day)
+    }
+
+    /// Validates whether a string represents a valid hour.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_time` or `is_valid_hour_u8`"
+    )]
+    #[must_use]
+    pub fn is_valid_hour(hour: &str) -> bool {
+        validators::is_valid_hour(hour)
+    }
+
+    /// Validates whether a string represents a valid minute.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_time` or `is_valid_minute_u8`"
+    )]
+    #[must_use]
+    pub fn is_valid_minute(minute: &str) -> bool {
+        validators::is_valid_minute(minute)
+    }
+
+    /// Validates whether a string represents a valid second.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_time` or `is_valid_second_u8`"
+    )]
+    #[must_use]
+    pub fn is_valid_second(second: &str) -> bool {
+        validators::is_valid_second(second)
+    }
+
+    /// Validates whether a string represents a valid month.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_date` or `is_valid_month_u8`"
+    )]
+    #[must_use]
+    pub fn is_valid_month(month: &str) -> bool {
+        validators::is_valid_month(month)
+    }
+
+    /// Validates whether a string represents a valid year (-9999 to
+    /// 9999).
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_date`"
+    )]
+    #[must_use]
+    pub fn is_valid_year(year: &str) -> bool {
+        validators::is_valid_year(year)
+    }
+
+    /// Validates whether a string represents a valid microsecond.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_time` or `is_valid_microsecond_u32`"
+    )]
+    #[must_use]
+    pub fn is_valid_microsecond(microsecond: &str) -> bool {
+        validators::is_valid_microsecond(microsecond)
+    }
+
+    /// Validates whether a string represents a valid ordinal day of the year.
+    #[deprecated(since = "0.0.9", note = "use `is_valid_ordinal_u16`")]
+    #[must_use]
+    pub fn is_valid_ordinal(ordinal: &str) -> bool {
+        validators::is_valid_ordinal(ordinal)
+    }
+
+    /// Validates whether a string represents a valid ISO week number.
+    #[deprecated(since = "0.0.9", note = "use `is_valid_iso_week_u8`")]
+    #[must_use]
+    pub fn is_valid_iso_week(week: &str) -> bool {
+        validators::is_valid_iso_week(week)
+    }
+
+    /// Validates whether a string represents a valid time in `HH:MM:SS` format.
+    #[deprecated(
+        since = "0.0.9",
+        note = "use `dtt::validation::validate_time`"
+    )]
+    #[must_use]
+    pub fn is_valid_time(time: &str) -> bool {
+        validators::is_valid_time(time)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Standard Trait Implementations
+// -----------------------------------------------------------------------------
+
+impl fmt::Display for DateTime {
+    /// Formats the `DateTime` using RFC 3339 format. Use
+    /// [`DateTime::display_as`] for other styles.
+    ///
+    /// Values RFC 3339 cannot express, with a negative year or an offset
+    /// with seconds, are written in extended ISO 8601 instead, such as
+    /// `-0044-03-15T12:00:00+00:00:00`. Either way, `FromStr` parses the
+    /// text back to an equal value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let written = if self.year() >= 0
+            && self.offset.seconds_past_minute() == 0
+            && self.offset.whole_hours().unsigned_abs() < 24
+        {
+            self.write_rfc3339_into(f)
+        } else if self.nanosecond() == 0 {
+            self.write_described(f, DISPLAY_EXTENDED_FORMAT)
+        } else {
+            self.write_described(f, DISPLAY_EXTENDED_SUBSECOND_FORMAT)
+        };
+        written.map_err(|_| fmt::Error)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateTimeError;
+
+    /// Parses a string into a `DateTime` instance (RFC 3339 or ISO 8601),
+    /// as [`DateTime::parse_display`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_display(s)
+    }
+}
+
+impl From<OffsetDateTime> for DateTime {
+    /// Converts an `OffsetDateTime` into a `DateTime`, keeping its offset.
+    fn from(datetime: OffsetDateTime) -> Self {
+        Self::from_offset_datetime(datetime)
+    }
+}
+
+impl From<DateTime> for OffsetDateTime {
+    /// Converts a `DateTime` into an `OffsetDateTime`.
+    fn from(datetime: DateTime) -> Self {
+        datetime.to_offset_datetime()
+    }
+}
+
+impl Default for DateTime {
+    /// Returns the current UTC time as the default `DateTime` value.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = Result<Self, DateTimeError>;
+
+    /// Adds a Duration to the `DateTime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - Duration to add
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`.
+    fn add(self, rhs: Duration) -> Self::Output {
+        let maybe_new = self.datetime.checked_add(rhs);
+        maybe_new.map_or(
+            Err(DateTimeError::InvalidDate),
+            |new_datetime| {
+                Ok(Self {
+                    datetime: new_datetime,
+                    offset: self.offset,
+                    zone: self.zone,
+                })
+            },
+        )
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = Result<Self, DateTimeError>;
+
+    /// Subtracts a Duration from the `DateTime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - Duration to subtract
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let maybe_new = self.datetime.checked_sub(rhs);
+        maybe_new.map_or(
+            Err(DateTimeError::InvalidDate),
+            |new_datetime| {
+                Ok(Self {
+                    datetime: new_datetime,
+                    offset: self.offset,
+                    zone: self.zone,
+                })
+            },
+        )
+    }
+}
+
+impl AddAssign<Duration> for DateTime {
+    /// Adds a Duration in place, saturating at the first or last
+    /// representable wall-clock time. Use [`DateTime::advance`] to detect
+    /// overflow instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Duration;
+    ///
+    /// let mut dt = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+    /// for _ in 0..4 {
+    ///     dt += Duration::hours(6);
+    /// }
+    /// assert_eq!(dt.to_string(), "2024-01-02T00:00:00Z");
+    /// ```
+    fn add_assign(&mut self, rhs: Duration) {
+        self.datetime = self.datetime.saturating_add(rhs);
+    }
+}
+
+impl SubAssign<Duration> for DateTime {
+    /// Subtracts a Duration in place, saturating at the first or last
+    /// representable wall-clock time.
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.datetime = self.datetime.saturating_sub(rhs);
+    }
+}
+
+impl Sub for DateTime {
+    type Output = Duration;
+
+    /// Returns the signed duration from `rhs` to `self`, as
+    /// [`DateTime::duration_since`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use time::Duration;
+    ///
+    /// let start = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+    /// let end = DateTime::parse("2024-01-01T01:30:00+01:00").unwrap();
+    /// assert_eq!(end - start, Duration::minutes(30));
+    /// assert_eq!(start - end, Duration::minutes(-30));
+    /// ```
+    fn sub(self, rhs: Self) -> Duration {
+        self.duration_since(&rhs)
+    }
+}
+
+impl Add<StdDuration> for DateTime {
+    type Output = Result<Self, DateTimeError>;
+
+    /// Adds a `std::time::Duration` to the `DateTime`.
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is out of
+    /// range.
+    fn add(self, rhs: StdDuration) -> Self::Output {
+        self + Duration::try_from(rhs)
+            .map_err(|_| DateTimeError::InvalidDate)?
+    }
+}
+
+impl Sub<StdDuration> for DateTime {
+    type Output = Result<Self, DateTimeError>;
+
+    /// Subtracts a `std::time::Duration` from the `DateTime`.
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is out of
+    /// range.
+    fn sub(self, rhs: StdDuration) -> Self::Output {
+        self - Duration::try_from(rhs)
+            .map_err(|_| DateTimeError::InvalidDate)?
+    }
+}
+
+impl PartialEq for DateTime {
+    /// Compares the wall-clock times and offsets, ignoring the zone
+    /// abbreviation.
+    fn eq(&self, other: &Self) -> bool {
+        self.datetime == other.datetime && self.offset == other.offset
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    /// Compares two `DateTime` for ordering, returning `Some(Ordering)`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    /// Compares two `DateTimes` for ordering by their wall-clock times,
+    /// ignoring offsets. See [`DateTime::cmp_instant`] and
+    /// [`InstantOrdered`] to compare instants.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+}
+
+impl Hash for DateTime {
+    /// Hashes the Unix timestamp in nanoseconds and the offset in whole
+    /// seconds, the same fields as [`DateTime::stable_hash_u64`], rather
+    /// than the `time` crate's internal representation.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.unix_timestamp_nanos().hash(state);
+        self.offset.whole_seconds().hash(state);
     }
 }
 
 // -----------------------------------------------------------------------------
-// Validation Methods
+// Helper Functions
 // -----------------------------------------------------------------------------
 
-impl DateTime {
-    /// Validates whether a string represents a valid day of the month.
-    #[must_use]
-    pub fn is_valid_day(day: &str) -> bool {
-        day.parse::<u8>()
-            .map(|d| (1..=MAX_DAY).contains(&d))
-            .unwrap_or(false)
+/// The 64-bit FNV-1a offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The 64-bit FNV-1a prime.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Continues a 64-bit FNV-1a hash over `bytes`.
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut index = 0;
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        index += 1;
     }
+    hash
+}
 
-    /// Validates whether a string represents a valid hour.
+/// Returns how many days after `from` the next `to` falls, from 0 to 6.
+pub(crate) const fn days_until(from: Weekday, to: Weekday) -> u8 {
+    (to.number_days_from_monday() + 7 - from.number_days_from_monday())
+        % 7
+}
+
+/// Helper function to determine the number of days in a given month and year.
+///
+/// # Arguments
+///
+/// * `year` - Calendar year
+/// * `month` - Month number (1-12)
+///
+/// # Returns
+///
+/// Returns a `Result` containing either the number of days or a `DateTimeError`.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError` if the day in the month is invalid.
+///
+pub const fn days_in_month(
+    year: i32,
+    month: u8,
+) -> Result<u8, DateTimeError> {
+    days_in_month_in(year, month, Calendar::Gregorian)
+}
+
+/// Helper function to determine if a year is a leap year.
+///
+/// # Arguments
+///
+/// * `year` - Calendar year to check
+///
+/// # Returns
+///
+/// Returns `true` if the year is a leap year, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(2023));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(1900));
+/// ```
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+/// Largest offset from UTC, in seconds, used by any real-world timezone
+/// (±14:00).
+pub const MAX_REAL_OFFSET_SECONDS: i32 = MAX_OFFSET_SECONDS;
+
+/// Checks that an offset lies within the real-world ±14:00 range.
+///
+/// Offsets outside this range are representable but never correspond to
+/// an actual timezone, and usually indicate a bug in caller input.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::validate_offset;
+/// use time::UtcOffset;
+///
+/// let kiribati = UtcOffset::from_hms(14, 0, 0).unwrap();
+/// assert_eq!(validate_offset(kiribati), Ok(kiribati));
+/// let bogus = UtcOffset::from_hms(-14, -30, 0).unwrap();
+/// assert!(validate_offset(bogus).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidTimezone` if the offset is beyond
+/// ±14:00.
+pub const fn validate_offset(
+    offset: UtcOffset,
+) -> Result<UtcOffset, DateTimeError> {
+    let seconds = offset.whole_seconds();
+    if seconds > MAX_REAL_OFFSET_SECONDS
+        || seconds < -MAX_REAL_OFFSET_SECONDS
+    {
+        Err(DateTimeError::InvalidTimezone)
+    } else {
+        Ok(offset)
+    }
+}
+
+/// How [`DateTime::parse_with_options`] handles leap seconds.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum LeapSecondPolicy {
+    /// Reject leap seconds, like [`DateTime::parse`].
+    #[default]
+    Reject,
+    /// Read `23:59:60` as `23:59:59.999999999`.
+    Clamp,
+    /// Read `23:59:60` as `00:00:00` on the following day.
+    Carry,
+}
+
+/// Options for [`DateTime::parse_with_options`].
+///
+/// The default options are as strict as [`DateTime::parse`].
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct ParseOptions {
+    /// How leap seconds are handled.
+    pub leap_seconds: LeapSecondPolicy,
+    /// Whether offsets beyond the real-world ±14:00 range are accepted.
+    pub allow_extreme_offsets: bool,
+}
+
+impl ParseOptions {
+    /// Creates options that are as strict as [`DateTime::parse`].
     #[must_use]
-    pub fn is_valid_hour(hour: &str) -> bool {
-        hour.parse::<u8>().map(|h| h <= MAX_HOUR).unwrap_or(false)
+    pub const fn new() -> Self {
+        Self {
+            leap_seconds: LeapSecondPolicy::Reject,
+            allow_extreme_offsets: false,
+        }
     }
 
-    /// Validates whether a string represents a valid minute.
+    /// Sets how leap seconds are handled.
     #[must_use]
-    pub fn is_valid_minute(minute: &str) -> bool {
-        minute
-            .parse::<u8>()
-            .map(|m| m <= MAX_MIN_SEC)
-            .unwrap_or(false)
+    pub const fn leap_seconds(
+        mut self,
+        policy: LeapSecondPolicy,
+    ) -> Self {
+        self.leap_seconds = policy;
+        self
     }
 
-    /// Validates whether a string represents a valid second.
+    /// Sets whether offsets beyond the real-world ±14:00 range are
+    /// accepted.
     #[must_use]
-    pub fn is_valid_second(second: &str) -> bool {
-        second
-            .parse::<u8>()
-            .map(|s| s <= MAX_MIN_SEC)
-            .unwrap_or(false)
+    pub const fn allow_extreme_offsets(mut self, allow: bool) -> Self {
+        self.allow_extreme_offsets = allow;
+        self
     }
+}
 
-    /// Validates whether a string represents a valid month.
+/// The result of [`DateTime::parse_with_options`].
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub struct ParseOutcome {
+    /// The parsed value.
+    pub datetime: DateTime,
+    /// Whether the input contained a leap second.
+    pub leap_second: bool,
+}
+
+/// The result of time-of-day arithmetic such as [`DateTime::add_hours`].
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub struct Carried {
+    /// The new value.
+    pub datetime: DateTime,
+    /// How many days the local date moved: positive when midnight was
+    /// crossed forwards, negative when crossed backwards.
+    pub days: i64,
+}
+
+/// A `DateTime` that compares, orders, and hashes by its instant alone,
+/// so values with different offsets for the same moment are equal.
+///
+/// Use it to key maps and sets by absolute time; the wrapped value, and
+/// so its offset, is still available as `.0`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::{DateTime, InstantOrdered};
+/// use std::collections::BTreeSet;
+///
+/// let set: BTreeSet<InstantOrdered> = [
+///     "2024-01-01T21:00:00+09:00",
+///     "2024-01-01T12:00:00Z",
+///     "2024-01-01T12:30:00+01:00",
+/// ]
+/// .iter()
+/// .map(|text| InstantOrdered(DateTime::parse(text).unwrap()))
+/// .collect();
+///
+/// assert_eq!(set.len(), 2);
+/// let first = set.iter().next().unwrap();
+/// assert_eq!(first.0.to_string(), "2024-01-01T12:30:00+01:00");
+/// ```
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct InstantOrdered(pub DateTime);
+
+impl InstantOrdered {
+    /// Returns the wrapped `DateTime`.
     #[must_use]
-    pub fn is_valid_month(month: &str) -> bool {
-        month
-            .parse::<u8>()
-            .map(|m| (1..=MAX_MONTH).contains(&m))
-            .unwrap_or(false)
+    pub const fn into_inner(self) -> DateTime {
+        self.0
+    }
+}
+
+impl From<DateTime> for InstantOrdered {
+    fn from(datetime: DateTime) -> Self {
+        Self(datetime)
+    }
+}
+
+impl From<InstantOrdered> for DateTime {
+    fn from(key: InstantOrdered) -> Self {
+        key.0
+    }
+}
+
+impl PartialEq for InstantOrdered {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.same_instant_as(&other.0)
+    }
+}
+
+impl Eq for InstantOrdered {}
+
+impl PartialOrd for InstantOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    /// Validates whether a string represents a valid year.
-    #[must_use]
-    pub fn is_valid_year(year: &str) -> bool {
-        year.parse::<i32>().is_ok()
+impl Ord for InstantOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_instant(&other.0)
     }
+}
 
-    /// Validates whether a string represents a valid microsecond.
-    #[must_use]
-    pub fn is_valid_microsecond(microsecond: &str) -> bool {
-        microsecond
-            .parse::<u32>()
-            .map(|us| us <= MAX_MICROSECOND)
-            .unwrap_or(false)
+impl Hash for InstantOrdered {
+    /// Hashes the instant only, consistent with `Eq`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.unix_timestamp_nanos().hash(state);
     }
+}
 
-    /// Validates whether a string represents a valid ordinal day of the year.
-    #[must_use]
-    pub fn is_valid_ordinal(ordinal: &str) -> bool {
-        ordinal
-            .parse::<u16>()
-            .map(|o| (1..=MAX_ORDINAL_DAY).contains(&o))
-            .unwrap_or(false)
+impl fmt::Display for InstantOrdered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
     }
+}
 
-    /// Validates whether a string represents a valid ISO week number.
-    #[must_use]
-    pub fn is_valid_iso_week(week: &str) -> bool {
-        week.parse::<u8>()
-            .map(|w| (1..=MAX_ISO_WEEK).contains(&w))
-            .unwrap_or(false)
+/// Finds where `input` stops matching a format description.
+///
+/// Returns a `DateTimeError::InvalidFormatAt` for the first item that does
+/// not match, or for trailing input. If every item matches (so the failure
+/// lies in the parsed values rather than their layout), returns a plain
+/// `DateTimeError::InvalidFormat`.
+pub(crate) fn locate_format_error(
+    input: &str,
+    items: &[FormatItem<'_>],
+) -> DateTimeError {
+    let mut parsed = Parsed::new();
+    let mut remaining = input.as_bytes();
+    for item in items {
+        match parsed.parse_item(remaining, item) {
+            Ok(rest) => remaining = rest,
+            Err(error) => {
+                let expected = match error {
+                    ParseFromDescription::InvalidComponent(name) => {
+                        name
+                    }
+                    _ => "literal",
+                };
+                return DateTimeError::InvalidFormatAt {
+                    index: input.len() - remaining.len(),
+                    expected,
+                };
+            }
+        }
     }
-
-    /// Validates whether a string represents a valid time in `HH:MM:SS` format.
-    #[must_use]
-    pub fn is_valid_time(time: &str) -> bool {
-        let parts: Vec<&str> = time.split(':').collect();
-        if parts.len() != 3 {
-            return false;
+    if remaining.is_empty() {
+        DateTimeError::InvalidFormat
+    } else {
+        DateTimeError::InvalidFormatAt {
+            index: input.len() - remaining.len(),
+            expected: "end of input",
         }
-
-        Self::is_valid_hour(parts[0])
-            && Self::is_valid_minute(parts[1])
-            && Self::is_valid_second(parts[2])
     }
 }
 
-// -----------------------------------------------------------------------------
-// Standard Trait Implementations
-// -----------------------------------------------------------------------------
-
-impl fmt::Display for DateTime {
-    /// Formats the `DateTime` using RFC 3339 format.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.format_rfc3339()
-            .map_or(Err(fmt::Error), |s| write!(f, "{s}"))
+/// Returns the byte index of the seconds field if the input's time part
+/// has a seconds value of `60`.
+fn leap_second_index(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let separator =
+        bytes.iter().position(|b| matches!(b, b'T' | b't' | b' '))?;
+    let index = separator + 7;
+    if bytes.get(separator + 6) == Some(&b':')
+        && bytes.get(index..index + 2) == Some(&b"60"[..])
+    {
+        Some(index)
+    } else {
+        None
     }
 }
 
-impl FromStr for DateTime {
-    type Err = DateTimeError;
+/// Suggests the supported timezone abbreviation closest to `input`,
+/// including abbreviations registered with the global
+/// [`TimezoneRegistry`].
+///
+/// Intended for error messages after `DateTime::new_with_tz` or
+/// `DateTime::convert_to_tz` fail with `DateTimeError::InvalidTimezone`.
+/// Matching is case-insensitive and uses the optimal string alignment
+/// distance, so a transposition such as `"ETS"` counts as a single edit.
+///
+/// # Arguments
+///
+/// * `input` - The unrecognized timezone abbreviation
+///
+/// # Returns
+///
+/// The closest abbreviation within two edits, preferring the
+/// alphabetically first on ties, or `None` if nothing is close enough.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::suggest_timezone;
+///
+/// assert_eq!(suggest_timezone("ETS"), Some("EST"));
+/// assert_eq!(suggest_timezone("jst"), Some("JST"));
+/// assert_eq!(suggest_timezone("Mars/Olympus"), None);
+/// ```
+#[must_use]
+pub fn suggest_timezone(input: &str) -> Option<&'static str> {
+    let input = input.trim().to_ascii_uppercase();
+    let max_distance = 2.min(input.chars().count().saturating_sub(1));
+
+    supported_timezones()
+        .into_iter()
+        .map(|name| (edit_distance(&input, name), name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, name)| name)
+}
 
-    /// Parses a string into a `DateTime` instance (RFC 3339 or ISO 8601).
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse(s)
-    }
+/// Returns the timezone abbreviations understood by the parser and
+/// conversion methods, sorted alphabetically: the built-in table and any
+/// abbreviations registered with the global [`TimezoneRegistry`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::supported_timezones;
+///
+/// let zones = supported_timezones();
+/// assert!(zones.contains(&"UTC"));
+/// assert!(zones.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+#[must_use]
+pub fn supported_timezones() -> Vec<&'static str> {
+    let mut names = TimezoneRegistry::global().names();
+    names.extend(TIMEZONE_OFFSETS.iter().map(|(name, _)| *name));
+    names.sort_unstable();
+    names.dedup();
+    names
 }
 
-impl Default for DateTime {
-    /// Returns the current UTC time as the default `DateTime` value.
-    fn default() -> Self {
-        Self::new()
+/// Counts the whole calendar months from `start` to `end`, assuming
+/// `start <= end`. A day of the month missing from a shorter month is
+/// reached on that month's last day.
+fn whole_months_between(start: Date, end: Date) -> i32 {
+    let mut months = (end.year() - start.year()) * 12
+        + (i32::from(end.month() as u8)
+            - i32::from(start.month() as u8));
+    let anniversary = start.day().min(end.month().length(end.year()));
+    if end.day() < anniversary {
+        months -= 1;
     }
+    months
 }
 
-impl Add<Duration> for DateTime {
-    type Output = Result<Self, DateTimeError>;
+/// Computes the optimal string alignment distance between two strings:
+/// the number of insertions, deletions, substitutions, and adjacent
+/// transpositions needed to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
 
-    /// Adds a Duration to the `DateTime`.
-    ///
-    /// # Arguments
-    ///
-    /// * `rhs` - Duration to add
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`.
-    fn add(self, rhs: Duration) -> Self::Output {
-        let maybe_new = self.datetime.checked_add(rhs);
-        maybe_new.map_or(
-            Err(DateTimeError::InvalidDate),
-            |new_datetime| {
-                Ok(Self {
-                    datetime: new_datetime,
-                    offset: self.offset,
-                })
-            },
-        )
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1
+                && j > 1
+                && a[i - 1] == b[j - 2]
+                && a[i - 2] == b[j - 1]
+            {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
     }
+    rows[a.len()][b.len()]
 }
 
-impl Sub<Duration> for DateTime {
-    type Output = Result<Self, DateTimeError>;
+/// Calendar systems supported by the leap-year helpers.
+///
+/// The Gregorian calendar is the default and is used throughout the rest
+/// of the crate. The proleptic Julian calendar is provided for historical
+/// dates, in particular those before the 1582 Gregorian reform.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum Calendar {
+    /// The proleptic Gregorian calendar (every 4 years, except centuries
+    /// not divisible by 400).
+    #[default]
+    Gregorian,
+    /// The proleptic Julian calendar (every 4 years, without exception).
+    Julian,
+}
 
-    /// Subtracts a Duration from the `DateTime`.
-    ///
-    /// # Arguments
-    ///
-    /// * `rhs` - Duration to subtract
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing either the new `DateTime` or a `DateTimeError`.
-    fn sub(self, rhs: Duration) -> Self::Output {
-        let maybe_new = self.datetime.checked_sub(rhs);
-        maybe_new.map_or(
-            Err(DateTimeError::InvalidDate),
-            |new_datetime| {
-                Ok(Self {
-                    datetime: new_datetime,
-                    offset: self.offset,
-                })
-            },
-        )
-    }
+/// Calendar units used by [`DateTime::truncate_to`],
+/// [`DateTime::round_to`], and [`DateTime::diff_in`].
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum Unit {
+    /// Whole seconds.
+    Second,
+    /// Whole minutes.
+    Minute,
+    /// Whole hours.
+    Hour,
+    /// Calendar days, starting at midnight.
+    Day,
+    /// ISO weeks, starting on Monday at midnight.
+    Week,
+    /// Calendar months, starting on the first day at midnight.
+    Month,
+    /// Calendar years, starting on January 1 at midnight.
+    Year,
 }
 
-impl PartialOrd for DateTime {
-    /// Compares two `DateTime` for ordering, returning `Some(Ordering)`.
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl Unit {
+    /// Returns the exact length of the unit, for units that have one.
+    ///
+    /// Months and years vary in length and are handled separately.
+    const fn fixed_duration(self) -> Duration {
+        match self {
+            Self::Second => Duration::SECOND,
+            Self::Minute => Duration::MINUTE,
+            Self::Hour => Duration::HOUR,
+            Self::Day => Duration::DAY,
+            Self::Week | Self::Month | Self::Year => Duration::WEEK,
+        }
     }
 }
 
-impl Ord for DateTime {
-    /// Compares two `DateTimes` for ordering.
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.datetime.cmp(&other.datetime)
+/// Splits the half-open span `[start, end)` into ISO weeks.
+///
+/// Each item is `(iso_year, iso_week, (from, to))`, where `(from, to)` is
+/// the half-open part of the span that falls in that week. The first and
+/// last weeks are clipped to the span, so a week that straddles a year
+/// boundary is reported once, under its ISO year. Weeks are computed on
+/// the wall clock of `start`; `end` is first converted to that offset.
+///
+/// An empty or reversed span yields nothing.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::{iter_iso_weeks, DateTime};
+///
+/// let start = DateTime::parse("2020-12-30T00:00:00Z").unwrap();
+/// let end = DateTime::parse("2021-01-06T12:00:00Z").unwrap();
+///
+/// let weeks: Vec<_> = iter_iso_weeks(&start, &end)
+///     .map(|(year, week, (from, to))| {
+///         (year, week, from.to_string(), to.to_string())
+///     })
+///     .collect();
+/// assert_eq!(
+///     weeks,
+///     [
+///         (
+///             2020,
+///             53,
+///             "2020-12-30T00:00:00Z".to_string(),
+///             "2021-01-04T00:00:00Z".to_string(),
+///         ),
+///         (
+///             2021,
+///             1,
+///             "2021-01-04T00:00:00Z".to_string(),
+///             "2021-01-06T12:00:00Z".to_string(),
+///         ),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn iter_iso_weeks(start: &DateTime, end: &DateTime) -> IsoWeeks {
+    let end = end.saturating_convert_to_offset(start.offset);
+    IsoWeeks {
+        next: Some(*start),
+        end,
     }
 }
 
-impl Hash for DateTime {
-    /// Computes a hash value for the `DateTime` based on its components.
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.datetime.hash(state);
-        self.offset.hash(state);
+/// Iterator over the ISO weeks of a span, created by [`iter_iso_weeks`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IsoWeeks {
+    next: Option<DateTime>,
+    end: DateTime,
+}
+
+impl Iterator for IsoWeeks {
+    type Item = (i32, u8, (DateTime, DateTime));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let from = self.next.take().filter(|from| *from < self.end)?;
+        let (year, week, _) = from.to_iso_week_date();
+        let to = from
+            .truncate_to(Unit::Week)
+            .and_then(|monday| monday.add_days(7))
+            .map_or(self.end, |boundary| boundary.min(self.end));
+        self.next = Some(to);
+        Some((year, week, (from, to)))
     }
 }
 
-// -----------------------------------------------------------------------------
-// Helper Functions
-// -----------------------------------------------------------------------------
+impl std::iter::FusedIterator for IsoWeeks {}
 
-/// Helper function to determine the number of days in a given month and year.
+/// Helper function to determine if a year is a leap year in the proleptic
+/// Julian calendar.
 ///
 /// # Arguments
 ///
-/// * `year` - Calendar year
-/// * `month` - Month number (1-12)
+/// * `year` - Calendar year to check (astronomical numbering, so 1 BC is 0)
 ///
 /// # Returns
 ///
-/// Returns a `Result` containing either the number of days or a `DateTimeError`.
+/// Returns `true` if the year is a Julian leap year, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::is_leap_year_julian;
+///
+/// assert!(is_leap_year_julian(1900));
+/// assert!(is_leap_year_julian(1500));
+/// assert!(!is_leap_year_julian(1501));
+/// assert!(is_leap_year_julian(-4));
+/// ```
+#[must_use]
+pub const fn is_leap_year_julian(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// Helper function to determine if a year is a leap year in the given
+/// calendar.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::{is_leap_year_in, Calendar};
+///
+/// assert!(!is_leap_year_in(1900, Calendar::Gregorian));
+/// assert!(is_leap_year_in(1900, Calendar::Julian));
+/// ```
+#[must_use]
+pub const fn is_leap_year_in(year: i32, calendar: Calendar) -> bool {
+    match calendar {
+        Calendar::Gregorian => is_leap_year(year),
+        Calendar::Julian => is_leap_year_julian(year),
+    }
+}
+
+/// Helper function to determine the number of days in a given month and
+/// year of the given calendar.
 ///
 /// # Errors
 ///
-/// Returns a `DateTimeError` if the day in the month is invalid.
+/// Returns a `DateTimeError` if the month is not in the range 1-12.
 ///
-pub const fn days_in_month(
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::{days_in_month_in, Calendar};
+///
+/// assert_eq!(days_in_month_in(1900, 2, Calendar::Gregorian), Ok(28));
+/// assert_eq!(days_in_month_in(1900, 2, Calendar::Julian), Ok(29));
+/// ```
+pub const fn days_in_month_in(
     year: i32,
     month: u8,
+    calendar: Calendar,
 ) -> Result<u8, DateTimeError> {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => Ok(31),
-        4 | 6 | 9 | 11 => Ok(30),
-        2 => Ok(if is_leap_year(year) { 29 } else { 28 }),
-        _ => Err(DateTimeError::InvalidDate),
+    if month < 1 || month > 12 {
+        return Err(DateTimeError::InvalidDate);
     }
+    let lengths = if is_leap_year_in(year, calendar) {
+        &MONTH_LENGTHS[1]
+    } else {
+        &MONTH_LENGTHS[0]
+    };
+    Ok(lengths[month as usize - 1])
 }
 
-/// Helper function to determine if a year is a leap year.
+/// Returns the number of days in `year` before the first day of `month`,
+/// so that the ordinal of a date is this value plus its day of the
+/// month.
 ///
-/// # Arguments
-///
-/// * `year` - Calendar year to check
-///
-/// # Returns
+/// # Errors
 ///
-/// Returns `true` if the year is a leap year, `false` otherwise.
+/// Returns a `DateTimeError::InvalidDate` if the month is not in the
+/// range 1-12.
 ///
 /// # Examples
 ///
 /// ```
-/// use dtt::datetime::is_leap_year;
+/// use dtt::datetime::cumulative_days_before_month;
 ///
-/// assert!(is_leap_year(2024));
-/// assert!(!is_leap_year(2023));
-/// assert!(is_leap_year(2000));
-/// assert!(!is_leap_year(1900));
+/// assert_eq!(cumulative_days_before_month(2023, 1), Ok(0));
+/// assert_eq!(cumulative_days_before_month(2023, 3), Ok(59));
+/// assert_eq!(cumulative_days_before_month(2024, 3), Ok(60));
+/// assert_eq!(cumulative_days_before_month(2024, 12), Ok(335));
 /// ```
-#[must_use]
-pub const fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+pub const fn cumulative_days_before_month(
+    year: i32,
+    month: u8,
+) -> Result<u16, DateTimeError> {
+    if month < 1 || month > 12 {
+        return Err(DateTimeError::InvalidDate);
+    }
+    let cumulative = if is_leap_year(year) {
+        &CUMULATIVE_DAYS[1]
+    } else {
+        &CUMULATIVE_DAYS[0]
+    };
+    Ok(cumulative[month as usize - 1])
+}
+
+/// Days in each month, indexed from January, for common years (row 0)
+/// and leap years (row 1).
+const MONTH_LENGTHS: [[u8; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// Days before the first of each month, indexed from January, for common
+/// years (row 0) and leap years (row 1). The last entry is the length of
+/// the year.
+const CUMULATIVE_DAYS: [[u16; 13]; 2] = [
+    cumulative_days(&MONTH_LENGTHS[0]),
+    cumulative_days(&MONTH_LENGTHS[1]),
+];
+
+/// Builds a row of [`CUMULATIVE_DAYS`] from a row of [`MONTH_LENGTHS`].
+const fn cumulative_days(lengths: &[u8; 12]) -> [u16; 13] {
+    let mut cumulative = [0; 13];
+    let mut month = 0;
+    while month < 12 {
+        cumulative[month + 1] =
+            cumulative[month] + lengths[month] as u16;
+        month += 1;
+    }
+    cumulative
+}
+
+/// A component scanned by [`IssueScanner`]: its name, start position, and
+/// value.
+type Scanned = Option<(&'static str, usize, i64)>;
+
+/// Walks a datetime string for [`DateTime::validate`], collecting issues
+/// instead of stopping at the first one.
+struct IssueScanner<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    ended: bool,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<'a> IssueScanner<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self {
+            bytes: input.as_bytes(),
+            position: 0,
+            ended: false,
+            issues: Vec::new(),
+        }
+    }
+
+    const fn at_end(&self) -> bool {
+        self.ended || self.position >= self.bytes.len()
+    }
+
+    fn push(
+        &mut self,
+        component: &'static str,
+        position: usize,
+        kind: ValidationIssueKind,
+    ) {
+        self.issues.push(ValidationIssue {
+            component,
+            position,
+            kind,
+        });
+    }
+
+    /// Records a missing component and stops scanning if the input is
+    /// exhausted. Returns `true` if scanning should continue.
+    fn present(&mut self, component: &'static str) -> bool {
+        if self.ended {
+            return false;
+        }
+        if self.position >= self.bytes.len() {
+            self.ended = true;
+            self.push(
+                component,
+                self.position,
+                ValidationIssueKind::Missing,
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Reads a fixed-width unsigned number.
+    fn number(
+        &mut self,
+        component: &'static str,
+        width: usize,
+    ) -> Scanned {
+        if !self.present(component) {
+            return None;
+        }
+        let start = self.position;
+        let end = (start + width).min(self.bytes.len());
+        self.position = end;
+        let digits = &self.bytes[start..end];
+        if digits.len() < width
+            || !digits.iter().all(u8::is_ascii_digit)
+        {
+            self.push(component, start, ValidationIssueKind::Malformed);
+            return None;
+        }
+        let value = digits
+            .iter()
+            .fold(0, |acc, b| acc * 10 + i64::from(b - b'0'));
+        Some((component, start, value))
+    }
+
+    /// Reads a single separator byte, which must be one of `allowed`.
+    fn separator(&mut self, component: &'static str, allowed: &[u8]) {
+        if !self.present(component) {
+            return;
+        }
+        if !allowed.contains(&self.bytes[self.position]) {
+            self.push(
+                component,
+                self.position,
+                ValidationIssueKind::Malformed,
+            );
+        }
+        self.position += 1;
+    }
+
+    /// Checks a scanned number against an inclusive range.
+    fn range(&mut self, scanned: Scanned, minimum: i64, maximum: i64) {
+        if let Some((component, position, value)) = scanned {
+            if !(minimum..=maximum).contains(&value) {
+                self.push(
+                    component,
+                    position,
+                    ValidationIssueKind::OutOfRange {
+                        value,
+                        minimum,
+                        maximum,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Reads an optional `.digits` fraction of a second.
+    fn fraction(&mut self) {
+        if self.at_end() || self.bytes[self.position] != b'.' {
+            return;
+        }
+        let start = self.position;
+        self.position += 1;
+        let digits = self.bytes[self.position..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 || digits > 9 {
+            self.push(
+                "fraction",
+                start,
+                ValidationIssueKind::Malformed,
+            );
+        }
+        self.position += digits;
+    }
+
+    /// Reads a `Z` or `±HH:MM` offset.
+    fn offset(&mut self) {
+        if !self.present("offset") {
+            return;
+        }
+        let start = self.position;
+        match self.bytes[start] {
+            b'Z' | b'z' => self.position += 1,
+            sign @ (b'+' | b'-') => {
+                self.position += 1;
+                let hours = self.number("offset hour", 2);
+                self.separator("offset separator", b":");
+                let minutes = self.number("offset minute", 2);
+                self.range(hours, 0, MAX_HOUR.into());
+                self.range(minutes, 0, MAX_MINUTE.into());
+                if let (Some((_, _, h)), Some((_, _, m))) =
+                    (hours, minutes)
+                {
+                    let seconds = (h * 3_600 + m * 60)
+                        * if sign == b'-' { -1 } else { 1 };
+                    let limit = i64::from(MAX_REAL_OFFSET_SECONDS);
+                    if h <= MAX_HOUR.into() && seconds.abs() > limit {
+                        self.push(
+                            "offset",
+                            start,
+                            ValidationIssueKind::OutOfRange {
+                                value: seconds,
+                                minimum: -limit,
+                                maximum: limit,
+                            },
+                        );
+                    }
+                }
+            }
+            _ => {
+                self.push(
+                    "offset",
+                    start,
+                    ValidationIssueKind::Malformed,
+                );
+                self.position = self.bytes.len();
+            }
+        }
+    }
+
+    /// Reports any input left after a complete datetime.
+    fn trailing(&mut self) {
+        if !self.at_end() {
+            self.push(
+                "input",
+                self.position,
+                ValidationIssueKind::TrailingInput,
+            );
+        }
+    }
+
+    fn finish(mut self) -> Result<(), Vec<ValidationIssue>> {
+        self.issues.sort_by_key(|issue| issue.position);
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(self.issues)
+        }
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1912,6 +6273,26 @@ mod tests {
     }
 
     #[test]
+    fn test_leap_year_julian() {
+        assert!(is_leap_year_julian(1900));
+        assert!(is_leap_year_julian(1700));
+        assert!(!is_leap_year_julian(1901));
+        assert!(is_leap_year_julian(0));
+        assert!(is_leap_year_julian(-4));
+        assert!(!is_leap_year_julian(-1));
+
+        assert_eq!(Calendar::default(), Calendar::Gregorian);
+        assert!(!is_leap_year_in(1900, Calendar::default()));
+        assert!(is_leap_year_in(1900, Calendar::Julian));
+        assert!(matches!(
+            days_in_month_in(1900, 2, Calendar::Julian),
+            Ok(29)
+        ));
+        assert!(days_in_month_in(1900, 13, Calendar::Julian).is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_validation() {
         // Test day validation
         assert!(DateTime::is_valid_day("1"));
@@ -2131,4 +6512,15 @@ mod tests {
             assert_eq!(value.second(), 45);
         }
     }
+
+    #[test]
+    fn test_timezone_table_is_sorted() {
+        assert!(TIMEZONE_OFFSETS
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        for (name, offset) in TIMEZONE_OFFSETS {
+            assert_eq!(timezone_offset(name), Some(*offset));
+        }
+        assert_eq!(timezone_offset("XYZ"), None);
+    }
 }
@@ -0,0 +1,199 @@
+// delta.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Calendar-aware differences between two `DateTime` values.
+//!
+//! Unlike [`DateTime::duration_since`], which yields an exact `Duration`,
+//! [`DateTime::difference`] breaks the gap down into years, months, days,
+//! hours, minutes, and seconds, in the spirit of Python's `relativedelta`.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//!
+//! let start = DateTime::parse("2023-01-31T08:00:00Z").unwrap();
+//! let end = DateTime::parse("2024-03-01T10:30:00Z").unwrap();
+//!
+//! let delta = end.difference(&start);
+//! assert_eq!((delta.years, delta.months, delta.days), (1, 1, 1));
+//! assert_eq!((delta.hours, delta.minutes), (2, 30));
+//! ```
+
+use crate::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use time::UtcOffset;
+
+/// A calendar-aware breakdown of the difference between two `DateTime`s.
+///
+/// All non-zero fields share the same sign: they are positive when the
+/// receiver of [`DateTime::difference`] is later than its argument, and
+/// negative otherwise.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct DateDelta {
+    /// Whole years.
+    pub years: i32,
+    /// Whole months (-11 to 11).
+    pub months: i32,
+    /// Whole days (-30 to 30).
+    pub days: i32,
+    /// Whole hours (-23 to 23).
+    pub hours: i32,
+    /// Whole minutes (-59 to 59).
+    pub minutes: i32,
+    /// Whole seconds (-59 to 59).
+    pub seconds: i32,
+    /// Remaining nanoseconds (-999,999,999 to 999,999,999).
+    pub nanoseconds: i32,
+}
+
+impl DateDelta {
+    /// Computes the difference `end - start`.
+    ///
+    /// `start` is first converted to the offset of `end`, so that the
+    /// breakdown is computed on the same wall clock. If `start` cannot be
+    /// shown in that offset, both are converted to UTC instead, clamping
+    /// to the representable range.
+    #[must_use]
+    pub fn between(start: &DateTime, end: &DateTime) -> Self {
+        let (start, end) =
+            start.try_convert_to_offset(end.offset()).map_or_else(
+                |_| {
+                    (
+                        start.saturating_convert_to_offset(
+                            UtcOffset::UTC,
+                        ),
+                        end.saturating_convert_to_offset(
+                            UtcOffset::UTC,
+                        ),
+                    )
+                },
+                |start| (start, *end),
+            );
+
+        if end >= start {
+            Self::forward(&start, &end)
+        } else {
+            Self::forward(&end, &start).negate()
+        }
+    }
+
+    /// Computes the positive difference from `start` to `end`, assuming
+    /// `start <= end` and both share the same offset.
+    fn forward(start: &DateTime, end: &DateTime) -> Self {
+        let mut months = (end.year() - start.year()) * 12
+            + (i32::from(end.month() as u8)
+                - i32::from(start.month() as u8));
+
+        let mut anchor = start.add_months(months).unwrap_or(*start);
+        while months > 0 && anchor > *end {
+            months -= 1;
+            anchor = start.add_months(months).unwrap_or(*start);
+        }
+
+        let remainder = end.duration_since(&anchor);
+        let total_seconds = remainder.whole_seconds();
+
+        Self {
+            years: months / 12,
+            months: months % 12,
+            days: i32::try_from(total_seconds / 86_400).unwrap_or(0),
+            hours: i32::try_from((total_seconds % 86_400) / 3_600)
+                .unwrap_or(0),
+            minutes: i32::try_from((total_seconds % 3_600) / 60)
+                .unwrap_or(0),
+            seconds: i32::try_from(total_seconds % 60).unwrap_or(0),
+            nanoseconds: remainder.subsec_nanoseconds(),
+        }
+    }
+
+    /// Returns the delta with every field negated.
+    #[must_use]
+    pub const fn negate(self) -> Self {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            nanoseconds: -self.nanoseconds,
+        }
+    }
+
+    /// Returns `true` if every field is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0
+            && self.months == 0
+            && self.days == 0
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0
+            && self.nanoseconds == 0
+    }
+
+    /// Returns `true` if the delta points backwards in time.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.years < 0
+            || self.months < 0
+            || self.days < 0
+            || self.hours < 0
+            || self.minutes < 0
+            || self.seconds < 0
+            || self.nanoseconds < 0
+    }
+}
+
+impl fmt::Display for DateDelta {
+    /// Formats the delta as a human-readable list of its non-zero
+    /// components, e.g. `1 year, 2 months, 3 days`.
+    ///
+    /// Negative deltas are prefixed with `-`. A zero delta renders as
+    /// `0 seconds`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+        ];
+
+        let rendered: Vec<String> = parts
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, unit)| {
+                let value = value.unsigned_abs();
+                if value == 1 {
+                    format!("{value} {unit}")
+                } else {
+                    format!("{value} {unit}s")
+                }
+            })
+            .collect();
+
+        if rendered.is_empty() {
+            return write!(f, "0 seconds");
+        }
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", rendered.join(", "))
+    }
+}
@@ -0,0 +1,222 @@
+// diff.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Human-readable comparison reports between two `DateTime` values.
+//!
+//! A [`DiffReport`] lists every wall-clock component that differs between
+//! two values, together with the offset-normalized delta between the two
+//! instants. It is primarily intended to make failing timestamp assertions
+//! readable.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use time::UtcOffset;
+//!
+//! let a = DateTime::from_components(2024, 1, 1, 12, 0, 0, UtcOffset::UTC).unwrap();
+//! let b = DateTime::from_components(2024, 1, 1, 13, 30, 0, UtcOffset::UTC).unwrap();
+//!
+//! let report = a.diff_report(&b);
+//! assert!(!report.is_identical());
+//! println!("{report}");
+//! ```
+
+use crate::datetime::DateTime;
+use std::fmt;
+use time::Duration;
+
+/// A single component of a `DateTime` that may differ between two values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DiffComponent {
+    /// Calendar year.
+    Year,
+    /// Month of the year (1-12).
+    Month,
+    /// Day of the month.
+    Day,
+    /// Hour of the day.
+    Hour,
+    /// Minute of the hour.
+    Minute,
+    /// Second of the minute.
+    Second,
+    /// Sub-second nanoseconds.
+    Nanosecond,
+    /// UTC offset, in seconds.
+    Offset,
+}
+
+impl DiffComponent {
+    /// Returns the lowercase name of the component.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Nanosecond => "nanosecond",
+            Self::Offset => "offset",
+        }
+    }
+}
+
+impl fmt::Display for DiffComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A component whose value differs between the two compared `DateTime`s.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ComponentDiff {
+    /// The component that differs.
+    pub component: DiffComponent,
+    /// The value on the left-hand side (`self`).
+    pub left: i64,
+    /// The value on the right-hand side (`other`).
+    pub right: i64,
+}
+
+/// A comparison report between two `DateTime` values.
+///
+/// Created by [`DateTime::diff_report`]. The `Display` implementation
+/// renders a multi-line summary suitable for assertion messages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiffReport {
+    left: DateTime,
+    right: DateTime,
+    differences: Vec<ComponentDiff>,
+    delta: Duration,
+}
+
+impl DiffReport {
+    /// Builds a report comparing `left` against `right`.
+    #[must_use]
+    pub fn new(left: &DateTime, right: &DateTime) -> Self {
+        let values = |dt: &DateTime| {
+            [
+                (DiffComponent::Year, i64::from(dt.year())),
+                (DiffComponent::Month, i64::from(dt.month() as u8)),
+                (DiffComponent::Day, i64::from(dt.day())),
+                (DiffComponent::Hour, i64::from(dt.hour())),
+                (DiffComponent::Minute, i64::from(dt.minute())),
+                (DiffComponent::Second, i64::from(dt.second())),
+                (DiffComponent::Nanosecond, i64::from(dt.nanosecond())),
+                (
+                    DiffComponent::Offset,
+                    i64::from(dt.offset().whole_seconds()),
+                ),
+            ]
+        };
+
+        let differences = values(left)
+            .iter()
+            .zip(values(right).iter())
+            .filter(|((_, l), (_, r))| l != r)
+            .map(|(&(component, l), &(_, r))| ComponentDiff {
+                component,
+                left: l,
+                right: r,
+            })
+            .collect();
+
+        Self {
+            left: *left,
+            right: *right,
+            differences,
+            delta: right.duration_since(left),
+        }
+    }
+
+    /// Returns the left-hand `DateTime`.
+    #[must_use]
+    pub const fn left(&self) -> &DateTime {
+        &self.left
+    }
+
+    /// Returns the right-hand `DateTime`.
+    #[must_use]
+    pub const fn right(&self) -> &DateTime {
+        &self.right
+    }
+
+    /// Returns the components that differ, in most- to least-significant order.
+    #[must_use]
+    pub fn differences(&self) -> &[ComponentDiff] {
+        &self.differences
+    }
+
+    /// Returns the offset-normalized delta (`right - left`).
+    #[must_use]
+    pub const fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns `true` if every component, including the offset, is equal.
+    #[must_use]
+    pub fn is_identical(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Returns `true` if both values represent the same instant, even if
+    /// their wall-clock components or offsets differ.
+    #[must_use]
+    pub const fn is_same_instant(&self) -> bool {
+        self.delta.is_zero()
+    }
+}
+
+/// Formats an offset expressed in seconds as `±HH:MM[:SS]`.
+fn format_offset_seconds(seconds: i64) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let abs = seconds.abs();
+    let (h, m, s) = (abs / 3600, (abs % 3600) / 60, abs % 60);
+    if s == 0 {
+        format!("{sign}{h:02}:{m:02}")
+    } else {
+        format!("{sign}{h:02}:{m:02}:{s:02}")
+    }
+}
+
+impl fmt::Display for DiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "left:  {}", self.left)?;
+        writeln!(f, "right: {}", self.right)?;
+
+        if self.is_identical() {
+            return write!(f, "no differences");
+        }
+
+        for diff in &self.differences {
+            if diff.component == DiffComponent::Offset {
+                writeln!(
+                    f,
+                    "  {:<10} {} != {}",
+                    diff.component.name(),
+                    format_offset_seconds(diff.left),
+                    format_offset_seconds(diff.right)
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "  {:<10} {} != {}",
+                    diff.component.name(),
+                    diff.left,
+                    diff.right
+                )?;
+            }
+        }
+
+        if self.is_same_instant() {
+            write!(f, "delta: 0s (same instant)")
+        } else {
+            write!(f, "delta: {}", self.delta)
+        }
+    }
+}
@@ -0,0 +1,425 @@
+// duration.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Parsing exact durations from text.
+//!
+//! [`parse_duration`] reads two notations into a `time::Duration`:
+//!
+//! - a human form made of numbers with units, such as `90s`, `1h30m`, or
+//!   `1d 2h 30m`. The units are `w`, `d`, `h`, `m`, `s`, `ms`, `us` (or
+//!   `µs`), and `ns`, and the long names `weeks`, `days`, `hours`,
+//!   `minutes`, and `seconds` (singular or plural, with `min` and `sec`
+//!   as abbreviations). Numbers may have a fractional part, as in `1.5h`.
+//! - ISO 8601 durations such as `PT90S`, `P1DT2H`, or `P2W`. A fraction is
+//!   allowed on the last component, as in `PT0.5S`.
+//!
+//! Either form may start with `-` for a negative duration. Years and
+//! months have no fixed length, so ISO 8601 durations containing them are
+//! rejected; use calendar arithmetic such as `DateTime::add_months` for
+//! those.
+//!
+//! The [`dtt_duration!`](crate::dtt_duration) macro is shorthand for
+//! [`parse_duration`].
+//!
+//! [`format_duration_iso8601`] writes a duration in ISO 8601 for
+//! exchange with other systems, such as XML Schema `xs:duration` fields
+//! or JSON APIs, and [`parse_duration_iso8601`] reads it back, accepting
+//! only the ISO 8601 form. Days are the largest unit written.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::parse_duration;
+//! use time::Duration;
+//!
+//! assert_eq!(
+//!     parse_duration("1d 2h 30m").unwrap(),
+//!     Duration::days(1) + Duration::hours(2) + Duration::minutes(30)
+//! );
+//! assert_eq!(parse_duration("90s").unwrap(), Duration::seconds(90));
+//! assert_eq!(parse_duration("P1DT2H").unwrap(), Duration::hours(26));
+//! assert_eq!(parse_duration("-1.5h").unwrap(), Duration::minutes(-90));
+//! ```
+
+use crate::error::DateTimeError;
+use std::fmt::Write;
+use time::Duration;
+
+/// Nanoseconds per second.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Nanoseconds per minute.
+const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+
+/// Nanoseconds per hour.
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+
+/// Nanoseconds per day.
+const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+
+/// Nanoseconds per week.
+const NANOS_PER_WEEK: i128 = 7 * NANOS_PER_DAY;
+
+/// Unit names accepted by the human form, with their length.
+const UNITS: [(&str, i128); 26] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", NANOS_PER_SECOND),
+    ("sec", NANOS_PER_SECOND),
+    ("secs", NANOS_PER_SECOND),
+    ("second", NANOS_PER_SECOND),
+    ("seconds", NANOS_PER_SECOND),
+    ("m", NANOS_PER_MINUTE),
+    ("min", NANOS_PER_MINUTE),
+    ("mins", NANOS_PER_MINUTE),
+    ("minute", NANOS_PER_MINUTE),
+    ("minutes", NANOS_PER_MINUTE),
+    ("h", NANOS_PER_HOUR),
+    ("hr", NANOS_PER_HOUR),
+    ("hrs", NANOS_PER_HOUR),
+    ("hour", NANOS_PER_HOUR),
+    ("hours", NANOS_PER_HOUR),
+    ("d", NANOS_PER_DAY),
+    ("day", NANOS_PER_DAY),
+    ("days", NANOS_PER_DAY),
+    ("w", NANOS_PER_WEEK),
+    ("week", NANOS_PER_WEEK),
+    ("weeks", NANOS_PER_WEEK),
+    ("wk", NANOS_PER_WEEK),
+];
+
+/// Parses a duration written as numbers with units (`1d 2h 30m`) or in
+/// ISO 8601 (`P1DT2H`). See the [module documentation](self) for the
+/// accepted notation.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormatAt` naming the position and
+/// the expected `"number"`, `"unit"`, `"designator"`, or
+/// `"end of input"` if the text does not parse, and a
+/// `DateTimeError::InvalidFormat` if the duration does not fit in a
+/// `Duration`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::error::DateTimeError;
+/// use dtt::parse_duration;
+///
+/// assert_eq!(
+///     parse_duration("2h 15x"),
+///     Err(DateTimeError::InvalidFormatAt { index: 5, expected: "unit" })
+/// );
+/// assert!(parse_duration("P1Y").is_err());
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, DateTimeError> {
+    let (negative, mut scanner) = Scanner::signed(input);
+    let nanos = if scanner.rest().starts_with('P') {
+        scanner.position += 1;
+        scanner.iso8601()?
+    } else {
+        scanner.human()?
+    };
+    to_duration(if negative { -nanos } else { nanos })
+}
+
+/// Parses an ISO 8601 duration such as `P1DT2H` or `-PT0.5S`.
+///
+/// Unlike [`parse_duration`], this rejects the human form, so it suits
+/// fields that must hold ISO 8601. Years and months are rejected because
+/// they have no fixed length.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormatAt` if the text is not an
+/// ISO 8601 duration of weeks, days, hours, minutes, and seconds, and a
+/// `DateTimeError::InvalidFormat` if the duration does not fit in a
+/// `Duration`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::duration::parse_duration_iso8601;
+/// use time::Duration;
+///
+/// assert_eq!(
+///     parse_duration_iso8601("P1DT2H30M").unwrap(),
+///     Duration::minutes(1_590)
+/// );
+/// assert!(parse_duration_iso8601("1d 2h").is_err());
+/// ```
+pub fn parse_duration_iso8601(
+    input: &str,
+) -> Result<Duration, DateTimeError> {
+    let (negative, mut scanner) = Scanner::signed(input);
+    if !scanner.rest().starts_with('P') {
+        return Err(scanner.error("designator"));
+    }
+    scanner.position += 1;
+    let nanos = scanner.iso8601()?;
+    to_duration(if negative { -nanos } else { nanos })
+}
+
+/// Formats a duration in ISO 8601, such as `P1DT2H30M` or `PT0.5S`.
+///
+/// Days are the largest unit, since weeks are rarely understood in
+/// combination with other units and years and months have no fixed
+/// length. Zero components are left out, a zero duration is `PT0S`, and a
+/// negative duration has a leading `-`, as accepted by
+/// [`parse_duration_iso8601`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::duration::{format_duration_iso8601, parse_duration_iso8601};
+/// use time::Duration;
+///
+/// let duration = Duration::days(3) + Duration::hours(4) + Duration::seconds(6);
+/// assert_eq!(format_duration_iso8601(duration), "P3DT4H6S");
+/// assert_eq!(format_duration_iso8601(Duration::milliseconds(-1_500)), "-PT1.5S");
+/// assert_eq!(format_duration_iso8601(Duration::ZERO), "PT0S");
+///
+/// let text = format_duration_iso8601(duration);
+/// assert_eq!(parse_duration_iso8601(&text).unwrap(), duration);
+/// ```
+#[must_use]
+pub fn format_duration_iso8601(duration: Duration) -> String {
+    let seconds = duration.whole_seconds().unsigned_abs();
+    let nanos = duration.subsec_nanoseconds().unsigned_abs();
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+    let seconds = seconds % 60;
+
+    let mut output =
+        String::from(if duration.is_negative() { "-P" } else { "P" });
+    if days > 0 {
+        let _ = write!(output, "{days}D");
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 || days == 0
+    {
+        output.push('T');
+        if hours > 0 {
+            let _ = write!(output, "{hours}H");
+        }
+        if minutes > 0 {
+            let _ = write!(output, "{minutes}M");
+        }
+        if nanos > 0 {
+            let fraction = format!("{nanos:09}");
+            let _ = write!(
+                output,
+                "{seconds}.{}S",
+                fraction.trim_end_matches('0')
+            );
+        } else if seconds > 0 || output.ends_with('T') {
+            let _ = write!(output, "{seconds}S");
+        }
+    }
+    output
+}
+
+/// Converts a count of nanoseconds to a `Duration`.
+fn to_duration(nanos: i128) -> Result<Duration, DateTimeError> {
+    let seconds = i64::try_from(nanos / NANOS_PER_SECOND)
+        .map_err(|_| DateTimeError::InvalidFormat)?;
+    let subsecond = i32::try_from(nanos % NANOS_PER_SECOND)
+        .map_err(|_| DateTimeError::InvalidFormat)?;
+    Ok(Duration::new(seconds, subsecond))
+}
+
+/// A position in the input being parsed.
+struct Scanner<'a> {
+    /// The whole input, so errors report absolute positions.
+    input: &'a str,
+    /// Byte offset of the next character.
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Starts scanning `input` after an optional `+` or `-` sign, and
+    /// returns whether the sign was `-`.
+    const fn signed(input: &'a str) -> (bool, Self) {
+        let (negative, position) = match input.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        (negative, Self { input, position })
+    }
+
+    /// Returns the unparsed remainder of the input.
+    fn rest(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    /// Returns an error at the current position.
+    const fn error(&self, expected: &'static str) -> DateTimeError {
+        DateTimeError::InvalidFormatAt {
+            index: self.position,
+            expected,
+        }
+    }
+
+    /// Skips whitespace.
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Reads a number with an optional fractional part, returning the
+    /// whole part and the fractional part.
+    fn number(&mut self) -> Result<(i128, Fraction), DateTimeError> {
+        let digits = self.digits();
+        if digits.is_empty() {
+            return Err(self.error("number"));
+        }
+        let whole = digits
+            .parse::<i128>()
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        if !self.rest().starts_with(['.', ',']) {
+            return Ok((whole, Fraction::default()));
+        }
+        self.position += 1;
+        let digits = self.digits();
+        if digits.is_empty() {
+            return Err(self.error("number"));
+        }
+        Ok((whole, Fraction::parse(digits)))
+    }
+
+    /// Reads a run of ASCII digits.
+    fn digits(&mut self) -> &str {
+        let start = self.position;
+        let length = self
+            .rest()
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or_else(|| self.rest().len());
+        self.position += length;
+        &self.input[start..self.position]
+    }
+
+    /// Parses the human form, such as `1d 2h 30m`.
+    fn human(&mut self) -> Result<i128, DateTimeError> {
+        let mut total: i128 = 0;
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            return Err(self.error("number"));
+        }
+        while !self.rest().is_empty() {
+            let (whole, fraction) = self.number()?;
+            self.skip_whitespace();
+            let length = self
+                .rest()
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or_else(|| self.rest().len());
+            let name = &self.rest()[..length];
+            let unit = UNITS
+                .iter()
+                .find(|(unit, _)| *unit == name)
+                .map(|&(_, nanos)| nanos)
+                .ok_or_else(|| self.error("unit"))?;
+            self.position += length;
+            total = add(total, whole, fraction, unit)?;
+            self.skip_whitespace();
+        }
+        Ok(total)
+    }
+
+    /// Parses an ISO 8601 duration after the leading `P`.
+    fn iso8601(&mut self) -> Result<i128, DateTimeError> {
+        let date_designators: [(char, i128); 2] =
+            [('W', NANOS_PER_WEEK), ('D', NANOS_PER_DAY)];
+        let time_designators: [(char, i128); 3] = [
+            ('H', NANOS_PER_HOUR),
+            ('M', NANOS_PER_MINUTE),
+            ('S', NANOS_PER_SECOND),
+        ];
+
+        let mut total: i128 = 0;
+        let mut components = 0;
+        let mut in_time = false;
+        let mut next = 0;
+        while !self.rest().is_empty() {
+            if !in_time && self.rest().starts_with('T') {
+                self.position += 1;
+                in_time = true;
+                next = 0;
+                if self.rest().is_empty() {
+                    return Err(self.error("number"));
+                }
+                continue;
+            }
+            let (whole, fraction) = self.number()?;
+            let designators: &[(char, i128)] = if in_time {
+                &time_designators
+            } else {
+                &date_designators
+            };
+            let designator = self.rest().chars().next();
+            let index = designators[next..]
+                .iter()
+                .position(|&(name, _)| Some(name) == designator)
+                .ok_or_else(|| self.error("designator"))?;
+            let unit = designators[next + index].1;
+            next += index + 1;
+            self.position += 1;
+            components += 1;
+            total = add(total, whole, fraction, unit)?;
+            if !fraction.is_zero() && !self.rest().is_empty() {
+                return Err(self.error("end of input"));
+            }
+        }
+        if components == 0 {
+            return Err(self.error("number"));
+        }
+        Ok(total)
+    }
+}
+
+/// The fractional part of a number, as a value scaled to nanoseconds of
+/// one unit (up to nine digits are kept).
+#[derive(Copy, Clone, Debug, Default)]
+struct Fraction {
+    /// The fraction times 10^9, truncated.
+    billionths: i128,
+}
+
+impl Fraction {
+    /// Reads fractional digits, keeping the first nine.
+    fn parse(digits: &str) -> Self {
+        let kept = &digits[..digits.len().min(9)];
+        let scale =
+            10_i128.pow(9 - u32::try_from(kept.len()).unwrap_or(9));
+        Self {
+            billionths: kept.parse::<i128>().unwrap_or(0) * scale,
+        }
+    }
+
+    /// Returns `true` if the fraction is zero.
+    const fn is_zero(self) -> bool {
+        self.billionths == 0
+    }
+}
+
+/// Adds `whole.fraction` units of `unit` nanoseconds to `total`.
+fn add(
+    total: i128,
+    whole: i128,
+    fraction: Fraction,
+    unit: i128,
+) -> Result<i128, DateTimeError> {
+    whole
+        .checked_mul(unit)
+        .and_then(|nanos| {
+            nanos.checked_add(
+                fraction.billionths * unit / NANOS_PER_SECOND,
+            )
+        })
+        .and_then(|nanos| total.checked_add(nanos))
+        .ok_or(DateTimeError::InvalidFormat)
+}
@@ -6,22 +6,73 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json;
 use std::{
-    env,
+    env, fmt,
     hash::{Hash, Hasher},
 };
 use thiserror::Error;
-use time::error::{ComponentRange, Parse};
+use time::{
+    error::{
+        ComponentRange, Parse, ParseFromDescription, TryFromParsed,
+    },
+    format_description, Date, Month, OffsetDateTime, Time, UtcOffset,
+    Weekday,
+};
+
+use crate::{
+    datetime::{suggest_timezone, DateTime},
+    limits::MAX_NANOSECOND,
+};
 
 /// Custom error type for the application.
 ///
 /// This error type encapsulates all possible errors that might occur in the application,
 /// including simulated errors for testing and environment variable retrieval errors.
+///
+/// Every wrapped error converts with `?`, so binaries built on the library
+/// can return `Result<(), AppError>` throughout and map the final error to
+/// a process exit status with [`AppError::exit_code`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::error::AppError;
+///
+/// fn load(deadline: &str) -> Result<DateTime, AppError> {
+///     if deadline.is_empty() {
+///         return Err(AppError::config("deadline", "must not be empty"));
+///     }
+///     Ok(DateTime::parse(deadline)?)
+/// }
+///
+/// assert!(load("2024-01-01").is_ok());
+/// assert_eq!(load("").unwrap_err().exit_code(), 78);
+/// assert_eq!(load("tomorrow").unwrap_err().exit_code(), 65);
+/// ```
 #[derive(Error, Debug)]
 pub enum AppError {
     /// Error that occurs during datetime operations.
     #[error("DateTime operation error: {0}")]
     DateTimeError(#[from] DateTimeError),
 
+    /// Error that occurs during datetime operations, with the instant at
+    /// which it occurred.
+    #[error("DateTime operation error: {0}")]
+    Contextual(#[from] ContextualError),
+
+    /// Error that occurs while parsing, with the offending input.
+    #[error("DateTime operation error: {0}")]
+    Input(#[from] InputError),
+
+    /// A configuration value is missing or invalid.
+    #[error("Configuration error for `{key}`: {message}")]
+    Config {
+        /// Name of the offending setting.
+        key: String,
+        /// What is wrong with it.
+        message: String,
+    },
+
     /// Error that occurs during serialization.
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
@@ -30,6 +81,10 @@ pub enum AppError {
     #[error("General I/O or parsing error: {0}")]
     GeneralError(#[from] std::io::Error),
 
+    /// The command line is malformed.
+    #[error("Usage error: {0}")]
+    Usage(String),
+
     /// Error that occurs during other operations.
     #[error("Other error: {0}")]
     Other(String),
@@ -43,6 +98,55 @@ pub enum AppError {
     EnvVarError(#[from] env::VarError),
 }
 
+impl AppError {
+    /// Creates a configuration error for a named setting.
+    #[must_use]
+    pub fn config(
+        key: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::Config {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a command-line usage error.
+    #[must_use]
+    pub fn usage(message: impl Into<String>) -> Self {
+        Self::Usage(message.into())
+    }
+
+    /// Returns the underlying `DateTimeError`, if there is one.
+    #[must_use]
+    pub const fn datetime_error(&self) -> Option<DateTimeError> {
+        match self {
+            Self::DateTimeError(error) => Some(*error),
+            Self::Contextual(error) => Some(error.error()),
+            Self::Input(error) => Some(error.error()),
+            _ => None,
+        }
+    }
+
+    /// Returns a process exit status for the error, following the BSD
+    /// `sysexits` conventions: 64 for command-line usage errors, 65 for
+    /// invalid input data, 74 for I/O errors, 78 for configuration errors,
+    /// and 1 otherwise.
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::DateTimeError(_)
+            | Self::Contextual(_)
+            | Self::Input(_)
+            | Self::SerializationError(_) => 65,
+            Self::Usage(_) => 64,
+            Self::GeneralError(_) => 74,
+            Self::Config { .. } | Self::EnvVarError(_) => 78,
+            Self::Other(_) | Self::SimulatedError => 1,
+        }
+    }
+}
+
 /// Custom error type for the `DateTime` library.
 ///
 /// This enum represents various errors that can occur when working with
@@ -65,6 +169,20 @@ pub enum DateTimeError {
     #[error("Invalid time")]
     InvalidTime,
 
+    /// The input did not match a custom format description. `index` is the
+    /// byte offset in the input where matching failed, and `expected` names
+    /// the format component that was expected there (for example `"month"`,
+    /// `"literal"`, or `"end of input"`).
+    #[error(
+        "Invalid date format at index {index}: expected {expected}"
+    )]
+    InvalidFormatAt {
+        /// Byte offset in the input where matching failed.
+        index: usize,
+        /// Name of the expected component.
+        expected: &'static str,
+    },
+
     /// An error occurred while parsing the date/time string.
     #[error("Parsing error")]
     ParseError(#[from] Parse),
@@ -72,6 +190,892 @@ pub enum DateTimeError {
     /// A component (year, month, day, etc.) is out of the valid range.
     #[error("Component range error")]
     ComponentRange(#[from] ComponentRange),
+
+    /// Converting an instant to another offset would move its wall-clock
+    /// time outside the supported date range.
+    #[error(
+        "Converting {} to offset {} overflows the supported date range",
+        .instant.to_offset_datetime(),
+        .target
+    )]
+    ConversionOverflow {
+        /// The instant being converted.
+        instant: DateTime,
+        /// The offset it was being converted to.
+        target: UtcOffset,
+    },
+
+    /// One or more components passed to
+    /// [`DateTimeBuilder::build`](crate::datetime::DateTimeBuilder::build)
+    /// were out of range. [`ComponentIssues::errors`] lists every one.
+    #[error("Invalid components: {0}")]
+    InvalidComponents(ComponentIssues),
+}
+
+/// Broad classification of a [`DateTimeError`].
+///
+/// Categories are stable and intended for mapping library errors onto
+/// machine-readable API payloads or metrics labels.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum ErrorCategory {
+    /// The input could not be parsed.
+    Parse,
+    /// A date, time, or component was outside its valid range.
+    Range,
+    /// A timezone was invalid or unsupported.
+    Timezone,
+    /// The input or a format description did not have the expected
+    /// format.
+    Format,
+    /// A date or time calculation overflowed or produced an invalid value.
+    Arithmetic,
+}
+
+/// The part of a date or time that a [`DateTimeError`] is about.
+///
+/// Finer-grained than [`ErrorCategory`]: it tells a bad month apart from a
+/// bad offset, so callers can point users at the field to fix.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum ErrorKind {
+    /// The year.
+    Year,
+    /// The month.
+    Month,
+    /// The day of the month.
+    Day,
+    /// The hour.
+    Hour,
+    /// The minute.
+    Minute,
+    /// The second.
+    Second,
+    /// The fractional second.
+    Subsecond,
+    /// The UTC offset.
+    Offset,
+    /// A timezone name.
+    Timezone,
+    /// The date as a whole, such as February 30 or an invalid ordinal
+    /// day.
+    Date,
+    /// The time of day as a whole.
+    Time,
+    /// The overall shape of the input or of a format description.
+    Format,
+    /// A calculation left the supported date range.
+    Overflow,
+}
+
+impl ErrorKind {
+    /// Maps a component name, as used by the `time` crate and by this
+    /// crate's validators, to its kind.
+    fn from_component(name: &str) -> Option<Self> {
+        match name {
+            "year" | "year_last_two" => Some(Self::Year),
+            "month" => Some(Self::Month),
+            "day" => Some(Self::Day),
+            "hour" | "period" => Some(Self::Hour),
+            "minute" => Some(Self::Minute),
+            "second" => Some(Self::Second),
+            "subsecond" | "millisecond" | "microsecond"
+            | "nanosecond" => Some(Self::Subsecond),
+            "ordinal" | "week" | "weekday" | "iso_week" => {
+                Some(Self::Date)
+            }
+            // `UtcOffset` range errors name their parts in the plural.
+            "hours" | "minutes" | "seconds" => Some(Self::Offset),
+            _ if name.starts_with("offset") => Some(Self::Offset),
+            _ => None,
+        }
+    }
+
+    /// Returns a hint for fixing a problem of this kind.
+    const fn hint(self) -> &'static str {
+        match self {
+            Self::Year => "years run from -9999 to 9999",
+            Self::Month => {
+                "months run from 01 (January) to 12 (December)"
+            }
+            Self::Day => {
+                "check the number of days in the month; February has 29 \
+                 days only in leap years"
+            }
+            Self::Hour => "hours run from 00 to 23",
+            Self::Minute => "minutes run from 00 to 59",
+            Self::Second => "seconds run from 00 to 59",
+            Self::Subsecond => {
+                "use at most nine digits after the decimal point"
+            }
+            Self::Offset => {
+                "write offsets as Z or ±HH:MM, within ±14:00"
+            }
+            Self::Timezone => {
+                "use an abbreviation such as UTC, EST, or CET, or an \
+                 offset such as UTC+05:30"
+            }
+            Self::Date => "check that the date exists",
+            Self::Time => "write times as HH:MM:SS",
+            Self::Format => {
+                "use an RFC 3339 timestamp such as 2024-01-31T12:00:00Z"
+            }
+            Self::Overflow => {
+                "keep results between the years -9999 and 9999"
+            }
+        }
+    }
+}
+
+impl DateTimeError {
+    /// Annotates the error with the instant at which it occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::DateTimeError;
+    ///
+    /// let now = DateTime::new();
+    /// let error = DateTimeError::InvalidTimezone.at(&now);
+    /// assert_eq!(error.error(), DateTimeError::InvalidTimezone);
+    /// assert_eq!(error.instant(), now);
+    /// ```
+    #[must_use]
+    pub const fn at(self, instant: &DateTime) -> ContextualError {
+        ContextualError {
+            error: self,
+            instant: *instant,
+        }
+    }
+
+    /// Annotates the error with the input that caused it. The position
+    /// and expected component of an `InvalidFormatAt` error are carried
+    /// over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::error::DateTimeError;
+    ///
+    /// let error = DateTimeError::InvalidFormat.with_input("2024-1-01");
+    /// assert_eq!(error.input(), "2024-1-01");
+    /// assert_eq!(error.to_string(), "Invalid date format in \"2024-1-01\"");
+    /// ```
+    #[must_use]
+    pub fn with_input(self, input: &str) -> InputError {
+        let (position, expected) = match self {
+            Self::InvalidFormatAt { index, expected } => {
+                (Some(index), Some(expected))
+            }
+            _ => (None, None),
+        };
+        InputError {
+            error: self,
+            input: input.to_string(),
+            position,
+            expected,
+        }
+    }
+
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// Codes never change meaning between releases, so they can be used in
+    /// API payloads and documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::error::DateTimeError;
+    ///
+    /// assert_eq!(DateTimeError::InvalidFormat.code(), "DTT-0001");
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidFormat => "DTT-0001",
+            Self::InvalidTimezone => "DTT-0002",
+            Self::InvalidDate => "DTT-0003",
+            Self::InvalidTime => "DTT-0004",
+            Self::ParseError(_) => "DTT-0005",
+            Self::ComponentRange(_) => "DTT-0006",
+            Self::ConversionOverflow { .. } => "DTT-0007",
+            Self::InvalidFormatAt { .. } => "DTT-0008",
+            Self::InvalidComponents(_) => "DTT-0009",
+        }
+    }
+
+    /// Returns the broad category of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::error::{DateTimeError, ErrorCategory};
+    ///
+    /// assert_eq!(
+    ///     DateTimeError::InvalidTimezone.category(),
+    ///     ErrorCategory::Timezone
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn category(&self) -> ErrorCategory {
+        match self {
+            Self::InvalidFormat | Self::InvalidFormatAt { .. } => {
+                ErrorCategory::Format
+            }
+            Self::InvalidTimezone => ErrorCategory::Timezone,
+            Self::InvalidDate
+            | Self::InvalidTime
+            | Self::ComponentRange(_)
+            | Self::InvalidComponents(_) => ErrorCategory::Range,
+            Self::ParseError(_) => ErrorCategory::Parse,
+            Self::ConversionOverflow { .. } => {
+                ErrorCategory::Arithmetic
+            }
+        }
+    }
+
+    /// Returns `true` if retrying the same operation could succeed.
+    ///
+    /// Every `DateTimeError` is caused by its input rather than by a
+    /// transient condition, so this currently always returns `false`.
+    /// Callers should still consult it rather than assume, as future
+    /// variants may be transient.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        match self.category() {
+            ErrorCategory::Parse
+            | ErrorCategory::Range
+            | ErrorCategory::Timezone
+            | ErrorCategory::Format
+            | ErrorCategory::Arithmetic => false,
+        }
+    }
+
+    /// Returns the part of the date or time the error is about.
+    ///
+    /// Errors such as `InvalidFormat` do not record which component was
+    /// at fault and are reported as [`ErrorKind::Format`];
+    /// [`InputError::kind`] can often narrow them down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::ErrorKind;
+    ///
+    /// let month = DateTime::parse_custom_format("2024-13-01", "[year]-[month]-[day]")
+    ///     .unwrap_err();
+    /// assert_eq!(month.kind(), ErrorKind::Month);
+    ///
+    /// let zone = DateTime::new().convert_to_tz("Nowhere").unwrap_err();
+    /// assert_eq!(zone.kind(), ErrorKind::Timezone);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidFormat => ErrorKind::Format,
+            Self::InvalidFormatAt { expected, .. } => {
+                ErrorKind::from_component(expected)
+                    .unwrap_or(ErrorKind::Format)
+            }
+            Self::InvalidTimezone => ErrorKind::Timezone,
+            Self::InvalidDate => ErrorKind::Date,
+            Self::InvalidTime => ErrorKind::Time,
+            Self::ParseError(error) => match error {
+                Parse::TryFromParsed(
+                    TryFromParsed::ComponentRange(range),
+                ) => ErrorKind::from_component(range.name())
+                    .unwrap_or(ErrorKind::Date),
+                Parse::ParseFromDescription(
+                    ParseFromDescription::InvalidComponent(name),
+                ) => ErrorKind::from_component(name)
+                    .unwrap_or(ErrorKind::Format),
+                _ => ErrorKind::Format,
+            },
+            Self::ComponentRange(range) => {
+                ErrorKind::from_component(range.name())
+                    .unwrap_or(ErrorKind::Date)
+            }
+            Self::ConversionOverflow { .. } => ErrorKind::Overflow,
+            Self::InvalidComponents(issues) => issues
+                .errors()
+                .first()
+                .and_then(|error| {
+                    ErrorKind::from_component(error.component)
+                })
+                .unwrap_or(ErrorKind::Date),
+        }
+    }
+
+    /// Returns a hint for fixing the input, suitable for showing to the
+    /// user alongside the error message, or `None` if the error does not
+    /// record enough to suggest anything specific.
+    ///
+    /// The hint comes from the component the error names, such as the
+    /// valid range of the hours or of each field rejected by the builder.
+    /// [`InputError::suggestion`] can also use the rejected input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::DateTimeError;
+    ///
+    /// let error = DateTime::parse_custom_format("24:00", "[hour]:[minute]")
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     error.suggestion().as_deref(),
+    ///     Some("hours run from 00 to 23")
+    /// );
+    /// assert_eq!(DateTimeError::InvalidDate.suggestion(), None);
+    /// ```
+    #[must_use]
+    pub fn suggestion(&self) -> Option<String> {
+        let component = match self {
+            Self::InvalidFormatAt { expected, .. } => {
+                return Some(
+                    ErrorKind::from_component(expected).map_or_else(
+                        || format!("expected {expected}"),
+                        |kind| kind.hint().to_string(),
+                    ),
+                );
+            }
+            Self::InvalidComponents(issues) => {
+                return issues.errors().first().map(|error| {
+                    format!(
+                        "{} must be between {} and {}",
+                        error.component, error.minimum, error.maximum
+                    )
+                });
+            }
+            Self::ComponentRange(range)
+            | Self::ParseError(Parse::TryFromParsed(
+                TryFromParsed::ComponentRange(range),
+            )) => range.name(),
+            Self::ParseError(Parse::ParseFromDescription(
+                ParseFromDescription::InvalidComponent(name),
+            )) => name,
+            Self::ConversionOverflow { .. } => {
+                return Some(ErrorKind::Overflow.hint().to_string())
+            }
+            Self::InvalidFormat
+            | Self::InvalidTimezone
+            | Self::InvalidDate
+            | Self::InvalidTime
+            | Self::ParseError(_) => return None,
+        };
+        ErrorKind::from_component(component)
+            .map(|kind| kind.hint().to_string())
+    }
+}
+
+/// A single out-of-range component reported by
+/// [`DateTime::validate_components`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+///
+/// let errors = DateTime::validate_components(2023, 2, 29, 24, 0, 0)
+///     .unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].to_string(), "day must be in the range 1..=28, got 29");
+/// assert_eq!(errors[1].component, "hour");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Error)]
+#[error("{component} must be in the range {minimum}..={maximum}, got {value}")]
+pub struct ComponentError {
+    /// Name of the component, e.g. `"day"`.
+    pub component: &'static str,
+    /// The rejected value.
+    pub value: i64,
+    /// Smallest valid value.
+    pub minimum: i64,
+    /// Largest valid value, which may depend on other components (the
+    /// number of days in a month, for example).
+    pub maximum: i64,
+}
+
+/// The components rejected by
+/// [`DateTimeBuilder::build`](crate::datetime::DateTimeBuilder::build),
+/// carried by [`DateTimeError::InvalidComponents`].
+///
+/// The rejected values are stored compactly so that `DateTimeError` stays
+/// `Copy`; [`ComponentIssues::errors`] expands them into one
+/// [`ComponentError`] per out-of-range field.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTimeBuilder;
+/// use dtt::error::DateTimeError;
+///
+/// let error = DateTimeBuilder::new()
+///     .month(13)
+///     .day(32)
+///     .hour(7)
+///     .build()
+///     .unwrap_err();
+/// if let DateTimeError::InvalidComponents(issues) = error {
+///     let names: Vec<&str> =
+///         issues.errors().iter().map(|e| e.component).collect();
+///     assert_eq!(names, ["month", "day"]);
+/// }
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid components: month must be in the range 1..=12, got 13; \
+///      day must be in the range 1..=31, got 32"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ComponentIssues {
+    /// Rejected year.
+    year: i32,
+    /// Rejected month.
+    month: u8,
+    /// Rejected day of the month.
+    day: u8,
+    /// Rejected hour.
+    hour: u8,
+    /// Rejected minute.
+    minute: u8,
+    /// Rejected second.
+    second: u8,
+    /// Rejected nanosecond.
+    nanosecond: u32,
+}
+
+impl ComponentIssues {
+    /// Checks the components and returns the issues, or `None` if every
+    /// component is in range.
+    pub(crate) fn check(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        let issues = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        if issues.errors().is_empty() {
+            None
+        } else {
+            Some(issues)
+        }
+    }
+
+    /// Returns every out-of-range component, in order from year to
+    /// nanosecond.
+    #[must_use]
+    pub fn errors(&self) -> Vec<ComponentError> {
+        let mut errors = DateTime::validate_components(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        )
+        .err()
+        .unwrap_or_default();
+        if self.nanosecond > MAX_NANOSECOND {
+            errors.push(ComponentError {
+                component: "nanosecond",
+                value: i64::from(self.nanosecond),
+                minimum: 0,
+                maximum: i64::from(MAX_NANOSECOND),
+            });
+        }
+        errors
+    }
+}
+
+impl fmt::Display for ComponentIssues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.errors().iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a component of a datetime string failed validation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ValidationIssueKind {
+    /// The input ended before the component.
+    Missing,
+    /// The component has the wrong characters or width.
+    Malformed,
+    /// The component is well-formed but its value is out of range.
+    OutOfRange {
+        /// The rejected value.
+        value: i64,
+        /// Smallest valid value.
+        minimum: i64,
+        /// Largest valid value.
+        maximum: i64,
+    },
+    /// Characters remain after a complete datetime.
+    TrailingInput,
+}
+
+impl fmt::Display for ValidationIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "is missing"),
+            Self::Malformed => write!(f, "is malformed"),
+            Self::OutOfRange {
+                value,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "must be in the range {minimum}..={maximum}, got {value}"
+            ),
+            Self::TrailingInput => write!(f, "is not expected"),
+        }
+    }
+}
+
+/// A single problem reported by [`DateTime::validate`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+///
+/// let issues = DateTime::validate("2024-02-30T25:00:00Z").unwrap_err();
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(
+///     issues[0].to_string(),
+///     "day at byte 8 must be in the range 1..=29, got 30"
+/// );
+/// assert_eq!(issues[1].component, "hour");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Error)]
+#[error("{component} at byte {position} {kind}")]
+pub struct ValidationIssue {
+    /// Name of the component, e.g. `"month"` or `"time separator"`.
+    pub component: &'static str,
+    /// Byte offset in the input where the component starts.
+    pub position: usize,
+    /// What is wrong with the component.
+    pub kind: ValidationIssueKind,
+}
+
+/// A [`DateTimeError`] annotated with the instant at which it occurred.
+///
+/// Created with [`DateTimeError::at`]. The instant keeps its original
+/// offset, and the rendering helpers show it alongside its UTC equivalent
+/// so that log lines from services in different timezones line up.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::error::DateTimeError;
+///
+/// let now = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// let error = DateTimeError::InvalidDate.at(&now);
+/// assert_eq!(error.to_string(), "[2024-01-01T12:00:00Z] Invalid date");
+/// assert_eq!(
+///     error.to_logfmt(),
+///     "at=2024-01-01T12:00:00Z at_utc=2024-01-01T12:00:00Z \
+///      code=DTT-0003 category=Range message=\"Invalid date\""
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ContextualError {
+    /// The underlying error.
+    error: DateTimeError,
+    /// The instant at which the error occurred.
+    instant: DateTime,
+}
+
+impl ContextualError {
+    /// Returns the underlying error.
+    #[must_use]
+    pub const fn error(&self) -> DateTimeError {
+        self.error
+    }
+
+    /// Returns the instant at which the error occurred.
+    #[must_use]
+    pub const fn instant(&self) -> DateTime {
+        self.instant
+    }
+
+    /// Renders the error as a single logfmt line with the instant in its
+    /// original offset and in UTC, the error code, category, and message.
+    #[must_use]
+    pub fn to_logfmt(&self) -> String {
+        let utc =
+            self.instant.saturating_convert_to_offset(UtcOffset::UTC);
+        format!(
+            "at={} at_utc={} code={} category={:?} message={:?}",
+            render_instant(&self.instant),
+            render_instant(&utc),
+            self.error.code(),
+            self.error.category(),
+            self.error.to_string()
+        )
+    }
+}
+
+impl fmt::Display for ContextualError {
+    /// Formats the error as `[<instant>] <message>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", render_instant(&self.instant), self.error)
+    }
+}
+
+impl std::error::Error for ContextualError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ContextualError> for DateTimeError {
+    fn from(error: ContextualError) -> Self {
+        error.error
+    }
+}
+
+/// A [`DateTimeError`] annotated with the input that caused it and, when
+/// known, the byte position and name of the offending component.
+///
+/// Created with [`DateTimeError::with_input`] or returned by
+/// [`DateTime::parse_detailed`], which locates the problem in inputs that
+/// [`DateTime::parse`] rejects without a position.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+///
+/// let error = DateTime::parse_detailed("2024-02-30T12:00:00Z").unwrap_err();
+/// assert_eq!(error.input(), "2024-02-30T12:00:00Z");
+/// assert_eq!(error.position(), Some(8));
+/// assert_eq!(error.expected(), Some("day"));
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid date format in \"2024-02-30T12:00:00Z\" at byte 8 (day)"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct InputError {
+    /// The underlying error.
+    error: DateTimeError,
+    /// The input that was rejected.
+    input: String,
+    /// Byte offset of the offending component, if known.
+    position: Option<usize>,
+    /// Name of the offending component, if known.
+    expected: Option<&'static str>,
+}
+
+impl InputError {
+    /// Records the position and name of the offending component.
+    pub(crate) const fn located(
+        mut self,
+        position: usize,
+        expected: &'static str,
+    ) -> Self {
+        self.position = Some(position);
+        self.expected = Some(expected);
+        self
+    }
+
+    /// Returns the underlying error.
+    #[must_use]
+    pub const fn error(&self) -> DateTimeError {
+        self.error
+    }
+
+    /// Returns the input that was rejected.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte offset of the offending component, if known.
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the name of the offending component, such as `"month"`,
+    /// if known.
+    #[must_use]
+    pub const fn expected(&self) -> Option<&'static str> {
+        self.expected
+    }
+
+    /// Returns the part of the date or time the error is about, using
+    /// the located component when the error itself does not name one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::error::ErrorKind;
+    ///
+    /// let error = DateTime::parse_detailed("2024-13-01").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::Month);
+    ///
+    /// let error =
+    ///     DateTime::parse_detailed("2024-01-01T00:00:00+15:00").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::Offset);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        self.expected
+            .and_then(ErrorKind::from_component)
+            .unwrap_or_else(|| self.error.kind())
+    }
+
+    /// Returns a hint for fixing the input, or `None` if there is
+    /// nothing specific to suggest.
+    ///
+    /// An unknown timezone suggests the closest supported name, as
+    /// [`suggest_timezone`] does. Other errors use
+    /// [`DateTimeError::suggestion`], falling back to the located
+    /// component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::error::DateTimeError;
+    ///
+    /// let error = DateTimeError::InvalidTimezone.with_input("ETS");
+    /// assert_eq!(error.suggestion().as_deref(), Some("did you mean EST?"));
+    /// ```
+    #[must_use]
+    pub fn suggestion(&self) -> Option<String> {
+        if matches!(self.error, DateTimeError::InvalidTimezone) {
+            return suggest_timezone(&self.input)
+                .map(|name| format!("did you mean {name}?"));
+        }
+        self.error.suggestion().or_else(|| {
+            self.expected
+                .and_then(ErrorKind::from_component)
+                .map(|kind| kind.hint().to_string())
+        })
+    }
+}
+
+impl fmt::Display for InputError {
+    /// Formats the error as `<message> in "<input>"`, followed by the
+    /// position and component when the message does not already name
+    /// them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in {:?}", self.error, self.input)?;
+        if matches!(self.error, DateTimeError::InvalidFormatAt { .. }) {
+            return Ok(());
+        }
+        match (self.position, self.expected) {
+            (Some(position), Some(expected)) => {
+                write!(f, " at byte {position} ({expected})")
+            }
+            (Some(position), None) => write!(f, " at byte {position}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<InputError> for DateTimeError {
+    fn from(error: InputError) -> Self {
+        error.error
+    }
+}
+
+/// Renders an instant as RFC 3339, falling back to the `time` crate's
+/// representation for years RFC 3339 cannot express.
+fn render_instant(instant: &DateTime) -> String {
+    instant
+        .format_rfc3339()
+        .unwrap_or_else(|_| instant.to_offset_datetime().to_string())
+}
+
+/// A [`DateTimeError`] with its code, category, and message alongside,
+/// for API payloads and logs.
+///
+/// The error itself is written in the same form `DateTimeError`
+/// serializes to, so it round-trips exactly; the other fields are for
+/// readers that do not know the variants.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::error::{DateTimeError, SerializableError};
+///
+/// let error = SerializableError::from(&DateTimeError::InvalidDate);
+/// let json = serde_json::to_string(&error).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"code":"DTT-0003","category":"Range","message":"Invalid date","error":"InvalidDate"}"#
+/// );
+/// let back: SerializableError = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, error);
+/// assert_eq!(back.to_error(), DateTimeError::InvalidDate);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct SerializableError {
+    /// Stable error code, see [`DateTimeError::code`].
+    pub code: String,
+    /// Error category, see [`DateTimeError::category`].
+    pub category: ErrorCategory,
+    /// Human-readable message, as produced by `Display`.
+    pub message: String,
+    /// The error, in its own serialized form.
+    pub error: DateTimeError,
+}
+
+impl SerializableError {
+    /// Returns the wrapped error.
+    #[must_use]
+    pub const fn to_error(&self) -> DateTimeError {
+        self.error
+    }
+}
+
+impl From<&DateTimeError> for SerializableError {
+    fn from(error: &DateTimeError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            category: error.category(),
+            message: error.to_string(),
+            error: *error,
+        }
+    }
+}
+
+impl From<DateTimeError> for SerializableError {
+    fn from(error: DateTimeError) -> Self {
+        Self::from(&error)
+    }
 }
 
 impl Hash for DateTimeError {
@@ -84,82 +1088,502 @@ impl Hash for DateTimeError {
     }
 }
 
+/// Component names that `time` and this crate report in parse errors.
+///
+/// Deserialization maps names back onto these so the `&'static str`
+/// fields of [`DateTimeError::InvalidFormatAt`] and of
+/// `ParseFromDescription::InvalidComponent` can be restored.
+const COMPONENT_NAMES: [&str; 23] = [
+    "day",
+    "end of input",
+    "hour",
+    "ignore",
+    "literal",
+    "minute",
+    "month",
+    "offset hour",
+    "offset minute",
+    "offset second",
+    "offset_hour",
+    "offset_minute",
+    "offset_second",
+    "ordinal",
+    "period",
+    "second",
+    "separator",
+    "subsecond",
+    "unix_timestamp",
+    "week",
+    "week number",
+    "weekday",
+    "year",
+];
+
+/// Returns the static copy of a component name.
+fn static_component_name<E: serde::de::Error>(
+    name: &str,
+) -> Result<&'static str, E> {
+    COMPONENT_NAMES
+        .iter()
+        .find(|known| **known == name)
+        .copied()
+        .ok_or_else(|| E::custom(format!("unknown component `{name}`")))
+}
+
+/// The serde form of a [`DateTimeError`], externally tagged so each
+/// variant carries its payload.
+#[derive(Serialize, Deserialize)]
+enum ErrorRepr {
+    InvalidFormat,
+    InvalidTimezone,
+    InvalidDate,
+    InvalidTime,
+    InvalidFormatAt {
+        index: usize,
+        expected: String,
+    },
+    ParseError(ParseRepr),
+    ComponentRange(RangeRepr),
+    ConversionOverflow {
+        instant: DateTime,
+        target: UtcOffset,
+    },
+    InvalidComponents(IssuesRepr),
+}
+
+/// The serde form of a `time::error::Parse`. `Other` holds variants
+/// added to `time` after this crate was written, which cannot be
+/// rebuilt.
+#[derive(Serialize, Deserialize)]
+enum ParseRepr {
+    InvalidLiteral,
+    InvalidComponent(String),
+    UnexpectedTrailingCharacters,
+    InsufficientInformation,
+    ComponentRange(RangeRepr),
+    Other(String),
+}
+
+/// The serde form of a `time::error::ComponentRange`: the component
+/// and the rejected value, which are enough to replay the error, and its
+/// message, which picks the range when several constructors reject the
+/// same value.
+#[derive(Serialize, Deserialize)]
+struct RangeRepr {
+    name: String,
+    value: i64,
+    conditional: bool,
+    message: String,
+}
+
+/// The serde form of [`ComponentIssues`]: the rejected components.
+#[derive(Serialize, Deserialize)]
+struct IssuesRepr {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+/// Reads the rejected value of a `ComponentRange` from its `Debug`
+/// output, the only place `time` exposes it.
+///
+/// The `Debug` layout is not a stable API, so a `None` here fails
+/// serialization rather than recording a wrong value.
+fn range_value(range: &ComponentRange) -> Option<i64> {
+    let debug = format!("{range:?}");
+    let start = debug.find(" value: ")? + " value: ".len();
+    debug[start..].split([',', ' ']).next()?.parse().ok()
+}
+
+impl RangeRepr {
+    /// Records a `time` range error.
+    fn from_range<E: serde::ser::Error>(
+        range: &ComponentRange,
+    ) -> Result<Self, E> {
+        let value = range_value(range).ok_or_else(|| {
+            E::custom(format!(
+                "cannot read the value of the `time` range error for `{}`",
+                range.name()
+            ))
+        })?;
+        Ok(Self {
+            name: range.name().to_string(),
+            value,
+            conditional: range.is_conditional(),
+            message: range.to_string(),
+        })
+    }
+}
+
+impl ParseRepr {
+    /// Records a `time` parse error.
+    fn from_parse<E: serde::ser::Error>(
+        error: &Parse,
+    ) -> Result<Self, E> {
+        Ok(match error {
+            Parse::ParseFromDescription(
+                ParseFromDescription::InvalidLiteral { .. },
+            ) => Self::InvalidLiteral,
+            Parse::ParseFromDescription(
+                ParseFromDescription::InvalidComponent(name),
+            ) => Self::InvalidComponent((*name).to_string()),
+            Parse::ParseFromDescription(
+                ParseFromDescription::UnexpectedTrailingCharacters {
+                    ..
+                },
+            ) => Self::UnexpectedTrailingCharacters,
+            Parse::TryFromParsed(
+                TryFromParsed::InsufficientInformation,
+            ) => Self::InsufficientInformation,
+            Parse::TryFromParsed(TryFromParsed::ComponentRange(
+                range,
+            )) => Self::ComponentRange(RangeRepr::from_range(range)?),
+            _ => Self::Other(format!("{error:?}")),
+        })
+    }
+}
+
+impl ErrorRepr {
+    /// Records a `DateTimeError`.
+    fn from_error<E: serde::ser::Error>(
+        error: &DateTimeError,
+    ) -> Result<Self, E> {
+        Ok(match error {
+            DateTimeError::InvalidFormat => Self::InvalidFormat,
+            DateTimeError::InvalidTimezone => Self::InvalidTimezone,
+            DateTimeError::InvalidDate => Self::InvalidDate,
+            DateTimeError::InvalidTime => Self::InvalidTime,
+            DateTimeError::InvalidFormatAt { index, expected } => {
+                Self::InvalidFormatAt {
+                    index: *index,
+                    expected: (*expected).to_string(),
+                }
+            }
+            DateTimeError::ParseError(inner) => {
+                Self::ParseError(ParseRepr::from_parse(inner)?)
+            }
+            DateTimeError::ComponentRange(inner) => {
+                Self::ComponentRange(RangeRepr::from_range(inner)?)
+            }
+            DateTimeError::ConversionOverflow { instant, target } => {
+                Self::ConversionOverflow {
+                    instant: *instant,
+                    target: *target,
+                }
+            }
+            DateTimeError::InvalidComponents(issues) => {
+                Self::InvalidComponents(IssuesRepr {
+                    year: issues.year,
+                    month: issues.month,
+                    day: issues.day,
+                    hour: issues.hour,
+                    minute: issues.minute,
+                    second: issues.second,
+                    nanosecond: issues.nanosecond,
+                })
+            }
+        })
+    }
+}
+
+/// Rebuilds a parse error `time` does not let other crates construct, by
+/// running the parse that produces it.
+fn replay_parse_error(
+    input: &str,
+    description: &[format_description::BorrowedFormatItem<'_>],
+) -> Option<Parse> {
+    Date::parse(input, description).err()
+}
+
+/// Rebuilds a `time` range error, whose fields are private, by calling
+/// the `time` constructors that report the component with the recorded
+/// value. Among those that agree on whether the range is conditional,
+/// the one with the recorded message wins, then the first.
+fn replay_range_error(range: &RangeRepr) -> Option<ComponentRange> {
+    let mut candidates = date_range_errors(&range.name, range.value);
+    candidates.extend(clock_range_errors(&range.name, range.value));
+    let candidates: Vec<ComponentRange> = candidates
+        .into_iter()
+        .flatten()
+        .filter(|candidate| {
+            candidate.name() == range.name
+                && candidate.is_conditional() == range.conditional
+        })
+        .collect();
+    candidates
+        .iter()
+        .find(|candidate| candidate.to_string() == range.message)
+        .or_else(|| candidates.first())
+        .copied()
+}
+
+/// The range errors `time` date constructors report for `value` as the
+/// component `name`.
+fn date_range_errors(
+    name: &str,
+    value: i64,
+) -> Vec<Option<ComponentRange>> {
+    let byte = u8::try_from(value).ok();
+    match name {
+        "year" => vec![i32::try_from(value).ok().and_then(|year| {
+            Date::from_calendar_date(year, Month::January, 1).err()
+        })],
+        "month" => {
+            vec![byte.and_then(|month| Month::try_from(month).err())]
+        }
+        // One month of each length, as the maximum depends on it.
+        "day" => [
+            (2023, Month::February),
+            (2024, Month::February),
+            (2024, Month::April),
+            (2024, Month::January),
+        ]
+        .iter()
+        .map(|&(year, month)| {
+            byte.and_then(|day| {
+                Date::from_calendar_date(year, month, day).err()
+            })
+        })
+        .collect(),
+        "ordinal" => [2023, 2024]
+            .iter()
+            .map(|&year| {
+                u16::try_from(value).ok().and_then(|ordinal| {
+                    Date::from_ordinal_date(year, ordinal).err()
+                })
+            })
+            .collect(),
+        // 2023 has 52 ISO weeks and 2020 has 53.
+        "week" => [2023, 2020]
+            .iter()
+            .map(|&year| {
+                byte.and_then(|week| {
+                    Date::from_iso_week_date(
+                        year,
+                        week,
+                        Weekday::Monday,
+                    )
+                    .err()
+                })
+            })
+            .collect(),
+        "julian_day" => vec![i32::try_from(value)
+            .ok()
+            .and_then(|day| Date::from_julian_day(day).err())],
+        _ => Vec::new(),
+    }
+}
+
+/// The range errors `time` time and offset constructors report for
+/// `value` as the component `name`.
+fn clock_range_errors(
+    name: &str,
+    value: i64,
+) -> Vec<Option<ComponentRange>> {
+    let byte = u8::try_from(value).ok();
+    match name {
+        "hour" => {
+            vec![byte.and_then(|hour| Time::from_hms(hour, 0, 0).err())]
+        }
+        "minute" => {
+            vec![byte
+                .and_then(|minute| Time::from_hms(0, minute, 0).err())]
+        }
+        "second" => vec![
+            byte.and_then(|second| Time::from_hms(0, 0, second).err()),
+            // A leap second outside the last minute of a UTC day.
+            match OffsetDateTime::parse(
+                "2016-06-30T12:59:60Z",
+                &format_description::well_known::Rfc3339,
+            ) {
+                Err(Parse::TryFromParsed(
+                    TryFromParsed::ComponentRange(range),
+                )) => Some(range),
+                _ => None,
+            },
+        ],
+        "millisecond" => {
+            vec![u16::try_from(value).ok().and_then(|milli| {
+                Time::from_hms_milli(0, 0, 0, milli).err()
+            })]
+        }
+        "microsecond" => {
+            vec![u32::try_from(value).ok().and_then(|micro| {
+                Time::from_hms_micro(0, 0, 0, micro).err()
+            })]
+        }
+        "nanosecond" => vec![u32::try_from(value)
+            .ok()
+            .and_then(|nano| Time::from_hms_nano(0, 0, 0, nano).err())],
+        "hours" => vec![i8::try_from(value)
+            .ok()
+            .and_then(|hours| UtcOffset::from_hms(hours, 0, 0).err())],
+        "minutes" => {
+            vec![i8::try_from(value).ok().and_then(|minutes| {
+                UtcOffset::from_hms(0, minutes, 0).err()
+            })]
+        }
+        "seconds" => vec![
+            i8::try_from(value).ok().and_then(|seconds| {
+                UtcOffset::from_hms(0, 0, seconds).err()
+            }),
+            i32::try_from(value).ok().and_then(|seconds| {
+                UtcOffset::from_whole_seconds(seconds).err()
+            }),
+        ],
+        "timestamp" => {
+            vec![OffsetDateTime::from_unix_timestamp(value).err()]
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl RangeRepr {
+    /// Rebuilds the `time` range error.
+    fn into_range<E: serde::de::Error>(
+        self,
+    ) -> Result<ComponentRange, E> {
+        replay_range_error(&self).ok_or_else(|| {
+            E::custom(format!(
+                "cannot rebuild the `time` range error for `{}` with \
+                 value {}",
+                self.name, self.value
+            ))
+        })
+    }
+}
+
+impl ParseRepr {
+    /// Rebuilds the `time` parse error.
+    fn into_parse<E: serde::de::Error>(self) -> Result<Parse, E> {
+        let replayed = match self {
+            Self::InvalidLiteral => replay_parse_error(
+                "x",
+                time::macros::format_description!("-"),
+            ),
+            Self::UnexpectedTrailingCharacters => replay_parse_error(
+                "2000-01-01x",
+                time::macros::format_description!(
+                    "[year]-[month]-[day]"
+                ),
+            ),
+            Self::InvalidComponent(name) => {
+                return Ok(Parse::ParseFromDescription(
+                    ParseFromDescription::InvalidComponent(
+                        static_component_name(&name)?,
+                    ),
+                ))
+            }
+            Self::InsufficientInformation => {
+                return Ok(Parse::TryFromParsed(
+                    TryFromParsed::InsufficientInformation,
+                ))
+            }
+            Self::ComponentRange(range) => {
+                return Ok(Parse::TryFromParsed(
+                    TryFromParsed::ComponentRange(range.into_range()?),
+                ))
+            }
+            Self::Other(message) => {
+                return Err(E::custom(format!(
+                "cannot rebuild the `time` parse error \"{message}\""
+            )))
+            }
+        };
+        replayed
+            .ok_or_else(|| E::custom("failed to rebuild parse error"))
+    }
+}
+
+impl ErrorRepr {
+    /// Rebuilds the `DateTimeError`.
+    fn into_error<E: serde::de::Error>(
+        self,
+    ) -> Result<DateTimeError, E> {
+        Ok(match self {
+            Self::InvalidFormat => DateTimeError::InvalidFormat,
+            Self::InvalidTimezone => DateTimeError::InvalidTimezone,
+            Self::InvalidDate => DateTimeError::InvalidDate,
+            Self::InvalidTime => DateTimeError::InvalidTime,
+            Self::InvalidFormatAt { index, expected } => {
+                DateTimeError::InvalidFormatAt {
+                    index,
+                    expected: static_component_name(&expected)?,
+                }
+            }
+            Self::ParseError(inner) => {
+                DateTimeError::ParseError(inner.into_parse()?)
+            }
+            Self::ComponentRange(range) => {
+                DateTimeError::ComponentRange(range.into_range()?)
+            }
+            Self::ConversionOverflow { instant, target } => {
+                DateTimeError::ConversionOverflow { instant, target }
+            }
+            Self::InvalidComponents(issues) => {
+                let issues = ComponentIssues::check(
+                    issues.year,
+                    issues.month,
+                    issues.day,
+                    issues.hour,
+                    issues.minute,
+                    issues.second,
+                    issues.nanosecond,
+                )
+                .ok_or_else(|| {
+                    E::custom(
+                        "InvalidComponents has no invalid component",
+                    )
+                })?;
+                DateTimeError::InvalidComponents(issues)
+            }
+        })
+    }
+}
+
 impl Serialize for DateTimeError {
-    /// Serializes the `DateTimeError` into a string representation.
+    /// Serializes the error externally tagged: variants without data are
+    /// their name, e.g. `"InvalidDate"`, and the others are an object
+    /// keyed by the name, e.g.
+    /// `{"InvalidFormatAt":{"index":5,"expected":"month"}}`.
     ///
-    /// This is a custom implementation to handle serialization for variants
-    /// that contain types (`Parse` and `ComponentRange`) which do not implement
-    /// `Serialize`.
+    /// Errors wrapping a `time` parse or range error record which one it
+    /// was, with the component, rejected value, and message, so they
+    /// deserialize back to an equal value.
     ///
     /// # Errors
     ///
-    /// This function will return a serialization error if the process fails.
+    /// This function will return a serialization error if the process
+    /// fails, or if the value of a `time` range error cannot be read.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        match self {
-            Self::InvalidFormat => {
-                serializer.serialize_str("InvalidFormat")
-            }
-            Self::InvalidTimezone => {
-                serializer.serialize_str("InvalidTimezone")
-            }
-            Self::InvalidDate => {
-                serializer.serialize_str("InvalidDate")
-            }
-            Self::InvalidTime => {
-                serializer.serialize_str("InvalidTime")
-            }
-            Self::ParseError(_) => {
-                serializer.serialize_str("ParseError")
-            }
-            Self::ComponentRange(_) => {
-                serializer.serialize_str("ComponentRange")
-            }
-        }
+        ErrorRepr::from_error(self)?.serialize(serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for DateTimeError {
-    /// Deserializes a string into a `DateTimeError`.
+    /// Deserializes the representation written by `Serialize`.
     ///
-    /// This is a custom implementation to handle deserialization for variants
-    /// that contain types (`Parse` and `ComponentRange`) which do not implement
-    /// `Deserialize`.
+    /// Every variant round-trips to an equal value. `time` errors are
+    /// rebuilt by calling the `time` function that reports them, and
+    /// fail to deserialize, rather than produce a different error, if no
+    /// such call reproduces the recorded one.
     ///
     /// # Errors
     ///
-    /// This function will return a deserialization error if the input string
-    /// does not match any of the known variants.
+    /// This function will return a deserialization error if the input is
+    /// not a known variant or its payload cannot be rebuilt.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s: &str = Deserialize::deserialize(deserializer)?;
-        match s {
-            "InvalidFormat" => Ok(Self::InvalidFormat),
-            "InvalidTimezone" => Ok(Self::InvalidTimezone),
-            "InvalidDate" => Ok(Self::InvalidDate),
-            "InvalidTime" => Ok(Self::InvalidTime),
-            "ParseError" => Err(serde::de::Error::custom(
-                "Cannot deserialize ParseError directly",
-            )),
-            "ComponentRange" => Err(serde::de::Error::custom(
-                "Cannot deserialize ComponentRange directly",
-            )),
-            _ => Err(serde::de::Error::unknown_variant(
-                s,
-                &[
-                    "InvalidFormat",
-                    "InvalidTimezone",
-                    "InvalidDate",
-                    "InvalidTime",
-                    "ParseError",
-                    "ComponentRange",
-                ],
-            )),
-        }
+        ErrorRepr::deserialize(deserializer)?.into_error()
     }
 }
 
@@ -0,0 +1,321 @@
+// flexible.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Lenient parsing of dates typed by people.
+//!
+//! [`parse_flexible`] (also available as [`DateTime::parse_flexible`])
+//! accepts, in addition to everything [`DateTime::parse`] understands:
+//!
+//! - numeric dates with `/`, `-` or `.` separators: `01/02/2024`,
+//!   `1-2-24`, `2024.02.01`;
+//! - dates with English month names: `Jan 2 2024`, `January 2nd, 2024`,
+//!   `2 Jan 2024`;
+//! - relative words: `today`, `tomorrow`, `yesterday`, `next monday`,
+//!   `last friday`.
+//!
+//! Whether `01/02/2024` means 2 January or 1 February is set by
+//! [`DateOrder`]. Numeric dates that start with a four-digit year are
+//! always read as year, month, day. Two-digit years follow the POSIX
+//! convention: `00`-`68` are 2000-2068 and `69`-`99` are 1969-1999.
+//!
+//! Absolute dates resolve to midnight UTC. Relative words resolve to
+//! midnight in the offset of the reference time.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::flexible::{DateOrder, FlexibleOptions};
+//!
+//! let us = FlexibleOptions::new();
+//! let eu = FlexibleOptions::new().order(DateOrder::DayFirst);
+//!
+//! let dt = DateTime::parse_flexible("01/02/2024", &us).unwrap();
+//! assert_eq!(dt.to_string(), "2024-01-02T00:00:00Z");
+//! let dt = DateTime::parse_flexible("01/02/2024", &eu).unwrap();
+//! assert_eq!(dt.to_string(), "2024-02-01T00:00:00Z");
+//!
+//! let monday = DateTime::parse("2024-03-11T15:00:00Z").unwrap();
+//! let options = FlexibleOptions::new().relative_to(monday);
+//! let dt = DateTime::parse_flexible("next monday", &options).unwrap();
+//! assert_eq!(dt.to_string(), "2024-03-18T00:00:00Z");
+//! ```
+
+use crate::{
+    datetime::{DateTime, Unit},
+    error::DateTimeError,
+};
+use time::{UtcOffset, Weekday};
+
+/// English month names, indexed from January.
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// English weekday names, indexed from Monday.
+const WEEKDAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Two-digit years below this value are read as 20xx, others as 19xx.
+const TWO_DIGIT_YEAR_PIVOT: i32 = 69;
+
+/// Order of the day and month in ambiguous numeric dates.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum DateOrder {
+    /// Month before day, as in `01/02/2024` for 2 January (US style).
+    #[default]
+    MonthFirst,
+    /// Day before month, as in `01/02/2024` for 1 February.
+    DayFirst,
+}
+
+/// Options for [`parse_flexible`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FlexibleOptions {
+    /// Order of the day and month in ambiguous numeric dates.
+    pub order: DateOrder,
+    /// Reference time for relative words such as `tomorrow`. When `None`,
+    /// the current time is used.
+    pub reference: Option<DateTime>,
+}
+
+impl FlexibleOptions {
+    /// Creates options with month-first order, relative to the current
+    /// time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            order: DateOrder::MonthFirst,
+            reference: None,
+        }
+    }
+
+    /// Sets the order of the day and month in ambiguous numeric dates.
+    #[must_use]
+    pub const fn order(mut self, order: DateOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the reference time for relative words.
+    #[must_use]
+    pub const fn relative_to(mut self, reference: DateTime) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+}
+
+/// Parses a date in one of the common human-written formats listed in the
+/// [module documentation](self).
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormat` if the input matches none of
+/// the supported formats, or a `DateTimeError::InvalidDate` if it names a
+/// date that does not exist.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::flexible::{parse_flexible, FlexibleOptions};
+///
+/// let options = FlexibleOptions::new();
+/// let dt = parse_flexible("January 2nd, 2024", &options).unwrap();
+/// assert_eq!(dt.to_string(), "2024-01-02T00:00:00Z");
+/// assert!(parse_flexible("Feb 30 2024", &options).is_err());
+/// ```
+pub fn parse_flexible(
+    input: &str,
+    options: &FlexibleOptions,
+) -> Result<DateTime, DateTimeError> {
+    let input = input.trim();
+    if let Ok(dt) = DateTime::parse(input) {
+        return Ok(dt);
+    }
+
+    let lower = input.to_ascii_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    let reference = options.reference.unwrap_or_default();
+    if let Some(days) = relative_days(&tokens, reference.weekday()) {
+        return reference.truncate_to(Unit::Day)?.add_days(days);
+    }
+
+    let (year, month, day) = match tokens.as_slice() {
+        [numeric] => numeric_date(numeric, options.order)?,
+        [first, second, third] => {
+            named_month_date(first, second, third)?
+        }
+        _ => return Err(DateTimeError::InvalidFormat),
+    };
+    DateTime::from_components(year, month, day, 0, 0, 0, UtcOffset::UTC)
+        .map_err(|_| DateTimeError::InvalidDate)
+}
+
+/// Returns the day offset named by a relative phrase, if the tokens form
+/// one, counting from a day that falls on `current`.
+fn relative_days(tokens: &[&str], current: Weekday) -> Option<i64> {
+    match tokens {
+        ["today"] => Some(0),
+        ["tomorrow"] => Some(1),
+        ["yesterday"] => Some(-1),
+        [direction @ ("next" | "last"), name] => {
+            let target = weekday(name)?;
+            let ahead = (i64::from(target.number_days_from_monday())
+                - i64::from(current.number_days_from_monday()))
+            .rem_euclid(7);
+            Some(if *direction == "next" {
+                if ahead == 0 {
+                    7
+                } else {
+                    ahead
+                }
+            } else if ahead == 0 {
+                -7
+            } else {
+                ahead - 7
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parses `a/b/c`, `a-b-c`, or `a.b.c` with all-numeric parts.
+fn numeric_date(
+    token: &str,
+    order: DateOrder,
+) -> Result<(i32, u8, u8), DateTimeError> {
+    let separator = token
+        .chars()
+        .find(|c| matches!(c, '/' | '-' | '.'))
+        .ok_or(DateTimeError::InvalidFormat)?;
+    let parts: Vec<&str> = token.split(separator).collect();
+    let [first, second, third] = match parts.as_slice() {
+        [a, b, c] => [*a, *b, *c],
+        _ => return Err(DateTimeError::InvalidFormat),
+    };
+
+    if first.len() == 4 {
+        return Ok((
+            number(first, 4)?,
+            small_number(second)?,
+            small_number(third)?,
+        ));
+    }
+
+    let year = year(third)?;
+    let (a, b) = (small_number(first)?, small_number(second)?);
+    Ok(match order {
+        DateOrder::MonthFirst => (year, a, b),
+        DateOrder::DayFirst => (year, b, a),
+    })
+}
+
+/// Parses `month day year` or `day month year` with an English month
+/// name.
+fn named_month_date(
+    first: &str,
+    second: &str,
+    third: &str,
+) -> Result<(i32, u8, u8), DateTimeError> {
+    let year = year(third)?;
+    if let Some(month) = month(first) {
+        return Ok((year, month, day(second)?));
+    }
+    if let Some(month) = month(second) {
+        return Ok((year, month, day(first)?));
+    }
+    Err(DateTimeError::InvalidFormat)
+}
+
+/// Parses a two- or four-digit year.
+fn year(token: &str) -> Result<i32, DateTimeError> {
+    match token.len() {
+        2 => {
+            let year = number(token, 2)?;
+            Ok(if year < TWO_DIGIT_YEAR_PIVOT {
+                2000 + year
+            } else {
+                1900 + year
+            })
+        }
+        _ => number(token, 4),
+    }
+}
+
+/// Parses a day of the month, allowing an English ordinal suffix.
+fn day(token: &str) -> Result<u8, DateTimeError> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| token.strip_suffix(suffix))
+        .unwrap_or(token);
+    small_number(digits)
+}
+
+/// Parses a one- or two-digit number.
+fn small_number(token: &str) -> Result<u8, DateTimeError> {
+    if token.is_empty() || token.len() > 2 {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    number(token, token.len()).and_then(|n| {
+        u8::try_from(n).map_err(|_| DateTimeError::InvalidFormat)
+    })
+}
+
+/// Parses exactly `width` ASCII digits.
+fn number(token: &str, width: usize) -> Result<i32, DateTimeError> {
+    if token.len() != width
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    token.parse().map_err(|_| DateTimeError::InvalidFormat)
+}
+
+/// Matches a month name or an abbreviation of at least three letters.
+fn month(token: &str) -> Option<u8> {
+    let token = token.trim_end_matches('.');
+    if token.len() < 3 {
+        return None;
+    }
+    MONTH_NAMES
+        .iter()
+        .position(|name| name.starts_with(token))
+        .and_then(|index| u8::try_from(index + 1).ok())
+}
+
+/// Matches a weekday name or an abbreviation of at least three letters.
+fn weekday(token: &str) -> Option<Weekday> {
+    if token.len() < 3 {
+        return None;
+    }
+    let index = WEEKDAY_NAMES
+        .iter()
+        .position(|name| name.starts_with(token))?;
+    u8::try_from(index)
+        .ok()
+        .map(|index| Weekday::Monday.nth_next(index))
+}
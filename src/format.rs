@@ -0,0 +1,630 @@
+// format.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Preset formats shared by every date and time type in the crate.
+//!
+//! [`FormatableDateTime`] is implemented by [`DateTime`],
+//! [`NaiveDateTime`], [`Date`], [`Time`], and [`TomlDateTime`], so APIs can
+//! accept `impl FormatableDateTime` and render any of them with one of
+//! the [`Format`] presets.
+//!
+//! Not every preset applies to every type: a [`Time`] has no calendar
+//! date, and only a [`DateTime`] carries the offset that RFC 3339 and HTTP
+//! dates require. Asking for a preset a value cannot express returns a
+//! `DateTimeError::InvalidFormat`.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::format::{Format, FormatableDateTime};
+//! use dtt::{DateTime, Date};
+//!
+//! fn label(value: &impl FormatableDateTime) -> String {
+//!     value.format_preset(Format::CalendarDate).unwrap()
+//! }
+//!
+//! let dt = DateTime::parse("2024-02-15T10:30:00+01:00").unwrap();
+//! let date = Date::from_calendar_date(2024, 2, 15).unwrap();
+//! assert_eq!(label(&dt), "2024-02-15");
+//! assert_eq!(label(&date), "2024-02-15");
+//! ```
+//!
+//! # Named formats
+//!
+//! [`FormatRegistry`] maps names to format descriptions, so an
+//! application can define its timestamp formats once and refer to them
+//! by name with [`DateTime::format_named`] and [`DateTime::parse_named`].
+//! The `dtt` binary loads the `DTT_FORMATS` environment variable into the
+//! [global registry](FormatRegistry::global), making the same names
+//! available to `--format`.
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::format::FormatRegistry;
+//!
+//! FormatRegistry::global()
+//!     .register("audit", "[year][month][day]T[hour][minute][second]")
+//!     .unwrap();
+//!
+//! let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+//! assert_eq!(dt.format_named("audit").unwrap(), "20240215T103000");
+//! ```
+//!
+//! # Compiled formats
+//!
+//! A [`FormatSpec`] (also named [`CompiledFormat`]) holds a format
+//! description parsed once, for [`DateTime::parse_with_spec`],
+//! [`DateTime::format_with`], and the batch parsers to reuse. The
+//! string-based [`DateTime::format`] and
+//! [`DateTime::parse_custom_format`] keep the most recently used
+//! descriptions compiled in a small per-thread cache, so passing the same
+//! string repeatedly is cheap too.
+//!
+//! [`DateTime::format_named`]: crate::datetime::DateTime::format_named
+//! [`DateTime::parse_with_spec`]: crate::datetime::DateTime::parse_with_spec
+//! [`DateTime::format_with`]: crate::datetime::DateTime::format_with
+//! [`DateTime::format`]: crate::datetime::DateTime::format
+//! [`DateTime::parse_custom_format`]: crate::datetime::DateTime::parse_custom_format
+//! [`DateTime::parse_named`]: crate::datetime::DateTime::parse_named
+
+use crate::{
+    datetime::DateTime,
+    error::DateTimeError,
+    local::TomlDateTime,
+    naive::NaiveDateTime,
+    plain::{Date, Time},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{self, Write},
+    rc::Rc,
+    str::FromStr,
+    sync::{PoisonError, RwLock},
+};
+use time::{
+    format_description::{self, FormatItem, OwnedFormatItem},
+    macros::format_description,
+};
+
+/// The registry consulted by `DateTime::format_named` and
+/// `DateTime::parse_named`.
+static GLOBAL_REGISTRY: FormatRegistry = FormatRegistry::new();
+
+/// How many format descriptions [`FORMAT_CACHE`] keeps per thread.
+const FORMAT_CACHE_CAPACITY: usize = 32;
+
+thread_local! {
+    /// Recently compiled format descriptions behind `DateTime::format`
+    /// and `DateTime::parse_custom_format`. Each thread keeps its own,
+    /// so lookups and inserts need no lock.
+    static FORMAT_CACHE: RefCell<FormatCache> =
+        RefCell::new(FormatCache::default());
+}
+
+/// A least recently used cache of compiled format descriptions.
+#[derive(Debug, Default)]
+struct FormatCache {
+    /// Compiled descriptions by source text, with the tick of their last
+    /// use.
+    specs: HashMap<String, (Rc<FormatSpec>, u64)>,
+    /// Incremented on every lookup.
+    tick: u64,
+}
+
+impl FormatCache {
+    /// Returns `description` compiled, compiling and inserting it on a
+    /// miss. A full cache first evicts its least recently used entry.
+    fn get_or_compile(
+        &mut self,
+        description: &str,
+    ) -> Result<Rc<FormatSpec>, DateTimeError> {
+        self.tick += 1;
+        if let Some((spec, used)) = self.specs.get_mut(description) {
+            *used = self.tick;
+            return Ok(Rc::clone(spec));
+        }
+
+        let spec = Rc::new(FormatSpec::new(description)?);
+        if self.specs.len() >= FORMAT_CACHE_CAPACITY {
+            let oldest = self
+                .specs
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                let _ = self.specs.remove(&oldest);
+            }
+        }
+        let _ = self.specs.insert(
+            description.to_owned(),
+            (Rc::clone(&spec), self.tick),
+        );
+        Ok(spec)
+    }
+}
+
+/// `HH:MM:SS`.
+const TIME_OF_DAY_FORMAT: &[FormatItem<'static>] =
+    format_description!("[hour]:[minute]:[second]");
+
+/// `YYYY-DDD`.
+const ORDINAL_DATE_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[ordinal]");
+
+/// `YYYY-MM-DD`.
+const DATE_ONLY_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[month]-[day]");
+
+/// ISO 8601 basic date and time to the second, `YYYYMMDDTHHMMSS`.
+const ISO8601_BASIC_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year][month][day]T[hour][minute][second]");
+
+/// ISO 8601 basic date and time with a fractional second.
+const ISO8601_BASIC_SUBSECOND_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year][month][day]T[hour][minute][second].[subsecond]"
+);
+
+/// ISO 8601 basic UTC offset, `+HHMM`.
+const ISO8601_BASIC_OFFSET_FORMAT: &[FormatItem<'static>] =
+    format_description!("[offset_hour sign:mandatory][offset_minute]");
+
+/// Preset output formats understood by [`FormatableDateTime`].
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum Format {
+    /// RFC 3339, e.g. `2024-02-15T10:30:00+01:00`. Requires an offset.
+    Rfc3339,
+    /// The ISO 8601 form of the value itself: `2024-02-15T10:30:00` for
+    /// date-times, `2024-02-15` for dates, and `10:30:00` for times, with
+    /// any fractional second.
+    Iso8601,
+    /// An HTTP `Date` header value in GMT, e.g.
+    /// `Thu, 15 Feb 2024 09:30:00 GMT`. Requires an offset.
+    HttpDate,
+    /// The calendar date, e.g. `2024-02-15`.
+    CalendarDate,
+    /// The ISO 8601 ordinal date, e.g. `2024-046`.
+    OrdinalDate,
+    /// The time of day to the second, e.g. `10:30:00`.
+    TimeOfDay,
+    /// The date and time followed by the timezone abbreviation, e.g.
+    /// `2024-02-15 10:30:00 EST`, or by the numeric offset if the value
+    /// has no abbreviation. Requires an offset.
+    Zoned,
+}
+
+/// A value that can be rendered with the crate's [`Format`] presets.
+pub trait FormatableDateTime {
+    /// Formats the value using a preset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the value lacks a
+    /// component the preset needs, such as an offset or a date.
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError>;
+}
+
+impl<T: FormatableDateTime + ?Sized> FormatableDateTime for &T {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        (**self).format_preset(format)
+    }
+}
+
+impl FormatableDateTime for DateTime {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        match format {
+            Format::Rfc3339 => self.format_rfc3339(),
+            Format::Iso8601 => self.format_iso8601(),
+            Format::HttpDate => self.format_http_date(),
+            Format::OrdinalDate => self.format_ordinal_date(),
+            Format::CalendarDate => {
+                self.date_only().format_preset(format)
+            }
+            Format::TimeOfDay => self.time_only().format_preset(format),
+            Format::Zoned => self.format_zoned(),
+        }
+    }
+}
+
+impl FormatableDateTime for NaiveDateTime {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        let datetime = self.to_primitive();
+        match format {
+            Format::Iso8601 => Ok(self.to_string()),
+            Format::CalendarDate | Format::OrdinalDate => {
+                Date::from(datetime.date()).format_preset(format)
+            }
+            Format::TimeOfDay => {
+                Time::from(datetime.time()).format_preset(format)
+            }
+            Format::Rfc3339 | Format::HttpDate | Format::Zoned => {
+                Err(DateTimeError::InvalidFormat)
+            }
+        }
+    }
+}
+
+impl FormatableDateTime for Date {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        let description = match format {
+            Format::Iso8601 | Format::CalendarDate => {
+                return Ok(self.to_string())
+            }
+            Format::OrdinalDate => ORDINAL_DATE_FORMAT,
+            Format::Rfc3339
+            | Format::HttpDate
+            | Format::TimeOfDay
+            | Format::Zoned => {
+                return Err(DateTimeError::InvalidFormat)
+            }
+        };
+        self.to_date()
+            .format(description)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+}
+
+impl FormatableDateTime for Time {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        match format {
+            Format::Iso8601 => Ok(self.to_string()),
+            Format::TimeOfDay => self
+                .to_time()
+                .format(TIME_OF_DAY_FORMAT)
+                .map_err(|_| DateTimeError::InvalidFormat),
+            Format::Rfc3339
+            | Format::HttpDate
+            | Format::CalendarDate
+            | Format::OrdinalDate
+            | Format::Zoned => Err(DateTimeError::InvalidFormat),
+        }
+    }
+}
+
+impl FormatableDateTime for TomlDateTime {
+    fn format_preset(
+        &self,
+        format: Format,
+    ) -> Result<String, DateTimeError> {
+        match self {
+            Self::OffsetDateTime(datetime) => {
+                datetime.format_preset(format)
+            }
+            Self::LocalDateTime(datetime) => {
+                datetime.format_preset(format)
+            }
+            Self::LocalDate(date) => date.format_preset(format),
+            Self::LocalTime(time) => time.format_preset(format),
+        }
+    }
+}
+
+/// A set of named format descriptions registered at runtime.
+///
+/// Descriptions use the syntax of [`DateTime::format`], such as
+/// `"[year]-[month]-[day]"`, and are checked when registered. Names are
+/// case-sensitive.
+#[derive(Debug, Default)]
+pub struct FormatRegistry {
+    /// Registered formats, sorted by name.
+    formats: RwLock<Vec<(String, String)>>,
+}
+
+impl FormatRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            formats: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Returns the process-wide registry used by
+    /// [`DateTime::format_named`] and [`DateTime::parse_named`].
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Registers `description` under `name`, replacing any earlier
+    /// registration of the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the name is empty or
+    /// contains whitespace, or if the description does not parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::format::FormatRegistry;
+    ///
+    /// let registry = FormatRegistry::new();
+    /// registry.register("day", "[year]-[month]-[day]").unwrap();
+    /// assert_eq!(
+    ///     registry.lookup("day").as_deref(),
+    ///     Some("[year]-[month]-[day]")
+    /// );
+    /// assert!(registry.register("broken", "[year").is_err());
+    /// ```
+    pub fn register(
+        &self,
+        name: &str,
+        description: &str,
+    ) -> Result<(), DateTimeError> {
+        if name.is_empty()
+            || name.chars().any(char::is_whitespace)
+            || format_description::parse(description).is_err()
+        {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let mut formats = self
+            .formats
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        match formats
+            .binary_search_by(|(key, _)| key.as_str().cmp(name))
+        {
+            Ok(index) => formats[index].1 = description.to_string(),
+            Err(index) => formats.insert(
+                index,
+                (name.to_string(), description.to_string()),
+            ),
+        }
+        drop(formats);
+        Ok(())
+    }
+
+    /// Removes a registration and returns its description, or `None` if
+    /// `name` was not registered.
+    pub fn unregister(&self, name: &str) -> Option<String> {
+        let mut formats = self
+            .formats
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        formats
+            .binary_search_by(|(key, _)| key.as_str().cmp(name))
+            .ok()
+            .map(|index| formats.remove(index).1)
+    }
+
+    /// Returns the description registered under `name`.
+    #[must_use]
+    pub fn lookup(&self, name: &str) -> Option<String> {
+        let formats =
+            self.formats.read().unwrap_or_else(PoisonError::into_inner);
+        formats
+            .binary_search_by(|(key, _)| key.as_str().cmp(name))
+            .ok()
+            .map(|index| formats[index].1.clone())
+    }
+
+    /// Returns the registered names and descriptions, sorted by name.
+    #[must_use]
+    pub fn registered(&self) -> Vec<(String, String)> {
+        self.formats
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// A format description parsed once, for parsing or formatting many
+/// values.
+///
+/// Compiling a description into a `FormatSpec` up front and passing that
+/// to [`DateTime::parse_with_spec`] or [`DateTime::format_with`] skips
+/// parsing the description on each call, which matters when the same
+/// format is applied to many lines of a log.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::format::FormatSpec;
+///
+/// let spec = FormatSpec::new("[day]/[month]/[year] [hour]:[minute]").unwrap();
+/// let dt = DateTime::parse_with_spec("15/02/2024 10:30", &spec).unwrap();
+/// assert_eq!(dt.to_string(), "2024-02-15T10:30:00Z");
+/// assert_eq!(spec.as_str(), "[day]/[month]/[year] [hour]:[minute]");
+/// ```
+///
+/// [`DateTime::parse_with_spec`]: crate::datetime::DateTime::parse_with_spec
+/// [`DateTime::format_with`]: crate::datetime::DateTime::format_with
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FormatSpec {
+    /// The description as written.
+    description: String,
+    /// The parsed description.
+    items: OwnedFormatItem,
+}
+
+impl FormatSpec {
+    /// Parses a format description, in the syntax of
+    /// [`DateTime::format`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the description does
+    /// not parse.
+    pub fn new(description: &str) -> Result<Self, DateTimeError> {
+        let items = format_description::parse_owned::<1>(description)
+            .map_err(|_| {
+            debug_event!(cause = "invalid format description");
+            DateTimeError::InvalidFormat
+        })?;
+        Ok(Self {
+            description: description.to_string(),
+            items,
+        })
+    }
+
+    /// Returns the description the spec was compiled from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the parsed description.
+    pub(crate) const fn items(&self) -> &OwnedFormatItem {
+        &self.items
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = DateTimeError;
+
+    fn from_str(description: &str) -> Result<Self, Self::Err> {
+        Self::new(description)
+    }
+}
+
+impl fmt::Display for FormatSpec {
+    /// Writes the description the spec was compiled from.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+/// A format description compiled for reuse: the formatting name for
+/// [`FormatSpec`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::format::CompiledFormat;
+///
+/// let compiled = CompiledFormat::new("[year]-[month]-[day]").unwrap();
+/// let dt = DateTime::parse("2024-02-15T10:30:00Z").unwrap();
+/// assert_eq!(dt.format_with(&compiled).unwrap(), "2024-02-15");
+/// ```
+pub type CompiledFormat = FormatSpec;
+
+/// Returns `description` compiled, from the thread's cache if it was used
+/// recently.
+///
+/// Descriptions that fail to parse are not cached. Nested calls and calls
+/// during thread teardown compile the description without caching it.
+pub(crate) fn cached_spec(
+    description: &str,
+) -> Result<Rc<FormatSpec>, DateTimeError> {
+    FORMAT_CACHE
+        .try_with(|cell| {
+            cell.try_borrow_mut()
+                .ok()
+                .map(|mut cache| cache.get_or_compile(description))
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| FormatSpec::new(description).map(Rc::new))
+}
+
+/// How [`DateTime::display_as`] renders a value.
+///
+/// [`DateTime::display_as`]: crate::datetime::DateTime::display_as
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum DisplayStyle<'a> {
+    /// RFC 3339, as `Display` for `DateTime` writes it, e.g.
+    /// `2024-02-15T10:30:05+01:00`.
+    #[default]
+    Rfc3339,
+    /// ISO 8601 basic format, e.g. `20240215T103005+0100` or
+    /// `20240215T093005Z`, with any fractional second.
+    Iso8601Basic,
+    /// The calendar date in the value's offset, e.g. `2024-02-15`.
+    DateOnly,
+    /// A pre-compiled format, as used by `DateTime::format_with`.
+    Custom(&'a CompiledFormat),
+}
+
+/// A [`DateTime`] paired with a [`DisplayStyle`], created by
+/// [`DateTime::display_as`].
+///
+/// Formatting writes straight into the formatter. `Rfc3339` writes
+/// exactly what `Display` for `DateTime` does, including its extended
+/// ISO 8601 fallback, and custom formats may use offset components. A
+/// value that still cannot be written in the style is written as
+/// `Display` would instead, so formatting only fails if the formatter
+/// does.
+///
+/// [`DateTime::display_as`]: crate::datetime::DateTime::display_as
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayAs<'a> {
+    /// The value displayed.
+    datetime: &'a DateTime,
+    /// How it is displayed.
+    style: DisplayStyle<'a>,
+}
+
+impl<'a> DisplayAs<'a> {
+    /// Pairs `datetime` with `style`.
+    pub(crate) const fn new(
+        datetime: &'a DateTime,
+        style: DisplayStyle<'a>,
+    ) -> Self {
+        Self { datetime, style }
+    }
+}
+
+impl fmt::Display for DisplayAs<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = self.datetime;
+        let result = match self.style {
+            DisplayStyle::Rfc3339 => {
+                return fmt::Display::fmt(datetime, f)
+            }
+            DisplayStyle::DateOnly => {
+                datetime.write_described(f, DATE_ONLY_FORMAT)
+            }
+            DisplayStyle::Custom(format) => {
+                datetime.write_described(f, format.items())
+            }
+            DisplayStyle::Iso8601Basic => {
+                let description = if datetime.nanosecond() == 0 {
+                    ISO8601_BASIC_FORMAT
+                } else {
+                    ISO8601_BASIC_SUBSECOND_FORMAT
+                };
+                datetime.write_described(f, description).and_then(
+                    |()| {
+                        if datetime.offset().is_utc() {
+                            f.write_char('Z').map_err(|_| {
+                                DateTimeError::InvalidFormat
+                            })
+                        } else {
+                            datetime.write_described(
+                                f,
+                                ISO8601_BASIC_OFFSET_FORMAT,
+                            )
+                        }
+                    },
+                )
+            }
+        };
+        result.or_else(|_| fmt::Display::fmt(datetime, f))
+    }
+}
@@ -0,0 +1,432 @@
+// holidays.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Holiday calendars and business-day arithmetic.
+//!
+//! # Overview
+//!
+//! - [`HolidayProvider`]: a source of holidays for a region. Implement
+//!   [`holidays`](HolidayProvider::holidays) to plug in your own calendar.
+//! - Built-in providers: [`UsFederal`] (US federal holidays),
+//!   [`UkBankHolidays`] (England and Wales), and [`Target2`] (closing days
+//!   of the euro area TARGET2 payment system).
+//! - [`is_business_day`] and [`add_business_days`]: skip weekends and
+//!   holidays. The same operations are available on
+//!   [`DateTime`](crate::datetime::DateTime) as
+//!   [`is_holiday`](crate::datetime::DateTime::is_holiday),
+//!   [`is_business_day`](crate::datetime::DateTime::is_business_day), and
+//!   [`add_business_days`](crate::datetime::DateTime::add_business_days).
+//!
+//! The built-in providers follow the regular statutory rules, including
+//! weekend substitutes. One-off holidays proclaimed for a single year,
+//! such as royal jubilees, are not included.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::holidays::{HolidayProvider, UsFederal};
+//!
+//! let friday = DateTime::parse("2024-07-03T09:00:00-04:00").unwrap();
+//! assert!(!friday.is_holiday(&UsFederal));
+//!
+//! let next = friday.add_business_days(1, &UsFederal).unwrap();
+//! assert_eq!(next.to_string(), "2024-07-05T09:00:00-04:00");
+//!
+//! let date = next.date_only().add_days(-1).unwrap();
+//! assert_eq!(UsFederal.holiday_name(date).as_deref(), Some("Independence Day"));
+//! ```
+
+use crate::{datetime::days_until, error::DateTimeError, plain::Date};
+use time::{Month, Weekday};
+
+/// A named holiday on a specific date.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Holiday {
+    /// The date the holiday is observed.
+    pub date: Date,
+    /// The name of the holiday.
+    pub name: String,
+}
+
+impl Holiday {
+    /// Creates a holiday.
+    #[must_use]
+    pub fn new(date: Date, name: impl Into<String>) -> Self {
+        Self {
+            date,
+            name: name.into(),
+        }
+    }
+}
+
+/// A source of holidays for a region or institution.
+///
+/// Only [`holidays`](Self::holidays) is required; the other methods are
+/// derived from it.
+pub trait HolidayProvider {
+    /// Returns the holidays observed in `year`, in date order.
+    fn holidays(&self, year: i32) -> Vec<Holiday>;
+
+    /// Returns the name of the holiday observed on `date`, if any.
+    fn holiday_name(&self, date: Date) -> Option<String> {
+        self.holidays(date.year())
+            .into_iter()
+            .find(|holiday| holiday.date == date)
+            .map(|holiday| holiday.name)
+    }
+
+    /// Returns `true` if a holiday is observed on `date`.
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holiday_name(date).is_some()
+    }
+
+    /// Returns `true` if `weekday` is a weekly rest day. Defaults to
+    /// Saturday and Sunday.
+    fn is_weekend(&self, weekday: Weekday) -> bool {
+        matches!(weekday, Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+impl<P: HolidayProvider + ?Sized> HolidayProvider for &P {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        (**self).holidays(year)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<String> {
+        (**self).holiday_name(date)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn is_weekend(&self, weekday: Weekday) -> bool {
+        (**self).is_weekend(weekday)
+    }
+}
+
+/// United States federal holidays (5 U.S.C. 6103).
+///
+/// A holiday that falls on a Saturday is observed on the preceding
+/// Friday, and one that falls on a Sunday on the following Monday. When
+/// New Year's Day falls on a Saturday it is observed on December 31 of
+/// the previous year. Juneteenth is included from 2021.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct UsFederal;
+
+impl HolidayProvider for UsFederal {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays = Vec::new();
+        let mut add = |date: Option<time::Date>, name: &str| {
+            if let Some(date) = date.filter(|date| date.year() == year)
+            {
+                holidays.push(Holiday::new(Date::from(date), name));
+            }
+        };
+
+        add(
+            observed_us(fixed(year, Month::January, 1)),
+            "New Year's Day",
+        );
+        add(
+            nth_weekday(year, Month::January, Weekday::Monday, 3),
+            "Birthday of Martin Luther King, Jr.",
+        );
+        add(
+            nth_weekday(year, Month::February, Weekday::Monday, 3),
+            "Washington's Birthday",
+        );
+        add(
+            last_weekday(year, Month::May, Weekday::Monday),
+            "Memorial Day",
+        );
+        if year >= 2021 {
+            add(
+                observed_us(fixed(year, Month::June, 19)),
+                "Juneteenth National Independence Day",
+            );
+        }
+        add(
+            observed_us(fixed(year, Month::July, 4)),
+            "Independence Day",
+        );
+        add(
+            nth_weekday(year, Month::September, Weekday::Monday, 1),
+            "Labor Day",
+        );
+        add(
+            nth_weekday(year, Month::October, Weekday::Monday, 2),
+            "Columbus Day",
+        );
+        add(
+            observed_us(fixed(year, Month::November, 11)),
+            "Veterans Day",
+        );
+        add(
+            nth_weekday(year, Month::November, Weekday::Thursday, 4),
+            "Thanksgiving Day",
+        );
+        add(
+            observed_us(fixed(year, Month::December, 25)),
+            "Christmas Day",
+        );
+        add(
+            observed_us(fixed(year + 1, Month::January, 1)),
+            "New Year's Day",
+        );
+
+        holidays
+    }
+}
+
+/// Bank holidays in England and Wales.
+///
+/// New Year's Day, Christmas Day, and Boxing Day that fall on a weekend
+/// are replaced by the next weekdays that are not already holidays.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct UkBankHolidays;
+
+impl HolidayProvider for UkBankHolidays {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let easter = easter_sunday(year);
+        let mut holidays = Vec::new();
+        let mut add = |date: Option<time::Date>, name: &str| {
+            if let Some(date) = date {
+                holidays.push(Holiday::new(Date::from(date), name));
+            }
+        };
+
+        add(
+            substitute(fixed(year, Month::January, 1)),
+            "New Year's Day",
+        );
+        add(
+            easter.and_then(|day| day.previous_day()?.previous_day()),
+            "Good Friday",
+        );
+        add(easter.and_then(time::Date::next_day), "Easter Monday");
+        add(
+            nth_weekday(year, Month::May, Weekday::Monday, 1),
+            "Early May bank holiday",
+        );
+        add(
+            last_weekday(year, Month::May, Weekday::Monday),
+            "Spring bank holiday",
+        );
+        add(
+            last_weekday(year, Month::August, Weekday::Monday),
+            "Summer bank holiday",
+        );
+
+        // When either falls on a weekend, Christmas keeps the 27th if it
+        // needs a substitute and Boxing Day takes the next free weekday.
+        if let Some(christmas) = fixed(year, Month::December, 25) {
+            let (christmas_day, boxing_day) = match christmas.weekday()
+            {
+                Weekday::Friday => (25, 28),
+                Weekday::Saturday => (27, 28),
+                Weekday::Sunday => (27, 26),
+                _ => (25, 26),
+            };
+            add(
+                fixed(year, Month::December, christmas_day),
+                "Christmas Day",
+            );
+            add(fixed(year, Month::December, boxing_day), "Boxing Day");
+        }
+
+        holidays.sort_by_key(|holiday| holiday.date);
+        holidays
+    }
+}
+
+/// Closing days of TARGET2, the euro area's real-time gross settlement
+/// system, which set the settlement calendar for euro payments.
+///
+/// TARGET2 has no weekend substitutes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Target2;
+
+impl HolidayProvider for Target2 {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let easter = easter_sunday(year);
+        [
+            (fixed(year, Month::January, 1), "New Year's Day"),
+            (
+                easter
+                    .and_then(|day| day.previous_day()?.previous_day()),
+                "Good Friday",
+            ),
+            (easter.and_then(time::Date::next_day), "Easter Monday"),
+            (fixed(year, Month::May, 1), "Labour Day"),
+            (fixed(year, Month::December, 25), "Christmas Day"),
+            (fixed(year, Month::December, 26), "Christmas Holiday"),
+        ]
+        .into_iter()
+        .filter_map(|(date, name)| {
+            date.map(|date| Holiday::new(Date::from(date), name))
+        })
+        .collect()
+    }
+}
+
+/// Returns `true` if `date` is neither a weekend day nor a holiday.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::holidays::{is_business_day, Target2};
+/// use dtt::Date;
+///
+/// let may_day = Date::from_calendar_date(2024, 5, 1).unwrap();
+/// assert!(!is_business_day(may_day, &Target2));
+/// ```
+pub fn is_business_day<P>(date: Date, provider: &P) -> bool
+where
+    P: HolidayProvider + ?Sized,
+{
+    !provider.is_weekend(date.weekday()) && !provider.is_holiday(date)
+}
+
+/// Moves `days` business days forward, or backward if negative.
+///
+/// Counting starts from the next business day, so adding one business
+/// day to a Friday gives the following Monday (or later, if Monday is a
+/// holiday). Adding zero returns `date` unchanged, even if it is not a
+/// business day.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidDate` if the result falls outside the
+/// supported date range, or if the provider never yields a business day.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::holidays::{add_business_days, UkBankHolidays};
+/// use dtt::Date;
+///
+/// // Thursday before Easter 2024.
+/// let date = Date::from_calendar_date(2024, 3, 28).unwrap();
+/// let next = add_business_days(date, 1, &UkBankHolidays).unwrap();
+/// assert_eq!(next.to_string(), "2024-04-02");
+/// ```
+pub fn add_business_days<P>(
+    date: Date,
+    days: i64,
+    provider: &P,
+) -> Result<Date, DateTimeError>
+where
+    P: HolidayProvider + ?Sized,
+{
+    let step = days.signum();
+    let mut remaining = days.unsigned_abs();
+    let mut current = date;
+    let mut cached_year = None;
+    let mut holidays = Vec::new();
+    let mut since_business_day = 0;
+
+    while remaining > 0 {
+        current = current.add_days(step)?;
+        if cached_year != Some(current.year()) {
+            cached_year = Some(current.year());
+            holidays = provider.holidays(current.year());
+        }
+        let closed = provider.is_weekend(current.weekday())
+            || holidays.iter().any(|holiday| holiday.date == current);
+        if closed {
+            since_business_day += 1;
+            if since_business_day > MAX_CONSECUTIVE_CLOSED_DAYS {
+                return Err(DateTimeError::InvalidDate);
+            }
+        } else {
+            since_business_day = 0;
+            remaining -= 1;
+        }
+    }
+    Ok(current)
+}
+
+/// Number of consecutive closed days after which a provider is assumed
+/// never to open.
+const MAX_CONSECUTIVE_CLOSED_DAYS: u32 = 366;
+
+/// Returns a fixed calendar date, or `None` if it is out of range.
+fn fixed(year: i32, month: Month, day: u8) -> Option<time::Date> {
+    time::Date::from_calendar_date(year, month, day).ok()
+}
+
+/// Applies the US federal observance rule: Saturday moves to Friday and
+/// Sunday to Monday.
+fn observed_us(date: Option<time::Date>) -> Option<time::Date> {
+    let date = date?;
+    match date.weekday() {
+        Weekday::Saturday => date.previous_day(),
+        Weekday::Sunday => date.next_day(),
+        _ => Some(date),
+    }
+}
+
+/// Moves a weekend date to the following Monday.
+fn substitute(date: Option<time::Date>) -> Option<time::Date> {
+    let date = date?;
+    match date.weekday() {
+        Weekday::Saturday => date.next_day()?.next_day(),
+        Weekday::Sunday => date.next_day(),
+        _ => Some(date),
+    }
+}
+
+/// Returns the `n`th (1-based) `weekday` of a month.
+fn nth_weekday(
+    year: i32,
+    month: Month,
+    weekday: Weekday,
+    n: u8,
+) -> Option<time::Date> {
+    let first = fixed(year, month, 1)?;
+    fixed(
+        year,
+        month,
+        1 + days_until(first.weekday(), weekday) + (n - 1) * 7,
+    )
+}
+
+/// Returns the last `weekday` of a month.
+fn last_weekday(
+    year: i32,
+    month: Month,
+    weekday: Weekday,
+) -> Option<time::Date> {
+    let last = fixed(year, month, month.length(year))?;
+    fixed(
+        year,
+        month,
+        month.length(year) - days_until(weekday, last.weekday()),
+    )
+}
+
+/// Returns Easter Sunday in the Gregorian calendar (anonymous Gregorian
+/// algorithm).
+#[allow(clippy::many_single_char_names)]
+fn easter_sunday(year: i32) -> Option<time::Date> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c.div_euclid(4);
+    let k = c.rem_euclid(4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114).div_euclid(31);
+    let day = (h + l - 7 * m + 114).rem_euclid(31) + 1;
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    fixed(year, month, u8::try_from(day).ok()?)
+}
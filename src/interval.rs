@@ -0,0 +1,427 @@
+// interval.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Half-open time intervals and sets of intervals.
+//!
+//! # Overview
+//!
+//! - [`DateTimeInterval`]: the span `[start, end)`, with overlap,
+//!   intersection, union, containment, and stepping.
+//! - [`IntervalSet`]: a normalized collection of intervals, kept sorted
+//!   with overlapping and adjacent intervals merged.
+//!
+//! Intervals compare instants, not wall clocks, so endpoints in different
+//! offsets are handled correctly. Each endpoint keeps the offset it was
+//! created with.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::interval::{DateTimeInterval, IntervalSet};
+//!
+//! let at = |s: &str| DateTime::parse(s).unwrap();
+//! let meeting = DateTimeInterval::new(
+//!     at("2024-03-11T09:00:00Z"),
+//!     at("2024-03-11T10:00:00Z"),
+//! )
+//! .unwrap();
+//! let lunch = DateTimeInterval::new(
+//!     at("2024-03-11T09:30:00Z"),
+//!     at("2024-03-11T11:00:00Z"),
+//! )
+//! .unwrap();
+//! assert!(meeting.overlaps(&lunch));
+//!
+//! let busy: IntervalSet = [meeting, lunch].into_iter().collect();
+//! assert_eq!(busy.len(), 1);
+//! assert_eq!(busy.total_duration().whole_minutes(), 120);
+//! ```
+
+use crate::{datetime::DateTime, error::DateTimeError};
+use std::{fmt, slice};
+use time::{Duration, OffsetDateTime};
+
+/// Returns the instant a `DateTime` denotes, for offset-aware ordering.
+const fn instant(datetime: &DateTime) -> OffsetDateTime {
+    datetime.to_offset_datetime()
+}
+
+/// Returns whichever of two `DateTime` values is the earlier instant.
+fn earliest(a: DateTime, b: DateTime) -> DateTime {
+    if instant(&b) < instant(&a) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns whichever of two `DateTime` values is the later instant.
+fn latest(a: DateTime, b: DateTime) -> DateTime {
+    if instant(&b) > instant(&a) {
+        b
+    } else {
+        a
+    }
+}
+
+/// The half-open span of time `[start, end)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DateTimeInterval {
+    start: DateTime,
+    end: DateTime,
+}
+
+impl DateTimeInterval {
+    /// Creates the interval `[start, end)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if `end` is an earlier
+    /// instant than `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::interval::DateTimeInterval;
+    ///
+    /// let start = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+    /// let end = DateTime::parse("2024-01-02T00:00:00Z").unwrap();
+    /// assert!(DateTimeInterval::new(start, end).is_ok());
+    /// assert!(DateTimeInterval::new(end, start).is_err());
+    /// ```
+    pub fn new(
+        start: DateTime,
+        end: DateTime,
+    ) -> Result<Self, DateTimeError> {
+        if instant(&end) < instant(&start) {
+            return Err(DateTimeError::InvalidDate);
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Creates the interval of the given length starting at `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if `length` is negative or
+    /// the end falls outside the supported date range.
+    pub fn with_duration(
+        start: DateTime,
+        length: Duration,
+    ) -> Result<Self, DateTimeError> {
+        if length.is_negative() {
+            return Err(DateTimeError::InvalidDate);
+        }
+        let end = start
+            .to_offset_datetime()
+            .checked_add(length)
+            .map(DateTime::from_offset_datetime)
+            .ok_or(DateTimeError::InvalidDate)?;
+        Ok(Self { start, end })
+    }
+
+    /// Returns the inclusive start of the interval.
+    #[must_use]
+    pub const fn start(&self) -> DateTime {
+        self.start
+    }
+
+    /// Returns the exclusive end of the interval.
+    #[must_use]
+    pub const fn end(&self) -> DateTime {
+        self.end
+    }
+
+    /// Returns the length of the interval.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        instant(&self.end) - instant(&self.start)
+    }
+
+    /// Returns `true` if the interval contains no instants.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        instant(&self.start) == instant(&self.end)
+    }
+
+    /// Returns `true` if `datetime` lies in `[start, end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::interval::DateTimeInterval;
+    ///
+    /// let start = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+    /// let end = DateTime::parse("2024-01-02T00:00:00Z").unwrap();
+    /// let day = DateTimeInterval::new(start, end).unwrap();
+    /// assert!(day.contains(&start));
+    /// assert!(!day.contains(&end));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, datetime: &DateTime) -> bool {
+        let at = instant(datetime);
+        instant(&self.start) <= at && at < instant(&self.end)
+    }
+
+    /// Returns `true` if the two intervals share at least one instant.
+    ///
+    /// Intervals that only touch, where one ends as the other starts, do
+    /// not overlap.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        instant(&self.start) < instant(&other.end)
+            && instant(&other.start) < instant(&self.end)
+    }
+
+    /// Returns the instants common to both intervals, or `None` if they
+    /// do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::interval::DateTimeInterval;
+    ///
+    /// let at = |s: &str| DateTime::parse(s).unwrap();
+    /// let a = DateTimeInterval::new(at("2024-01-01T00:00:00Z"), at("2024-01-03T00:00:00Z"))
+    ///     .unwrap();
+    /// let b = DateTimeInterval::new(at("2024-01-02T00:00:00Z"), at("2024-01-04T00:00:00Z"))
+    ///     .unwrap();
+    /// let common = a.intersection(&b).unwrap();
+    /// assert_eq!(common.start().to_string(), "2024-01-02T00:00:00Z");
+    /// assert_eq!(common.end().to_string(), "2024-01-03T00:00:00Z");
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Self {
+            start: latest(self.start, other.start),
+            end: earliest(self.end, other.end),
+        })
+    }
+
+    /// Returns the single interval covering both, or `None` if there is a
+    /// gap between them.
+    ///
+    /// Touching intervals are joined.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if instant(&self.end) < instant(&other.start)
+            || instant(&other.end) < instant(&self.start)
+        {
+            return None;
+        }
+        Some(Self {
+            start: earliest(self.start, other.start),
+            end: latest(self.end, other.end),
+        })
+    }
+
+    /// Returns an iterator over the instants `start`, `start + step`, ...
+    /// that lie before `end`.
+    ///
+    /// A zero or negative `step` yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::interval::DateTimeInterval;
+    /// use time::Duration;
+    ///
+    /// let start = DateTime::parse("2024-01-01T09:00:00Z").unwrap();
+    /// let end = DateTime::parse("2024-01-01T10:00:00Z").unwrap();
+    /// let slots = DateTimeInterval::new(start, end).unwrap();
+    /// assert_eq!(slots.iter(Duration::minutes(15)).count(), 4);
+    /// ```
+    #[must_use]
+    pub const fn iter(&self, step: Duration) -> Steps {
+        Steps {
+            next: Some(self.start),
+            end: self.end,
+            step,
+        }
+    }
+}
+
+impl fmt::Display for DateTimeInterval {
+    /// Formats the interval as an ISO 8601 `start/end` pair.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+/// Iterator over evenly spaced instants of an interval, created by
+/// [`DateTimeInterval::iter`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Steps {
+    next: Option<DateTime>,
+    end: DateTime,
+    step: Duration,
+}
+
+impl Iterator for Steps {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take().filter(|current| {
+            self.step.is_positive()
+                && instant(current) < instant(&self.end)
+        })?;
+        self.next = current
+            .to_offset_datetime()
+            .checked_add(self.step)
+            .map(DateTime::from_offset_datetime);
+        Some(current)
+    }
+}
+
+impl std::iter::FusedIterator for Steps {}
+
+/// A set of instants stored as sorted, non-overlapping intervals.
+///
+/// Inserting an interval merges it with any intervals it overlaps or
+/// touches. Empty intervals are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::interval::{DateTimeInterval, IntervalSet};
+///
+/// let at = |s: &str| DateTime::parse(s).unwrap();
+/// let mut set = IntervalSet::new();
+/// set.insert(DateTimeInterval::new(at("2024-01-01T10:00:00Z"), at("2024-01-01T11:00:00Z")).unwrap());
+/// set.insert(DateTimeInterval::new(at("2024-01-01T08:00:00Z"), at("2024-01-01T09:00:00Z")).unwrap());
+/// set.insert(DateTimeInterval::new(at("2024-01-01T09:00:00Z"), at("2024-01-01T09:30:00Z")).unwrap());
+///
+/// let spans: Vec<String> = set.iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     spans,
+///     [
+///         "2024-01-01T08:00:00Z/2024-01-01T09:30:00Z",
+///         "2024-01-01T10:00:00Z/2024-01-01T11:00:00Z",
+///     ]
+/// );
+/// assert!(set.contains(&at("2024-01-01T09:15:00Z")));
+/// assert!(!set.contains(&at("2024-01-01T09:45:00Z")));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<DateTimeInterval>,
+}
+
+impl IntervalSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Adds an interval, merging it with any it overlaps or touches.
+    pub fn insert(&mut self, interval: DateTimeInterval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|existing| {
+            instant(&existing.end) < instant(&interval.start)
+        });
+        let mut merged = interval;
+        let mut last = first;
+        while let Some(existing) = self.intervals.get(last) {
+            match merged.union(existing) {
+                Some(joined) => merged = joined,
+                None => break,
+            }
+            last += 1;
+        }
+        let _ = self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Returns the intervals in the set, sorted by start.
+    #[must_use]
+    pub fn intervals(&self) -> &[DateTimeInterval] {
+        &self.intervals
+    }
+
+    /// Returns an iterator over the intervals in the set, sorted by start.
+    pub fn iter(&self) -> slice::Iter<'_, DateTimeInterval> {
+        self.intervals.iter()
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns `true` if the set contains no intervals.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns `true` if any interval in the set contains `datetime`.
+    #[must_use]
+    pub fn contains(&self, datetime: &DateTime) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(datetime))
+    }
+
+    /// Returns `true` if any interval in the set overlaps `interval`.
+    #[must_use]
+    pub fn overlaps(&self, interval: &DateTimeInterval) -> bool {
+        self.intervals
+            .iter()
+            .any(|existing| existing.overlaps(interval))
+    }
+
+    /// Returns the total length covered by the set.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.intervals
+            .iter()
+            .map(DateTimeInterval::duration)
+            .fold(Duration::ZERO, |total, length| total + length)
+    }
+}
+
+impl Extend<DateTimeInterval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = DateTimeInterval>>(
+        &mut self,
+        iter: I,
+    ) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<DateTimeInterval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = DateTimeInterval>>(
+        iter: I,
+    ) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a DateTimeInterval;
+    type IntoIter = slice::Iter<'a, DateTimeInterval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright © 2025 DateTime (DTT) library. All rights reserved.
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-//! DateTime (DTT) is a comprehensive library for date and time manipulation.
+//! `DateTime` (DTT) is a comprehensive library for date and time manipulation.
 //!
 //! # Overview
 //!
@@ -47,26 +47,81 @@
     unsafe_code
 )]
 #![allow(clippy::module_name_repetitions)]
+// Style choice: early returns are written as `match` throughout the
+// crate rather than mixing in `let-else`.
+#![allow(clippy::manual_let_else)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-// Standard library imports
-use std::env;
+// Internal instrumentation. With the `tracing` feature these emit debug
+// spans and events; without it they expand to nothing.
+
+/// Opens a debug span covering the rest of the enclosing block.
+#[cfg(feature = "tracing")]
+macro_rules! instrument {
+    ($($arg:tt)*) => {
+        let _span = ::tracing::debug_span!($($arg)*).entered();
+    };
+}
+
+/// Opens a debug span covering the rest of the enclosing block.
+#[cfg(not(feature = "tracing"))]
+macro_rules! instrument {
+    ($($arg:tt)*) => {};
+}
+
+/// Emits a debug event.
+#[cfg(feature = "tracing")]
+macro_rules! debug_event {
+    ($($arg:tt)*) => {
+        ::tracing::debug!($($arg)*)
+    };
+}
+
+/// Emits a debug event.
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug_event {
+    ($($arg:tt)*) => {};
+}
+
+/// Emits a debug event carrying the error if `$result` is an `Err`.
+#[cfg(feature = "tracing")]
+macro_rules! trace_failure {
+    ($result:expr, $operation:literal) => {
+        if let Err(error) = &$result {
+            ::tracing::debug!(
+                operation = $operation,
+                %error,
+                "dtt operation failed"
+            );
+        }
+    };
+}
+
+/// Emits a debug event carrying the error if `$result` is an `Err`.
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_failure {
+    ($($arg:tt)*) => {};
+}
 
 /// Library constants and configuration values
 pub mod constants {
     /// Current version of the library from Cargo.toml
     pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-    /// Environment variable controlling test mode
+    /// Environment variable controlling the binary's test mode
     pub const TEST_MODE_ENV: &str = "DTT_TEST_MODE";
 
     /// Value indicating test mode is enabled
     pub const TEST_MODE_ENABLED: &str = "1";
 
-    /// Welcome message displayed during initialization
+    /// Environment variable holding named formats for the binary, as
+    /// `name=description` entries separated by `;`
+    pub const FORMATS_ENV: &str = "DTT_FORMATS";
+
+    /// Welcome message displayed by the binary
     pub const WELCOME_MSG: &str = "Welcome to `DTT` 👋!";
 
-    /// Library description displayed during initialization
+    /// Library description displayed by the binary
     pub const DESCRIPTION: &str = "A Rust library for parsing, validating,manipulating, and formatting dates and times.";
 }
 
@@ -74,7 +129,11 @@ pub mod constants {
 #[doc(inline)]
 pub use crate::datetime::DateTime;
 #[doc(inline)]
+pub use crate::duration::parse_duration;
+#[doc(inline)]
 pub use crate::error::AppError;
+#[doc(inline)]
+pub use crate::plain::{Date, Time};
 
 /// Core datetime functionality and operations.
 ///
@@ -82,37 +141,229 @@ pub use crate::error::AppError;
 /// for date and time manipulation.
 pub mod datetime;
 
+/// Clock utilities built on top of `DateTime`.
+///
+/// Provides `MonotonicStamp`, a generator of strictly increasing timestamps
+/// for event ordering and ID generation.
+pub mod clock;
+
+/// Parsing and formatting many timestamps at once.
+///
+/// Provides `parse_many` and `format_many`, and with the `rayon` feature
+/// their parallel counterparts.
+pub mod batch;
+
+/// Text calendars in the style of the Unix `cal` command.
+///
+/// Provides `CalendarOptions` for `DateTime::render_month_calendar` and
+/// `DateTime::render_year_calendar`.
+pub mod calendar;
+
+/// Compact encodings for sequences of `DateTime` values.
+///
+/// Provides Gorilla-style delta-of-delta compression for dense time-series
+/// timestamp storage.
+pub mod compression;
+
+/// Crontab-style schedules.
+///
+/// Provides `CronSchedule`, a five-field cron expression parser that can
+/// describe schedules in plain English and build them from simple phrases.
+pub mod cron;
+
+/// Broken-out views of `DateTime` components.
+///
+/// Provides `DateTimeComponents`, returned by `DateTime::components`, for
+/// introspection and serialization of every field of a `DateTime`, and
+/// `DateTimeParts`, returned by `DateTime::to_components`, for
+/// destructuring.
+pub mod components;
+
+/// Calendar-aware differences between `DateTime` values.
+///
+/// Provides the `DateDelta` type returned by `DateTime::difference`,
+/// breaking a time span down into years, months, days, and smaller units.
+pub mod delta;
+
+/// Parsing exact durations from text.
+///
+/// Provides `parse_duration`, re-exported at the crate root, for human
+/// forms such as `1d 2h 30m` and ISO 8601 durations such as `P1DT2H`.
+pub mod duration;
+
+/// Human-readable comparison reports between `DateTime` values.
+///
+/// Provides the `DiffReport` type returned by `DateTime::diff_report`,
+/// highlighting differing components and the offset-normalized delta.
+pub mod diff;
+
+/// Lenient parsing of human-written dates.
+///
+/// Provides `parse_flexible`, which accepts formats such as `01/02/2024`,
+/// `Jan 2 2024`, and `next monday`, with a configurable day/month order.
+pub mod flexible;
+
+/// Preset formats shared by the crate's date and time types.
+///
+/// Provides the `Format` presets and the `FormatableDateTime` trait, so
+/// APIs can accept any of the crate's value types and format them the
+/// same way.
+pub mod format;
+
 /// Error handling types and implementations.
 ///
 /// Provides custom error types for handling various error conditions that may
 /// occur during datetime operations.
 pub mod error;
 
+/// Holiday calendars and business-day arithmetic.
+///
+/// Provides the `HolidayProvider` trait, built-in US federal, UK bank
+/// holiday, and TARGET2 calendars, and `add_business_days`.
+pub mod holidays;
+
+/// Time intervals and interval sets.
+///
+/// Provides `DateTimeInterval`, a half-open span with overlap,
+/// intersection, and union operations, and `IntervalSet` for merging
+/// many intervals.
+pub mod interval;
+
+/// Bounds used when validating dates and times.
+///
+/// Provides the `MIN_*`/`MAX_*` constants shared by the parser and
+/// validators, and `is_representable` for checking whole dates.
+pub mod limits;
+
+/// Local dates, local times, and TOML-style datetime values.
+///
+/// Provides `TomlDateTime`, which serializes as a string for use in
+/// configuration files, the `LocalDate` and `LocalTime` aliases, and the
+/// `rfc3339` serde adapter for `DateTime` fields.
+pub mod local;
+
+/// Log timestamps for `tracing-subscriber`.
+///
+/// Provides `DttTimer`, which stamps log lines using the crate's format
+/// presets, format descriptions, and timezones. Requires the `tracing`
+/// feature.
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod logging;
+
 /// Macro definitions for common operations.
 ///
 /// Contains utility macros to simplify common datetime operations and reduce
 /// boilerplate code.
 pub mod macros;
 
+/// Wall-clock date and time values without an offset.
+///
+/// Provides `NaiveDateTime`, which must be paired with an explicit offset
+/// before it can be used as an instant.
+pub mod naive;
+
+/// Named UTC offsets as constants.
+///
+/// Provides `IST`, `EST`, `JST`, and the other abbreviations the timezone
+/// table knows, as `UtcOffset` values.
+pub mod offsets;
+
+/// Time-partitioned paths for data lakes.
+///
+/// Provides `partition_path` and `parse_partition_path` for Hive-style and
+/// plain `year/month/day/hour` directory layouts.
+pub mod partition;
+
+/// Calendar periods of years, months, and days.
+///
+/// Provides `Period`, applied with `DateTime::add_period` under calendar
+/// rules, as opposed to the exact `time::Duration`.
+pub mod period;
+
+/// Retention policies for timestamped items.
+///
+/// Provides `RetentionPolicy`, which applies grandfather-father-son
+/// daily, weekly, and monthly retention rules to a list of timestamps.
+pub mod retention;
+
+/// Time-boxed feature flags and gradual rollouts.
+///
+/// Provides `RolloutWindow`, which reports whether a rollout is active and
+/// how far through it a moment is.
+pub mod rollout;
+
+/// Standalone date and time-of-day values.
+///
+/// Provides `Date` and `Time`, re-exported at the crate root, for APIs that
+/// deal with only a date or only a time of day.
+pub mod plain;
+
+/// Side-effect-free library self-test.
+///
+/// Provides `self_test`, which returns a `SelfTestReport` of enabled
+/// features, available timezone data, and system clock sanity.
+pub mod selftest;
+
+/// Conformance test vectors.
+///
+/// Provides canonical parse, format, arithmetic, and leap year cases, and
+/// `check_*` functions that run them against any implementation.
+pub mod testvectors;
+
+/// Stopwatches and deadlines.
+///
+/// Provides `Stopwatch`, with start, stop, and lap support, and `Deadline`
+/// for checking remaining time against a `DateTime`.
+pub mod timer;
+
+/// Runtime registration of timezone abbreviations.
+///
+/// Provides `TimezoneRegistry`, consulted by `DateTime::new_with_tz` and
+/// `DateTime::convert_to_tz` before the built-in abbreviation table, and
+/// `ZoneResolver` for converting many values to one zone.
+pub mod timezone;
+
+/// Typed validation of date and time components.
+///
+/// Provides `validate_date`, `validate_time`, and the `ValidatedDate`
+/// newtype, which replace the string-based `DateTime::is_valid_*`
+/// functions.
+pub mod validation;
+
+/// String validators for date and time components.
+///
+/// Provides the `is_valid_*` functions behind `is_valid!` and
+/// `dtt_is_valid_function!`. Call the macros, or use `validation` for
+/// new code.
+#[doc(hidden)]
+pub mod validators;
+
+#[doc(hidden)]
+pub use paste::paste as __paste;
+
 /// Commonly used types and traits.
 ///
 /// Provides a convenient way to import commonly used types with a single use statement.
 pub mod prelude {
     pub use crate::datetime::DateTime;
-    pub use crate::error::{AppError, DateTimeError};
+    pub use crate::error::{
+        AppError, ComponentError, ComponentIssues, ContextualError,
+        DateTimeError, ErrorCategory, ErrorKind, InputError,
+        SerializableError,
+    };
 }
 
-/// Runs the main library functionality with proper error handling.
+/// Runs the library self-test.
 ///
-/// This function initializes the library and performs basic setup operations.
-/// It checks for test mode and returns appropriate results based on the
-/// environment configuration.
+/// Checks that timezone data is present and that the system clock looks
+/// sane, and returns the full [`SelfTestReport`](selftest::SelfTestReport).
+/// The library reads no environment variables and prints nothing; use
+/// [`selftest::self_test`] to get the report even when a check fails.
 ///
 /// # Errors
 ///
-/// Returns `AppError::SimulatedError` in the following cases:
-/// - When the `DTT_TEST_MODE` environment variable is set to "1"
-/// - When environment variable access fails
+/// Returns `AppError::Other` describing the first failed check.
 ///
 /// # Examples
 ///
@@ -120,39 +371,19 @@ pub mod prelude {
 /// use dtt::prelude::*;
 ///
 /// fn main() -> Result<(), AppError> {
-///     match dtt::run() {
-///         Ok(()) => println!("Library initialized successfully"),
-///         Err(e) => eprintln!("Error during initialization: {}", e),
-///     }
+///     let report = dtt::run()?;
+///     println!("{report}");
 ///     Ok(())
 /// }
 /// ```
-pub fn run() -> Result<(), AppError> {
-    if is_test_mode() {
-        return Err(AppError::SimulatedError);
+pub fn run() -> Result<selftest::SelfTestReport, AppError> {
+    let report = selftest::self_test();
+    if let Some(failure) = report.failure() {
+        return Err(AppError::Other(format!(
+            "self-test failed: {failure}"
+        )));
     }
-
-    display_welcome_message();
-    Ok(())
-}
-
-/// Checks if the library is running in test mode.
-///
-/// Examines the environment variable `DTT_TEST_MODE` to determine if the library
-/// should operate in test mode.
-fn is_test_mode() -> bool {
-    env::var(constants::TEST_MODE_ENV)
-        .map(|val| val == constants::TEST_MODE_ENABLED)
-        .unwrap_or(false)
-}
-
-/// Displays the welcome message with library information.
-///
-/// Prints a welcome message along with the library description and current version.
-fn display_welcome_message() {
-    println!("{}", constants::WELCOME_MSG);
-    println!("{}", constants::DESCRIPTION);
-    println!("Version: {}", constants::VERSION);
+    Ok(report)
 }
 
 #[cfg(test)]
@@ -164,17 +395,17 @@ mod tests {
 
         #[test]
         fn test_normal_run() {
-            env::remove_var(constants::TEST_MODE_ENV);
-            assert!(run().is_ok());
+            let report = run();
+            assert!(report.is_ok());
         }
 
         #[test]
-        fn test_simulated_error() {
-            env::set_var(
+        fn test_run_ignores_test_mode() {
+            std::env::set_var(
                 constants::TEST_MODE_ENV,
                 constants::TEST_MODE_ENABLED,
             );
-            assert!(matches!(run(), Err(AppError::SimulatedError)));
+            assert!(run().is_ok());
         }
     }
 
@@ -191,23 +422,9 @@ mod tests {
         }
 
         #[test]
-        fn test_is_test_mode() {
-            env::remove_var(constants::TEST_MODE_ENV);
-            let first_check = is_test_mode();
-            assert!(
-                !first_check,
-                "Should not be in test mode by default"
-            );
-
-            env::set_var(
-                constants::TEST_MODE_ENV,
-                constants::TEST_MODE_ENABLED,
-            );
-            let second_check = is_test_mode();
-            assert!(
-                second_check,
-                "Should be in test mode after enabling it"
-            );
+        fn test_report_version() -> Result<(), AppError> {
+            assert_eq!(run()?.version, constants::VERSION);
+            Ok(())
         }
     }
 }
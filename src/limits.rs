@@ -0,0 +1,97 @@
+// limits.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Bounds used when validating and parsing dates and times.
+//!
+//! These are the exact limits applied by the parser and the `is_valid_*`
+//! helpers, so external validators can share them instead of repeating
+//! magic numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::limits::{self, MAX_MONTH, MIN_MONTH};
+//!
+//! let month = 13;
+//! assert!(!(MIN_MONTH..=MAX_MONTH).contains(&month));
+//! assert!(limits::is_representable(2024, 2, 29));
+//! assert!(!limits::is_representable(2023, 2, 29));
+//! ```
+
+use crate::datetime::days_in_month;
+use time::Date;
+
+/// Smallest supported calendar year.
+pub const MIN_YEAR: i32 = Date::MIN.year();
+
+/// Largest supported calendar year.
+pub const MAX_YEAR: i32 = Date::MAX.year();
+
+/// Smallest valid month.
+pub const MIN_MONTH: u8 = 1;
+
+/// Largest valid month.
+pub const MAX_MONTH: u8 = 12;
+
+/// Smallest valid day of the month.
+pub const MIN_DAY: u8 = 1;
+
+/// Largest day of the month in any month. Use [`is_representable`] for
+/// the limit of a specific month.
+pub const MAX_DAY: u8 = 31;
+
+/// Largest valid hour.
+pub const MAX_HOUR: u8 = 23;
+
+/// Largest valid minute.
+pub const MAX_MINUTE: u8 = 59;
+
+/// Largest valid second. Leap seconds are handled separately, see
+/// [`LeapSecondPolicy`](crate::datetime::LeapSecondPolicy).
+pub const MAX_SECOND: u8 = 59;
+
+/// Largest valid microsecond.
+pub const MAX_MICROSECOND: u32 = 999_999;
+
+/// Largest valid nanosecond.
+pub const MAX_NANOSECOND: u32 = 999_999_999;
+
+/// Smallest valid ISO week number.
+pub const MIN_ISO_WEEK: u8 = 1;
+
+/// Largest valid ISO week number.
+pub const MAX_ISO_WEEK: u8 = 53;
+
+/// Smallest valid ordinal day of the year.
+pub const MIN_ORDINAL_DAY: u16 = 1;
+
+/// Largest valid ordinal day of the year.
+pub const MAX_ORDINAL_DAY: u16 = 366;
+
+/// Largest UTC offset, in seconds, accepted by default (±14:00).
+pub const MAX_OFFSET_SECONDS: i32 = 14 * 3_600;
+
+/// Returns `true` if the year, month, and day name a date that exists
+/// within the supported range.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::limits::is_representable;
+///
+/// assert!(is_representable(-9999, 1, 1));
+/// assert!(!is_representable(10_000, 1, 1));
+/// assert!(!is_representable(2024, 4, 31));
+/// ```
+#[must_use]
+pub const fn is_representable(year: i32, month: u8, day: u8) -> bool {
+    if year < MIN_YEAR || year > MAX_YEAR || day < MIN_DAY {
+        return false;
+    }
+    match days_in_month(year, month) {
+        Ok(days) => day <= days,
+        Err(_) => false,
+    }
+}
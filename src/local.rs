@@ -0,0 +1,254 @@
+// local.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Local dates and times, and TOML-style datetime values.
+//!
+//! Configuration formats such as TOML and YAML distinguish four kinds of
+//! datetime value:
+//!
+//! | Kind             | Example                     | Type                 |
+//! |------------------|-----------------------------|----------------------|
+//! | Offset date-time | `1979-05-27T07:32:00-08:00` | [`DateTime`]         |
+//! | Local date-time  | `1979-05-27T07:32:00`       | [`NaiveDateTime`]    |
+//! | Local date       | `1979-05-27`                | [`LocalDate`]        |
+//! | Local time       | `07:32:00.999999`           | [`LocalTime`]        |
+//!
+//! [`TomlDateTime`] holds any of the four. It and the local types
+//! serialize as strings, and deserialize from strings or from the
+//! single-entry map `toml` uses to mark datetime values, so they can be
+//! used directly as fields of config structs. For a [`DateTime`] field
+//! that must always carry an offset, use the [`rfc3339`] serde adapter.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::local::TomlDateTime;
+//!
+//! let value: TomlDateTime = "1979-05-27 07:32:00Z".parse().unwrap();
+//! assert!(matches!(value, TomlDateTime::OffsetDateTime(_)));
+//! assert_eq!(value.to_string(), "1979-05-27T07:32:00Z");
+//!
+//! let value: TomlDateTime = "07:32:00".parse().unwrap();
+//! assert!(matches!(value, TomlDateTime::LocalTime(_)));
+//! ```
+
+use crate::{
+    datetime::DateTime, error::DateTimeError, naive::NaiveDateTime,
+};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// A calendar date without a time of day or offset.
+///
+/// The TOML name for [`Date`](crate::plain::Date).
+pub type LocalDate = crate::plain::Date;
+
+/// A time of day without a date or offset.
+///
+/// The TOML name for [`Time`](crate::plain::Time).
+pub type LocalTime = crate::plain::Time;
+
+/// Any of the four datetime kinds found in TOML documents.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TomlDateTime {
+    /// A date and time with an offset, e.g. `1979-05-27T07:32:00Z`.
+    OffsetDateTime(DateTime),
+    /// A date and time without an offset, e.g. `1979-05-27T07:32:00`.
+    LocalDateTime(NaiveDateTime),
+    /// A date alone, e.g. `1979-05-27`.
+    LocalDate(LocalDate),
+    /// A time of day alone, e.g. `07:32:00`.
+    LocalTime(LocalTime),
+}
+
+impl From<DateTime> for TomlDateTime {
+    fn from(datetime: DateTime) -> Self {
+        Self::OffsetDateTime(datetime)
+    }
+}
+
+impl From<NaiveDateTime> for TomlDateTime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        Self::LocalDateTime(datetime)
+    }
+}
+
+impl From<LocalDate> for TomlDateTime {
+    fn from(date: LocalDate) -> Self {
+        Self::LocalDate(date)
+    }
+}
+
+impl From<LocalTime> for TomlDateTime {
+    fn from(time: LocalTime) -> Self {
+        Self::LocalTime(time)
+    }
+}
+
+impl fmt::Display for TomlDateTime {
+    /// Formats the value in the TOML syntax for its kind.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OffsetDateTime(datetime) => datetime.fmt(f),
+            Self::LocalDateTime(datetime) => datetime.fmt(f),
+            Self::LocalDate(date) => date.fmt(f),
+            Self::LocalTime(time) => time.fmt(f),
+        }
+    }
+}
+
+impl FromStr for TomlDateTime {
+    type Err = DateTimeError;
+
+    /// Parses any TOML datetime. The date and time may be separated by
+    /// `T`, `t`, or a space.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.as_bytes().get(2) == Some(&b':') {
+            return s.parse().map(Self::LocalTime);
+        }
+        let (date, time) = match s.get(10..11) {
+            Some("T" | "t" | " ") => (&s[..10], &s[11..]),
+            _ => return s.parse().map(Self::LocalDate),
+        };
+
+        let date: LocalDate = date.parse()?;
+        if time.ends_with(['Z', 'z']) || time.contains(['+', '-']) {
+            return DateTime::parse(&format!("{date}T{time}"))
+                .map(Self::OffsetDateTime);
+        }
+        let time: LocalTime = time.parse()?;
+        Ok(Self::LocalDateTime(date.at(time)))
+    }
+}
+
+/// Serializes a value through its `Display` implementation.
+macro_rules! serialize_as_string {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_any(StrVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+serialize_as_string!(LocalDate, LocalTime, TomlDateTime);
+
+/// Deserializes a value from a string, or from a single-entry map whose
+/// value is a string (the form `toml` uses for datetime values).
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for StrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a TOML datetime string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<T, E>
+    where
+        E: de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<T, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match map.next_entry::<de::IgnoredAny, String>()? {
+            Some((_, value)) => self.visit_str(&value),
+            None => Err(de::Error::invalid_length(0, &self)),
+        }
+    }
+}
+
+/// Serde adapter that reads and writes a [`DateTime`] as an RFC 3339
+/// string, for use with `#[serde(with = "dtt::local::rfc3339")]`.
+///
+/// Deserialization requires an offset; local datetimes are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "dtt::local::rfc3339")]
+///     released: DateTime,
+/// }
+///
+/// let config: Config =
+///     serde_json::from_str(r#"{"released":"1979-05-27T07:32:00-08:00"}"#)
+///         .unwrap();
+/// assert_eq!(config.released.hour(), 7);
+/// assert_eq!(
+///     serde_json::to_string(&config).unwrap(),
+///     r#"{"released":"1979-05-27T07:32:00-08:00"}"#
+/// );
+/// ```
+pub mod rfc3339 {
+    use super::TomlDateTime;
+    use crate::datetime::DateTime;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes a `DateTime` as an RFC 3339 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if writing the string fails.
+    pub fn serialize<S>(
+        datetime: &DateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(datetime)
+    }
+
+    /// Deserializes a `DateTime` from an RFC 3339 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserialization error if the input is not a datetime
+    /// with an offset.
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match TomlDateTime::deserialize(deserializer)? {
+            TomlDateTime::OffsetDateTime(datetime) => Ok(datetime),
+            other => Err(de::Error::custom(format!(
+                "expected a datetime with an offset, found `{other}`"
+            ))),
+        }
+    }
+}
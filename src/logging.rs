@@ -0,0 +1,226 @@
+// logging.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Log timestamps for `tracing-subscriber`.
+//!
+//! [`DttTimer`] implements `tracing_subscriber`'s `FormatTime`, so log
+//! lines are stamped by the same formatting code as the rest of an
+//! application: a [`Format`] preset, a format description, or a name from
+//! the [`FormatRegistry`], in any timezone the [`TimezoneRegistry`]
+//! resolves. Timestamps are RFC 3339 in UTC unless configured otherwise.
+//!
+//! This module requires the `tracing` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::logging::DttTimer;
+//!
+//! let timer = DttTimer::new()
+//!     .with_description("[year]-[month]-[day] [hour]:[minute]:[second]")
+//!     .unwrap()
+//!     .with_timezone("JST")
+//!     .unwrap();
+//!
+//! let subscriber = tracing_subscriber::fmt().with_timer(timer);
+//! # let _ = subscriber;
+//! ```
+//!
+//! [`TimezoneRegistry`]: crate::timezone::TimezoneRegistry
+
+use crate::{
+    clock::{Clock, SystemClock},
+    datetime::{zone_label, DateTime},
+    error::DateTimeError,
+    format::{Format, FormatRegistry, FormatableDateTime},
+    timezone::ZoneResolver,
+};
+use std::fmt;
+use time::UtcOffset;
+use tracing_subscriber::fmt::{format::Writer, time::FormatTime};
+
+/// How a [`DttTimer`] renders a timestamp.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+enum Style {
+    /// One of the crate's presets.
+    Preset(Format),
+    /// A format description such as `[hour]:[minute]:[second]`.
+    Description(String),
+}
+
+/// A `tracing-subscriber` timer that renders timestamps with this crate.
+///
+/// Pass it to `tracing_subscriber::fmt().with_timer(..)`. The current time
+/// is read on every event from the system clock, or from the [`Clock`]
+/// given to [`DttTimer::with_clock`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DttTimer<C = SystemClock> {
+    /// The clock read on every event.
+    clock: C,
+    /// The timestamp format.
+    style: Style,
+    /// The offset timestamps are shown in.
+    offset: UtcOffset,
+    /// The abbreviation of the timezone, if it was set by a built-in or
+    /// registered name.
+    zone: Option<&'static str>,
+}
+
+impl Default for DttTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DttTimer {
+    /// Creates a timer writing RFC 3339 timestamps in UTC, read from the
+    /// system clock.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            clock: SystemClock,
+            style: Style::Preset(Format::Rfc3339),
+            offset: UtcOffset::UTC,
+            zone: None,
+        }
+    }
+}
+
+impl<C> DttTimer<C> {
+    /// Reads the current time from `clock` instead, keeping the other
+    /// settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    /// use dtt::logging::DttTimer;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let timer = DttTimer::new().with_clock(FixedClock::new(noon));
+    /// # let _ = timer;
+    /// ```
+    #[must_use]
+    pub fn with_clock<D: Clock>(self, clock: D) -> DttTimer<D> {
+        DttTimer {
+            clock,
+            style: self.style,
+            offset: self.offset,
+            zone: self.zone,
+        }
+    }
+
+    /// Writes timestamps with a [`Format`] preset.
+    #[must_use]
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.style = Style::Preset(format);
+        self
+    }
+
+    /// Writes timestamps with a format description, as accepted by
+    /// [`DateTime::format`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the description does
+    /// not parse.
+    pub fn with_description(
+        mut self,
+        description: &str,
+    ) -> Result<Self, DateTimeError> {
+        let _ = time::format_description::parse(description)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        self.style = Style::Description(description.to_string());
+        Ok(self)
+    }
+
+    /// Writes timestamps with the format registered as `name` in the
+    /// [global format registry](FormatRegistry::global). The description
+    /// is looked up now; later registrations do not affect the timer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if no format is registered
+    /// under `name`.
+    pub fn with_named_format(
+        self,
+        name: &str,
+    ) -> Result<Self, DateTimeError> {
+        let description = FormatRegistry::global()
+            .lookup(name)
+            .ok_or(DateTimeError::InvalidFormat)?;
+        self.with_description(&description)
+    }
+
+    /// Shows timestamps in a named timezone, resolved now through
+    /// [`ZoneResolver`]. Built-in and registered abbreviations are kept,
+    /// so [`Format::Zoned`] shows them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the name is unknown.
+    pub fn with_timezone(
+        self,
+        name: &str,
+    ) -> Result<Self, DateTimeError> {
+        let mut timer =
+            self.with_offset(ZoneResolver::new(name)?.offset());
+        timer.zone = zone_label(name);
+        Ok(timer)
+    }
+
+    /// Shows timestamps at a fixed UTC offset.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: UtcOffset) -> Self {
+        self.offset = offset;
+        self.zone = None;
+        self
+    }
+
+    /// Renders `datetime` as the timer would, after converting it to the
+    /// timer's offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if the conversion or formatting fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::format::Format;
+    /// use dtt::logging::DttTimer;
+    ///
+    /// let timer = DttTimer::new()
+    ///     .with_format(Format::TimeOfDay)
+    ///     .with_timezone("CET")
+    ///     .unwrap();
+    /// let dt = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// assert_eq!(timer.render(&dt).unwrap(), "13:00:00");
+    /// ```
+    pub fn render(
+        &self,
+        datetime: &DateTime,
+    ) -> Result<String, DateTimeError> {
+        let local = datetime
+            .try_convert_to_offset(self.offset)?
+            .with_zone_label(self.zone);
+        match &self.style {
+            Style::Preset(format) => local.format_preset(*format),
+            Style::Description(description) => {
+                local.format(description)
+            }
+        }
+    }
+}
+
+impl<C: Clock> FormatTime for DttTimer<C> {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        let timestamp =
+            self.render(&self.clock.now()).map_err(|_| fmt::Error)?;
+        write!(w, "{timestamp}")
+    }
+}
@@ -51,6 +51,26 @@ macro_rules! dtt_parse {
     }};
 }
 
+/// Parses a duration such as `"2h 15m"` or `"PT90S"` with
+/// [`parse_duration`](crate::duration::parse_duration).
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::dtt_duration;
+/// use time::Duration;
+///
+/// let timeout = dtt_duration!("2h 15m").unwrap();
+/// assert_eq!(timeout, Duration::minutes(135));
+/// assert!(dtt_duration!("soon").is_err());
+/// ```
+#[macro_export]
+macro_rules! dtt_duration {
+    ($input:expr) => {{
+        $crate::duration::parse_duration($input)
+    }};
+}
+
 /// Prints the arguments to the console.
 ///
 /// # Example
@@ -146,33 +166,40 @@ macro_rules! dtt_assert {
     };
 }
 
-/// Generates a function that validates a given input string based on a specified type.
+/// Generates a documented `is_valid_<component>` function that checks
+/// whether a string is a valid value for a date or time component.
+///
+/// The component is one of `year`, `month`, `day`, `ordinal`,
+/// `iso_week`, `hour`, `minute`, `second`, `microsecond`, `nanosecond`,
+/// `time`, or `offset`. If a type is given, the input must also parse as
+/// that type. The caller does not need to depend on `paste`.
 ///
 /// # Arguments
 ///
-/// - `$name:ident`: The name of the validation function.
-/// - `$type:ty`: The type to validate.
+/// - `$name:ident`: The component to validate.
+/// - `$type:ty` (optional): A type the input must parse as.
 ///
 /// # Example
 ///
 /// ```rust
 /// use dtt::dtt_is_valid_function;
-/// use paste::paste;
 ///
 /// dtt_is_valid_function!(day, u8);
+/// dtt_is_valid_function!(offset);
+///
 /// assert!(is_valid_day("15"));
 /// assert!(!is_valid_day("32"));
+/// assert!(is_valid_offset("+09:00"));
 /// ```
 #[macro_export]
 macro_rules! dtt_is_valid_function {
-    ($name:ident, $type:ty) => {
-        paste! {
+    ($name:ident $(, $type:ty)? $(,)?) => {
+        $crate::__paste! {
+            #[doc = concat!("Returns `true` if `input` is a valid ", stringify!($name), ".")]
+            #[must_use]
             pub fn [<is_valid_ $name>](input: &str) -> bool {
-                if let Ok(parsed_val) = input.parse::<$type>() {
-                    $crate::datetime::DateTime::[<is_valid_ $name>](&parsed_val.to_string())
-                } else {
-                    false
-                }
+                true $(&& input.parse::<$type>().is_ok())?
+                    && $crate::validators::[<is_valid_ $name>](input)
             }
         }
     };
@@ -298,34 +325,48 @@ macro_rules! dtt_print_vec {
     }};
 }
 
-/// Generates a function that validates a given input string based on a specified type.
+/// Checks whether a string is a valid value for a date or time
+/// component, or generates an `is_valid_<component>` function that
+/// does.
 ///
-/// # Arguments
+/// `is_valid!(component, input)` evaluates to a `bool`. The component is
+/// one of `year`, `month`, `day`, `ordinal`, `iso_week`, `hour`,
+/// `minute`, `second`, `microsecond`, `nanosecond`, `time`, or `offset`.
 ///
-/// - `$name:ident`: The name of the validation function.
-/// - `$type:ty`: The type to validate.
+/// `is_valid!(fn is_valid_day)` defines a public, documented function
+/// of that name, checking the component its name ends with.
 ///
 /// # Example
 ///
 /// ```rust
-/// use dtt::dtt_is_valid_function;
-/// use paste::paste;
+/// use dtt::is_valid;
 ///
-/// dtt_is_valid_function!(day, u8);
-/// assert!(is_valid_day("15"));
-/// assert!(!is_valid_day("32"));
+/// assert!(is_valid!(iso_week, "53"));
+/// assert!(!is_valid!(hour, "24"));
+///
+/// is_valid!(fn is_valid_time);
+/// assert!(is_valid_time("12:00:00"));
 /// ```
 #[macro_export]
 macro_rules! is_valid {
-    ($name:ident, $type:ty) => {
+    (fn $name:ident) => {
+        #[doc = concat!("Checks the component named by `", stringify!($name), "`.")]
+        #[must_use]
         pub fn $name(input: &str) -> bool {
-            $crate::datetime::$name(input)
+            $crate::validators::$name(input)
+        }
+    };
+    ($name:ident, $input:expr) => {
+        $crate::__paste! {
+            $crate::validators::[<is_valid_ $name>]($input)
         }
     };
 }
 
 /// Creates a new `DateTime` instance with the specified timezone.
 ///
+/// Prefer [`dtt_now_tz!`], which returns a `Result` instead of panicking.
+///
 /// # Arguments
 ///
 /// - `$tz:expr`: The timezone string.
@@ -338,6 +379,10 @@ macro_rules! is_valid {
 /// let dt = dtt_new_with_tz!("CET");
 /// assert_eq!(dt.offset().to_string(), "+01:00:00");
 /// ```
+///
+/// # Panics
+///
+/// Panics if the timezone is not recognised.
 #[macro_export]
 macro_rules! dtt_new_with_tz {
     ($tz:expr) => {{
@@ -347,6 +392,64 @@ macro_rules! dtt_new_with_tz {
     }};
 }
 
+/// Returns the current time in the specified timezone, or a
+/// `DateTimeError::InvalidTimezone` if the name is not recognised.
+///
+/// # Arguments
+///
+/// - `$tz:expr`: The timezone string.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::dtt_now_tz;
+///
+/// let dt = dtt_now_tz!("CET").unwrap();
+/// assert_eq!(dt.offset().whole_hours(), 1);
+/// assert!(dtt_now_tz!("Mars/Olympus_Mons").is_err());
+/// ```
+#[macro_export]
+macro_rules! dtt_now_tz {
+    ($tz:expr) => {{
+        $crate::datetime::DateTime::new_with_tz($tz)
+    }};
+}
+
+/// Returns the current date at midnight UTC, or the date on the given
+/// clock at midnight in the clock's offset.
+///
+/// # Arguments
+///
+/// - `$clock:expr` (optional): A reference to a
+///   [`Clock`](crate::clock::Clock) to read instead of the system clock.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::clock::FixedClock;
+/// use dtt::datetime::DateTime;
+/// use dtt::{dtt_now, dtt_today};
+///
+/// let today = dtt_today!();
+/// assert_eq!((today.hour(), today.minute(), today.second()), (0, 0, 0));
+/// assert!(today <= dtt_now!());
+///
+/// let clock = FixedClock::new(DateTime::parse("2024-03-10T15:45:00Z").unwrap());
+/// assert_eq!(dtt_today!(&clock).to_string(), "2024-03-10T00:00:00Z");
+/// ```
+#[macro_export]
+macro_rules! dtt_today {
+    () => {{
+        $crate::dtt_today!(&$crate::clock::SystemClock)
+    }};
+    ($clock:expr) => {{
+        let mut today =
+            $crate::datetime::DateTime::now_with_clock($clock);
+        today.make_start_of_day();
+        today
+    }};
+}
+
 /// Adds the specified number of days to the given `DateTime` instance.
 ///
 /// # Arguments
@@ -393,89 +496,418 @@ macro_rules! dtt_sub_days {
     };
 }
 
-/// A helper macro to calculate the difference between two `DateTime` instances.
+/// Applies a sequence of calendar and clock shifts to a `DateTime`.
+///
+/// Each step is a signed amount and a unit, or `at HH:MM` (optionally
+/// `at HH:MM:SS`) to set the time of day. Steps are applied left to right
+/// with [`add_years`], [`add_months`], [`add_days`], [`add_hours`],
+/// [`add_minutes`], [`add_seconds`], and [`set_time`], and the first
+/// error stops the chain. Amounts are literals or parenthesised
+/// expressions.
+///
+/// Units are `year(s)`, `month(s)`, `week(s)`, `day(s)`, `hour(s)`,
+/// `minute(s)`, and `second(s)`; any other unit is a compile error.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::{dtt_parse, dtt_shift};
+///
+/// let dt = dtt_parse!("2024-01-31T17:45:00Z").unwrap();
+/// let due = dtt_shift!(dt, +1 month, -2 days, at 09:00).unwrap();
+/// assert_eq!(due.to_string(), "2024-02-27T09:00:00Z");
+///
+/// let weeks = 3;
+/// let later = dtt_shift!(dt, +(weeks) weeks, +90 minutes).unwrap();
+/// assert_eq!(later.to_string(), "2024-02-21T19:15:00Z");
+/// ```
+///
+/// ```compile_fail
+/// use dtt::{dtt_now, dtt_shift};
+///
+/// let _ = dtt_shift!(dtt_now!(), +1 fortnight);
+/// ```
+///
+/// [`add_years`]: crate::datetime::DateTime::add_years
+/// [`add_months`]: crate::datetime::DateTime::add_months
+/// [`add_days`]: crate::datetime::DateTime::add_days
+/// [`add_hours`]: crate::datetime::DateTime::add_hours
+/// [`add_minutes`]: crate::datetime::DateTime::add_minutes
+/// [`add_seconds`]: crate::datetime::DateTime::add_seconds
+/// [`set_time`]: crate::datetime::DateTime::set_time
+#[macro_export]
+macro_rules! dtt_shift {
+    (@apply $acc:expr;) => {
+        $acc
+    };
+    (@apply $acc:expr; at $h:literal : $m:literal : $s:literal
+        $(, $($rest:tt)*)?) => {
+        $crate::dtt_shift!(@apply $crate::dtt_shift!(@at $acc, $h, $m, $s);
+            $($($rest)*)?)
+    };
+    (@apply $acc:expr; at $h:literal : $m:literal $(, $($rest:tt)*)?) => {
+        $crate::dtt_shift!(@apply $crate::dtt_shift!(@at $acc, $h, $m, 0);
+            $($($rest)*)?)
+    };
+    // The clock fields are read from their text, so `09:00` does not
+    // trip zero-prefixed literal lints in the caller.
+    (@at $acc:expr, $h:literal, $m:literal, $s:literal) => {
+        $acc.and_then(|dt| {
+            let field = |text: &str| {
+                text.parse::<u8>()
+                    .map_err(|_| $crate::error::DateTimeError::InvalidTime)
+            };
+            dt.set_time(
+                field(stringify!($h))?,
+                field(stringify!($m))?,
+                field(stringify!($s))?,
+            )
+        })
+    };
+    (@apply $acc:expr; + $n:tt $unit:ident $(, $($rest:tt)*)?) => {
+        $crate::dtt_shift!(@apply $crate::dtt_shift!(@unit $acc, $unit, $n);
+            $($($rest)*)?)
+    };
+    (@apply $acc:expr; - $n:tt $unit:ident $(, $($rest:tt)*)?) => {
+        $crate::dtt_shift!(@apply $crate::dtt_shift!(@unit $acc, $unit, -$n);
+            $($($rest)*)?)
+    };
+    (@unit $acc:expr, year, $n:expr) => {
+        $acc.and_then(|dt| dt.add_years($n))
+    };
+    (@unit $acc:expr, years, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, year, $n)
+    };
+    (@unit $acc:expr, month, $n:expr) => {
+        $acc.and_then(|dt| dt.add_months($n))
+    };
+    (@unit $acc:expr, months, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, month, $n)
+    };
+    (@unit $acc:expr, week, $n:expr) => {
+        $acc.and_then(|dt| dt.add_days(7 * $n))
+    };
+    (@unit $acc:expr, weeks, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, week, $n)
+    };
+    (@unit $acc:expr, day, $n:expr) => {
+        $acc.and_then(|dt| dt.add_days($n))
+    };
+    (@unit $acc:expr, days, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, day, $n)
+    };
+    (@unit $acc:expr, hour, $n:expr) => {
+        $acc.and_then(|dt| dt.add_hours($n)).map(|carried| carried.datetime)
+    };
+    (@unit $acc:expr, hours, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, hour, $n)
+    };
+    (@unit $acc:expr, minute, $n:expr) => {
+        $acc.and_then(|dt| dt.add_minutes($n)).map(|carried| carried.datetime)
+    };
+    (@unit $acc:expr, minutes, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, minute, $n)
+    };
+    (@unit $acc:expr, second, $n:expr) => {
+        $acc.and_then(|dt| dt.add_seconds($n)).map(|carried| carried.datetime)
+    };
+    (@unit $acc:expr, seconds, $n:expr) => {
+        $crate::dtt_shift!(@unit $acc, second, $n)
+    };
+    (@unit $acc:expr, $unit:ident, $n:expr) => {
+        compile_error!(concat!(
+            "unknown dtt_shift! unit `",
+            stringify!($unit),
+            "`; expected years, months, weeks, days, hours, minutes, or seconds"
+        ))
+    };
+    ($dt:expr $(, $($steps:tt)*)?) => {{
+        let start: ::core::result::Result<
+            $crate::datetime::DateTime,
+            $crate::error::DateTimeError,
+        > = Ok($dt);
+        $crate::dtt_shift!(@apply start; $($($steps)*)?)
+    }};
+}
+
+/// Creates a [`DateTimeInterval`] from two endpoints.
+///
+/// Endpoints are strings accepted by [`DateTime::parse`], or tuples of
+/// `(year, month, day)` or `(year, month, day, hour, minute, second)` in
+/// UTC. Write them as a half-open range, `start..end`, or as two
+/// expressions separated by a comma.
+///
+/// Tuple components must be literals, and out-of-range months, days,
+/// hours, minutes, and seconds are compile errors. Strings, and days past
+/// the end of their month, are checked when the macro runs.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::dtt_interval;
+///
+/// let january = dtt_interval!("2024-01-01".."2024-02-01").unwrap();
+/// assert_eq!(january.duration().whole_days(), 31);
+///
+/// let shift = dtt_interval!((2024, 3, 1, 9, 0, 0)..(2024, 3, 1, 17, 30, 0))
+///     .unwrap();
+/// assert_eq!(shift.duration().whole_minutes(), 510);
+///
+/// let end = String::from("2024-01-01T12:00:00Z");
+/// assert!(dtt_interval!("2024-01-01T00:00:00Z", &end).is_ok());
+/// assert!(dtt_interval!("2024-02-01".."2024-01-01").is_err());
+/// ```
+///
+/// ```compile_fail
+/// use dtt::dtt_interval;
+///
+/// let _ = dtt_interval!((2024, 13, 1)..(2024, 14, 1));
+/// ```
+///
+/// [`DateTimeInterval`]: crate::interval::DateTimeInterval
+/// [`DateTime::parse`]: crate::datetime::DateTime::parse
+#[macro_export]
+macro_rules! dtt_interval {
+    (@at ($y:literal, $m:literal, $d:literal)) => {
+        $crate::dtt_interval!(@at ($y, $m, $d, 0, 0, 0))
+    };
+    (@at ($y:literal, $m:literal, $d:literal,
+          $h:literal, $mi:literal, $s:literal)) => {{
+        const _: () = assert!(1 <= $m && $m <= 12, "month must be in 1..=12");
+        const _: () = assert!(1 <= $d && $d <= 31, "day must be in 1..=31");
+        const _: () = assert!($h <= 23, "hour must be in 0..=23");
+        const _: () = assert!($mi <= 59, "minute must be in 0..=59");
+        const _: () = assert!($s <= 59, "second must be in 0..=59");
+        $crate::datetime::DateTimeBuilder::new()
+            .year($y)
+            .month($m)
+            .day($d)
+            .hour($h)
+            .minute($mi)
+            .second($s)
+            .build()
+    }};
+    (@at $text:expr) => {
+        $crate::datetime::DateTime::parse($text)
+    };
+    ($start:tt ..= $end:tt) => {
+        compile_error!(
+            "intervals are half-open; write `start..end` or use dtt_range!"
+        )
+    };
+    ($start:tt .. $end:tt) => {
+        $crate::dtt_interval!(@at $start).and_then(|start| {
+            $crate::dtt_interval!(@at $end).and_then(|end| {
+                $crate::interval::DateTimeInterval::new(start, end)
+            })
+        })
+    };
+    ($start:expr, $end:expr $(,)?) => {
+        $crate::dtt_interval!(@at $start).and_then(|start| {
+            $crate::dtt_interval!(@at $end).and_then(|end| {
+                $crate::interval::DateTimeInterval::new(start, end)
+            })
+        })
+    };
+}
+
+/// Creates a `Range<DateTime>` or `RangeInclusive<DateTime>` from two
+/// endpoints written as `start..end` or `start..=end`.
+///
+/// Endpoints take the same forms as in [`dtt_interval!`], with the same
+/// compile-time checks on tuple components. Unlike an interval, a range
+/// whose end precedes its start is allowed and simply empty.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::{dtt_parse, dtt_range};
+///
+/// let q1 = dtt_range!((2024, 1, 1)..=(2024, 3, 31, 23, 59, 59)).unwrap();
+/// assert!(q1.contains(&dtt_parse!("2024-03-31T12:00:00Z").unwrap()));
+///
+/// let half_open = dtt_range!("2024-01-01".."2024-04-01").unwrap();
+/// assert!(!half_open.contains(&dtt_parse!("2024-04-01").unwrap()));
+/// ```
+#[macro_export]
+macro_rules! dtt_range {
+    ($start:tt ..= $end:tt) => {
+        $crate::dtt_interval!(@at $start).and_then(|start| {
+            $crate::dtt_interval!(@at $end).map(|end| start..=end)
+        })
+    };
+    ($start:tt .. $end:tt) => {
+        $crate::dtt_interval!(@at $start).and_then(|start| {
+            $crate::dtt_interval!(@at $end).map(|end| start..end)
+        })
+    };
+}
+
+/// Counts the whole units between two `DateTime` values, ignoring which
+/// comes first.
+///
+/// The unit is one of `seconds`, `minutes`, `hours`, `days`, `weeks`,
+/// `months`, or `years`; any other unit is a compile error. The count is
+/// the absolute value of [`DateTime::diff_in`], so it is truncated
+/// towards zero and offsets are taken into account.
 ///
 /// # Parameters
 ///
-/// - `$dt1:expr`: The first `DateTime` instance.
-/// - `$dt2:expr`: The second `DateTime` instance.
-/// - `$unit:expr`: The unit for the difference (seconds, days, etc.).
+/// - `$dt1:expr`: The first `DateTime` value.
+/// - `$dt2:expr`: The second `DateTime` value.
+/// - `$unit:ident`: The unit to count.
 ///
 /// # Returns
 ///
-/// The difference in the specified unit between the two `DateTime` instances.
+/// A `Result<i64, DateTimeError>` with the number of whole units.
 ///
 /// # Example
 ///
 /// ```rust
 /// use dtt::{dtt_diff, dtt_parse};
 ///
-/// let dt1 = "1609459200"; // 2021-01-01 00:00:00 UTC
-/// let dt2 = "1609459230"; // 2021-01-01 00:00:30 UTC
-/// let seconds_difference = dtt_diff!(dt1, dt2, 1);
-/// assert_eq!(seconds_difference, 30i64);
+/// let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2021-01-01T02:00:30+01:00").unwrap();
+/// assert_eq!(dtt_diff!(dt1, dt2, seconds), Ok(3630));
+/// assert_eq!(dtt_diff!(dt2, dt1, minutes), Ok(60));
+/// ```
+///
+/// ```compile_fail
+/// use dtt::{dtt_diff, dtt_now};
+///
+/// let _ = dtt_diff!(dtt_now!(), dtt_now!(), fortnights);
 /// ```
+///
+/// [`DateTime::diff_in`]: crate::datetime::DateTime::diff_in
 #[macro_export]
 macro_rules! dtt_diff {
-    ($dt1:expr, $dt2:expr, $unit:expr) => {{
-        match ($dt1.parse::<i64>(), $dt2.parse::<i64>()) {
-            (Ok(dt1), Ok(dt2)) => {
-                let difference =
-                    if dt1 <= dt2 { dt2 - dt1 } else { dt1 - dt2 };
-                (difference / $unit).abs()
-            }
-            _ => panic!("Error: Invalid input"),
-        }
+    (@unit seconds) => { $crate::datetime::Unit::Second };
+    (@unit minutes) => { $crate::datetime::Unit::Minute };
+    (@unit hours) => { $crate::datetime::Unit::Hour };
+    (@unit days) => { $crate::datetime::Unit::Day };
+    (@unit weeks) => { $crate::datetime::Unit::Week };
+    (@unit months) => { $crate::datetime::Unit::Month };
+    (@unit years) => { $crate::datetime::Unit::Year };
+    (@unit $unit:ident) => {
+        compile_error!(concat!(
+            "unknown dtt_diff! unit `",
+            stringify!($unit),
+            "`; expected seconds, minutes, hours, days, weeks, months, or years"
+        ))
+    };
+    ($dt1:expr, $dt2:expr, $unit:ident) => {{
+        let (first, second): (
+            $crate::datetime::DateTime,
+            $crate::datetime::DateTime,
+        ) = ($dt1, $dt2);
+        first
+            .diff_in(&second, $crate::dtt_diff!(@unit $unit))
+            .map(i64::abs)
     }};
 }
 
-/// Calculates the difference in seconds between two `DateTime` instances.
+/// Calculates the difference in whole seconds between two `DateTime`
+/// values, as [`dtt_diff!`] with `seconds`.
 ///
 /// # Arguments
 ///
-/// - `$dt1:expr`: The first `DateTime` instance.
-/// - `$dt2:expr`: The second `DateTime` instance.
+/// - `$dt1:expr`: The first `DateTime` value.
+/// - `$dt2:expr`: The second `DateTime` value.
 ///
 /// # Example
 ///
 /// ```rust
-/// use dtt::dtt_diff_seconds;
-/// use dtt::dtt_diff;
+/// use dtt::{dtt_diff_seconds, dtt_parse};
 ///
-/// let dt1 = "1609459200"; // 2021-01-01 00:00:00 UTC
-/// let dt2 = "1609459230"; // 2021-01-01 00:00:30 UTC
-/// let seconds_difference = dtt_diff_seconds!(dt1, dt2);
-/// assert_eq!(seconds_difference, 30i64);
+/// let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2021-01-01T00:00:30Z").unwrap();
+/// assert_eq!(dtt_diff_seconds!(dt1, dt2), Ok(30));
 /// ```
 #[macro_export]
 macro_rules! dtt_diff_seconds {
     ($dt1:expr, $dt2:expr) => {
-        dtt_diff!($dt1, $dt2, 1)
+        $crate::dtt_diff!($dt1, $dt2, seconds)
+    };
+}
+
+/// Calculates the difference in whole minutes between two `DateTime`
+/// values, as [`dtt_diff!`] with `minutes`.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::{dtt_diff_minutes, dtt_parse};
+///
+/// let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2021-01-01T01:29:59Z").unwrap();
+/// assert_eq!(dtt_diff_minutes!(dt1, dt2), Ok(89));
+/// ```
+#[macro_export]
+macro_rules! dtt_diff_minutes {
+    ($dt1:expr, $dt2:expr) => {
+        $crate::dtt_diff!($dt1, $dt2, minutes)
+    };
+}
+
+/// Calculates the difference in whole hours between two `DateTime`
+/// values, as [`dtt_diff!`] with `hours`.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::{dtt_diff_hours, dtt_parse};
+///
+/// let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2021-01-02T00:00:00+09:00").unwrap();
+/// assert_eq!(dtt_diff_hours!(dt1, dt2), Ok(15));
+/// ```
+#[macro_export]
+macro_rules! dtt_diff_hours {
+    ($dt1:expr, $dt2:expr) => {
+        $crate::dtt_diff!($dt1, $dt2, hours)
     };
 }
 
-/// Calculates the difference in days between two `DateTime` instances.
+/// Calculates the difference in whole days between two `DateTime`
+/// values, as [`dtt_diff!`] with `days`.
 ///
 /// # Arguments
 ///
-/// - `$dt1:expr`: The first `DateTime` instance.
-/// - `$dt2:expr`: The second `DateTime` instance.
+/// - `$dt1:expr`: The first `DateTime` value.
+/// - `$dt2:expr`: The second `DateTime` value.
 ///
 /// # Example
 ///
 /// ```rust
-/// use dtt::dtt_diff_days;
-/// use dtt::dtt_diff;
+/// use dtt::{dtt_diff_days, dtt_parse};
 ///
-/// let dt1 = "1609459200"; // 2021-01-01 00:00:00 UTC
-/// let dt2 = "1609545600"; // 2021-01-02 00:00:00 UTC
-/// let days_difference = dtt_diff_days!(dt1, dt2);
-/// assert_eq!(days_difference, 1i64);
+/// let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2021-01-02T00:00:00Z").unwrap();
+/// assert_eq!(dtt_diff_days!(dt1, dt2), Ok(1));
 /// ```
 #[macro_export]
 macro_rules! dtt_diff_days {
     ($dt1:expr, $dt2:expr) => {
-        dtt_diff!($dt1, $dt2, 86400) // 86400 seconds in a day
+        $crate::dtt_diff!($dt1, $dt2, days)
+    };
+}
+
+/// Calculates the difference in whole weeks between two `DateTime`
+/// values, as [`dtt_diff!`] with `weeks`.
+///
+/// # Example
+///
+/// ```rust
+/// use dtt::{dtt_diff_weeks, dtt_parse};
+///
+/// let dt1 = dtt_parse!("2024-01-01T00:00:00Z").unwrap();
+/// let dt2 = dtt_parse!("2024-02-01T00:00:00Z").unwrap();
+/// assert_eq!(dtt_diff_weeks!(dt1, dt2), Ok(4));
+/// ```
+#[macro_export]
+macro_rules! dtt_diff_weeks {
+    ($dt1:expr, $dt2:expr) => {
+        $crate::dtt_diff!($dt1, $dt2, weeks)
     };
 }
 
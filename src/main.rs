@@ -3,11 +3,823 @@
 // Copyright © 2025 DateTime (DTT) library. All rights reserved.
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-//! This is the main entry point for the dtt application.
+//! The `dtt` command-line tool.
+//!
+//! Run `dtt --help` for the list of commands. With no command, the binary
+//! prints its banner and the library self-test report.
+use dtt::{
+    calendar::CalendarOptions,
+    constants,
+    datetime::{supported_timezones, DateTime},
+    delta::DateDelta,
+    error::DateTimeError,
+    flexible::FlexibleOptions,
+    format::{Format, FormatRegistry, FormatableDateTime},
+    timezone::{TimezoneRegistry, ZoneResolver},
+    AppError,
+};
+use serde_json::{json, Value};
+use std::{
+    env,
+    io::{self, Write},
+    thread,
+    time::Duration as StdDuration,
+};
+use time::{Duration, Month, UtcOffset, Weekday};
+
+/// Help text printed by `dtt --help`.
+const USAGE: &str = "\
+Usage: dtt [COMMAND] [OPTIONS]
+
+Commands:
+  now                  Print the current time
+  parse <DATE>         Parse a date and print it in RFC 3339 form
+  add <DATE> <AMOUNT>  Add an amount such as 3d, -2h, or 1mo to a date
+  diff <FROM> <TO>     Print the calendar difference between two dates
+  since <DATE>         Print how long ago a date was
+  until <DATE>         Print how long until a date
+  fmt <DATE>           Print a date in the format given by --format
+  convert <DATE>       Print a date in the timezone given by --to
+  zones                List the known timezone names and offsets
+  cal [MONTH] [YEAR]   Print a month calendar, or a whole year when
+                       given only a year
+  watch                Print the current time in every --tz zone, once
+                       per --interval, until interrupted
+  epoch <VALUE>        Convert a Unix timestamp to a date, or a date to
+                       a Unix timestamp
+
+With no command, dtt prints its banner and a library self-test.
+
+Dates are RFC 3339 or ISO 8601 strings, common written forms such as
+\"Jan 2 2024\", or the words now, today, tomorrow, and yesterday.
+Amounts are a signed number followed by s, m, h, d, w, mo, or y.
+
+Options:
+  --tz <ZONE>          Show dates in a timezone, such as EST or CET;
+                       `watch` takes it more than once
+  --from <ZONE>        Source timezone for `convert`; the date's wall
+                       clock time is read in this zone
+  --to <ZONE>          Target timezone for `convert`
+  --unit <UNIT>        Timestamp unit for `epoch`: s (the default), ms,
+                       us, or ns
+  --start-of-week <DAY>
+                       First weekday column for `cal`, such as sunday
+                       (the default is monday)
+  --interval <TIME>    Time between updates for `watch`, such as 500ms,
+                       1s (the default), or 1m
+  --count <N>          Stop `watch` after N updates
+  --format <FORMAT>    Show dates as rfc3339, iso8601, http, date,
+                       ordinal, time, zoned, a name from DTT_FORMATS,
+                       or a format description such as
+                       \"[year]/[month]/[day]\"
+  --output <FORMAT>    Print text (the default) or json
+  --json               Same as --output json
+  -h, --help           Print this help
+  -V, --version        Print the version
+
+Environment:
+  DTT_FORMATS          Named formats for --format, as name=description
+                       entries separated by ;, for example
+                       \"audit=[year][month][day]T[hour][minute][second]\"";
+
 fn main() {
-    // Call the `run()` function from the `DateTime (DTT)` module.
-    if let Err(err) = dtt::run() {
-        eprintln!("Error running dtt: {}", err);
-        std::process::exit(1);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) if output.is_empty() => {}
+        Ok(output) => println!("{}", output),
+        Err(err) => {
+            eprintln!("Error running dtt: {}", err);
+            std::process::exit(err.exit_code());
+        }
+    }
+}
+
+/// A parsed command line.
+#[derive(Debug, Default)]
+struct Cli {
+    /// The subcommand and its positional arguments.
+    args: Vec<String>,
+    /// Timezone to show dates in; the last `--tz` given.
+    tz: Option<String>,
+    /// Every `--tz` given, for `watch`.
+    zones: Vec<String>,
+    /// Source timezone for `convert`.
+    from: Option<String>,
+    /// Target timezone for `convert`.
+    to: Option<String>,
+    /// Timestamp unit for `epoch`.
+    unit: Option<String>,
+    /// First weekday column for `cal`.
+    start_of_week: Option<String>,
+    /// Time between updates for `watch`.
+    interval: Option<String>,
+    /// Number of updates for `watch`.
+    count: Option<String>,
+    /// Output format for dates.
+    format: Option<String>,
+    /// Whether to print JSON, from `--json` or `--output json`.
+    json: bool,
+    /// Whether `--help` was given.
+    help: bool,
+    /// Whether `--version` was given.
+    version: bool,
+}
+
+impl Cli {
+    /// Parses the arguments that follow the program name.
+    fn parse(args: &[String]) -> Result<Self, AppError> {
+        let mut cli = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag, Some(value.to_string()))
+                }
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next().cloned())
+                    .ok_or_else(|| {
+                        AppError::usage(format!(
+                            "{} needs a value",
+                            flag
+                        ))
+                    })
+            };
+            match flag {
+                "--tz" => {
+                    let tz = value()?;
+                    cli.zones.push(tz.clone());
+                    cli.tz = Some(tz);
+                }
+                "--from" => cli.from = Some(value()?),
+                "--to" => cli.to = Some(value()?),
+                "--unit" => cli.unit = Some(value()?),
+                "--start-of-week" => cli.start_of_week = Some(value()?),
+                "--interval" => cli.interval = Some(value()?),
+                "--count" => cli.count = Some(value()?),
+                "--format" => cli.format = Some(value()?),
+                "--json" => cli.json = true,
+                "--output" => {
+                    let output = value()?;
+                    cli.json = match output.as_str() {
+                        "json" => true,
+                        "text" => false,
+                        _ => {
+                            return Err(AppError::usage(format!(
+                                "unknown output `{}`; use text or json",
+                                output
+                            )));
+                        }
+                    };
+                }
+                "-h" | "--help" => cli.help = true,
+                "-V" | "--version" => cli.version = true,
+                _ if flag.starts_with('-') && !is_number(flag) => {
+                    return Err(AppError::usage(format!(
+                        "unknown option `{}`",
+                        flag
+                    )));
+                }
+                _ => cli.args.push(arg.clone()),
+            }
+        }
+        Ok(cli)
+    }
+
+    /// Returns the positional argument at `index`, naming it in the
+    /// error if it is missing.
+    fn arg(&self, index: usize, name: &str) -> Result<&str, AppError> {
+        self.args.get(index).map(String::as_str).ok_or_else(|| {
+            AppError::usage(format!(
+                "`{}` needs {}; see `dtt --help`",
+                self.args[0], name
+            ))
+        })
+    }
+
+    /// Fails if more than `count` positional arguments were given.
+    fn expect_args(&self, count: usize) -> Result<(), AppError> {
+        match self.args.get(count) {
+            Some(extra) => Err(AppError::usage(format!(
+                "unexpected argument `{}`",
+                extra
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns `true` for arguments such as `-2h` that look like options but
+/// are negative amounts.
+fn is_number(arg: &str) -> bool {
+    arg[1..].starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Runs the command line and returns the text to print.
+///
+/// Setting `DTT_TEST_MODE=1` makes the binary fail with a simulated error,
+/// for exercising error handling in scripts.
+fn run(args: &[String]) -> Result<String, AppError> {
+    if env::var(constants::TEST_MODE_ENV)
+        .map_or(false, |val| val == constants::TEST_MODE_ENABLED)
+    {
+        return Err(AppError::SimulatedError);
+    }
+
+    let cli = Cli::parse(args)?;
+    if let Ok(definitions) = env::var(constants::FORMATS_ENV) {
+        register_formats(&definitions)?;
+    }
+    if cli.help {
+        return Ok(USAGE.to_string());
+    }
+    if cli.version {
+        return Ok(format!("dtt {}", constants::VERSION));
+    }
+
+    let command = match cli.args.first() {
+        Some(command) => command.as_str(),
+        None => return self_test(&cli),
+    };
+    if (cli.from.is_some() || cli.to.is_some()) && command != "convert"
+    {
+        return Err(AppError::usage(
+            "only `convert` takes --from and --to",
+        ));
+    }
+    if cli.unit.is_some() && command != "epoch" {
+        return Err(AppError::usage("only `epoch` takes --unit"));
+    }
+    if cli.start_of_week.is_some() && command != "cal" {
+        return Err(AppError::usage(
+            "only `cal` takes --start-of-week",
+        ));
+    }
+    if (cli.zones.len() > 1
+        || cli.interval.is_some()
+        || cli.count.is_some())
+        && command != "watch"
+    {
+        return Err(AppError::usage(
+            "only `watch` takes several --tz, --interval, and --count",
+        ));
+    }
+    match command {
+        "now" => {
+            cli.expect_args(1)?;
+            show(&cli, DateTime::new())
+        }
+        "parse" => {
+            cli.expect_args(2)?;
+            show(&cli, parse_date(cli.arg(1, "a <DATE>")?)?)
+        }
+        "add" => {
+            cli.expect_args(3)?;
+            let date =
+                parse_date(cli.arg(1, "a <DATE> and an <AMOUNT>")?)?;
+            let amount = cli.arg(2, "an <AMOUNT>")?;
+            show(&cli, add(&date, amount)?)
+        }
+        "since" | "until" => {
+            cli.expect_args(2)?;
+            let date = parse_date(cli.arg(1, "a <DATE>")?)?;
+            relative(&cli, &date, command == "since")
+        }
+        "diff" => {
+            cli.expect_args(3)?;
+            let from =
+                parse_date(cli.arg(1, "<FROM> and <TO> dates")?)?;
+            let to = parse_date(cli.arg(2, "a <TO> date")?)?;
+            diff(&cli, &from, &to)
+        }
+        "fmt" => {
+            cli.expect_args(2)?;
+            if cli.format.is_none() {
+                return Err(AppError::usage("`fmt` needs --format"));
+            }
+            show(&cli, parse_date(cli.arg(1, "a <DATE>")?)?)
+        }
+        "convert" => {
+            cli.expect_args(2)?;
+            convert(&cli, &parse_date(cli.arg(1, "a <DATE>")?)?)
+        }
+        "zones" => {
+            cli.expect_args(1)?;
+            zones(&cli)
+        }
+        "watch" => {
+            cli.expect_args(1)?;
+            watch(&cli)
+        }
+        "cal" => {
+            cli.expect_args(3)?;
+            cal(&cli)
+        }
+        "epoch" => {
+            cli.expect_args(2)?;
+            epoch(&cli, cli.arg(1, "a timestamp or a date")?)
+        }
+        _ => Err(AppError::usage(format!(
+            "unknown command `{}`; see `dtt --help`",
+            command
+        ))),
+    }
+}
+
+/// Returns the banner and the library self-test report.
+fn self_test(cli: &Cli) -> Result<String, AppError> {
+    let report = dtt::run()?;
+    if cli.json {
+        return to_json(&json!({
+            "version": report.version,
+            "features": report.features,
+            "timezones": report.timezones,
+            "healthy": report.is_healthy(),
+        }));
+    }
+    Ok(format!(
+        "{}\n{}\n{}",
+        constants::WELCOME_MSG,
+        constants::DESCRIPTION,
+        report
+    ))
+}
+
+/// Parses a date argument.
+fn parse_date(input: &str) -> Result<DateTime, AppError> {
+    if input.eq_ignore_ascii_case("now") {
+        return Ok(DateTime::new());
+    }
+    Ok(DateTime::parse_flexible(input, &FlexibleOptions::new())?)
+}
+
+/// Adds an amount such as `3d`, `-2h`, or `1mo` to `date`.
+fn add(date: &DateTime, amount: &str) -> Result<DateTime, AppError> {
+    let split = amount
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(amount.len());
+    let (count, unit) = amount.split_at(split);
+    let count: i32 = count.parse().map_err(|_| {
+        AppError::usage(format!("invalid amount `{}`", amount))
+    })?;
+    let count64 = i64::from(count);
+    let result = match unit {
+        "s" => *date + Duration::seconds(count64),
+        "m" => *date + Duration::minutes(count64),
+        "h" => *date + Duration::hours(count64),
+        "d" => date.add_days(count64),
+        "w" => date.add_days(count64 * 7),
+        "mo" => date.add_months(count),
+        "y" => date.add_years(count),
+        _ => {
+            return Err(AppError::usage(format!(
+                "unknown unit in `{}`; use s, m, h, d, w, mo, or y",
+                amount
+            )))
+        }
+    };
+    Ok(result?)
+}
+
+/// Converts `date` to the `--to` timezone and renders it.
+fn convert(cli: &Cli, date: &DateTime) -> Result<String, AppError> {
+    if cli.tz.is_some() {
+        return Err(AppError::usage(
+            "`convert` takes --to instead of --tz",
+        ));
+    }
+    let to = cli
+        .to
+        .as_deref()
+        .ok_or_else(|| AppError::usage("`convert` needs --to"))?;
+    let date = match &cli.from {
+        Some(from) => date.to_naive().assume_offset(zone_offset(from)?),
+        None => *date,
+    };
+    show(cli, date.convert_to_tz(to)?)
+}
+
+/// Lists the runtime-registered and built-in timezones with their
+/// offsets.
+fn zones(cli: &Cli) -> Result<String, AppError> {
+    if cli.tz.is_some() || cli.format.is_some() {
+        return Err(AppError::usage(
+            "`zones` does not take --tz or --format",
+        ));
+    }
+    let registry = TimezoneRegistry::global();
+    let mut zones = registry.registered();
+    for name in supported_timezones() {
+        if !zones.iter().any(|(registered, _)| registered == name) {
+            zones.push((name.to_string(), zone_offset(name)?));
+        }
+    }
+    if cli.json {
+        let zones: Vec<Value> = zones
+            .iter()
+            .map(|(name, offset)| {
+                json!({
+                    "name": name,
+                    "offset": offset_label(*offset),
+                    "seconds": offset.whole_seconds(),
+                })
+            })
+            .collect();
+        return to_json(&Value::from(zones));
+    }
+    let width = zones.iter().map(|(name, _)| name.len()).max();
+    Ok(zones
+        .iter()
+        .map(|(name, offset)| {
+            format!(
+                "{:<width$}  {}",
+                name,
+                offset_label(*offset),
+                width = width.unwrap_or(0)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Resolves a timezone name through the global registry.
+fn zone_offset(name: &str) -> Result<UtcOffset, AppError> {
+    Ok(TimezoneRegistry::global()
+        .lookup(name)
+        .ok_or(DateTimeError::InvalidTimezone)?)
+}
+
+/// Renders an offset as `+HH:MM`.
+fn offset_label(offset: UtcOffset) -> String {
+    let (hours, minutes, _) = offset.as_hms();
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    format!("{}{:02}:{:02}", sign, hours.abs(), minutes.abs())
+}
+
+/// Prints the current time in every `--tz` zone, or in UTC, once per
+/// `--interval` until interrupted or `--count` updates have been
+/// printed. Each update is one line, or one JSON array with `--json`.
+fn watch(cli: &Cli) -> Result<String, AppError> {
+    let names = if cli.zones.is_empty() {
+        vec![String::from("UTC")]
+    } else {
+        cli.zones.clone()
+    };
+    let zones = names
+        .iter()
+        .map(|name| ZoneResolver::new(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let interval =
+        parse_interval(cli.interval.as_deref().unwrap_or("1s"))?;
+    let count = match &cli.count {
+        Some(count) => Some(count.parse::<u64>().map_err(|_| {
+            AppError::usage(format!("invalid count `{}`", count))
+        })?),
+        None => None,
+    };
+
+    let stdout = io::stdout();
+    let mut printed = 0_u64;
+    loop {
+        let now = DateTime::now_utc();
+        let mut parts = Vec::with_capacity(zones.len());
+        let mut values = Vec::with_capacity(zones.len());
+        for zone in &zones {
+            let date = zone.convert(&now)?;
+            let formatted = match &cli.format {
+                Some(format) => format_date(&date, format)?,
+                None => date.format_rfc3339()?,
+            };
+            if cli.json {
+                values.push(json!({
+                    "zone": zone.name(),
+                    "datetime": date.format_rfc3339()?,
+                    "formatted": formatted,
+                }));
+            } else {
+                parts.push(format!("{} {}", zone.name(), formatted));
+            }
+        }
+        let line = if cli.json {
+            serde_json::to_string(&values)?
+        } else {
+            parts.join("  ")
+        };
+
+        let mut out = stdout.lock();
+        writeln!(out, "{}", line)?;
+        out.flush()?;
+        drop(out);
+
+        printed += 1;
+        if count.map_or(false, |count| printed >= count) {
+            return Ok(String::new());
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Parses an interval such as `500ms`, `2s`, or `1m`.
+fn parse_interval(input: &str) -> Result<StdDuration, AppError> {
+    let invalid = || {
+        AppError::usage(format!(
+            "invalid interval `{}`; use a positive number of ms, s, or m",
+            input
+        ))
+    };
+    let split = input
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    let (count, unit) = input.split_at(split);
+    let count: u64 = count.parse().map_err(|_| invalid())?;
+    let interval = match unit {
+        "ms" => StdDuration::from_millis(count),
+        "s" | "" => StdDuration::from_secs(count),
+        "m" => StdDuration::from_secs(count.saturating_mul(60)),
+        _ => return Err(invalid()),
+    };
+    if interval.is_zero() {
+        return Err(invalid());
+    }
+    Ok(interval)
+}
+
+/// Renders a month or year calendar, like cal(1).
+///
+/// With no arguments, shows the current month in the `--tz` timezone. A
+/// single argument is a month of the current year if it names one or is
+/// 1 to 12, and a whole year otherwise.
+fn cal(cli: &Cli) -> Result<String, AppError> {
+    if cli.format.is_some() || cli.json {
+        return Err(AppError::usage(
+            "`cal` does not take --format or --json",
+        ));
+    }
+    let today = match &cli.tz {
+        Some(tz) => DateTime::new_with_tz(tz)?,
+        None => DateTime::new(),
+    };
+    let mut options = CalendarOptions::new();
+    if let Some(day) = &cli.start_of_week {
+        options = options.first_weekday(parse_weekday(day)?);
+    }
+
+    let (month, year) = match (cli.args.get(1), cli.args.get(2)) {
+        (None, _) => (Some(today.month()), today.year()),
+        (Some(arg), None) => match parse_month(arg) {
+            Some(month) => (Some(month), today.year()),
+            None => (None, parse_year(arg)?),
+        },
+        (Some(month), Some(year)) => {
+            let month = parse_month(month).ok_or_else(|| {
+                AppError::usage(format!("invalid month `{}`", month))
+            })?;
+            (Some(month), parse_year(year)?)
+        }
+    };
+    let first = DateTime::from_components(
+        year,
+        month.map_or(1, u8::from),
+        1,
+        0,
+        0,
+        0,
+        UtcOffset::UTC,
+    )?;
+    Ok(match month {
+        Some(_) => first.render_month_calendar(&options),
+        None => first.render_year_calendar(&options),
+    })
+}
+
+/// Parses a month number from 1 to 12, or an English month name or
+/// its first three letters.
+fn parse_month(input: &str) -> Option<Month> {
+    if let Ok(number) = input.parse::<u8>() {
+        return Month::try_from(number).ok();
+    }
+    let input = input.to_ascii_lowercase();
+    let mut month = Month::January;
+    for _ in 0..12 {
+        let name = month.to_string().to_ascii_lowercase();
+        if input.len() >= 3 && name.starts_with(&input) {
+            return Some(month);
+        }
+        month = month.next();
+    }
+    None
+}
+
+/// Parses a year for `cal`.
+fn parse_year(input: &str) -> Result<i32, AppError> {
+    input.parse().map_err(|_| {
+        AppError::usage(format!("invalid month or year `{}`", input))
+    })
+}
+
+/// Parses an English weekday name or its first two or more letters.
+fn parse_weekday(input: &str) -> Result<Weekday, AppError> {
+    let lower = input.to_ascii_lowercase();
+    let mut weekday = Weekday::Monday;
+    for _ in 0..7 {
+        let name = weekday.to_string().to_ascii_lowercase();
+        if lower.len() >= 2 && name.starts_with(&lower) {
+            return Ok(weekday);
+        }
+        weekday = weekday.next();
     }
+    Err(AppError::usage(format!("invalid weekday `{}`", input)))
+}
+
+/// Converts a Unix timestamp to a date, or a date to a Unix timestamp,
+/// in the `--unit` unit.
+fn epoch(cli: &Cli, value: &str) -> Result<String, AppError> {
+    let unit = cli.unit.as_deref().unwrap_or("s");
+    let nanos_per_unit: i128 = match unit {
+        "s" => 1_000_000_000,
+        "ms" => 1_000_000,
+        "us" => 1_000,
+        "ns" => 1,
+        _ => {
+            return Err(AppError::usage(format!(
+                "unknown unit `{}`; use s, ms, us, or ns",
+                unit
+            )))
+        }
+    };
+    if let Ok(count) = value.parse::<i128>() {
+        let nanos = count.saturating_mul(nanos_per_unit);
+        return show(cli, DateTime::from_unix_timestamp_nanos(nanos)?);
+    }
+
+    if cli.tz.is_some() || cli.format.is_some() {
+        return Err(AppError::usage(
+            "`epoch` takes --tz and --format only for timestamps",
+        ));
+    }
+    let date = parse_date(value)?;
+    let count = date.unix_timestamp_nanos().div_euclid(nanos_per_unit);
+    if cli.json {
+        // Nanosecond timestamps past 2262 do not fit a JSON integer.
+        let epoch = match i64::try_from(count) {
+            Ok(count) => json!(count),
+            Err(_) => json!(count.to_string()),
+        };
+        return to_json(&json!({
+            "datetime": date.format_rfc3339()?,
+            "epoch": epoch,
+            "unit": unit,
+        }));
+    }
+    Ok(count.to_string())
+}
+
+/// Renders `date` as requested by `--tz`, `--format`, and `--json`.
+fn show(cli: &Cli, date: DateTime) -> Result<String, AppError> {
+    let date = match &cli.tz {
+        Some(tz) => date.convert_to_tz(tz)?,
+        None => date,
+    };
+    let formatted = match &cli.format {
+        Some(format) => format_date(&date, format)?,
+        None => date.format_rfc3339()?,
+    };
+    if cli.json {
+        return to_json(&json!({
+            "datetime": date.format_rfc3339()?,
+            "formatted": formatted,
+            "year": date.year(),
+            "month": u8::from(date.month()),
+            "day": date.day(),
+            "hour": date.hour(),
+            "minute": date.minute(),
+            "second": date.second(),
+            "nanosecond": date.nanosecond(),
+            "weekday": date.weekday().to_string(),
+            "iso_week": date.iso_week(),
+            "ordinal": date.ordinal(),
+            "unix_timestamp": date.unix_timestamp(),
+            "offset": date.offset().to_string(),
+        }));
+    }
+    Ok(formatted)
+}
+
+/// Registers the `name=description` entries of `DTT_FORMATS` in the
+/// global format registry.
+fn register_formats(definitions: &str) -> Result<(), AppError> {
+    for entry in definitions.split(';').map(str::trim) {
+        if entry.is_empty() {
+            continue;
+        }
+        let registered = entry.split_once('=').map_or(
+            false,
+            |(name, description)| {
+                FormatRegistry::global()
+                    .register(name.trim(), description.trim())
+                    .is_ok()
+            },
+        );
+        if !registered {
+            return Err(AppError::usage(format!(
+                "invalid {} entry `{}`",
+                constants::FORMATS_ENV,
+                entry
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Formats `date` with a registered format name, a preset name, or a
+/// format description.
+fn format_date(
+    date: &DateTime,
+    format: &str,
+) -> Result<String, AppError> {
+    if let Some(description) = FormatRegistry::global().lookup(format) {
+        return Ok(date.format(&description)?);
+    }
+    let preset = match format.to_ascii_lowercase().as_str() {
+        "rfc3339" => Format::Rfc3339,
+        "iso8601" => Format::Iso8601,
+        "http" => Format::HttpDate,
+        "date" => Format::CalendarDate,
+        "ordinal" => Format::OrdinalDate,
+        "time" => Format::TimeOfDay,
+        "zoned" => Format::Zoned,
+        _ => return Ok(date.format(format)?),
+    };
+    Ok(date.format_preset(preset)?)
+}
+
+/// Renders the calendar difference from `from` to `to`.
+fn diff(
+    cli: &Cli,
+    from: &DateTime,
+    to: &DateTime,
+) -> Result<String, AppError> {
+    if cli.tz.is_some() || cli.format.is_some() {
+        return Err(AppError::usage(
+            "`diff` does not take --tz or --format",
+        ));
+    }
+    let delta = DateDelta::between(from, to);
+    if cli.json {
+        return to_json(&json!({
+            "from": from.format_rfc3339()?,
+            "to": to.format_rfc3339()?,
+            "seconds": to.duration_since(from).whole_seconds(),
+            "delta": delta,
+            "text": delta.to_string(),
+        }));
+    }
+    Ok(delta.to_string())
+}
+
+/// Renders the calendar difference between now and `date`, measured in
+/// the `--tz` timezone. `--format` appends the date in that format.
+fn relative(
+    cli: &Cli,
+    date: &DateTime,
+    since: bool,
+) -> Result<String, AppError> {
+    let (now, date) = match &cli.tz {
+        Some(tz) => {
+            (DateTime::new_with_tz(tz)?, date.convert_to_tz(tz)?)
+        }
+        None => (DateTime::new(), *date),
+    };
+    let (from, to) = if since { (date, now) } else { (now, date) };
+    let delta = DateDelta::between(&from, &to);
+    let formatted = match &cli.format {
+        Some(format) => Some(format_date(&date, format)?),
+        None => None,
+    };
+    if cli.json {
+        return to_json(&json!({
+            "date": date.format_rfc3339()?,
+            "formatted": formatted,
+            "seconds": to.duration_since(&from).whole_seconds(),
+            "delta": delta,
+            "text": delta.to_string(),
+        }));
+    }
+    Ok(match formatted {
+        Some(formatted) => format!(
+            "{} {} {}",
+            delta,
+            if since { "since" } else { "until" },
+            formatted
+        ),
+        None => delta.to_string(),
+    })
+}
+
+/// Serializes a JSON value for printing.
+fn to_json(value: &Value) -> Result<String, AppError> {
+    Ok(serde_json::to_string_pretty(value)?)
 }
@@ -0,0 +1,125 @@
+// naive.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Wall-clock date and time values without an offset.
+//!
+//! A [`NaiveDateTime`] is a calendar date and time of day that does not
+//! identify an instant until it is paired with an offset. Keeping it as a
+//! separate type makes it impossible to accidentally compute a Unix
+//! timestamp from a local wall-clock value as if it were UTC.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::naive::NaiveDateTime;
+//! use time::UtcOffset;
+//!
+//! let naive = NaiveDateTime::parse_custom_format(
+//!     "2024-01-01 12:00:00",
+//!     "[year]-[month]-[day] [hour]:[minute]:[second]",
+//! ).unwrap();
+//!
+//! let ist = naive.assume_offset(UtcOffset::from_hms(5, 30, 0).unwrap());
+//! assert_eq!(ist.to_string(), "2024-01-01T12:00:00+05:30");
+//! assert_eq!(naive.assume_utc().unix_timestamp() - ist.unix_timestamp(), 19_800);
+//! ```
+
+use crate::{
+    datetime::{locate_format_error, DateTime},
+    error::DateTimeError,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use time::{format_description, PrimitiveDateTime, UtcOffset};
+
+/// A date and time of day without an offset.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct NaiveDateTime(PrimitiveDateTime);
+
+impl NaiveDateTime {
+    /// Creates a `NaiveDateTime` from a `PrimitiveDateTime`.
+    #[must_use]
+    pub const fn new(datetime: PrimitiveDateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Parses a wall-clock date and time using a custom format
+    /// specification (see the `time` crate documentation).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or the input does not describe a valid date/time, or a
+    /// `DateTimeError::InvalidFormatAt` locating the first part of the
+    /// input that does not match the format.
+    pub fn parse_custom_format(
+        input: &str,
+        format: &str,
+    ) -> Result<Self, DateTimeError> {
+        let format_desc = format_description::parse(format)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        PrimitiveDateTime::parse(input, &format_desc)
+            .map(Self)
+            .map_err(|_| locate_format_error(input, &format_desc))
+    }
+
+    /// Interprets the wall-clock value as UTC.
+    #[must_use]
+    pub const fn assume_utc(self) -> DateTime {
+        DateTime::from_primitive(self.0, UtcOffset::UTC)
+    }
+
+    /// Interprets the wall-clock value as local time in the given offset.
+    #[must_use]
+    pub const fn assume_offset(self, offset: UtcOffset) -> DateTime {
+        DateTime::from_primitive(self.0, offset)
+    }
+
+    /// Returns the underlying `PrimitiveDateTime`.
+    #[must_use]
+    pub const fn to_primitive(self) -> PrimitiveDateTime {
+        self.0
+    }
+}
+
+impl From<PrimitiveDateTime> for NaiveDateTime {
+    fn from(datetime: PrimitiveDateTime) -> Self {
+        Self(datetime)
+    }
+}
+
+impl fmt::Display for NaiveDateTime {
+    /// Formats the value as an ISO 8601 date and time without an offset,
+    /// e.g. `2024-01-01T12:00:00`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.0.date();
+        let time = self.0.time();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            date.year(),
+            date.month() as u8,
+            date.day(),
+            time.hour(),
+            time.minute(),
+            time.second()
+        )?;
+        if time.nanosecond() != 0 {
+            write!(f, ".{:09}", time.nanosecond())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+// offsets.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Named UTC offsets as constants.
+//!
+//! Each constant is the offset the built-in timezone table gives its
+//! abbreviation, so `offsets::IST` is exactly what `"IST"` resolves to in
+//! [`DateTime::new_with_tz`] and the global [`TimezoneRegistry`]. Code
+//! that knows its zone at compile time can use these instead of a string
+//! lookup that may fail.
+//!
+//! These are fixed offsets: `EST` is always UTC-05:00, and daylight
+//! saving time is a separate constant such as `EDT`.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::offsets;
+//!
+//! let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+//! let tokyo = utc.try_convert_to_offset(offsets::JST).unwrap();
+//! assert_eq!(tokyo.to_string(), "2024-01-01T21:00:00+09:00");
+//! assert_eq!(Some(offsets::IST), DateTime::new_with_tz("IST").ok().map(|dt| dt.offset()));
+//! ```
+//!
+//! [`DateTime::new_with_tz`]: crate::datetime::DateTime::new_with_tz
+//! [`TimezoneRegistry`]: crate::timezone::TimezoneRegistry
+
+use time::{macros::offset, UtcOffset};
+
+/// Defines a constant for each named offset and the table of all of
+/// them, so the two cannot drift apart.
+macro_rules! named_offsets {
+    ($($(#[$doc:meta])* $name:ident = $offset:expr;)*) => {
+        $(
+            $(#[$doc])*
+            pub const $name: UtcOffset = $offset;
+        )*
+
+        /// Every named offset with its abbreviation, sorted by name so
+        /// lookups can use a binary search.
+        pub(crate) const ALL: &[(&str, UtcOffset)] =
+            &[$((stringify!($name), $name)),*];
+    };
+}
+
+named_offsets! {
+    /// Australian Eastern Daylight Time, UTC+11:00.
+    AEDT = offset!(+11);
+    /// Australian Eastern Standard Time, UTC+10:00.
+    AEST = offset!(+10);
+    /// Central Daylight Time (North America), UTC-05:00.
+    CDT = offset!(-5);
+    /// Central European Summer Time, UTC+02:00.
+    CEST = offset!(+2);
+    /// Central European Time, UTC+01:00.
+    CET = offset!(+1);
+    /// Central Standard Time (North America), UTC-06:00.
+    CST = offset!(-6);
+    /// Eastern Daylight Time (North America), UTC-04:00.
+    EDT = offset!(-4);
+    /// Eastern European Summer Time, UTC+03:00.
+    EEST = offset!(+3);
+    /// Eastern European Time, UTC+02:00.
+    EET = offset!(+2);
+    /// Eastern Standard Time (North America), UTC-05:00.
+    EST = offset!(-5);
+    /// Greenwich Mean Time, UTC+00:00.
+    GMT = offset!(UTC);
+    /// Hong Kong Time, UTC+08:00.
+    HKT = offset!(+8);
+    /// India Standard Time, UTC+05:30.
+    IST = offset!(+5:30);
+    /// Japan Standard Time, UTC+09:00.
+    JST = offset!(+9);
+    /// Mountain Daylight Time (North America), UTC-06:00.
+    MDT = offset!(-6);
+    /// Mountain Standard Time (North America), UTC-07:00.
+    MST = offset!(-7);
+    /// Pacific Daylight Time (North America), UTC-07:00.
+    PDT = offset!(-7);
+    /// Pacific Standard Time (North America), UTC-08:00.
+    PST = offset!(-8);
+    /// Coordinated Universal Time.
+    UTC = offset!(UTC);
+    /// Western Australia Daylight Time, UTC+08:45.
+    WADT = offset!(+8:45);
+}
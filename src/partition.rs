@@ -0,0 +1,249 @@
+// partition.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Time-partitioned paths for data lakes and ETL jobs.
+//!
+//! # Overview
+//!
+//! - [`partition_path`]: renders the partition a `DateTime` belongs to,
+//!   either Hive-style (`year=2024/month=01/day=15/hour=12`) or plain
+//!   (`2024/01/15`).
+//! - [`parse_partition_path`]: recovers the half-open period covered by a
+//!   partition path.
+//!
+//! Partitions are always computed on the UTC wall clock, so values with
+//! different offsets that denote the same instant land in the same
+//! partition.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::{DateTime, Unit};
+//! use dtt::partition::{parse_partition_path, partition_path, PartitionScheme};
+//!
+//! let dt = DateTime::parse("2024-01-15T12:34:56Z").unwrap();
+//! let path = partition_path(&dt, PartitionScheme::hive(Unit::Hour)).unwrap();
+//! assert_eq!(path, "year=2024/month=01/day=15/hour=12");
+//!
+//! let (start, end) = parse_partition_path(&path).unwrap();
+//! assert_eq!(start.to_string(), "2024-01-15T12:00:00Z");
+//! assert_eq!(end.to_string(), "2024-01-15T13:00:00Z");
+//! ```
+
+use crate::{
+    datetime::{DateTime, Unit},
+    error::DateTimeError,
+};
+use time::{Duration, UtcOffset};
+
+/// Keys used by Hive-style partitions, from coarsest to finest.
+const HIVE_KEYS: [&str; 5] = ["year", "month", "day", "hour", "minute"];
+
+/// Layout of the path segments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PartitionStyle {
+    /// `key=value` segments, e.g. `year=2024/month=01/day=15`.
+    Hive,
+    /// Bare value segments, e.g. `2024/01/15`.
+    Plain,
+}
+
+/// How timestamps are mapped onto partition paths.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PartitionScheme {
+    /// Layout of the path segments.
+    pub style: PartitionStyle,
+    /// Finest unit present in the path.
+    ///
+    /// Supported units are `Year`, `Month`, `Day`, `Hour`, and `Minute`.
+    pub granularity: Unit,
+}
+
+impl PartitionScheme {
+    /// Creates a Hive-style scheme with the given granularity.
+    #[must_use]
+    pub const fn hive(granularity: Unit) -> Self {
+        Self {
+            style: PartitionStyle::Hive,
+            granularity,
+        }
+    }
+
+    /// Creates a plain scheme with the given granularity.
+    #[must_use]
+    pub const fn plain(granularity: Unit) -> Self {
+        Self {
+            style: PartitionStyle::Plain,
+            granularity,
+        }
+    }
+}
+
+/// Returns the number of path segments for a granularity.
+const fn depth(granularity: Unit) -> Option<usize> {
+    match granularity {
+        Unit::Year => Some(1),
+        Unit::Month => Some(2),
+        Unit::Day => Some(3),
+        Unit::Hour => Some(4),
+        Unit::Minute => Some(5),
+        Unit::Second | Unit::Week => None,
+    }
+}
+
+/// Returns the granularity for a number of path segments.
+const fn granularity(depth: usize) -> Option<Unit> {
+    match depth {
+        1 => Some(Unit::Year),
+        2 => Some(Unit::Month),
+        3 => Some(Unit::Day),
+        4 => Some(Unit::Hour),
+        5 => Some(Unit::Minute),
+        _ => None,
+    }
+}
+
+/// Renders the partition path containing `dt`.
+///
+/// # Arguments
+///
+/// * `dt` - The timestamp to place; it is converted to UTC first
+/// * `scheme` - The path layout and granularity
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormat` if the scheme's granularity is
+/// not supported (`Second` or `Week`), or a
+/// `DateTimeError::ConversionOverflow` if `dt` is out of range in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::{DateTime, Unit};
+/// use dtt::partition::{partition_path, PartitionScheme};
+///
+/// let dt = DateTime::parse("2024-01-15T12:34:56Z").unwrap();
+/// let path = partition_path(&dt, PartitionScheme::plain(Unit::Day)).unwrap();
+/// assert_eq!(path, "2024/01/15");
+/// ```
+pub fn partition_path(
+    dt: &DateTime,
+    scheme: PartitionScheme,
+) -> Result<String, DateTimeError> {
+    let depth = depth(scheme.granularity)
+        .ok_or(DateTimeError::InvalidFormat)?;
+    let utc = dt.to_utc()?;
+
+    let values = [
+        format!("{:04}", utc.year()),
+        format!("{:02}", utc.month() as u8),
+        format!("{:02}", utc.day()),
+        format!("{:02}", utc.hour()),
+        format!("{:02}", utc.minute()),
+    ];
+
+    let segments: Vec<String> = values
+        .iter()
+        .zip(HIVE_KEYS)
+        .take(depth)
+        .map(|(value, key)| match scheme.style {
+            PartitionStyle::Hive => format!("{key}={value}"),
+            PartitionStyle::Plain => value.clone(),
+        })
+        .collect();
+    Ok(segments.join("/"))
+}
+
+/// Recovers the period covered by a partition path.
+///
+/// The style and granularity are inferred from the path. Hive-style paths
+/// may be embedded in a longer path (a bucket prefix or a trailing file
+/// name, for example); only the `key=value` segments are read. Plain paths
+/// must consist solely of the partition segments.
+///
+/// # Returns
+///
+/// The half-open UTC interval `(start, end)` covered by the partition.
+///
+/// # Errors
+///
+/// Returns a `DateTimeError::InvalidFormat` if the path is not a
+/// recognized partition layout, or a `DateTimeError::InvalidDate` if it
+/// names a date that does not exist.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::partition::parse_partition_path;
+///
+/// let (start, end) =
+///     parse_partition_path("s3://lake/events/year=2024/month=02/part-0.parquet")
+///         .unwrap();
+/// assert_eq!(start.to_string(), "2024-02-01T00:00:00Z");
+/// assert_eq!(end.to_string(), "2024-03-01T00:00:00Z");
+/// ```
+pub fn parse_partition_path(
+    path: &str,
+) -> Result<(DateTime, DateTime), DateTimeError> {
+    let segments: Vec<&str> = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let values: Vec<&str> =
+        if segments.iter().any(|segment| segment.contains('=')) {
+            let pairs: Vec<(&str, &str)> = segments
+                .iter()
+                .filter_map(|segment| segment.split_once('='))
+                .filter(|(key, _)| HIVE_KEYS.contains(key))
+                .collect();
+            if pairs
+                .iter()
+                .zip(HIVE_KEYS)
+                .any(|((key, _), expected)| *key != expected)
+            {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            pairs.into_iter().map(|(_, value)| value).collect()
+        } else {
+            segments
+        };
+
+    let unit = granularity(values.len())
+        .ok_or(DateTimeError::InvalidFormat)?;
+    let mut fields = [0_i32, 1, 1, 0, 0];
+    for (field, value) in fields.iter_mut().zip(&values) {
+        if value.is_empty()
+            || !value.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        *field =
+            value.parse().map_err(|_| DateTimeError::InvalidFormat)?;
+    }
+
+    let component = |value: i32| {
+        u8::try_from(value).map_err(|_| DateTimeError::InvalidDate)
+    };
+    let start = DateTime::from_components(
+        fields[0],
+        component(fields[1])?,
+        component(fields[2])?,
+        component(fields[3])?,
+        component(fields[4])?,
+        0,
+        UtcOffset::UTC,
+    )?;
+
+    let end = match unit {
+        Unit::Year => start.add_years(1)?,
+        Unit::Month => start.add_months(1)?,
+        Unit::Day => start.add_days(1)?,
+        Unit::Hour => (start + Duration::HOUR)?,
+        _ => (start + Duration::MINUTE)?,
+    };
+    Ok((start, end))
+}
@@ -0,0 +1,247 @@
+// period.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Calendar periods of years, months, and days.
+//!
+//! A `time::Duration` is an exact number of seconds, so "one month" cannot
+//! be expressed as one. A [`Period`] counts calendar units instead, and
+//! [`DateTime::add_period`] applies them with calendar rules: the years
+//! and months are added first, clamping the day to the end of a shorter
+//! month, and then the days. The time of day and offset are kept.
+//!
+//! [`Period::between`] computes the period separating two dates, and
+//! periods display and parse in ISO 8601, as in `P1Y2M3D`.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::period::Period;
+//!
+//! let start = DateTime::parse("2024-01-31T09:00:00Z").unwrap();
+//! let later = start.add_period(&Period::new(0, 1, 1)).unwrap();
+//! assert_eq!(later.to_string(), "2024-03-01T09:00:00Z");
+//!
+//! let period = Period::between(&start, &later);
+//! assert_eq!(period, Period::new(0, 1, 1));
+//! assert_eq!(period.to_string(), "P1M1D");
+//! ```
+//!
+//! [`DateTime::add_period`]: crate::datetime::DateTime::add_period
+
+use crate::{
+    datetime::DateTime, delta::DateDelta, error::DateTimeError,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use time::UtcOffset;
+
+/// The ISO 8601 period designators, in the order they must appear.
+const DESIGNATORS: [char; 4] = ['Y', 'M', 'W', 'D'];
+
+/// A number of calendar years, months, and days.
+///
+/// Fields may have different signs; each is applied in turn by
+/// [`DateTime::add_period`](crate::datetime::DateTime::add_period).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct Period {
+    /// Calendar years.
+    pub years: i32,
+    /// Calendar months.
+    pub months: i32,
+    /// Days.
+    pub days: i32,
+}
+
+impl Period {
+    /// The empty period.
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// Creates a period.
+    #[must_use]
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// Creates a period of whole years.
+    #[must_use]
+    pub const fn years(years: i32) -> Self {
+        Self::new(years, 0, 0)
+    }
+
+    /// Creates a period of whole months.
+    #[must_use]
+    pub const fn months(months: i32) -> Self {
+        Self::new(0, months, 0)
+    }
+
+    /// Creates a period of whole days.
+    #[must_use]
+    pub const fn days(days: i32) -> Self {
+        Self::new(0, 0, days)
+    }
+
+    /// Computes the period from the calendar date of `start` to that of
+    /// `end`, ignoring the time of day. `start` is first converted to the
+    /// offset of `end`.
+    ///
+    /// All non-zero fields share a sign, and adding the result to `start`
+    /// reaches the date of `end` unless the day had to be clamped to a
+    /// shorter month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::datetime::DateTime;
+    /// use dtt::period::Period;
+    ///
+    /// let a = DateTime::parse("2023-03-15T23:00:00Z").unwrap();
+    /// let b = DateTime::parse("2024-05-20T01:00:00Z").unwrap();
+    /// assert_eq!(Period::between(&a, &b), Period::new(1, 2, 5));
+    /// assert_eq!(Period::between(&b, &a), Period::new(-1, -2, -5));
+    /// ```
+    #[must_use]
+    pub fn between(start: &DateTime, end: &DateTime) -> Self {
+        let start = start.saturating_convert_to_offset(end.offset());
+        let delta =
+            DateDelta::between(&midnight(&start), &midnight(end));
+        Self::new(delta.years, delta.months, delta.days)
+    }
+
+    /// Returns the period with every field negated.
+    #[must_use]
+    pub const fn negate(self) -> Self {
+        Self::new(-self.years, -self.months, -self.days)
+    }
+
+    /// Returns `true` if every field is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Returns the years and months as a number of months, or `None` on
+    /// overflow.
+    #[must_use]
+    pub const fn total_months(&self) -> Option<i32> {
+        match self.years.checked_mul(12) {
+            Some(months) => months.checked_add(self.months),
+            None => None,
+        }
+    }
+}
+
+/// Returns the date of `datetime` at midnight UTC, so differences count
+/// whole days.
+fn midnight(datetime: &DateTime) -> DateTime {
+    DateTime::from_components(
+        datetime.year(),
+        datetime.month() as u8,
+        datetime.day(),
+        0,
+        0,
+        0,
+        UtcOffset::UTC,
+    )
+    .unwrap_or(*datetime)
+}
+
+impl fmt::Display for Period {
+    /// Formats the period in ISO 8601, e.g. `P1Y2M3D`, leaving out zero
+    /// fields. The empty period is `P0D`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "P0D");
+        }
+        write!(f, "P")?;
+        for (value, designator) in
+            [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')]
+        {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Period {
+    type Err = DateTimeError;
+
+    /// Parses an ISO 8601 period of years, months, weeks, and days, such
+    /// as `P1Y2M3D` or `P2W`. A leading `-` negates the whole period and
+    /// each number may carry its own sign, as in `P-1M`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the text is not such a
+    /// period or a field overflows.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let negative = input.starts_with('-');
+        let rest = input.strip_prefix(['-', '+']).unwrap_or(input);
+        let mut rest = rest
+            .strip_prefix('P')
+            .filter(|rest| !rest.is_empty())
+            .ok_or(DateTimeError::InvalidFormat)?;
+
+        let mut period = Self::ZERO;
+        let mut next = 0;
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .ok_or(DateTimeError::InvalidFormat)?;
+            let value = rest[..end]
+                .parse::<i32>()
+                .ok()
+                .and_then(|value| {
+                    if negative {
+                        value.checked_neg()
+                    } else {
+                        Some(value)
+                    }
+                })
+                .ok_or(DateTimeError::InvalidFormat)?;
+            let designator = rest[end..].chars().next();
+            let index = DESIGNATORS[next..]
+                .iter()
+                .position(|&name| Some(name) == designator)
+                .ok_or(DateTimeError::InvalidFormat)?
+                + next;
+            match index {
+                0 => period.years = value,
+                1 => period.months = value,
+                2 => {
+                    period.days = value
+                        .checked_mul(7)
+                        .ok_or(DateTimeError::InvalidFormat)?;
+                }
+                _ => {
+                    period.days = period
+                        .days
+                        .checked_add(value)
+                        .ok_or(DateTimeError::InvalidFormat)?;
+                }
+            }
+            next = index + 1;
+            rest = &rest[end + 1..];
+        }
+
+        Ok(period)
+    }
+}
@@ -0,0 +1,718 @@
+// plain.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Standalone date and time-of-day values.
+//!
+//! [`Date`] is a calendar date with no time of day, and [`Time`] is a
+//! time of day with no date. Neither carries an offset. Use them for APIs
+//! that only deal with one half of a `DateTime` (birthdays, opening
+//! hours), where a full `DateTime` invites bugs such as comparisons that
+//! depend on the time of day.
+//!
+//! Both parse and display in ISO 8601 (`2024-01-15`, `09:30:00`) and
+//! serialize as strings.
+//!
+//! The partial types [`YearMonth`] (`2026-03`, e.g. a card expiry date)
+//! and [`MonthDay`] (`--02-29`, e.g. a birthday) become a [`Date`] once
+//! the missing day or year is supplied.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::{Date, Time};
+//!
+//! let date: Date = "2024-01-15".parse().unwrap();
+//! let time: Time = "09:30:00".parse().unwrap();
+//! let dt = date.at(time).assume_utc();
+//! assert_eq!(dt.to_string(), "2024-01-15T09:30:00Z");
+//!
+//! let now = DateTime::parse("2024-01-15T17:45:00+01:00").unwrap();
+//! assert_eq!(now.date_only(), date);
+//! assert_eq!(now.time_only().to_string(), "17:45:00");
+//! ```
+
+use crate::{
+    datetime::{days_in_month, DateTime},
+    error::DateTimeError,
+    limits::{MAX_MONTH, MAX_YEAR, MIN_DAY, MIN_MONTH, MIN_YEAR},
+    naive::NaiveDateTime,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+use time::{
+    format_description::{self, FormatItem},
+    macros::format_description,
+    Duration, Month, PrimitiveDateTime, Weekday,
+};
+
+/// ISO 8601 date format, `YYYY-MM-DD`.
+const DATE_FORMAT: &[FormatItem<'_>] =
+    format_description!("[year]-[month]-[day]");
+
+/// ISO 8601 time format, `HH:MM:SS` with optional fractional seconds.
+const TIME_FORMAT: &[FormatItem<'_>] = format_description!(
+    "[hour]:[minute]:[second][optional [.[subsecond]]]"
+);
+
+/// A calendar date without a time of day or offset.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date(time::Date);
+
+/// A time of day without a date or offset.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Time(time::Time);
+
+/// A month of a specific year, such as `2026-03`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct YearMonth {
+    year: i32,
+    month: u8,
+}
+
+/// A day of a month in no particular year, such as `--02-29`.
+///
+/// February 29 is a valid `MonthDay`; whether it exists in a given year is
+/// checked when converting with [`MonthDay::in_year`].
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct MonthDay {
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a `Date` from a `time::Date`.
+    #[must_use]
+    pub const fn new(date: time::Date) -> Self {
+        Self(date)
+    }
+
+    /// Creates a `Date` from a year, month, and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the date does not exist.
+    pub fn from_calendar_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Self, DateTimeError> {
+        let month = Month::try_from(month)
+            .map_err(|_| DateTimeError::InvalidDate)?;
+        time::Date::from_calendar_date(year, month, day)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidDate)
+    }
+
+    /// Parses a date using a custom format specification (see the `time`
+    /// crate documentation).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or the input does not match it.
+    pub fn parse_custom_format(
+        input: &str,
+        format: &str,
+    ) -> Result<Self, DateTimeError> {
+        let format_desc = format_description::parse(format)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        time::Date::parse(input, &format_desc)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+
+    /// Formats the date using a custom format specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or refers to time or offset components.
+    pub fn format(
+        &self,
+        format: &str,
+    ) -> Result<String, DateTimeError> {
+        let format_desc = format_description::parse(format)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        self.0
+            .format(&format_desc)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+
+    /// Returns the underlying `time::Date`.
+    #[must_use]
+    pub const fn to_date(self) -> time::Date {
+        self.0
+    }
+
+    /// Returns the year.
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.0.year()
+    }
+
+    /// Returns the month.
+    #[must_use]
+    pub const fn month(self) -> Month {
+        self.0.month()
+    }
+
+    /// Returns the day of the month.
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.0.day()
+    }
+
+    /// Returns the day of the year (1-366).
+    #[must_use]
+    pub const fn ordinal(self) -> u16 {
+        self.0.ordinal()
+    }
+
+    /// Returns the day of the week.
+    #[must_use]
+    pub const fn weekday(self) -> Weekday {
+        self.0.weekday()
+    }
+
+    /// Adds a number of days, which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is outside the
+    /// supported range.
+    pub fn add_days(self, days: i64) -> Result<Self, DateTimeError> {
+        self.0
+            .checked_add(Duration::days(days))
+            .map(Self)
+            .ok_or(DateTimeError::InvalidDate)
+    }
+
+    /// Returns the number of days from `self` to `other`, negative if
+    /// `other` is earlier.
+    #[must_use]
+    pub fn days_until(self, other: Self) -> i64 {
+        (other.0 - self.0).whole_days()
+    }
+
+    /// Combines the date with a time of day.
+    #[must_use]
+    pub const fn at(self, time: Time) -> NaiveDateTime {
+        NaiveDateTime::new(PrimitiveDateTime::new(self.0, time.0))
+    }
+
+    /// Returns the year and month of the date.
+    #[must_use]
+    pub const fn year_month(self) -> YearMonth {
+        YearMonth {
+            year: self.0.year(),
+            month: self.0.month() as u8,
+        }
+    }
+
+    /// Returns the month and day of the date.
+    #[must_use]
+    pub const fn month_day(self) -> MonthDay {
+        MonthDay {
+            month: self.0.month() as u8,
+            day: self.0.day(),
+        }
+    }
+}
+
+impl YearMonth {
+    /// Creates a `YearMonth`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the month is not 1-12 or
+    /// the year is outside the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::plain::YearMonth;
+    ///
+    /// let expiry = YearMonth::new(2026, 3).unwrap();
+    /// assert_eq!(expiry.to_string(), "2026-03");
+    /// assert!(YearMonth::new(2026, 13).is_err());
+    /// ```
+    pub fn new(year: i32, month: u8) -> Result<Self, DateTimeError> {
+        if (MIN_YEAR..=MAX_YEAR).contains(&year)
+            && (MIN_MONTH..=MAX_MONTH).contains(&month)
+        {
+            Ok(Self { year, month })
+        } else {
+            Err(DateTimeError::InvalidDate)
+        }
+    }
+
+    /// Returns the year.
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month.
+    #[must_use]
+    pub fn month(self) -> Month {
+        Month::try_from(self.month).unwrap_or(Month::January)
+    }
+
+    /// Returns the number of days in the month.
+    #[must_use]
+    pub const fn length_of_month(self) -> u8 {
+        match days_in_month(self.year, self.month) {
+            Ok(days) => days,
+            Err(_) => 0,
+        }
+    }
+
+    /// Returns `true` if `day` exists in this month.
+    #[must_use]
+    pub const fn is_valid_day(self, day: u8) -> bool {
+        day >= MIN_DAY && day <= self.length_of_month()
+    }
+
+    /// Adds a number of months, which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is outside the
+    /// supported range of years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::plain::YearMonth;
+    ///
+    /// let ym = YearMonth::new(2024, 11).unwrap();
+    /// assert_eq!(ym.plus_months(3).unwrap().to_string(), "2025-02");
+    /// assert_eq!(ym.plus_months(-11).unwrap().to_string(), "2023-12");
+    /// ```
+    pub fn plus_months(
+        self,
+        months: i32,
+    ) -> Result<Self, DateTimeError> {
+        let index = i64::from(self.year) * 12
+            + i64::from(self.month - 1)
+            + i64::from(months);
+        let year = i32::try_from(index.div_euclid(12))
+            .map_err(|_| DateTimeError::InvalidDate)?;
+        let month = u8::try_from(index.rem_euclid(12) + 1)
+            .map_err(|_| DateTimeError::InvalidDate)?;
+        Self::new(year, month)
+    }
+
+    /// Subtracts a number of months, which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the result is outside the
+    /// supported range of years.
+    pub fn minus_months(
+        self,
+        months: i32,
+    ) -> Result<Self, DateTimeError> {
+        let months =
+            months.checked_neg().ok_or(DateTimeError::InvalidDate)?;
+        self.plus_months(months)
+    }
+
+    /// Returns the date for a day of this month.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the day does not exist in
+    /// this month.
+    pub fn at_day(self, day: u8) -> Result<Date, DateTimeError> {
+        Date::from_calendar_date(self.year, self.month, day)
+    }
+
+    /// Returns `true` if `date` falls in this month.
+    #[must_use]
+    pub const fn contains(self, date: Date) -> bool {
+        date.0.year() == self.year && date.0.month() as u8 == self.month
+    }
+}
+
+impl MonthDay {
+    /// Creates a `MonthDay`. February 29 is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the day does not exist in
+    /// the month in any year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::plain::MonthDay;
+    ///
+    /// let leap_day = MonthDay::new(2, 29).unwrap();
+    /// assert!(leap_day.in_year(2024).is_ok());
+    /// assert!(leap_day.in_year(2023).is_err());
+    /// assert!(MonthDay::new(4, 31).is_err());
+    /// ```
+    pub fn new(month: u8, day: u8) -> Result<Self, DateTimeError> {
+        // 2000 is a leap year, so February allows 29 days.
+        match days_in_month(2000, month) {
+            Ok(days) if (MIN_DAY..=days).contains(&day) => {
+                Ok(Self { month, day })
+            }
+            _ => Err(DateTimeError::InvalidDate),
+        }
+    }
+
+    /// Returns the month.
+    #[must_use]
+    pub fn month(self) -> Month {
+        Month::try_from(self.month).unwrap_or(Month::January)
+    }
+
+    /// Returns the day of the month.
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns `true` if the day exists in `year`, which is only false for
+    /// February 29 outside leap years.
+    #[must_use]
+    pub const fn is_valid_in_year(self, year: i32) -> bool {
+        match days_in_month(year, self.month) {
+            Ok(days) => self.day <= days,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the date of this day in `year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the day does not exist in
+    /// `year` or the year is outside the supported range.
+    pub fn in_year(self, year: i32) -> Result<Date, DateTimeError> {
+        Date::from_calendar_date(year, self.month, self.day)
+    }
+}
+
+impl Time {
+    /// Midnight, `00:00:00`.
+    pub const MIDNIGHT: Self = Self(time::Time::MIDNIGHT);
+
+    /// Creates a `Time` from a `time::Time`.
+    #[must_use]
+    pub const fn new(time: time::Time) -> Self {
+        Self(time)
+    }
+
+    /// Creates a `Time` from an hour, minute, and second.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTime` if any component is out of
+    /// range.
+    pub fn from_hms(
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, DateTimeError> {
+        time::Time::from_hms(hour, minute, second)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidTime)
+    }
+
+    /// Parses a time using a custom format specification (see the `time`
+    /// crate documentation).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or the input does not match it.
+    pub fn parse_custom_format(
+        input: &str,
+        format: &str,
+    ) -> Result<Self, DateTimeError> {
+        let format_desc = format_description::parse(format)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        time::Time::parse(input, &format_desc)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+
+    /// Formats the time using a custom format specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidFormat` if the format description
+    /// is invalid or refers to date or offset components.
+    pub fn format(
+        &self,
+        format: &str,
+    ) -> Result<String, DateTimeError> {
+        let format_desc = format_description::parse(format)
+            .map_err(|_| DateTimeError::InvalidFormat)?;
+        self.0
+            .format(&format_desc)
+            .map_err(|_| DateTimeError::InvalidFormat)
+    }
+
+    /// Returns the underlying `time::Time`.
+    #[must_use]
+    pub const fn to_time(self) -> time::Time {
+        self.0
+    }
+
+    /// Returns the hour.
+    #[must_use]
+    pub const fn hour(self) -> u8 {
+        self.0.hour()
+    }
+
+    /// Returns the minute.
+    #[must_use]
+    pub const fn minute(self) -> u8 {
+        self.0.minute()
+    }
+
+    /// Returns the second.
+    #[must_use]
+    pub const fn second(self) -> u8 {
+        self.0.second()
+    }
+
+    /// Returns the sub-second nanoseconds.
+    #[must_use]
+    pub const fn nanosecond(self) -> u32 {
+        self.0.nanosecond()
+    }
+}
+
+impl From<time::Date> for Date {
+    fn from(date: time::Date) -> Self {
+        Self(date)
+    }
+}
+
+impl From<Date> for time::Date {
+    fn from(date: Date) -> Self {
+        date.0
+    }
+}
+
+impl From<time::Time> for Time {
+    fn from(time: time::Time) -> Self {
+        Self(time)
+    }
+}
+
+impl From<Time> for time::Time {
+    fn from(time: Time) -> Self {
+        time.0
+    }
+}
+
+impl From<Date> for DateTime {
+    /// Converts the date to midnight UTC.
+    fn from(date: Date) -> Self {
+        date.at(Time::MIDNIGHT).assume_utc()
+    }
+}
+
+impl Sub for Date {
+    type Output = Duration;
+
+    /// Returns the whole-day duration between two dates.
+    fn sub(self, rhs: Self) -> Duration {
+        self.0 - rhs.0
+    }
+}
+
+impl Add<Duration> for Time {
+    type Output = Self;
+
+    /// Adds a duration, wrapping around midnight.
+    fn add(self, rhs: Duration) -> Self {
+        Self(self.0 + rhs)
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Self;
+
+    /// Subtracts a duration, wrapping around midnight.
+    fn sub(self, rhs: Duration) -> Self {
+        Self(self.0 - rhs)
+    }
+}
+
+impl Sub for Time {
+    type Output = Duration;
+
+    /// Returns the signed duration between two times of day.
+    fn sub(self, rhs: Self) -> Duration {
+        self.0 - rhs.0
+    }
+}
+
+impl fmt::Display for Date {
+    /// Formats the date as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.0.year(),
+            self.0.month() as u8,
+            self.0.day()
+        )
+    }
+}
+
+impl fmt::Display for Time {
+    /// Formats the time as `HH:MM:SS`, followed by nine fractional digits
+    /// when the time has a sub-second part.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.0.hour(),
+            self.0.minute(),
+            self.0.second()
+        )?;
+        if self.0.nanosecond() != 0 {
+            write!(f, ".{:09}", self.0.nanosecond())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateTimeError;
+
+    /// Parses a `YYYY-MM-DD` date.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        time::Date::parse(s, DATE_FORMAT)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidDate)
+    }
+}
+
+impl FromStr for Time {
+    type Err = DateTimeError;
+
+    /// Parses an `HH:MM:SS[.fraction]` time.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        time::Time::parse(s, TIME_FORMAT)
+            .map(Self)
+            .map_err(|_| DateTimeError::InvalidTime)
+    }
+}
+
+impl fmt::Display for YearMonth {
+    /// Formats the value as `YYYY-MM`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+impl fmt::Display for MonthDay {
+    /// Formats the value as `--MM-DD`, the ISO 8601 form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "--{:02}-{:02}", self.month, self.day)
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = DateTimeError;
+
+    /// Parses a `YYYY-MM` value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, month) =
+            s.split_once('-').ok_or(DateTimeError::InvalidFormat)?;
+        if year.len() != 4 || month.len() != 2 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        Self::new(
+            year.parse().map_err(|_| DateTimeError::InvalidFormat)?,
+            month.parse().map_err(|_| DateTimeError::InvalidFormat)?,
+        )
+    }
+}
+
+impl FromStr for MonthDay {
+    type Err = DateTimeError;
+
+    /// Parses a `--MM-DD` or `MM-DD` value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("--").unwrap_or(s);
+        let (month, day) =
+            s.split_once('-').ok_or(DateTimeError::InvalidFormat)?;
+        if month.len() != 2 || day.len() != 2 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        Self::new(
+            month.parse().map_err(|_| DateTimeError::InvalidFormat)?,
+            day.parse().map_err(|_| DateTimeError::InvalidFormat)?,
+        )
+    }
+}
+
+impl TryFrom<String> for YearMonth {
+    type Error = DateTimeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for MonthDay {
+    type Error = DateTimeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<YearMonth> for String {
+    fn from(value: YearMonth) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<MonthDay> for String {
+    fn from(value: MonthDay) -> Self {
+        value.to_string()
+    }
+}
@@ -0,0 +1,144 @@
+// retention.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Grandfather-father-son retention policies.
+//!
+//! A [`RetentionPolicy`] decides which timestamped items (typically backup
+//! snapshots) to keep: the newest item of each of the last N days, M weeks,
+//! and K months. Everything else is marked for deletion.
+//!
+//! Buckets are computed on the UTC wall clock, weeks start on Monday, and
+//! an item kept by one rule also counts towards the others.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::retention::RetentionPolicy;
+//!
+//! let snapshots: Vec<DateTime> = [
+//!     "2024-03-10T02:00:00Z",
+//!     "2024-03-10T01:00:00Z",
+//!     "2024-03-09T02:00:00Z",
+//!     "2024-03-01T02:00:00Z",
+//! ]
+//! .iter()
+//! .map(|s| DateTime::parse(s).unwrap())
+//! .collect();
+//!
+//! let policy = RetentionPolicy::new().keep_daily(2);
+//! let decision = policy.evaluate(&snapshots);
+//! assert_eq!(decision.keep, vec![0, 2]);
+//! assert_eq!(decision.delete, vec![1, 3]);
+//! ```
+
+use crate::datetime::{DateTime, Unit};
+use std::collections::HashSet;
+use time::UtcOffset;
+
+/// How many daily, weekly, and monthly items to keep.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RetentionPolicy {
+    /// Number of most recent days for which to keep the newest item.
+    pub daily: usize,
+    /// Number of most recent weeks for which to keep the newest item.
+    pub weekly: usize,
+    /// Number of most recent months for which to keep the newest item.
+    pub monthly: usize,
+}
+
+/// The outcome of evaluating a [`RetentionPolicy`].
+///
+/// Both lists hold indices into the evaluated slice, in ascending order.
+/// Together they cover every index exactly once.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RetentionDecision {
+    /// Indices of items to keep.
+    pub keep: Vec<usize>,
+    /// Indices of items to delete.
+    pub delete: Vec<usize>,
+}
+
+impl RetentionPolicy {
+    /// Creates a policy that keeps nothing.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            daily: 0,
+            weekly: 0,
+            monthly: 0,
+        }
+    }
+
+    /// Sets the number of daily items to keep.
+    #[must_use]
+    pub const fn keep_daily(mut self, daily: usize) -> Self {
+        self.daily = daily;
+        self
+    }
+
+    /// Sets the number of weekly items to keep.
+    #[must_use]
+    pub const fn keep_weekly(mut self, weekly: usize) -> Self {
+        self.weekly = weekly;
+        self
+    }
+
+    /// Sets the number of monthly items to keep.
+    #[must_use]
+    pub const fn keep_monthly(mut self, monthly: usize) -> Self {
+        self.monthly = monthly;
+        self
+    }
+
+    /// Decides which of the given timestamps to keep.
+    ///
+    /// For each rule, items are walked from newest to oldest and the
+    /// newest item in each bucket is kept until the rule's count of
+    /// buckets is reached. The input does not need to be sorted.
+    /// Timestamps are bucketed in UTC; one too close to the end of the
+    /// range to be shown in UTC is clamped to the first or last
+    /// representable time.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        timestamps: &[DateTime],
+    ) -> RetentionDecision {
+        let utc: Vec<DateTime> = timestamps
+            .iter()
+            .map(|dt| dt.saturating_convert_to_offset(UtcOffset::UTC))
+            .collect();
+
+        let mut newest_first: Vec<usize> = (0..utc.len()).collect();
+        newest_first
+            .sort_by(|a, b| utc[*b].cmp(&utc[*a]).then(a.cmp(b)));
+
+        let mut keep = vec![false; utc.len()];
+        for (unit, count) in [
+            (Unit::Day, self.daily),
+            (Unit::Week, self.weekly),
+            (Unit::Month, self.monthly),
+        ] {
+            let mut buckets = HashSet::new();
+            for &index in &newest_first {
+                if buckets.len() >= count {
+                    break;
+                }
+                let bucket =
+                    utc[index].truncate_to(unit).unwrap_or(utc[index]);
+                if buckets.insert(bucket) {
+                    keep[index] = true;
+                }
+            }
+        }
+
+        let (kept, deleted): (Vec<usize>, Vec<usize>) =
+            (0..utc.len()).partition(|index| keep[*index]);
+        RetentionDecision {
+            keep: kept,
+            delete: deleted,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+// rollout.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Time-boxed feature flags and gradual rollouts.
+//!
+//! A [`RolloutWindow`] is the half-open period `[start, end)` during which
+//! a change is being rolled out. It answers whether the window is active
+//! and how far through it a given moment is, so a flag can be enabled for
+//! a growing share of users as time passes.
+//!
+//! Windows serialize as a map of two RFC 3339 strings, so they can live
+//! in configuration files:
+//!
+//! ```json
+//! {"start": "2024-03-01T00:00:00Z", "end": "2024-03-08T00:00:00Z"}
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::rollout::RolloutWindow;
+//!
+//! let window: RolloutWindow = serde_json::from_str(
+//!     r#"{"start":"2024-03-01T00:00:00Z","end":"2024-03-05T00:00:00Z"}"#,
+//! )
+//! .unwrap();
+//!
+//! let now = DateTime::parse("2024-03-02T00:00:00Z").unwrap();
+//! assert!(window.is_active(&now));
+//! assert_eq!(window.fraction_elapsed(&now), 0.25);
+//!
+//! // A user whose bucket is below the elapsed fraction gets the feature.
+//! assert!(window.is_rolled_out(&now, 0.1));
+//! assert!(!window.is_rolled_out(&now, 0.9));
+//! ```
+
+use crate::{
+    datetime::DateTime, error::DateTimeError,
+    interval::DateTimeInterval, local::rfc3339,
+};
+use serde::{Deserialize, Serialize};
+
+/// The period over which a feature is rolled out.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "WindowRepr", into = "WindowRepr")]
+pub struct RolloutWindow {
+    interval: DateTimeInterval,
+}
+
+/// Serialized form of a [`RolloutWindow`].
+#[derive(Serialize, Deserialize)]
+struct WindowRepr {
+    #[serde(with = "rfc3339")]
+    start: DateTime,
+    #[serde(with = "rfc3339")]
+    end: DateTime,
+}
+
+impl TryFrom<WindowRepr> for RolloutWindow {
+    type Error = DateTimeError;
+
+    fn try_from(repr: WindowRepr) -> Result<Self, Self::Error> {
+        Self::new(repr.start, repr.end)
+    }
+}
+
+impl From<RolloutWindow> for WindowRepr {
+    fn from(window: RolloutWindow) -> Self {
+        Self {
+            start: window.start(),
+            end: window.end(),
+        }
+    }
+}
+
+impl RolloutWindow {
+    /// Creates a window that opens at `start` and closes at `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if `end` is an earlier
+    /// instant than `start`.
+    pub fn new(
+        start: DateTime,
+        end: DateTime,
+    ) -> Result<Self, DateTimeError> {
+        DateTimeInterval::new(start, end).map(Self::from)
+    }
+
+    /// Returns the time the window opens.
+    #[must_use]
+    pub const fn start(&self) -> DateTime {
+        self.interval.start()
+    }
+
+    /// Returns the time the window closes.
+    #[must_use]
+    pub const fn end(&self) -> DateTime {
+        self.interval.end()
+    }
+
+    /// Returns the window as an interval.
+    #[must_use]
+    pub const fn interval(&self) -> DateTimeInterval {
+        self.interval
+    }
+
+    /// Returns `true` if `now` is inside the window.
+    #[must_use]
+    pub fn is_active(&self, now: &DateTime) -> bool {
+        self.interval.contains(now)
+    }
+
+    /// Returns `true` if the window has opened by `now`.
+    #[must_use]
+    pub fn has_started(&self, now: &DateTime) -> bool {
+        !now.duration_since(&self.start()).is_negative()
+    }
+
+    /// Returns `true` if the window has closed by `now`.
+    #[must_use]
+    pub fn has_ended(&self, now: &DateTime) -> bool {
+        !now.duration_since(&self.end()).is_negative()
+    }
+
+    /// Returns how far through the window `now` is, from `0.0` before it
+    /// opens to `1.0` once it has closed.
+    ///
+    /// An empty window jumps straight from `0.0` to `1.0` when it closes.
+    #[must_use]
+    pub fn fraction_elapsed(&self, now: &DateTime) -> f64 {
+        if self.has_ended(now) {
+            return 1.0;
+        }
+        if !self.has_started(now) {
+            return 0.0;
+        }
+        let elapsed =
+            now.duration_since(&self.start()).as_seconds_f64();
+        let total = self.interval.duration().as_seconds_f64();
+        (elapsed / total).clamp(0.0, 1.0)
+    }
+
+    /// Returns `true` if a user assigned to `bucket`, a value in
+    /// `0.0..1.0`, should have the feature at `now`.
+    ///
+    /// Buckets are enabled in increasing order as the window progresses,
+    /// and every bucket is enabled once the window has closed.
+    #[must_use]
+    pub fn is_rolled_out(&self, now: &DateTime, bucket: f64) -> bool {
+        self.has_ended(now) || bucket < self.fraction_elapsed(now)
+    }
+}
+
+impl From<DateTimeInterval> for RolloutWindow {
+    fn from(interval: DateTimeInterval) -> Self {
+        Self { interval }
+    }
+}
@@ -0,0 +1,153 @@
+// selftest.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Library self-test.
+//!
+//! [`self_test`] inspects the library and its environment without side
+//! effects: it reports the enabled cargo features, the timezone data
+//! compiled in, and whether the system clock looks sane. [`crate::run`]
+//! wraps it and turns a failed check into an error.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::selftest::self_test;
+//!
+//! let report = self_test();
+//! assert!(report.timezones.contains(&"UTC"));
+//! assert!(report.is_healthy());
+//! println!("{report}");
+//! ```
+
+use crate::{
+    clock::{Clock, SystemClock},
+    constants,
+    datetime::{supported_timezones, DateTime},
+};
+use std::fmt;
+
+/// Earliest year the system clock may report before it is considered
+/// unset.
+pub const MIN_PLAUSIBLE_YEAR: i32 = 2024;
+
+/// Cargo features this build may have been compiled with.
+const KNOWN_FEATURES: [(&str, bool); 3] = [
+    ("bench", cfg!(feature = "bench")),
+    ("rayon", cfg!(feature = "rayon")),
+    ("tracing", cfg!(feature = "tracing")),
+];
+
+/// Result of checking the system clock.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ClockCheck {
+    /// The current UTC time as read during the check.
+    pub now: DateTime,
+    /// `true` if the clock reports a year no earlier than
+    /// [`MIN_PLAUSIBLE_YEAR`].
+    pub plausible: bool,
+    /// `true` if a second reading was not earlier than the first.
+    pub monotonic: bool,
+}
+
+impl ClockCheck {
+    /// Reads the system clock twice and checks the readings.
+    #[must_use]
+    pub fn run() -> Self {
+        Self::run_with_clock(&SystemClock)
+    }
+
+    /// Reads `clock` twice and checks the readings.
+    #[must_use]
+    pub fn run_with_clock<C: Clock + ?Sized>(clock: &C) -> Self {
+        let now = clock.now();
+        let again = clock.now();
+        Self {
+            now,
+            plausible: now.year() >= MIN_PLAUSIBLE_YEAR,
+            monotonic: again >= now,
+        }
+    }
+
+    /// Returns `true` if every clock check passed.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.plausible && self.monotonic
+    }
+}
+
+/// Structured report returned by [`self_test`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SelfTestReport {
+    /// Version of the library.
+    pub version: &'static str,
+    /// Names of the cargo features enabled in this build.
+    pub features: Vec<&'static str>,
+    /// Timezone abbreviations available for parsing and conversion.
+    pub timezones: Vec<&'static str>,
+    /// Result of the system clock check.
+    pub clock: ClockCheck,
+}
+
+impl SelfTestReport {
+    /// Returns `true` if timezone data is present and the clock is sane.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        !self.timezones.is_empty() && self.clock.is_ok()
+    }
+
+    /// Describes the first failed check, or returns `None` if the report
+    /// is healthy.
+    #[must_use]
+    pub fn failure(&self) -> Option<&'static str> {
+        if self.timezones.is_empty() {
+            Some("no timezone data available")
+        } else if !self.clock.plausible {
+            Some("system clock is not set")
+        } else if !self.clock.monotonic {
+            Some("system clock went backwards")
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    /// Formats the report as one `name: value` line per check.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let features = if self.features.is_empty() {
+            "none".to_string()
+        } else {
+            self.features.join(", ")
+        };
+        let status = |ok: bool| if ok { "ok" } else { "FAILED" };
+        writeln!(f, "version: {}", self.version)?;
+        writeln!(f, "features: {features}")?;
+        writeln!(f, "timezones: {}", self.timezones.len())?;
+        writeln!(f, "clock: {}", self.clock.now)?;
+        writeln!(
+            f,
+            "clock plausible: {}",
+            status(self.clock.plausible)
+        )?;
+        write!(f, "clock monotonic: {}", status(self.clock.monotonic))
+    }
+}
+
+/// Inspects the library and the system clock.
+///
+/// Reads no environment variables and prints nothing.
+#[must_use]
+pub fn self_test() -> SelfTestReport {
+    SelfTestReport {
+        version: constants::VERSION,
+        features: KNOWN_FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect(),
+        timezones: supported_timezones(),
+        clock: ClockCheck::run(),
+    }
+}
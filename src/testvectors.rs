@@ -0,0 +1,435 @@
+// testvectors.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conformance test vectors for parsing, formatting, and arithmetic.
+//!
+//! The vectors record the canonical behaviour of this crate for the
+//! cases most likely to differ between implementations: leap years and
+//! leap days, offset extremes, year and month boundaries, fractional
+//! seconds, and the edges of the supported date range. Wrappers and
+//! alternate backends can run them with [`check_parse`], [`check_format`],
+//! and [`check_arithmetic`], passing a closure that drives their own
+//! implementation and renders results as RFC 3339.
+//!
+//! Daylight saving transitions will be added once named timezones with
+//! rules are supported; today every offset is fixed.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::testvectors::check_parse;
+//!
+//! let mismatches = check_parse(|input| {
+//!     DateTime::parse(input).ok().map(|dt| dt.to_string())
+//! });
+//! assert!(mismatches.is_empty(), "{mismatches:?}");
+//! ```
+
+use std::fmt;
+
+/// A parsing case.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseVector {
+    /// Text to parse.
+    pub input: &'static str,
+    /// The parsed value rendered as RFC 3339, or `None` if the input must
+    /// be rejected.
+    pub expected: Option<&'static str>,
+}
+
+/// A formatting case.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FormatVector {
+    /// The value to format, as RFC 3339.
+    pub input: &'static str,
+    /// A `time` format description.
+    pub format: &'static str,
+    /// The expected output.
+    pub expected: &'static str,
+}
+
+/// An arithmetic operation applied by an [`ArithmeticVector`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Operation {
+    /// Adds whole seconds, keeping the offset.
+    AddSeconds(i64),
+    /// Adds calendar days, keeping the time of day and offset.
+    AddDays(i64),
+    /// Adds calendar months, clamping the day to the end of the month.
+    AddMonths(i32),
+    /// Adds calendar years, clamping February 29 to February 28.
+    AddYears(i32),
+    /// Converts to another offset, given in seconds east of UTC.
+    ToOffset(i32),
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddSeconds(n) => write!(f, "add {n} seconds"),
+            Self::AddDays(n) => write!(f, "add {n} days"),
+            Self::AddMonths(n) => write!(f, "add {n} months"),
+            Self::AddYears(n) => write!(f, "add {n} years"),
+            Self::ToOffset(n) => write!(f, "convert to offset {n}s"),
+        }
+    }
+}
+
+/// An arithmetic case.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArithmeticVector {
+    /// The starting value, as RFC 3339.
+    pub start: &'static str,
+    /// The operation to apply.
+    pub operation: Operation,
+    /// The result rendered as RFC 3339, or `None` if the operation must
+    /// fail because the result is out of range.
+    pub expected: Option<&'static str>,
+}
+
+/// A vector whose result differed from the expected one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Mismatch {
+    /// Description of the case, including its input.
+    pub case: String,
+    /// The expected result, or `None` for an expected rejection.
+    pub expected: Option<String>,
+    /// The result produced, or `None` for a rejection.
+    pub actual: Option<String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<String>| {
+            value.clone().unwrap_or_else(|| "<error>".to_string())
+        };
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.case,
+            show(&self.expected),
+            show(&self.actual)
+        )
+    }
+}
+
+/// Leap year cases: `(year, is_leap)`.
+pub const LEAP_YEARS: &[(i32, bool)] = &[
+    (1600, true),
+    (1700, false),
+    (1900, false),
+    (2000, true),
+    (2023, false),
+    (2024, true),
+    (2100, false),
+    (2400, true),
+    (0, true),
+    (-4, true),
+    (-100, false),
+];
+
+/// Parsing cases.
+pub const PARSE: &[ParseVector] = &[
+    // RFC 3339 basics and separators.
+    ParseVector {
+        input: "1970-01-01T00:00:00Z",
+        expected: Some("1970-01-01T00:00:00Z"),
+    },
+    ParseVector {
+        input: "2024-01-01t10:00:00z",
+        expected: Some("2024-01-01T10:00:00Z"),
+    },
+    ParseVector {
+        input: "2024-01-01 00:00:00Z",
+        expected: Some("2024-01-01T00:00:00Z"),
+    },
+    ParseVector {
+        input: "2024-01-01T00:00:00-00:00",
+        expected: Some("2024-01-01T00:00:00Z"),
+    },
+    // Leap days.
+    ParseVector {
+        input: "2024-02-29T12:00:00Z",
+        expected: Some("2024-02-29T12:00:00Z"),
+    },
+    ParseVector {
+        input: "2000-02-29T00:00:00Z",
+        expected: Some("2000-02-29T00:00:00Z"),
+    },
+    ParseVector {
+        input: "2023-02-29T12:00:00Z",
+        expected: None,
+    },
+    ParseVector {
+        input: "1900-02-29T00:00:00Z",
+        expected: None,
+    },
+    // Out-of-range components.
+    ParseVector {
+        input: "2024-13-01T00:00:00Z",
+        expected: None,
+    },
+    ParseVector {
+        input: "2024-04-31T00:00:00Z",
+        expected: None,
+    },
+    ParseVector {
+        input: "2024-01-01T24:00:00Z",
+        expected: None,
+    },
+    ParseVector {
+        input: "2024-12-31T23:59:60Z",
+        expected: None,
+    },
+    // Fractional seconds.
+    ParseVector {
+        input: "2024-01-01T00:00:00.123456789Z",
+        expected: Some("2024-01-01T00:00:00.123456789Z"),
+    },
+    ParseVector {
+        input: "2024-01-01T00:00:00.5+05:30",
+        expected: Some("2024-01-01T00:00:00.5+05:30"),
+    },
+    // Offset extremes: real-world offsets run from -12:00 to +14:00.
+    ParseVector {
+        input: "2024-01-01T00:00:00+14:00",
+        expected: Some("2024-01-01T00:00:00+14:00"),
+    },
+    ParseVector {
+        input: "2024-01-01T00:00:00-12:00",
+        expected: Some("2024-01-01T00:00:00-12:00"),
+    },
+    ParseVector {
+        input: "2024-01-01T00:00:00+14:01",
+        expected: None,
+    },
+    ParseVector {
+        input: "2024-01-01T00:00:00+24:00",
+        expected: None,
+    },
+    // Range limits.
+    ParseVector {
+        input: "0000-01-01T00:00:00Z",
+        expected: Some("0000-01-01T00:00:00Z"),
+    },
+    ParseVector {
+        input: "9999-12-31T23:59:59.999999999+14:00",
+        expected: Some("9999-12-31T23:59:59.999999999+14:00"),
+    },
+    // ISO 8601 date forms.
+    ParseVector {
+        input: "2024-02-15",
+        expected: Some("2024-02-15T00:00:00Z"),
+    },
+    ParseVector {
+        input: "2024-046",
+        expected: Some("2024-02-15T00:00:00Z"),
+    },
+    ParseVector {
+        input: "2020-W53-5",
+        expected: Some("2021-01-01T00:00:00Z"),
+    },
+    ParseVector {
+        input: "not a date",
+        expected: None,
+    },
+];
+
+/// Formatting cases.
+pub const FORMAT: &[FormatVector] = &[
+    FormatVector {
+        input: "2024-02-15T10:30:00Z",
+        format: "[year]-[month]-[day]",
+        expected: "2024-02-15",
+    },
+    FormatVector {
+        input: "2024-02-15T10:30:00Z",
+        format: "[weekday], [day] [month repr:long] [year]",
+        expected: "Thursday, 15 February 2024",
+    },
+    FormatVector {
+        input: "2024-02-29T23:59:59Z",
+        format: "[year]-[ordinal]",
+        expected: "2024-060",
+    },
+    FormatVector {
+        input: "2024-12-31T00:00:00Z",
+        format: "[year]-[ordinal]",
+        expected: "2024-366",
+    },
+    FormatVector {
+        input: "2020-12-31T00:00:00Z",
+        format: "[year base:iso_week]-W[week_number]-[weekday repr:monday]",
+        expected: "2020-W53-4",
+    },
+    FormatVector {
+        input: "2019-12-30T00:00:00Z",
+        format: "[year base:iso_week]-W[week_number]-[weekday repr:monday]",
+        expected: "2020-W01-1",
+    },
+    FormatVector {
+        input: "2024-01-01T09:05:03.5Z",
+        format: "[hour repr:12]:[minute]:[second].[subsecond digits:3] [period]",
+        expected: "09:05:03.500 AM",
+    },
+];
+
+/// Arithmetic cases.
+pub const ARITHMETIC: &[ArithmeticVector] = &[
+    ArithmeticVector {
+        start: "2024-12-31T23:59:59Z",
+        operation: Operation::AddSeconds(1),
+        expected: Some("2025-01-01T00:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-02-28T12:00:00Z",
+        operation: Operation::AddDays(1),
+        expected: Some("2024-02-29T12:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2023-02-28T12:00:00Z",
+        operation: Operation::AddDays(1),
+        expected: Some("2023-03-01T12:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-01-31T00:00:00Z",
+        operation: Operation::AddMonths(1),
+        expected: Some("2024-02-29T00:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2023-01-31T00:00:00Z",
+        operation: Operation::AddMonths(1),
+        expected: Some("2023-02-28T00:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-03-31T00:00:00+02:00",
+        operation: Operation::AddMonths(-1),
+        expected: Some("2024-02-29T00:00:00+02:00"),
+    },
+    ArithmeticVector {
+        start: "2024-02-29T00:00:00Z",
+        operation: Operation::AddYears(1),
+        expected: Some("2025-02-28T00:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-02-29T00:00:00Z",
+        operation: Operation::AddYears(4),
+        expected: Some("2028-02-29T00:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-12-31T23:00:00-05:00",
+        operation: Operation::ToOffset(0),
+        expected: Some("2025-01-01T04:00:00Z"),
+    },
+    ArithmeticVector {
+        start: "2024-01-01T00:00:00Z",
+        operation: Operation::ToOffset(-43_200),
+        expected: Some("2023-12-31T12:00:00-12:00"),
+    },
+    ArithmeticVector {
+        start: "9999-12-31T00:00:00Z",
+        operation: Operation::AddDays(1),
+        expected: None,
+    },
+    ArithmeticVector {
+        start: "9999-12-31T23:00:00Z",
+        operation: Operation::ToOffset(50_400),
+        expected: None,
+    },
+];
+
+/// Runs the [`PARSE`] vectors through `parse`, which returns the parsed
+/// value as RFC 3339 or `None` on rejection.
+pub fn check_parse<F>(mut parse: F) -> Vec<Mismatch>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    PARSE
+        .iter()
+        .filter_map(|vector| {
+            mismatch(
+                format!("parse {:?}", vector.input),
+                vector.expected,
+                parse(vector.input),
+            )
+        })
+        .collect()
+}
+
+/// Runs the [`FORMAT`] vectors through `format`, which receives the
+/// RFC 3339 input and the format description.
+pub fn check_format<F>(mut format: F) -> Vec<Mismatch>
+where
+    F: FnMut(&str, &str) -> Option<String>,
+{
+    FORMAT
+        .iter()
+        .filter_map(|vector| {
+            mismatch(
+                format!(
+                    "format {:?} with {:?}",
+                    vector.input, vector.format
+                ),
+                Some(vector.expected),
+                format(vector.input, vector.format),
+            )
+        })
+        .collect()
+}
+
+/// Runs the [`ARITHMETIC`] vectors through `apply`, which receives the
+/// RFC 3339 start value and the operation, and returns the result as
+/// RFC 3339 or `None` on failure.
+pub fn check_arithmetic<F>(mut apply: F) -> Vec<Mismatch>
+where
+    F: FnMut(&str, Operation) -> Option<String>,
+{
+    ARITHMETIC
+        .iter()
+        .filter_map(|vector| {
+            mismatch(
+                format!("{} to {:?}", vector.operation, vector.start),
+                vector.expected,
+                apply(vector.start, vector.operation),
+            )
+        })
+        .collect()
+}
+
+/// Runs the [`LEAP_YEARS`] vectors through `is_leap`.
+pub fn check_leap_years<F>(mut is_leap: F) -> Vec<Mismatch>
+where
+    F: FnMut(i32) -> bool,
+{
+    LEAP_YEARS
+        .iter()
+        .filter_map(|&(year, expected)| {
+            let actual = is_leap(year);
+            (actual != expected).then(|| Mismatch {
+                case: format!("is_leap_year({year})"),
+                expected: Some(expected.to_string()),
+                actual: Some(actual.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Compares one result against its expected value.
+fn mismatch(
+    case: String,
+    expected: Option<&str>,
+    actual: Option<String>,
+) -> Option<Mismatch> {
+    if expected == actual.as_deref() {
+        None
+    } else {
+        Some(Mismatch {
+            case,
+            expected: expected.map(str::to_string),
+            actual,
+        })
+    }
+}
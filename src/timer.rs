@@ -0,0 +1,323 @@
+// timer.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Stopwatches and deadlines.
+//!
+//! # Overview
+//!
+//! - [`Stopwatch`]: measures elapsed time with start, stop, and lap
+//!   support. Elapsed time comes from a monotonic clock, so it is
+//!   unaffected by changes to the wall clock; the wall-clock start time is
+//!   kept as a [`DateTime`] for reporting.
+//! - [`Deadline`]: a point in time with [`remaining`](Deadline::remaining)
+//!   and [`is_expired`](Deadline::is_expired) checks.
+//!
+//! All durations are [`time::Duration`] values, like the rest of the
+//! crate. Both read the system clock by default; pass a
+//! [`TimeSource`] or [`Clock`] such as [`MockClock`] to drive them from
+//! a test instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::timer::{Deadline, Stopwatch};
+//! use time::Duration;
+//!
+//! let mut stopwatch = Stopwatch::start_new();
+//! let deadline = Deadline::after(Duration::minutes(5)).unwrap();
+//!
+//! let lap = stopwatch.lap();
+//! assert!(lap >= Duration::ZERO);
+//! assert!(!deadline.is_expired());
+//!
+//! let total = stopwatch.stop();
+//! assert!(total >= lap);
+//! ```
+//!
+//! [`MockClock`]: crate::clock::MockClock
+
+use crate::{
+    clock::{Clock, SystemClock, TimeSource},
+    datetime::DateTime,
+    error::DateTimeError,
+};
+use time::Duration;
+
+/// Measures elapsed time across one or more running periods.
+///
+/// A new stopwatch is stopped with nothing on it. Time only accumulates
+/// while it is running; stopping and starting again continues from the
+/// previous total.
+///
+/// Elapsed time is read from the stopwatch's [`TimeSource`], the system
+/// clock unless created with [`Stopwatch::with_source`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::timer::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::new();
+/// assert!(!stopwatch.is_running());
+///
+/// stopwatch.start();
+/// stopwatch.lap();
+/// stopwatch.lap();
+/// stopwatch.stop();
+/// assert_eq!(stopwatch.laps().len(), 2);
+/// assert!(stopwatch.elapsed() >= stopwatch.laps()[0]);
+/// ```
+///
+/// Driven by a [`MockClock`](crate::clock::MockClock):
+///
+/// ```
+/// use dtt::clock::MockClock;
+/// use dtt::timer::Stopwatch;
+/// use time::Duration;
+///
+/// let clock = MockClock::default();
+/// let mut stopwatch = Stopwatch::start_new_with(&clock);
+/// clock.advance(Duration::seconds(3)).unwrap();
+/// assert_eq!(stopwatch.lap(), Duration::seconds(3));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Stopwatch<S = SystemClock> {
+    /// Source of the elapsed and start times.
+    source: S,
+    /// Time accumulated in previous running periods.
+    accumulated: Duration,
+    /// Monotonic reading at the start of the current running period, if
+    /// running.
+    running_since: Option<Duration>,
+    /// Wall-clock time the stopwatch was first started.
+    started_at: Option<DateTime>,
+    /// Total elapsed time at the last lap.
+    lap_mark: Duration,
+    /// Recorded lap times.
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// Creates a stopped stopwatch with no elapsed time, reading the
+    /// system clock.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_source(SystemClock)
+    }
+
+    /// Creates a stopwatch reading the system clock and starts it.
+    #[must_use]
+    pub fn start_new() -> Self {
+        Self::start_new_with(SystemClock)
+    }
+}
+
+impl<S: TimeSource> Stopwatch<S> {
+    /// Creates a stopped stopwatch with no elapsed time, reading
+    /// `source`.
+    #[must_use]
+    pub const fn with_source(source: S) -> Self {
+        Self {
+            source,
+            accumulated: Duration::ZERO,
+            running_since: None,
+            started_at: None,
+            lap_mark: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+
+    /// Creates a stopwatch reading `source` and starts it.
+    #[must_use]
+    pub fn start_new_with(source: S) -> Self {
+        let mut stopwatch = Self::with_source(source);
+        stopwatch.start();
+        stopwatch
+    }
+
+    /// Returns the running time since the monotonic reading `start`.
+    fn since(&self, start: Duration) -> Duration {
+        (self.source.monotonic() - start).max(Duration::ZERO)
+    }
+
+    /// Starts or resumes the stopwatch. Does nothing if it is already
+    /// running.
+    pub fn start(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.source.monotonic());
+            if self.started_at.is_none() {
+                self.started_at = Some(self.source.now());
+            }
+        }
+    }
+
+    /// Stops the stopwatch and returns the total elapsed time.
+    pub fn stop(&mut self) -> Duration {
+        if let Some(start) = self.running_since.take() {
+            self.accumulated += self.since(start);
+        }
+        self.accumulated
+    }
+
+    /// Stops the stopwatch and clears the elapsed time and laps. The
+    /// source is kept.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.running_since = None;
+        self.started_at = None;
+        self.lap_mark = Duration::ZERO;
+        self.laps.clear();
+    }
+
+    /// Clears the elapsed time and laps and starts again.
+    pub fn restart(&mut self) {
+        self.reset();
+        self.start();
+    }
+
+    /// Records a lap and returns its length: the running time since the
+    /// previous lap, or since the first start.
+    pub fn lap(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.lap_mark;
+        self.lap_mark = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+
+    /// Returns the recorded lap times, oldest first.
+    #[must_use]
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// Returns the total running time so far.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.running_since.map_or(self.accumulated, |start| {
+            self.accumulated + self.since(start)
+        })
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Returns the time, according to the source, the stopwatch was first
+    /// started, or `None` if it has never run since it was created or
+    /// reset.
+    #[must_use]
+    pub const fn started_at(&self) -> Option<DateTime> {
+        self.started_at
+    }
+}
+
+/// A point in time by which something must happen.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::timer::Deadline;
+/// use time::Duration;
+///
+/// let due = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// let deadline = Deadline::at(due);
+///
+/// let now = DateTime::parse("2024-01-01T11:59:30Z").unwrap();
+/// assert_eq!(deadline.remaining_at(&now), Duration::seconds(30));
+/// assert!(!deadline.is_expired_at(&now));
+///
+/// let later = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+/// assert_eq!(deadline.remaining_at(&later), Duration::ZERO);
+/// assert!(deadline.is_expired_at(&later));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Deadline {
+    due: DateTime,
+}
+
+impl Deadline {
+    /// Creates a deadline at the given time.
+    #[must_use]
+    pub const fn at(due: DateTime) -> Self {
+        Self { due }
+    }
+
+    /// Creates a deadline the given duration from now.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the deadline falls outside
+    /// the supported date range.
+    pub fn after(duration: Duration) -> Result<Self, DateTimeError> {
+        Self::after_with_clock(duration, &SystemClock)
+    }
+
+    /// Creates a deadline the given duration after the time on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidDate` if the deadline falls outside
+    /// the supported date range.
+    pub fn after_with_clock<C: Clock + ?Sized>(
+        duration: Duration,
+        clock: &C,
+    ) -> Result<Self, DateTimeError> {
+        (clock.now() + duration).map(Self::at)
+    }
+
+    /// Returns the time the deadline falls due.
+    #[must_use]
+    pub const fn due(&self) -> DateTime {
+        self.due
+    }
+
+    /// Returns the time left until the deadline, or zero if it has passed.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining_with_clock(&SystemClock)
+    }
+
+    /// Returns the time left at the time on `clock`, or zero if the
+    /// deadline has passed.
+    #[must_use]
+    pub fn remaining_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> Duration {
+        self.remaining_at(&clock.now())
+    }
+
+    /// Returns the time left at `now`, or zero if the deadline has passed.
+    #[must_use]
+    pub fn remaining_at(&self, now: &DateTime) -> Duration {
+        self.due.duration_since(now).max(Duration::ZERO)
+    }
+
+    /// Returns `true` if the deadline has been reached.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_with_clock(&SystemClock)
+    }
+
+    /// Returns `true` if the deadline has been reached at the time on
+    /// `clock`.
+    #[must_use]
+    pub fn is_expired_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> bool {
+        self.is_expired_at(&clock.now())
+    }
+
+    /// Returns `true` if the deadline has been reached at `now`.
+    #[must_use]
+    pub fn is_expired_at(&self, now: &DateTime) -> bool {
+        self.due.duration_since(now) <= Duration::ZERO
+    }
+}
@@ -0,0 +1,349 @@
+// timezone.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Runtime registration of timezone abbreviations.
+//!
+//! [`DateTime::new_with_tz`] and [`DateTime::convert_to_tz`] resolve names
+//! through the process-wide [`TimezoneRegistry::global`]. A name is looked
+//! up in this order:
+//!
+//! 1. abbreviations registered at runtime;
+//! 2. the built-in abbreviations, listed with the registered ones by
+//!    [`supported_timezones`];
+//! 3. numeric offsets such as `UTC+05:30`, `+0530`, or `GMT-7`.
+//!
+//! Registered abbreviations therefore take precedence over built-in ones,
+//! which lets an application settle ambiguous names such as `IST` (India,
+//! Ireland, or Israel). Names are case-sensitive, like the built-in table.
+//!
+//! The registry is guarded by a `RwLock`, so lookups from many threads do
+//! not block each other.
+//!
+//! Values created through a registered name report it from
+//! [`DateTime::zone_abbreviation`], like built-in names. Each distinct
+//! registered name is kept for the rest of the process so that values can
+//! carry it, even after it is unregistered.
+//!
+//! Code that converts many values to the same zone can resolve the name
+//! once with a [`ZoneResolver`] and skip the lookup on every conversion.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::datetime::DateTime;
+//! use dtt::timezone::TimezoneRegistry;
+//!
+//! TimezoneRegistry::global().register("NZDT", 13, 0).unwrap();
+//!
+//! let utc = DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+//! let auckland = utc.convert_to_tz("NZDT").unwrap();
+//! assert_eq!(auckland.to_string(), "2024-01-01T13:00:00+13:00");
+//! ```
+//!
+//! [`DateTime::new_with_tz`]: crate::datetime::DateTime::new_with_tz
+//! [`DateTime::convert_to_tz`]: crate::datetime::DateTime::convert_to_tz
+//! [`DateTime::zone_abbreviation`]: crate::datetime::DateTime::zone_abbreviation
+//! [`supported_timezones`]: crate::datetime::supported_timezones
+
+use crate::{
+    clock::{Clock, SystemClock},
+    datetime::{builtin_timezone_offset, builtin_zone_label, DateTime},
+    error::DateTimeError,
+    limits::MAX_OFFSET_SECONDS,
+};
+use std::sync::{Mutex, PoisonError, RwLock};
+use time::UtcOffset;
+
+/// The registry consulted by the timezone-aware `DateTime` methods.
+static GLOBAL_REGISTRY: TimezoneRegistry = TimezoneRegistry::new();
+
+/// Every name ever registered, sorted, shared by all registries.
+static INTERNED_NAMES: Mutex<Vec<&'static str>> =
+    Mutex::new(Vec::new());
+
+/// Returns the `'static` copy of `name`, leaking it the first time the
+/// name is seen.
+fn intern(name: &str) -> &'static str {
+    let mut names = INTERNED_NAMES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    match names.binary_search(&name) {
+        Ok(index) => names[index],
+        Err(index) => {
+            let interned: &'static str =
+                Box::leak(name.to_owned().into_boxed_str());
+            names.insert(index, interned);
+            interned
+        }
+    }
+}
+
+/// A set of timezone abbreviations registered at runtime, on top of the
+/// built-in table.
+#[derive(Debug, Default)]
+pub struct TimezoneRegistry {
+    /// Registered abbreviations, sorted by name.
+    custom: RwLock<Vec<(&'static str, UtcOffset)>>,
+}
+
+impl TimezoneRegistry {
+    /// Creates a registry with no runtime registrations. Built-in
+    /// abbreviations and numeric offsets still resolve.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            custom: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Returns the process-wide registry used by
+    /// [`DateTime::new_with_tz`](crate::datetime::DateTime::new_with_tz)
+    /// and [`DateTime::convert_to_tz`](crate::datetime::DateTime::convert_to_tz).
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Registers `name` as an abbreviation for UTC offset
+    /// `hours:minutes`, replacing any earlier registration of the same
+    /// name. Both parts carry the sign, so -3:30 is `(-3, -30)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the name is empty or
+    /// contains whitespace, or if the offset is outside ±14:00.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::timezone::TimezoneRegistry;
+    ///
+    /// let registry = TimezoneRegistry::new();
+    /// registry.register("NST", -3, -30).unwrap();
+    /// assert_eq!(registry.lookup("NST").unwrap().whole_minutes(), -210);
+    /// assert!(registry.register("Far Away", 15, 0).is_err());
+    /// ```
+    pub fn register(
+        &self,
+        name: &str,
+        hours: i8,
+        minutes: i8,
+    ) -> Result<(), DateTimeError> {
+        let offset = UtcOffset::from_hms(hours, minutes, 0)
+            .map_err(|_| DateTimeError::InvalidTimezone)?;
+        self.register_offset(name, offset)
+    }
+
+    /// Registers `name` as an abbreviation for `offset`, replacing any
+    /// earlier registration of the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the name is empty or
+    /// contains whitespace, or if the offset is outside ±14:00.
+    pub fn register_offset(
+        &self,
+        name: &str,
+        offset: UtcOffset,
+    ) -> Result<(), DateTimeError> {
+        if name.is_empty()
+            || name.chars().any(char::is_whitespace)
+            || offset.whole_seconds().abs() > MAX_OFFSET_SECONDS
+        {
+            return Err(DateTimeError::InvalidTimezone);
+        }
+        let mut custom =
+            self.custom.write().unwrap_or_else(PoisonError::into_inner);
+        match custom.binary_search_by(|(key, _)| (*key).cmp(name)) {
+            Ok(index) => custom[index].1 = offset,
+            Err(index) => custom.insert(index, (intern(name), offset)),
+        }
+        drop(custom);
+        Ok(())
+    }
+
+    /// Removes a runtime registration and returns its offset, or `None`
+    /// if `name` was not registered. Built-in abbreviations cannot be
+    /// removed.
+    pub fn unregister(&self, name: &str) -> Option<UtcOffset> {
+        let mut custom =
+            self.custom.write().unwrap_or_else(PoisonError::into_inner);
+        custom
+            .binary_search_by(|(key, _)| (*key).cmp(name))
+            .ok()
+            .map(|index| custom.remove(index).1)
+    }
+
+    /// Resolves a timezone name to its offset, in the order described in
+    /// the [module documentation](self).
+    #[must_use]
+    pub fn lookup(&self, name: &str) -> Option<UtcOffset> {
+        let custom =
+            self.custom.read().unwrap_or_else(PoisonError::into_inner);
+        custom
+            .binary_search_by(|(key, _)| (*key).cmp(name))
+            .ok()
+            .map(|index| custom[index].1)
+            .or_else(|| builtin_timezone_offset(name))
+    }
+
+    /// Returns the registered or built-in abbreviation equal to `name`,
+    /// as the `'static` label a `DateTime` carries, or `None` for numeric
+    /// offsets and unknown names.
+    pub(crate) fn label(&self, name: &str) -> Option<&'static str> {
+        let custom =
+            self.custom.read().unwrap_or_else(PoisonError::into_inner);
+        custom
+            .binary_search_by(|(key, _)| (*key).cmp(name))
+            .ok()
+            .map(|index| custom[index].0)
+            .or_else(|| builtin_zone_label(name))
+    }
+
+    /// Returns the abbreviations registered at runtime and their offsets,
+    /// sorted by name.
+    #[must_use]
+    pub fn registered(&self) -> Vec<(String, UtcOffset)> {
+        self.custom
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, offset)| ((*name).to_string(), *offset))
+            .collect()
+    }
+
+    /// Returns the abbreviations registered at runtime, sorted.
+    pub(crate) fn names(&self) -> Vec<&'static str> {
+        self.custom
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, _)| *name)
+            .collect()
+    }
+}
+
+/// A timezone name resolved once, for converting many values to it.
+///
+/// The offset is looked up when the resolver is created; later changes to
+/// the registry do not affect an existing resolver.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::datetime::DateTime;
+/// use dtt::timezone::ZoneResolver;
+///
+/// let tokyo = ZoneResolver::new("JST").unwrap();
+/// let times = ["2024-01-01T00:00:00Z", "2024-07-01T12:00:00Z"];
+/// for input in times {
+///     let dt = DateTime::parse(input).unwrap();
+///     assert_eq!(tokyo.convert(&dt).unwrap().offset().whole_hours(), 9);
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ZoneResolver {
+    /// The name the resolver was created with.
+    name: String,
+    /// The offset `name` resolved to.
+    offset: UtcOffset,
+    /// The abbreviation values converted by the resolver carry, if `name`
+    /// is a built-in or registered one.
+    zone: Option<&'static str>,
+}
+
+impl ZoneResolver {
+    /// Resolves `name` through the [global registry](TimezoneRegistry::global).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the name is unknown.
+    pub fn new(name: &str) -> Result<Self, DateTimeError> {
+        Self::with_registry(TimezoneRegistry::global(), name)
+    }
+
+    /// Resolves `name` through `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::InvalidTimezone` if the name is unknown.
+    pub fn with_registry(
+        registry: &TimezoneRegistry,
+        name: &str,
+    ) -> Result<Self, DateTimeError> {
+        let offset = registry
+            .lookup(name)
+            .ok_or(DateTimeError::InvalidTimezone)?;
+        Ok(Self {
+            name: name.to_string(),
+            offset,
+            zone: registry.label(name),
+        })
+    }
+
+    /// Returns the name the resolver was created with.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the resolved offset.
+    #[must_use]
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Converts `datetime` to the resolved offset, keeping the same
+    /// instant. The result carries the zone abbreviation, as with
+    /// [`DateTime::convert_to_tz`](crate::datetime::DateTime::convert_to_tz).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError::ConversionOverflow` if the converted
+    /// value falls outside the supported date range.
+    pub fn convert(
+        &self,
+        datetime: &DateTime,
+    ) -> Result<DateTime, DateTimeError> {
+        datetime
+            .try_convert_to_offset(self.offset)
+            .map(|dt| dt.with_zone_label(self.zone))
+    }
+
+    /// Returns the current time in the resolved offset, carrying the zone
+    /// abbreviation.
+    #[must_use]
+    pub fn now(&self) -> DateTime {
+        self.now_with_clock(&SystemClock)
+    }
+
+    /// Returns the time on `clock` in the resolved offset, carrying the
+    /// zone abbreviation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dtt::clock::FixedClock;
+    /// use dtt::datetime::DateTime;
+    /// use dtt::timezone::ZoneResolver;
+    ///
+    /// let noon = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+    /// let tokyo = ZoneResolver::new("JST").unwrap();
+    /// assert_eq!(
+    ///     tokyo.now_with_clock(&FixedClock::new(noon)).to_string(),
+    ///     "2024-01-01T21:00:00+09:00"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn now_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> DateTime {
+        clock
+            .now()
+            .saturating_convert_to_offset(self.offset)
+            .with_zone_label(self.zone)
+    }
+}
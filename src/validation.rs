@@ -0,0 +1,207 @@
+// validation.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Typed validation of date and time components.
+//!
+//! [`validate_date`] and [`validate_time`] take numbers rather than
+//! strings, so values that are already integers need no round trip
+//! through `to_string`. On success they return the checked value:
+//! a [`ValidatedDate`] or a [`Time`]. On failure they report every
+//! out-of-range component as a [`ComponentError`], like
+//! [`DateTime::validate_components`].
+//!
+//! These replace the string-based `DateTime::is_valid_day`,
+//! `is_valid_month`, `is_valid_time`, and related functions, which remain
+//! for compatibility but are deprecated.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::validation::{validate_date, validate_time};
+//!
+//! let date = validate_date(2024, 2, 29).unwrap();
+//! assert_eq!(date.to_string(), "2024-02-29");
+//!
+//! let errors = validate_date(2023, 2, 29).unwrap_err();
+//! assert_eq!(errors[0].to_string(), "day must be in the range 1..=28, got 29");
+//!
+//! assert!(validate_time(23, 59, 59, 999_999_999).is_ok());
+//! assert_eq!(validate_time(24, 60, 0, 0).unwrap_err().len(), 2);
+//! ```
+//!
+//! [`DateTime::validate_components`]: crate::datetime::DateTime::validate_components
+
+use crate::{
+    datetime::DateTime,
+    error::ComponentError,
+    limits::{
+        MAX_DAY, MAX_HOUR, MAX_MINUTE, MAX_NANOSECOND, MAX_SECOND,
+    },
+    plain::{Date, Time},
+};
+use std::fmt;
+
+/// A calendar date whose year, month, and day have been checked by
+/// [`validate_date`].
+///
+/// # Examples
+///
+/// ```
+/// use dtt::validation::ValidatedDate;
+///
+/// let date = ValidatedDate::new(2024, 12, 25).unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (2024, 12, 25));
+/// assert!(ValidatedDate::new(2024, 13, 1).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ValidatedDate(Date);
+
+impl ValidatedDate {
+    /// Validates the components; see [`validate_date`].
+    ///
+    /// # Errors
+    ///
+    /// Returns every out-of-range component, in order from year to day.
+    pub fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Self, Vec<ComponentError>> {
+        validate_date(year, month, day)
+    }
+
+    /// Returns the year.
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.0.year()
+    }
+
+    /// Returns the month number (1-12).
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.0.month() as u8
+    }
+
+    /// Returns the day of the month.
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.0.day()
+    }
+
+    /// Returns the validated value as a [`Date`].
+    #[must_use]
+    pub const fn to_date(self) -> Date {
+        self.0
+    }
+}
+
+impl From<ValidatedDate> for Date {
+    fn from(date: ValidatedDate) -> Self {
+        date.0
+    }
+}
+
+impl fmt::Display for ValidatedDate {
+    /// Formats the date as ISO 8601, e.g. `2024-02-29`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Checks a calendar date. The valid day range depends on the month and,
+/// for February, on whether the year is a leap year.
+///
+/// # Errors
+///
+/// Returns every out-of-range component, in order from year to day.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::validation::validate_date;
+///
+/// let errors = validate_date(2024, 13, 0).unwrap_err();
+/// let names: Vec<&str> = errors.iter().map(|e| e.component).collect();
+/// assert_eq!(names, ["month", "day"]);
+/// ```
+pub fn validate_date(
+    year: i32,
+    month: u8,
+    day: u8,
+) -> Result<ValidatedDate, Vec<ComponentError>> {
+    DateTime::validate_components(year, month, day, 0, 0, 0)?;
+    Date::from_calendar_date(year, month, day)
+        .map(ValidatedDate)
+        .map_err(|_| {
+            vec![out_of_range("day", i64::from(day), 1, MAX_DAY.into())]
+        })
+}
+
+/// Checks a time of day.
+///
+/// # Errors
+///
+/// Returns every out-of-range component, in order from hour to
+/// nanosecond.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::validation::validate_time;
+///
+/// let time = validate_time(9, 30, 0, 0).unwrap();
+/// assert_eq!(time.to_string(), "09:30:00");
+///
+/// let errors = validate_time(9, 30, 60, 1_000_000_000).unwrap_err();
+/// let names: Vec<&str> = errors.iter().map(|e| e.component).collect();
+/// assert_eq!(names, ["second", "nanosecond"]);
+/// ```
+pub fn validate_time(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+) -> Result<Time, Vec<ComponentError>> {
+    let checks = [
+        ("hour", i64::from(hour), i64::from(MAX_HOUR)),
+        ("minute", i64::from(minute), i64::from(MAX_MINUTE)),
+        ("second", i64::from(second), i64::from(MAX_SECOND)),
+        (
+            "nanosecond",
+            i64::from(nanosecond),
+            i64::from(MAX_NANOSECOND),
+        ),
+    ];
+    let errors: Vec<ComponentError> = checks
+        .iter()
+        .filter(|(_, value, maximum)| *value > *maximum)
+        .map(|&(component, value, maximum)| {
+            out_of_range(component, value, 0, maximum)
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    time::Time::from_hms_nano(hour, minute, second, nanosecond)
+        .map(Time::new)
+        .map_err(|_| {
+            vec![out_of_range("hour", hour.into(), 0, MAX_HOUR.into())]
+        })
+}
+
+/// Builds a [`ComponentError`].
+const fn out_of_range(
+    component: &'static str,
+    value: i64,
+    minimum: i64,
+    maximum: i64,
+) -> ComponentError {
+    ComponentError {
+        component,
+        value,
+        minimum,
+        maximum,
+    }
+}
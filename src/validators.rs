@@ -0,0 +1,160 @@
+// validators.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! String validators for date and time components.
+//!
+//! Each `is_valid_*` function takes text, such as a form field or a
+//! command-line argument, and returns `true` if it names a valid value
+//! for that component. The ranges are those of the [`limits`] module.
+//!
+//! These are the functions behind [`is_valid!`] and
+//! [`dtt_is_valid_function!`], and the deprecated string checks on
+//! `DateTime` delegate to them. The module is hidden from the
+//! documentation: like those checks, it is superseded by
+//! [`validation`], which takes numbers and reports what is wrong.
+//!
+//! # Examples
+//!
+//! ```
+//! use dtt::validators::{is_valid_iso_week, is_valid_offset, is_valid_time};
+//!
+//! assert!(is_valid_iso_week("53"));
+//! assert!(!is_valid_iso_week("54"));
+//! assert!(is_valid_time("23:59:59"));
+//! assert!(is_valid_offset("+05:30"));
+//! assert!(!is_valid_offset("+15:00"));
+//! ```
+//!
+//! [`limits`]: crate::limits
+//! [`validation`]: crate::validation
+//! [`is_valid!`]: crate::is_valid
+//! [`dtt_is_valid_function!`]: crate::dtt_is_valid_function
+
+use crate::{
+    datetime::{parse_offset_name, DateTime},
+    limits::{MAX_NANOSECOND, MAX_YEAR, MIN_YEAR},
+    validation::validate_time,
+};
+
+/// Returns `true` if `input` is a year in the supported range
+/// (-9999 to 9999).
+#[must_use]
+pub fn is_valid_year(input: &str) -> bool {
+    input
+        .parse::<i32>()
+        .map_or(false, |year| (MIN_YEAR..=MAX_YEAR).contains(&year))
+}
+
+/// Returns `true` if `input` is a month number (1-12).
+#[must_use]
+pub fn is_valid_month(input: &str) -> bool {
+    input
+        .parse::<u8>()
+        .map_or(false, DateTime::is_valid_month_u8)
+}
+
+/// Returns `true` if `input` is a day of the month (1-31).
+#[must_use]
+pub fn is_valid_day(input: &str) -> bool {
+    input.parse::<u8>().map_or(false, DateTime::is_valid_day_u8)
+}
+
+/// Returns `true` if `input` is an ordinal day of the year (1-366).
+#[must_use]
+pub fn is_valid_ordinal(input: &str) -> bool {
+    input
+        .parse::<u16>()
+        .map_or(false, DateTime::is_valid_ordinal_u16)
+}
+
+/// Returns `true` if `input` is an ISO week number (1-53).
+#[must_use]
+pub fn is_valid_iso_week(input: &str) -> bool {
+    input
+        .parse::<u8>()
+        .map_or(false, DateTime::is_valid_iso_week_u8)
+}
+
+/// Returns `true` if `input` is an hour (0-23).
+#[must_use]
+pub fn is_valid_hour(input: &str) -> bool {
+    input
+        .parse::<u8>()
+        .map_or(false, DateTime::is_valid_hour_u8)
+}
+
+/// Returns `true` if `input` is a minute (0-59).
+#[must_use]
+pub fn is_valid_minute(input: &str) -> bool {
+    input
+        .parse::<u8>()
+        .map_or(false, DateTime::is_valid_minute_u8)
+}
+
+/// Returns `true` if `input` is a second (0-59).
+#[must_use]
+pub fn is_valid_second(input: &str) -> bool {
+    input
+        .parse::<u8>()
+        .map_or(false, DateTime::is_valid_second_u8)
+}
+
+/// Returns `true` if `input` is a microsecond (0-999,999).
+#[must_use]
+pub fn is_valid_microsecond(input: &str) -> bool {
+    input
+        .parse::<u32>()
+        .map_or(false, DateTime::is_valid_microsecond_u32)
+}
+
+/// Returns `true` if `input` is a nanosecond (0-999,999,999).
+#[must_use]
+pub fn is_valid_nanosecond(input: &str) -> bool {
+    input
+        .parse::<u32>()
+        .map_or(false, |nanosecond| nanosecond <= MAX_NANOSECOND)
+}
+
+/// Returns `true` if `input` is a time of day written `HH:MM:SS`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::validators::is_valid_time;
+///
+/// assert!(is_valid_time("09:30:00"));
+/// assert!(!is_valid_time("24:00:00"));
+/// assert!(!is_valid_time("09:30"));
+/// ```
+#[must_use]
+pub fn is_valid_time(input: &str) -> bool {
+    let mut parts = input.split(':').map(str::parse::<u8>);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(hour)), Some(Ok(minute)), Some(Ok(second)), None) => {
+            validate_time(hour, minute, second, 0).is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `input` is a UTC offset within ±14:00: `Z`, or a
+/// sign and hours with optional minutes, such as `+05:30`, `-0800`, or
+/// `UTC+1`.
+///
+/// # Examples
+///
+/// ```
+/// use dtt::validators::is_valid_offset;
+///
+/// assert!(is_valid_offset("Z"));
+/// assert!(is_valid_offset("-08:00"));
+/// assert!(is_valid_offset("GMT+0530"));
+/// assert!(!is_valid_offset("+05:60"));
+/// assert!(!is_valid_offset("05:30"));
+/// ```
+#[must_use]
+pub fn is_valid_offset(input: &str) -> bool {
+    input == "Z" || parse_offset_name(input).is_some()
+}
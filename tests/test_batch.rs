@@ -0,0 +1,135 @@
+// test_batch.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `batch` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::batch::{format_many, parse_many};
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::format::Format;
+
+    fn inputs() -> Vec<String> {
+        (0..500)
+            .map(|i| {
+                if i % 7 == 0 {
+                    format!("garbage-{}", i)
+                } else {
+                    format!(
+                        "2024-03-{:02}T{:02}:00:00Z",
+                        i % 28 + 1,
+                        i % 24
+                    )
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_many_keeps_order() {
+        let inputs = inputs();
+        let parsed = parse_many(&inputs);
+        assert_eq!(parsed.len(), inputs.len());
+        for (input, result) in inputs.iter().zip(&parsed) {
+            assert_eq!(*result, DateTime::parse(input));
+        }
+    }
+
+    #[test]
+    fn test_parse_many_accepts_str_slices() {
+        let parsed = parse_many(&["2024-01-01", "2024-13-01"]);
+        assert!(parsed[0].is_ok());
+        assert_eq!(parsed[1], Err(DateTimeError::InvalidFormat));
+        assert!(parse_many::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_format_many() -> Result<(), DateTimeError> {
+        let dates = [
+            DateTime::parse("2024-02-15T10:30:00+01:00")?,
+            DateTime::parse("1999-12-31T23:59:59Z")?,
+        ];
+        let formatted = format_many(&dates, Format::HttpDate);
+        assert_eq!(
+            formatted,
+            [
+                Ok("Thu, 15 Feb 2024 09:30:00 GMT".to_string()),
+                Ok("Fri, 31 Dec 1999 23:59:59 GMT".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        use dtt::batch::{format_many_parallel, parse_many_parallel};
+
+        let inputs = inputs();
+        let parsed = parse_many_parallel(&inputs);
+        assert_eq!(parsed, parse_many(&inputs));
+
+        let dates: Vec<DateTime> =
+            parsed.into_iter().flatten().collect();
+        assert_eq!(
+            format_many_parallel(&dates, Format::Rfc3339),
+            format_many(&dates, Format::Rfc3339)
+        );
+    }
+
+    #[test]
+    fn test_parse_many_with_spec_matches_custom_format() {
+        use dtt::batch::parse_many_with_spec;
+        use dtt::format::FormatSpec;
+
+        let format = "[year]-[month]-[day] [hour]:[minute]";
+        let spec = FormatSpec::new(format).unwrap();
+        let inputs =
+            ["2024-03-01 10:15", "2024-03-01", "2024-02-30 00:00"];
+        let parsed = parse_many_with_spec(&inputs, &spec);
+        for (input, result) in inputs.iter().zip(&parsed) {
+            assert_eq!(
+                *result,
+                DateTime::parse_custom_format(input, format)
+            );
+        }
+        assert!(parsed[0].is_ok());
+        assert!(matches!(
+            parsed[1],
+            Err(DateTimeError::InvalidFormatAt { index: 10, .. })
+        ));
+        assert_eq!(parsed[2], Err(DateTimeError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_datetime_parse_many() {
+        let inputs = inputs();
+        assert_eq!(DateTime::parse_many(&inputs), parse_many(&inputs));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_spec_matches_serial() {
+        use dtt::batch::{
+            parse_many_with_spec, parse_many_with_spec_parallel,
+        };
+        use dtt::format::FormatSpec;
+
+        let spec = FormatSpec::new(
+            "[year]-[month]-[day]T[hour]:[minute]:[second]Z",
+        )
+        .unwrap();
+        let inputs = inputs();
+        assert_eq!(
+            parse_many_with_spec_parallel(&inputs, &spec),
+            parse_many_with_spec(&inputs, &spec)
+        );
+        assert_eq!(
+            DateTime::parse_many_parallel(&inputs),
+            parse_many(&inputs)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+// test_calendar.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `calendar` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::calendar::CalendarOptions;
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::Date;
+    use time::Weekday;
+
+    #[test]
+    fn test_month_monday_first() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2023-10-15T12:00:00Z")?;
+        let grid = dt.render_month_calendar(&CalendarOptions::new());
+        let expected = [
+            "    October 2023",
+            "Mo Tu We Th Fr Sa Su",
+            "                   1",
+            " 2  3  4  5  6  7  8",
+            " 9 10 11 12 13 14 15",
+            "16 17 18 19 20 21 22",
+            "23 24 25 26 27 28 29",
+            "30 31",
+        ];
+        assert_eq!(grid, expected.join("\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_month_sunday_first() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2023-10-15T12:00:00Z")?;
+        let options =
+            CalendarOptions::new().first_weekday(Weekday::Sunday);
+        let lines: Vec<String> = dt
+            .render_month_calendar(&options)
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(lines[1], "Su Mo Tu We Th Fr Sa");
+        assert_eq!(lines[2], " 1  2  3  4  5  6  7");
+        assert_eq!(lines.last().map(String::as_str), Some("29 30 31"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_month_uses_wall_clock_date() -> Result<(), DateTimeError> {
+        // Still January in UTC-05:00.
+        let dt = DateTime::parse("2024-01-31T22:00:00-05:00")?;
+        let grid = dt.render_month_calendar(&CalendarOptions::new());
+        assert!(grid.starts_with("    January 2024\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-02-15T10:00:00Z")?;
+        let options = CalendarOptions::new()
+            .highlight(Date::from_calendar_date(2024, 2, 15)?);
+        let grid = dt.render_month_calendar(&options);
+        assert!(grid.contains("14 \x1b[7m15\x1b[0m 16"));
+
+        let elsewhere = CalendarOptions::new()
+            .highlight(Date::from_calendar_date(2023, 2, 15)?);
+        assert!(!dt.render_month_calendar(&elsewhere).contains('\x1b'));
+        Ok(())
+    }
+
+    #[test]
+    fn test_year_layout() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-06-01T00:00:00Z")?;
+        let grid = dt.render_year_calendar(&CalendarOptions::new());
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines[0].trim(), "2024");
+        assert_eq!(lines[1], "");
+        assert_eq!(
+            lines[2],
+            "      January               February               March"
+        );
+        assert_eq!(
+            lines[3],
+            "Mo Tu We Th Fr Sa Su  Mo Tu We Th Fr Sa Su  Mo Tu We Th Fr Sa Su"
+        );
+        assert_eq!(
+            lines[4],
+            " 1  2  3  4  5  6  7            1  2  3  4               1  2  3"
+        );
+        assert!(lines.iter().all(|line| line.len() <= 64));
+        assert!(lines.iter().all(|line| line.trim_end() == *line));
+        Ok(())
+    }
+
+    #[test]
+    fn test_year_highlight() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-12-25T00:00:00Z")?;
+        let options = CalendarOptions::new()
+            .highlight(Date::from_calendar_date(2024, 12, 25)?);
+        let grid = dt.render_year_calendar(&options);
+        assert_eq!(grid.matches("\x1b[7m25\x1b[0m").count(), 1);
+        assert_eq!(CalendarOptions::default(), CalendarOptions::new());
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+// test_clock.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `clock` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::clock::MonotonicStamp;
+    use dtt::datetime::DateTime;
+    use std::{collections::HashSet, sync::Arc, thread};
+
+    /// Tests that consecutive values are strictly increasing.
+    #[test]
+    fn test_strictly_increasing() {
+        let stamps = MonotonicStamp::new();
+        let mut previous = stamps.now();
+        for _ in 0..10_000 {
+            let next = stamps.now();
+            assert!(next > previous);
+            previous = next;
+        }
+    }
+
+    /// Tests that values follow the given clock, bumped past ties and
+    /// regressions.
+    #[test]
+    fn test_stamps_follow_the_clock() {
+        use dtt::clock::MockClock;
+        use time::Duration;
+
+        let start = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+        let clock = MockClock::new(start);
+        let stamps = MonotonicStamp::new();
+        assert_eq!(stamps.now_with_clock(&clock), start);
+        assert_eq!(
+            stamps.now_with_clock(&clock),
+            (start + Duration::NANOSECOND).unwrap()
+        );
+
+        let _ = clock.advance(-Duration::minutes(5)).unwrap();
+        assert_eq!(
+            stamps.now_with_clock(&clock),
+            (start + Duration::nanoseconds(2)).unwrap()
+        );
+        let _ = clock.advance(Duration::minutes(10)).unwrap();
+        assert_eq!(
+            stamps.now_with_clock(&clock).to_string(),
+            "2024-01-01T12:05:00Z"
+        );
+    }
+
+    /// Tests that the global generator is strictly increasing.
+    #[test]
+    fn test_global_strictly_increasing() {
+        let a = DateTime::strictly_monotonic_now();
+        let b = DateTime::strictly_monotonic_now();
+        assert!(b > a);
+        assert_eq!(b.offset(), time::UtcOffset::UTC);
+    }
+
+    /// Tests that values issued concurrently are all distinct.
+    #[test]
+    fn test_unique_across_threads() {
+        let stamps = Arc::new(MonotonicStamp::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let stamps = Arc::clone(&stamps);
+                thread::spawn(move || {
+                    (0..1_000).map(|_| stamps.now()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for dt in handle.join().unwrap_or_default() {
+                assert!(seen.insert(dt));
+            }
+        }
+        assert_eq!(seen.len(), 4_000);
+    }
+
+    /// Tests the NTP skew calculation with a server clock behind the local one.
+    #[test]
+    fn test_estimate_skew_server_behind(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::clock::estimate_skew;
+        use time::Duration;
+
+        let t1 = DateTime::parse("2024-01-01T12:00:00Z")?;
+        let t2 = (t1 - Duration::seconds(2))?;
+        let t2 = (t2 + Duration::milliseconds(20))?;
+        let t3 = (t2 + Duration::milliseconds(5))?;
+        let t4 = (t1 + Duration::milliseconds(45))?;
+
+        let estimate = estimate_skew(&t1, &t2, &t3, &t4);
+        assert_eq!(
+            estimate.round_trip_delay,
+            Duration::milliseconds(40)
+        );
+        assert_eq!(estimate.offset, Duration::milliseconds(-2000));
+        Ok(())
+    }
+
+    /// Tests that identical clocks produce a zero offset.
+    #[test]
+    fn test_estimate_skew_synchronized(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::clock::estimate_skew;
+        use time::Duration;
+
+        let t1 = DateTime::parse("2024-01-01T12:00:00Z")?;
+        let t2 = (t1 + Duration::milliseconds(10))?;
+        let t3 = (t2 + Duration::milliseconds(1))?;
+        let t4 = (t3 + Duration::milliseconds(10))?;
+
+        let estimate = estimate_skew(&t1, &t2, &t3, &t4);
+        assert_eq!(estimate.offset, Duration::ZERO);
+        assert_eq!(
+            estimate.round_trip_delay,
+            Duration::milliseconds(20)
+        );
+        Ok(())
+    }
+
+    /// Tests HTTP date parsing and formatting round trips.
+    #[test]
+    fn test_http_date_round_trip(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = "Tue, 15 Nov 1994 08:12:31 GMT";
+        let dt = DateTime::parse_http_date(header)?;
+        assert_eq!(dt.offset(), time::UtcOffset::UTC);
+        assert_eq!(dt.format_http_date()?, header);
+
+        let ist = dt.convert_to_tz("IST")?;
+        assert_eq!(ist.format_http_date()?, header);
+        assert!(DateTime::parse_http_date("1994-11-15").is_err());
+        Ok(())
+    }
+
+    /// Tests that HTTP dates beyond the range in UTC are errors.
+    #[test]
+    fn test_http_date_out_of_range(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::clock::ServerClock;
+        use dtt::error::DateTimeError;
+
+        let header = "Fri, 31 Dec 9999 23:00:00 -0500";
+        assert_eq!(
+            DateTime::parse_http_date(header),
+            Err(DateTimeError::InvalidFormat)
+        );
+        assert!(ServerClock::new().observe_http_date(header).is_err());
+
+        let last = DateTime::parse("9999-12-31T23:00:00-05:00")?;
+        assert!(matches!(
+            last.format_http_date(),
+            Err(DateTimeError::ConversionOverflow { .. })
+        ));
+        Ok(())
+    }
+
+    /// Tests that a server clock applies observed offsets.
+    #[test]
+    fn test_server_clock() -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::clock::{Clock, ServerClock, SystemClock};
+
+        let clock = ServerClock::new();
+        assert_eq!(clock.offset(), time::Duration::ZERO);
+
+        let local = DateTime::parse("2024-01-01T12:00:10Z")?;
+        let offset = clock.observe_http_date_at(
+            "Mon, 01 Jan 2024 12:00:00 GMT",
+            &local,
+        )?;
+        assert_eq!(offset, time::Duration::seconds(-10));
+        assert_eq!(clock.offset(), offset);
+
+        let server = clock.now();
+        let system = SystemClock.now();
+        let behind = system.duration_since(&server);
+        assert!(behind >= time::Duration::seconds(9));
+        assert!(behind <= time::Duration::seconds(11));
+
+        assert!(clock.observe_http_date("garbage").is_err());
+        assert_eq!(clock.offset(), offset);
+        Ok(())
+    }
+
+    /// Tests the fixed and mock test clocks.
+    #[test]
+    fn test_fixed_and_mock_clocks(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::clock::{Clock, FixedClock, MockClock};
+
+        /// Stands in for application code that takes a clock.
+        fn is_expired(clock: &impl Clock, deadline: &DateTime) -> bool {
+            DateTime::now_with_clock(clock) > *deadline
+        }
+
+        let start = DateTime::parse("2024-01-01T12:00:00Z")?;
+        let deadline = DateTime::parse("2024-01-01T12:30:00Z")?;
+        assert!(!is_expired(&FixedClock::new(start), &deadline));
+
+        let clock = MockClock::new(start);
+        assert!(!is_expired(&clock, &deadline));
+        let _ = clock.advance(time::Duration::minutes(31))?;
+        assert!(is_expired(&clock, &deadline));
+        let _ = clock.advance(time::Duration::minutes(-2))?;
+        assert!(!is_expired(&clock, &deadline));
+
+        clock.set(deadline);
+        assert_eq!(clock.now(), deadline);
+        assert!(clock.advance(time::Duration::MAX).is_err());
+        assert_eq!(clock.now(), deadline);
+
+        assert_eq!(MockClock::default().now().unix_timestamp(), 0);
+        Ok(())
+    }
+
+    /// Tests that `MonotonicStamp` works through the `Clock` trait.
+    #[test]
+    fn test_monotonic_stamp_as_clock() {
+        use dtt::clock::Clock;
+
+        let stamps = MonotonicStamp::new();
+        let clock: &dyn Clock = &stamps;
+        let a = DateTime::now_with_clock(clock);
+        let b = DateTime::now_with_clock(&clock);
+        assert!(b > a);
+    }
+
+    /// Tests the monotonic readings of each `TimeSource`.
+    #[test]
+    fn test_time_source_monotonic() {
+        use dtt::clock::{
+            FixedClock, MockClock, SystemClock, TimeSource,
+        };
+        use time::Duration;
+
+        let a = SystemClock.monotonic();
+        let b = SystemClock.monotonic();
+        assert!(b >= a);
+        assert!(!a.is_negative());
+
+        let fixed = FixedClock::new(DateTime::new());
+        assert_eq!(fixed.monotonic(), Duration::ZERO);
+
+        let start = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+        let clock = MockClock::new(start);
+        assert_eq!(clock.monotonic(), Duration::ZERO);
+        let _ = clock.advance(Duration::seconds(10)).unwrap();
+        let _ = clock.advance(Duration::seconds(-4)).unwrap();
+        clock.set(start);
+        assert_eq!(clock.monotonic(), Duration::seconds(10));
+        let by_ref: &dyn TimeSource = &&clock;
+        assert_eq!(by_ref.monotonic(), Duration::seconds(10));
+    }
+
+    /// Tests that constructors taking a clock read it instead of the
+    /// system clock.
+    #[test]
+    fn test_constructors_read_the_clock() {
+        use dtt::clock::{FixedClock, MockClock};
+        use time::Duration;
+
+        let noon = DateTime::parse("2024-06-30T23:30:00Z").unwrap();
+        let fixed = FixedClock::new(noon);
+        let cet =
+            DateTime::new_with_tz_and_clock("CET", &fixed).unwrap();
+        assert_eq!(cet.to_string(), "2024-07-01T00:30:00+01:00");
+        assert!(
+            DateTime::new_with_tz_and_clock("Nowhere", &fixed).is_err()
+        );
+
+        let clock = MockClock::new(noon);
+        let _ = clock.advance(Duration::minutes(45)).unwrap();
+        assert_eq!(
+            cet.update_with_clock(&clock).unwrap().to_string(),
+            "2024-07-01T01:15:00+01:00"
+        );
+
+        let offset = time::UtcOffset::from_hms(-3, -30, 0).unwrap();
+        assert_eq!(
+            DateTime::now_fixed_with_clock(offset, &fixed).to_string(),
+            "2024-06-30T20:00:00-03:30"
+        );
+        let lenient =
+            DateTime::new_with_custom_offset_lenient_and_clock(
+                -20, 0, &clock,
+            )
+            .unwrap();
+        assert_eq!(lenient.to_string(), "2024-06-30T04:15:00-20:00");
+        assert!(DateTime::new_with_custom_offset_lenient_and_clock(
+            24, 0, &clock
+        )
+        .is_err());
+    }
+
+    /// Tests that a server clock measures and applies its offset against
+    /// the given local clock.
+    #[test]
+    fn test_server_clock_with_local_clock() {
+        use dtt::clock::{MockClock, ServerClock};
+        use time::Duration;
+
+        let local = MockClock::new(
+            DateTime::parse("2015-10-21T07:27:30Z").unwrap(),
+        );
+        let server = ServerClock::new();
+        assert_eq!(
+            server
+                .observe_http_date_with_clock(
+                    "Wed, 21 Oct 2015 07:28:00 GMT",
+                    &local
+                )
+                .unwrap(),
+            Duration::seconds(30)
+        );
+        let _ = local.advance(Duration::minutes(10)).unwrap();
+        assert_eq!(
+            server.now_server_with_clock(&local).to_string(),
+            "2015-10-21T07:38:00Z"
+        );
+        assert!(server
+            .observe_http_date_with_clock("not a date", &local)
+            .is_err());
+        assert_eq!(server.offset(), Duration::seconds(30));
+    }
+}
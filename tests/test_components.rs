@@ -0,0 +1,92 @@
+// test_components.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `components` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use time::Weekday;
+
+    /// Tests that every component is broken out correctly.
+    #[test]
+    fn test_components() -> Result<(), Box<dyn std::error::Error>> {
+        let dt = DateTime::parse("2024-12-30T08:15:30Z")?;
+        let c = dt.components();
+        assert_eq!(c.year, 2024);
+        assert_eq!(c.month, 12);
+        assert_eq!(c.day, 30);
+        assert_eq!(c.hour, 8);
+        assert_eq!(c.minute, 15);
+        assert_eq!(c.second, 30);
+        assert_eq!(c.weekday, Weekday::Monday);
+        assert_eq!(c.ordinal, 365);
+        assert_eq!(c.iso_week, 1);
+        assert_eq!(c.offset_seconds, 0);
+        assert_eq!(c.unix_timestamp, dt.unix_timestamp());
+        Ok(())
+    }
+
+    /// Tests the offset component of a non-UTC value.
+    #[test]
+    fn test_components_offset() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let dt = DateTime::parse("2024-01-01T12:00:00Z")?
+            .convert_to_tz("IST")?;
+        let c = dt.components();
+        assert_eq!(c.offset_seconds, 19_800);
+        assert_eq!(c.hour, 17);
+        assert_eq!(c.unix_timestamp, 1_704_110_400);
+        Ok(())
+    }
+
+    /// Tests the serialized and table-style representations.
+    #[test]
+    fn test_components_serialize_and_display(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let c = DateTime::parse("2024-01-01T12:00:00Z")?.components();
+        let json = serde_json::to_value(c)?;
+        assert_eq!(json["year"], 2024);
+        assert_eq!(json["weekday"], 1);
+        assert_eq!(json["iso_week"], 1);
+
+        let table = c.to_string();
+        assert!(table.starts_with("year            2024\n"));
+        assert!(table.ends_with("unix_timestamp  1704110400"));
+        assert_eq!(table.lines().count(), 12);
+        Ok(())
+    }
+
+    /// Tests the plain parts struct and its round trip.
+    #[test]
+    fn test_to_components() -> Result<(), Box<dyn std::error::Error>> {
+        use dtt::components::DateTimeParts;
+        use std::convert::TryFrom;
+        use time::UtcOffset;
+
+        let dt =
+            DateTime::parse("2024-02-29T23:59:58.123456789-04:00")?;
+        let parts = dt.to_components();
+        assert_eq!(
+            parts,
+            DateTimeParts {
+                year: 2024,
+                month: 2,
+                day: 29,
+                hour: 23,
+                minute: 59,
+                second: 58,
+                nanos: 123_456_789,
+                offset: UtcOffset::from_hms(-4, 0, 0)?,
+            }
+        );
+        assert_eq!(DateTimeParts::from(dt), parts);
+        assert_eq!(DateTime::try_from(parts)?, dt);
+
+        let invalid = DateTimeParts { day: 30, ..parts };
+        assert!(DateTime::try_from(invalid).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+// test_compression.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `compression` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::compression::{
+        compress, decode_deltas, decompress, encode_deltas,
+        pack_varints, unpack_varints, Precision, TimestampDecoder,
+        TimestampEncoder,
+    };
+    use dtt::datetime::DateTime;
+    use time::Duration;
+
+    fn series(start: &str, steps: &[i64]) -> Vec<DateTime> {
+        let start = DateTime::parse(start).unwrap_or_default();
+        steps
+            .iter()
+            .map(|ms| {
+                (start + Duration::milliseconds(*ms)).unwrap_or(start)
+            })
+            .collect()
+    }
+
+    /// Tests that regular series compress to about one bit per value.
+    #[test]
+    fn test_regular_series_is_compact(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let steps: Vec<i64> = (0..10_000).map(|i| i * 60_000).collect();
+        let values = series("2024-01-01T00:00:00Z", &steps);
+        let bytes = compress(&values, Precision::Seconds)?;
+        assert!(bytes.len() < 10_000 / 8 + 32);
+        assert_eq!(decompress(&bytes)?, values);
+        Ok(())
+    }
+
+    /// Tests lossless round trips with irregular, unsorted, and extreme
+    /// deltas at every bucket size.
+    #[test]
+    fn test_irregular_round_trip(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let steps = [
+            0,
+            1,
+            3,
+            100,
+            90,
+            5_000,
+            -86_400_000,
+            31_536_000_000,
+            31_536_000_001,
+            -999_999_999_999,
+        ];
+        let values = series("2024-01-01T00:00:00Z", &steps);
+        let bytes = compress(&values, Precision::Milliseconds)?;
+        assert_eq!(decompress(&bytes)?, values);
+        Ok(())
+    }
+
+    /// Tests that sub-precision components are truncated.
+    #[test]
+    fn test_precision_truncation(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let values = series("1969-12-31T23:59:59Z", &[0, 1_500, 2_999]);
+        let bytes = compress(&values, Precision::Seconds)?;
+        let decoded = decompress(&bytes)?;
+        let secs: Vec<i64> =
+            decoded.iter().map(DateTime::unix_timestamp).collect();
+        assert_eq!(secs, vec![-1, 0, 1]);
+        Ok(())
+    }
+
+    /// Tests that offsets are normalized to UTC.
+    #[test]
+    fn test_offsets_normalized(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let utc = DateTime::parse("2024-01-01T12:00:00Z")?;
+        let jst = utc.convert_to_tz("JST")?;
+        let bytes = compress(&[jst], Precision::Nanoseconds)?;
+        assert_eq!(decompress(&bytes)?, vec![utc]);
+        Ok(())
+    }
+
+    /// Tests the streaming encoder and decoder APIs.
+    #[test]
+    fn test_streaming() -> Result<(), Box<dyn std::error::Error>> {
+        let values = series("2024-01-01T00:00:00Z", &[0, 10, 20, 35]);
+        let mut encoder =
+            TimestampEncoder::new(Precision::Microseconds);
+        assert!(encoder.is_empty());
+        for dt in &values {
+            encoder.push(dt)?;
+        }
+        assert_eq!(encoder.len(), 4);
+
+        let bytes = encoder.finish();
+        let decoder = TimestampDecoder::new(&bytes)?;
+        assert_eq!(decoder.precision(), Precision::Microseconds);
+        assert_eq!(decoder.collect::<Result<Vec<_>, _>>()?, values);
+        Ok(())
+    }
+
+    /// Tests that empty input and malformed streams are handled.
+    #[test]
+    fn test_empty_and_malformed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = compress(&[], Precision::Seconds)?;
+        assert_eq!(bytes.len(), 5);
+        assert!(decompress(&bytes)?.is_empty());
+
+        assert!(decompress(&[]).is_err());
+        assert!(decompress(&[9, 0, 0, 0, 0]).is_err());
+        assert!(decompress(&[0, 0, 0, 0, 2, 0xFF]).is_err());
+        Ok(())
+    }
+
+    /// Tests that delta encoding round-trips, including unsorted input.
+    #[test]
+    fn test_delta_round_trip() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let values = series(
+            "2024-01-01T00:00:00.123Z",
+            &[0, 1_000, 500, 86_400_000],
+        );
+        let deltas = encode_deltas(&values, Precision::Milliseconds)?;
+        assert_eq!(deltas[1..], [1_000, -500, 86_399_500]);
+        assert_eq!(
+            decode_deltas(&deltas, Precision::Milliseconds)?,
+            values
+        );
+
+        assert!(encode_deltas(&[], Precision::Seconds)?.is_empty());
+        assert!(
+            decode_deltas(&[i64::MAX, 1], Precision::Seconds).is_err()
+        );
+        Ok(())
+    }
+
+    /// Tests varint packing sizes, extremes, and malformed input.
+    #[test]
+    fn test_varints() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            pack_varints(&[0, -1, 1, 63, -64]),
+            [0, 1, 2, 126, 127]
+        );
+        assert_eq!(pack_varints(&[64]), [0x80, 0x01]);
+
+        let extremes = [i64::MIN, i64::MAX, 0];
+        let bytes = pack_varints(&extremes);
+        assert_eq!(bytes.len(), 21);
+        assert_eq!(unpack_varints(&bytes)?, extremes);
+
+        assert!(unpack_varints(&[])?.is_empty());
+        assert!(unpack_varints(&[0x80]).is_err());
+        assert!(unpack_varints(&[0xFF; 11]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+// test_cron.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `cron` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::cron::{CronField, CronSchedule};
+    use dtt::error::DateTimeError;
+
+    fn describe(expression: &str) -> Result<String, DateTimeError> {
+        Ok(CronSchedule::parse(expression)?.describe())
+    }
+
+    /// Tests parsing of lists, ranges, steps, and names.
+    #[test]
+    fn test_parse() -> Result<(), DateTimeError> {
+        let schedule =
+            CronSchedule::parse("0,30 9-17/4 */2 JAN-mar 7")?;
+        assert_eq!(schedule.minute, CronField::Values(vec![0, 30]));
+        assert_eq!(schedule.hour, CronField::Values(vec![9, 13, 17]));
+        assert_eq!(schedule.day_of_month, CronField::Every(2));
+        assert_eq!(schedule.month, CronField::Values(vec![1, 2, 3]));
+        assert_eq!(schedule.day_of_week, CronField::Values(vec![0]));
+        assert_eq!(schedule.to_string(), "0,30 9,13,17 */2 1-3 0");
+        assert_eq!("0,30 9,13,17 */2 1-3 0".parse(), Ok(schedule));
+        Ok(())
+    }
+
+    /// Tests steps on `*` inside lists.
+    #[test]
+    fn test_parse_star_step_in_list() -> Result<(), DateTimeError> {
+        let schedule = CronSchedule::parse("*/20,7 */8,1 * * *")?;
+        assert_eq!(
+            schedule.minute,
+            CronField::Values(vec![0, 7, 20, 40])
+        );
+        assert_eq!(schedule.hour, CronField::Values(vec![0, 1, 8, 16]));
+        let schedule = CronSchedule::parse("*/5,10 * */10,31 * */3,2")?;
+        assert_eq!(
+            schedule.minute.to_string(),
+            "0,5,10,15,20,25,30,35,40,45,50,55"
+        );
+        assert_eq!(
+            schedule.day_of_month,
+            CronField::Values(vec![1, 11, 21, 31])
+        );
+        assert_eq!(
+            schedule.day_of_week,
+            CronField::Values(vec![0, 2, 3, 6])
+        );
+        assert_eq!(
+            CronSchedule::parse("*/0,5 * * * *"),
+            Err(DateTimeError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    /// Tests that describing fields built by hand with out-of-range
+    /// values does not panic.
+    #[test]
+    fn test_describe_out_of_range_fields() -> Result<(), DateTimeError>
+    {
+        let mut schedule = CronSchedule::parse("0 12 * * *")?;
+        schedule.month = CronField::Values(vec![0, 13]);
+        assert_eq!(
+            schedule.describe(),
+            "At 12:00 in month 0 and month 13"
+        );
+        schedule.month = CronField::Any;
+        schedule.day_of_week = CronField::Values(vec![7]);
+        assert_eq!(schedule.describe(), "At 12:00 on every weekday 7");
+        schedule.day_of_week = CronField::Values(vec![5, 6, 7, 8]);
+        assert_eq!(
+            schedule.describe(),
+            "At 12:00 on Friday through weekday 8"
+        );
+        Ok(())
+    }
+
+    /// Tests that malformed expressions are rejected.
+    #[test]
+    fn test_parse_invalid() {
+        for expression in [
+            "",
+            "* * * *",
+            "* * * * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+            "-1 * * * *",
+        ] {
+            assert_eq!(
+                CronSchedule::parse(expression),
+                Err(DateTimeError::InvalidFormat),
+                "{expression}"
+            );
+        }
+    }
+
+    /// Tests descriptions of common schedules.
+    #[test]
+    fn test_describe() -> Result<(), DateTimeError> {
+        let cases = [
+            ("0 12 * * 1-5", "At 12:00 on every weekday"),
+            ("* * * * *", "Every minute"),
+            ("*/15 * * * *", "Every 15 minutes"),
+            ("0 * * * *", "Every hour"),
+            ("0 */6 * * *", "Every 6 hours"),
+            ("30 9 * * *", "At 09:30 every day"),
+            ("0 9,17 * * *", "At 09:00 and 17:00 every day"),
+            ("0 10 * * 0,6", "At 10:00 on weekends"),
+            ("0 10 * * fri", "At 10:00 on every Friday"),
+            (
+                "0 10 * * 1,3,5",
+                "At 10:00 on Monday, Wednesday, and Friday",
+            ),
+            ("0 0 1 * *", "At 00:00 on day 1 of the month"),
+            ("0 0 1 1 *", "At 00:00 on day 1 of the month in January"),
+            ("0 0 * 6-8 *", "At 00:00 in June through August"),
+            (
+                "0 0 13 * 5",
+                "At 00:00 on day 13 of the month or every Friday",
+            ),
+            ("5,10 * * * *", "At minutes 5 and 10"),
+            (
+                "*/10 9-17 * * *",
+                "Every 10 minutes past hours 9 through 17",
+            ),
+        ];
+        for (expression, expected) in cases {
+            assert_eq!(describe(expression)?, expected);
+        }
+        Ok(())
+    }
+
+    /// Tests building schedules from phrases.
+    #[test]
+    fn test_from_description() -> Result<(), DateTimeError> {
+        let cases = [
+            ("every minute", "* * * * *"),
+            ("Every 15 minutes.", "*/15 * * * *"),
+            ("every hour", "0 * * * *"),
+            ("every 2 hours", "0 */2 * * *"),
+            ("at 07:05", "5 7 * * *"),
+            ("daily at 23:59", "59 23 * * *"),
+            ("every weekday at 12:00", "0 12 * * 1-5"),
+            ("every  weekend at 8:30", "30 8 * * 0,6"),
+            ("every Sunday at 18:00", "0 18 * * 0"),
+            ("at 18:00 on mondays", "0 18 * * 1"),
+        ];
+        for (phrase, expected) in cases {
+            assert_eq!(
+                CronSchedule::from_description(phrase)?.to_string(),
+                expected
+            );
+        }
+        Ok(())
+    }
+
+    /// Tests that descriptions of simple schedules parse back.
+    #[test]
+    fn test_describe_round_trip() -> Result<(), DateTimeError> {
+        for expression in [
+            "* * * * *",
+            "*/5 * * * *",
+            "0 * * * *",
+            "0 */3 * * *",
+            "15 6 * * *",
+            "0 12 * * 1-5",
+            "0 12 * * 0,6",
+            "0 12 * * 3",
+        ] {
+            let schedule = CronSchedule::parse(expression)?;
+            assert_eq!(
+                CronSchedule::from_description(&schedule.describe())?,
+                schedule
+            );
+        }
+        Ok(())
+    }
+
+    /// Tests that unrecognized phrases are rejected.
+    #[test]
+    fn test_from_description_invalid() {
+        for phrase in [
+            "",
+            "sometimes",
+            "every 0 minutes",
+            "every 60 minutes",
+            "every fortnight at 12:00",
+            "at 24:00",
+            "at noon",
+        ] {
+            assert_eq!(
+                CronSchedule::from_description(phrase),
+                Err(DateTimeError::InvalidFormat),
+                "{phrase}"
+            );
+        }
+    }
+}
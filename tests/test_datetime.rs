@@ -361,6 +361,26 @@ mod tests {
             assert_eq!(hash1, hash2);
         }
 
+        /// Test that the stable hash is pinned and consistent with `Eq`.
+        #[test]
+        fn test_stable_hash_u64() {
+            let dt =
+                DateTime::parse("2023-11-14T23:13:20+01:00").unwrap();
+            assert_eq!(dt.unix_timestamp(), 1_700_000_000);
+            assert_eq!(dt.stable_hash_u64(), 0xf9fc_1ef8_af26_aa97);
+
+            let same =
+                DateTime::parse("2023-11-14T23:13:20+01:00").unwrap();
+            assert_eq!(dt.stable_hash_u64(), same.stable_hash_u64());
+
+            let utc = dt.convert_to_tz("UTC").unwrap();
+            assert_ne!(dt.stable_hash_u64(), utc.stable_hash_u64());
+            assert_ne!(
+                dt.stable_hash_u64(),
+                dt.set_nanosecond(1).unwrap().stable_hash_u64()
+            );
+        }
+
         /// Test for checking if a `DateTime` is within a specific range.
         #[test]
         fn test_is_within_range(
@@ -1676,13 +1696,20 @@ mod tests {
         #[test]
         fn test_new_with_custom_offset_edge_cases(
         ) -> Result<(), Box<dyn std::error::Error>> {
+            // Offsets beyond ±14:00 are rejected by default
+            assert!(DateTime::new_with_custom_offset(23, 59).is_err());
+            assert!(DateTime::new_with_custom_offset(-14, -1).is_err());
+            let dt = DateTime::new_with_custom_offset(14, 0)?;
+            assert_eq!(dt.offset(), UtcOffset::from_hms(14, 0, 0)?);
+
             // Test with positive custom offset
-            let dt = DateTime::new_with_custom_offset(23, 59)?;
+            let dt = DateTime::new_with_custom_offset_lenient(23, 59)?;
             let expected_offset = UtcOffset::from_hms(23, 59, 0)?;
             assert_eq!(dt.offset(), expected_offset);
 
             // Test with negative custom offset
-            let dt = DateTime::new_with_custom_offset(-23, -59)?;
+            let dt =
+                DateTime::new_with_custom_offset_lenient(-23, -59)?;
             let expected_offset = UtcOffset::from_hms(-23, -59, 0)?;
             assert_eq!(dt.offset(), expected_offset);
 
@@ -1855,10 +1882,10 @@ mod tests {
                 UtcOffset::from_hms(0, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt = DateTime { datetime, offset };
+            let dt = DateTime::from_primitive(datetime, offset);
 
-            assert_eq!(dt.datetime, datetime);
-            assert_eq!(dt.offset, offset);
+            assert_eq!(dt.to_primitive(), datetime);
+            assert_eq!(dt.offset(), offset);
             Ok(())
         }
 
@@ -1878,7 +1905,7 @@ mod tests {
                 UtcOffset::from_hms(0, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt = DateTime { datetime, offset };
+            let dt = DateTime::from_primitive(datetime, offset);
 
             let serialized =
                 serde_json::to_string(&dt).map_err(|err| {
@@ -1909,10 +1936,7 @@ mod tests {
                 UtcOffset::from_hms(0, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt1 = DateTime {
-                datetime: datetime1,
-                offset: offset1,
-            };
+            let dt1 = DateTime::from_primitive(datetime1, offset1);
 
             let date2 =
                 Date::from_calendar_date(2023, Month::January, 2)
@@ -1927,10 +1951,7 @@ mod tests {
                 UtcOffset::from_hms(0, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt2 = DateTime {
-                datetime: datetime2,
-                offset: offset2,
-            };
+            let dt2 = DateTime::from_primitive(datetime2, offset2);
 
             assert_ne!(dt1, dt2);
             assert!(dt1 < dt2);
@@ -1953,9 +1974,9 @@ mod tests {
                 UtcOffset::from_hms(2, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt = DateTime { datetime, offset };
+            let dt = DateTime::from_primitive(datetime, offset);
 
-            assert_eq!(dt.offset, offset);
+            assert_eq!(dt.offset(), offset);
             Ok(())
         }
 
@@ -1975,7 +1996,7 @@ mod tests {
                 UtcOffset::from_hms(0, 0, 0).map_err(|err| {
                     format!("Failed to create UtcOffset: {:?}", err)
                 })?;
-            let dt = DateTime { datetime, offset };
+            let dt = DateTime::from_primitive(datetime, offset);
 
             let dt_copy = dt;
 
@@ -2016,7 +2037,7 @@ mod tests {
                     );
                     return;
                 };
-            let dt = DateTime { datetime, offset };
+            let dt = DateTime::from_primitive(datetime, offset);
 
             let debug_output = format!("{:?}", dt);
             assert!(debug_output.contains("DateTime"));
@@ -2063,6 +2084,7 @@ mod tests {
         }
 
         #[test]
+        #[allow(deprecated)]
         fn test_is_valid_day_edge_cases() {
             assert!(DateTime::is_valid_day("1"));
             assert!(DateTime::is_valid_day("31"));
@@ -2469,4 +2491,2387 @@ mod tests {
             }
         }
     }
+
+    /// Tests for ISO 8601 week date construction and accessors.
+    mod iso_week_date_tests {
+        use super::*;
+        use time::Month;
+
+        /// Tests construction from a week date in the middle of a year.
+        #[test]
+        fn test_from_iso_week_date(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let dt = DateTime::from_iso_week_date(
+                2024,
+                7,
+                Weekday::Wednesday,
+            )?;
+            assert_eq!(dt.year(), 2024);
+            assert_eq!(dt.month(), Month::February);
+            assert_eq!(dt.day(), 14);
+            assert_eq!(dt.hour(), 0);
+            assert_eq!(dt.offset(), UtcOffset::UTC);
+            Ok(())
+        }
+
+        /// Tests week dates whose ISO year differs from the calendar year.
+        #[test]
+        fn test_iso_year_boundaries(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let dt =
+                DateTime::from_iso_week_date(2026, 1, Weekday::Monday)?;
+            assert_eq!(
+                (dt.year(), dt.month(), dt.day()),
+                (2025, Month::December, 29)
+            );
+
+            let dt = DateTime::parse("2027-01-03")?;
+            assert_eq!(
+                dt.to_iso_week_date(),
+                (2026, 53, Weekday::Sunday)
+            );
+            Ok(())
+        }
+
+        /// Tests that week dates round-trip across several years.
+        #[test]
+        fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+            let mut dt = DateTime::parse("2019-12-20")?;
+            for _ in 0..800 {
+                let (year, week, weekday) = dt.to_iso_week_date();
+                let rebuilt =
+                    DateTime::from_iso_week_date(year, week, weekday)?;
+                assert_eq!(rebuilt, dt);
+                dt = dt.next_day()?;
+            }
+            Ok(())
+        }
+
+        /// Tests that non-existent weeks are rejected.
+        #[test]
+        fn test_invalid_week() {
+            assert!(matches!(
+                DateTime::from_iso_week_date(2025, 53, Weekday::Monday),
+                Err(DateTimeError::InvalidDate)
+            ));
+            assert!(matches!(
+                DateTime::from_iso_week_date(2024, 0, Weekday::Monday),
+                Err(DateTimeError::InvalidDate)
+            ));
+        }
+    }
+
+    /// Tests for the sanctioned construction and accessor paths.
+    mod accessor_tests {
+        use super::*;
+        use time::macros::datetime;
+        use time::OffsetDateTime;
+
+        /// Tests round-tripping through `PrimitiveDateTime`.
+        #[test]
+        fn test_primitive_round_trip() {
+            let primitive = datetime!(2024-03-10 08:45:12.5);
+            let offset = UtcOffset::UTC;
+            let dt = DateTime::from_primitive(primitive, offset);
+            assert_eq!(dt.to_primitive(), primitive);
+            assert_eq!(dt.offset(), offset);
+            assert_eq!(dt.nanosecond(), 500_000_000);
+        }
+
+        /// Tests round-tripping through `OffsetDateTime`.
+        #[test]
+        fn test_offset_datetime_round_trip() {
+            let odt = datetime!(2024-03-10 08:45:12 +05:30);
+            let dt = DateTime::from(odt);
+            assert_eq!(dt.hour(), 8);
+            assert_eq!(dt.offset().whole_minutes(), 330);
+            assert_eq!(OffsetDateTime::from(dt), odt);
+            assert_eq!(
+                dt.to_offset_datetime().unix_timestamp(),
+                dt.unix_timestamp()
+            );
+        }
+    }
+
+    /// Tests for ISO 8601 ordinal date construction, parsing, and formatting.
+    mod ordinal_date_tests {
+        use super::*;
+        use time::Month;
+
+        /// Tests construction from an ordinal date.
+        #[test]
+        fn test_from_ordinal_date(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let dt = DateTime::from_ordinal_date(2023, 60)?;
+            assert_eq!((dt.month(), dt.day()), (Month::March, 1));
+            let dt = DateTime::from_ordinal_date(2024, 60)?;
+            assert_eq!((dt.month(), dt.day()), (Month::February, 29));
+            assert_eq!(dt.ordinal(), 60);
+            Ok(())
+        }
+
+        /// Tests that out-of-range ordinals are rejected.
+        #[test]
+        fn test_invalid_ordinal() {
+            assert!(matches!(
+                DateTime::from_ordinal_date(2023, 366),
+                Err(DateTimeError::InvalidDate)
+            ));
+            assert!(matches!(
+                DateTime::from_ordinal_date(2024, 0),
+                Err(DateTimeError::InvalidDate)
+            ));
+            assert!(DateTime::parse_ordinal_date("2023-366").is_err());
+            assert!(DateTime::parse_ordinal_date("2023-46").is_err());
+        }
+
+        /// Tests that the ordinal form round-trips through `parse`.
+        #[test]
+        fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+            let dt = DateTime::from_ordinal_date(2024, 46)?;
+            let formatted = dt.format_ordinal_date()?;
+            assert_eq!(formatted, "2024-046");
+            assert_eq!(DateTime::parse(&formatted)?, dt);
+            assert_eq!(DateTime::parse_ordinal_date(&formatted)?, dt);
+            Ok(())
+        }
+    }
+
+    /// Tests for `truncate_to` and `round_to`.
+    mod rounding_tests {
+        use super::*;
+        use dtt::datetime::Unit;
+
+        fn render(
+            input: &str,
+            unit: Unit,
+            round: bool,
+        ) -> Result<String, DateTimeError> {
+            let dt = DateTime::parse(input)?;
+            let result = if round {
+                dt.round_to(unit)?
+            } else {
+                dt.truncate_to(unit)?
+            };
+            Ok(result.to_string())
+        }
+
+        /// Tests truncation to every unit.
+        #[test]
+        fn test_truncate_to() -> Result<(), DateTimeError> {
+            let input = "2024-05-16T12:34:56.789Z";
+            let cases = [
+                (Unit::Second, "2024-05-16T12:34:56Z"),
+                (Unit::Minute, "2024-05-16T12:34:00Z"),
+                (Unit::Hour, "2024-05-16T12:00:00Z"),
+                (Unit::Day, "2024-05-16T00:00:00Z"),
+                (Unit::Week, "2024-05-13T00:00:00Z"),
+                (Unit::Month, "2024-05-01T00:00:00Z"),
+                (Unit::Year, "2024-01-01T00:00:00Z"),
+            ];
+            for (unit, expected) in cases {
+                assert_eq!(render(input, unit, false)?, expected);
+            }
+            Ok(())
+        }
+
+        /// Tests rounding to every unit.
+        #[test]
+        fn test_round_to() -> Result<(), DateTimeError> {
+            let input = "2024-05-16T12:34:56.789Z";
+            let cases = [
+                (Unit::Second, "2024-05-16T12:34:57Z"),
+                (Unit::Minute, "2024-05-16T12:35:00Z"),
+                (Unit::Hour, "2024-05-16T13:00:00Z"),
+                (Unit::Day, "2024-05-17T00:00:00Z"),
+                (Unit::Week, "2024-05-20T00:00:00Z"),
+                (Unit::Month, "2024-06-01T00:00:00Z"),
+                (Unit::Year, "2024-01-01T00:00:00Z"),
+            ];
+            for (unit, expected) in cases {
+                assert_eq!(render(input, unit, true)?, expected);
+            }
+            Ok(())
+        }
+
+        /// Tests that halfway values round up and boundaries are kept.
+        #[test]
+        fn test_round_half_and_exact() -> Result<(), DateTimeError> {
+            assert_eq!(
+                render("2024-05-16T12:30:00Z", Unit::Hour, true)?,
+                "2024-05-16T13:00:00Z"
+            );
+            assert_eq!(
+                render("2024-05-16T12:29:59Z", Unit::Hour, true)?,
+                "2024-05-16T12:00:00Z"
+            );
+            assert_eq!(
+                render("2024-05-16T00:00:00Z", Unit::Day, true)?,
+                "2024-05-16T00:00:00Z"
+            );
+            assert_eq!(
+                render("2023-02-15T00:00:00Z", Unit::Month, true)?,
+                "2023-03-01T00:00:00Z"
+            );
+            assert_eq!(
+                render("2023-12-31T23:59:59Z", Unit::Year, true)?,
+                "2024-01-01T00:00:00Z"
+            );
+            Ok(())
+        }
+
+        /// Tests that rounding operates on the local wall clock.
+        #[test]
+        fn test_offset_preserved() -> Result<(), DateTimeError> {
+            let utc = DateTime::parse("2024-05-16T18:15:00Z")?;
+            let ist = utc.convert_to_tz("IST")?;
+            assert_eq!(
+                ist.truncate_to(Unit::Day)?.to_string(),
+                "2024-05-16T00:00:00+05:30"
+            );
+            assert_eq!(
+                ist.round_to(Unit::Hour)?.to_string(),
+                "2024-05-17T00:00:00+05:30"
+            );
+            Ok(())
+        }
+    }
+
+    /// Tests for calendar quarter helpers.
+    mod quarter_tests {
+        use super::*;
+
+        /// Tests the quarter of each month.
+        #[test]
+        fn test_quarter() -> Result<(), DateTimeError> {
+            let quarters: Vec<u8> = (1..=12)
+                .map(|month| {
+                    DateTime::from_components(
+                        2024,
+                        month,
+                        1,
+                        0,
+                        0,
+                        0,
+                        UtcOffset::UTC,
+                    )
+                    .map(|dt| dt.quarter())
+                })
+                .collect::<Result<_, _>>()?;
+            assert_eq!(
+                quarters,
+                vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
+            );
+            Ok(())
+        }
+
+        /// Tests quarter boundaries, which keep the time of day.
+        #[test]
+        fn test_quarter_boundaries() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2024-08-15T10:30:00Z")?;
+            assert_eq!(
+                dt.start_of_quarter()?.to_string(),
+                "2024-07-01T10:30:00Z"
+            );
+            assert_eq!(
+                dt.end_of_quarter()?.to_string(),
+                "2024-09-30T10:30:00Z"
+            );
+
+            let dt = DateTime::parse("2024-02-10T00:00:00Z")?;
+            assert_eq!(dt.end_of_quarter()?.day(), 31);
+            assert_eq!(
+                DateTime::parse("2024-12-01T00:00:00Z")?
+                    .end_of_quarter()?
+                    .to_string(),
+                "2024-12-31T00:00:00Z"
+            );
+            Ok(())
+        }
+
+        /// Tests adding and subtracting quarters.
+        #[test]
+        fn test_add_quarters() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2024-11-30T00:00:00Z")?;
+            assert_eq!(
+                dt.add_quarters(1)?.to_string(),
+                "2025-02-28T00:00:00Z"
+            );
+            assert_eq!(
+                dt.add_quarters(-4)?.to_string(),
+                "2023-11-30T00:00:00Z"
+            );
+            assert_eq!(dt.add_quarters(0)?, dt);
+            assert!(matches!(
+                dt.add_quarters(i32::MAX),
+                Err(DateTimeError::InvalidDate)
+            ));
+            Ok(())
+        }
+    }
+
+    /// Tests for sub-second precision in constructors, setters, and
+    /// round trips.
+    mod nanosecond_tests {
+        use super::*;
+        use dtt::datetime::DateTimeBuilder;
+
+        /// Tests construction with nanoseconds.
+        #[test]
+        fn test_from_components_with_nanos() -> Result<(), DateTimeError>
+        {
+            let dt = DateTime::from_components_with_nanos(
+                2024,
+                2,
+                29,
+                23,
+                59,
+                59,
+                999_999_999,
+                UtcOffset::UTC,
+            )?;
+            assert_eq!(dt.nanosecond(), 999_999_999);
+            assert_eq!(dt.microsecond(), 999_999);
+            assert!(matches!(
+                DateTime::from_components_with_nanos(
+                    2024,
+                    1,
+                    1,
+                    0,
+                    0,
+                    0,
+                    1_000_000_000,
+                    UtcOffset::UTC,
+                ),
+                Err(DateTimeError::InvalidTime)
+            ));
+            Ok(())
+        }
+
+        /// Tests the setter and builder.
+        #[test]
+        fn test_set_nanosecond_and_builder() -> Result<(), DateTimeError>
+        {
+            let dt = DateTime::parse("2024-01-01T12:00:00Z")?
+                .set_nanosecond(1_500)?;
+            assert_eq!(dt.microsecond(), 1);
+            assert_eq!(dt.nanosecond(), 1_500);
+            assert_eq!(dt.set_nanosecond(0)?.nanosecond(), 0);
+
+            let built = DateTimeBuilder::new()
+                .year(2024)
+                .hour(12)
+                .nanosecond(1_500)
+                .build()?;
+            assert_eq!(built, dt);
+            assert!(DateTimeBuilder::new()
+                .nanosecond(1_000_000_000)
+                .build()
+                .is_err());
+            Ok(())
+        }
+
+        /// Tests that formatting and parsing preserve sub-second values.
+        #[test]
+        fn test_round_trips() -> Result<(), Box<dyn std::error::Error>>
+        {
+            let dt = DateTime::from_components_with_nanos(
+                2024,
+                1,
+                1,
+                12,
+                0,
+                0,
+                120_000_000,
+                UtcOffset::UTC,
+            )?;
+            let rendered = dt.to_string();
+            assert_eq!(rendered, "2024-01-01T12:00:00.12Z");
+            assert_eq!(DateTime::parse(&rendered)?, dt);
+            assert_eq!(dt.format_iso8601()?, "2024-01-01T12:00:00.12");
+
+            let json = serde_json::to_string(&dt)?;
+            assert_eq!(serde_json::from_str::<DateTime>(&json)?, dt);
+            Ok(())
+        }
+    }
+
+    /// Tests for closest-match timezone suggestions.
+    mod suggest_timezone_tests {
+        use dtt::datetime::{suggest_timezone, DateTime};
+
+        /// Tests typical typos.
+        #[test]
+        fn test_typos() {
+            assert_eq!(suggest_timezone("ETS"), Some("EST"));
+            assert_eq!(suggest_timezone("PTS"), Some("PST"));
+            assert_eq!(suggest_timezone("JTS"), Some("JST"));
+            assert_eq!(suggest_timezone("UT"), Some("UTC"));
+            assert_eq!(suggest_timezone(" aest "), Some("AEST"));
+        }
+
+        /// Tests that distant or tiny inputs yield no suggestion.
+        #[test]
+        fn test_no_suggestion() {
+            assert_eq!(suggest_timezone(""), None);
+            assert_eq!(suggest_timezone("X"), None);
+            assert_eq!(suggest_timezone("Europe/Paris"), None);
+        }
+
+        /// Tests that every suggestion is accepted by `convert_to_tz`.
+        #[test]
+        fn test_suggestion_is_valid() {
+            let dt = DateTime::new();
+            assert!(dt.convert_to_tz("ETS").is_err());
+            let suggestion = suggest_timezone("ETS").unwrap_or("UTC");
+            assert!(dt.convert_to_tz(suggestion).is_ok());
+        }
+    }
+
+    /// Tests for leap-second tolerant parsing.
+    mod leap_second_tests {
+        use super::*;
+        use dtt::datetime::{LeapSecondPolicy, ParseOptions};
+
+        fn options(policy: LeapSecondPolicy) -> ParseOptions {
+            ParseOptions::new().leap_seconds(policy)
+        }
+
+        /// Tests that strict parsing rejects leap seconds.
+        #[test]
+        fn test_strict_rejects() {
+            assert!(matches!(
+                DateTime::parse("2016-12-31T23:59:60Z"),
+                Err(DateTimeError::InvalidTime)
+            ));
+            assert!(matches!(
+                DateTime::parse_with_options(
+                    "2016-12-31T23:59:60Z",
+                    &ParseOptions::default()
+                ),
+                Err(DateTimeError::InvalidTime)
+            ));
+        }
+
+        /// Tests the clamp and carry policies.
+        #[test]
+        fn test_clamp_and_carry() -> Result<(), DateTimeError> {
+            let clamped = DateTime::parse_with_options(
+                "2016-12-31T23:59:60Z",
+                &options(LeapSecondPolicy::Clamp),
+            )?;
+            assert!(clamped.leap_second);
+            assert_eq!(
+                clamped.datetime.to_string(),
+                "2016-12-31T23:59:59.999999999Z"
+            );
+
+            let carried = DateTime::parse_with_options(
+                "2016-12-31T23:59:60.25Z",
+                &options(LeapSecondPolicy::Carry),
+            )?;
+            assert!(carried.leap_second);
+            assert_eq!(
+                carried.datetime.to_string(),
+                "2017-01-01T00:00:00.25Z"
+            );
+            Ok(())
+        }
+
+        /// Tests that ordinary inputs are unaffected.
+        #[test]
+        fn test_regular_input() -> Result<(), DateTimeError> {
+            let outcome = DateTime::parse_with_options(
+                "2016-12-31T23:59:59Z",
+                &options(LeapSecondPolicy::Carry),
+            )?;
+            assert!(!outcome.leap_second);
+            assert_eq!(
+                outcome.datetime,
+                DateTime::parse("2016-12-31T23:59:59Z")?
+            );
+            Ok(())
+        }
+
+        /// Tests that leap seconds must fall on the last UTC minute.
+        #[test]
+        fn test_leap_second_position() {
+            let carry = options(LeapSecondPolicy::Carry);
+            assert!(matches!(
+                DateTime::parse_with_options(
+                    "2016-12-31T12:59:60Z",
+                    &carry
+                ),
+                Err(DateTimeError::InvalidTime)
+            ));
+            assert!(DateTime::parse_with_options(
+                "2016-12-31T18:59:60-05:00",
+                &carry
+            )
+            .map(|outcome| outcome.leap_second)
+            .unwrap_or(false));
+        }
+
+        /// Tests that a leap second beyond the range in UTC is an error.
+        #[test]
+        fn test_leap_second_out_of_range() {
+            assert!(matches!(
+                DateTime::parse_with_options(
+                    "9999-12-31T23:59:60-05:00",
+                    &options(LeapSecondPolicy::Clamp)
+                ),
+                Err(DateTimeError::InvalidTime)
+            ));
+        }
+    }
+
+    /// Tests for offset conversions at the representable boundaries.
+    mod conversion_overflow_tests {
+        use super::*;
+
+        fn offset(hours: i8) -> UtcOffset {
+            UtcOffset::from_hms(hours, 0, 0).unwrap_or(UtcOffset::UTC)
+        }
+
+        /// Tests conversions just inside and just outside the upper bound.
+        #[test]
+        fn test_upper_bound() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("9999-12-31T13:59:59Z")?;
+            assert_eq!(
+                dt.try_convert_to_offset(offset(10))?.to_string(),
+                "9999-12-31T23:59:59+10:00"
+            );
+
+            let dt = DateTime::parse("9999-12-31T23:00:00Z")?;
+            match dt.try_convert_to_offset(offset(10)) {
+                Err(DateTimeError::ConversionOverflow {
+                    instant,
+                    target,
+                }) => {
+                    assert_eq!(instant, dt);
+                    assert_eq!(target, offset(10));
+                }
+                other => panic!("unexpected result: {other:?}"),
+            }
+            assert!(matches!(
+                dt.convert_to_tz("AEST"),
+                Err(DateTimeError::ConversionOverflow { .. })
+            ));
+            assert_eq!(
+                dt.saturating_convert_to_offset(offset(10)).to_string(),
+                "9999-12-31T23:59:59.999999999+10:00"
+            );
+            Ok(())
+        }
+
+        /// Tests conversions at the lower bound.
+        #[test]
+        fn test_lower_bound() -> Result<(), DateTimeError> {
+            let dt = DateTime::from_components(
+                -9999,
+                1,
+                1,
+                2,
+                0,
+                0,
+                UtcOffset::UTC,
+            )?;
+            assert!(matches!(
+                dt.try_convert_to_offset(offset(-5)),
+                Err(DateTimeError::ConversionOverflow { .. })
+            ));
+            let clamped = dt.saturating_convert_to_offset(offset(-5));
+            assert_eq!(
+                (clamped.year(), clamped.hour(), clamped.offset()),
+                (-9999, 0, offset(-5))
+            );
+            assert_eq!(
+                dt.saturating_convert_to_offset(offset(1)).hour(),
+                3
+            );
+            Ok(())
+        }
+    }
+
+    /// Tests that parsing preserves the offset of the input.
+    mod offset_preservation_tests {
+        use super::*;
+
+        /// Tests positive, negative, and zero offsets.
+        #[test]
+        fn test_offsets_preserved() -> Result<(), DateTimeError> {
+            let cases = [
+                ("2024-01-01T12:00:00+05:30", 12, 19_800),
+                ("2024-01-01T12:00:00-08:00", 12, -28_800),
+                ("2024-01-01T12:00:00+00:00", 12, 0),
+                ("2024-01-01T12:00:00Z", 12, 0),
+            ];
+            for (input, hour, offset_seconds) in cases {
+                let dt = DateTime::parse(input)?;
+                assert_eq!(dt.hour(), hour);
+                assert_eq!(dt.offset().whole_seconds(), offset_seconds);
+                assert_eq!(
+                    dt.to_string(),
+                    input.replace("+00:00", "Z")
+                );
+            }
+            Ok(())
+        }
+
+        /// Tests that the parsed value denotes the correct instant.
+        #[test]
+        fn test_instant() -> Result<(), DateTimeError> {
+            let ist = DateTime::parse("2024-01-01T12:00:00+05:30")?;
+            let utc = DateTime::parse("2024-01-01T06:30:00Z")?;
+            assert_eq!(ist.unix_timestamp(), utc.unix_timestamp());
+            assert_eq!(ist.convert_to_tz("UTC")?, utc);
+            Ok(())
+        }
+    }
+
+    /// Tests for the ±14:00 real-world offset validation.
+    mod offset_validation_tests {
+        use super::*;
+        use dtt::datetime::{
+            validate_offset, ParseOptions, MAX_REAL_OFFSET_SECONDS,
+        };
+
+        /// Tests the range boundaries.
+        #[test]
+        fn test_validate_offset(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            for (hours, minutes, valid) in [
+                (14, 0, true),
+                (-14, 0, true),
+                (-12, 0, true),
+                (5, 45, true),
+                (14, 1, false),
+                (-14, -1, false),
+                (23, 59, false),
+            ] {
+                let offset = UtcOffset::from_hms(hours, minutes, 0)?;
+                assert_eq!(validate_offset(offset).is_ok(), valid);
+            }
+            assert_eq!(MAX_REAL_OFFSET_SECONDS, 50_400);
+            Ok(())
+        }
+
+        /// Tests that parsing rejects extreme offsets unless opted out.
+        #[test]
+        fn test_parse_extreme_offset() -> Result<(), DateTimeError> {
+            let input = "2024-01-01T12:00:00+20:00";
+            assert!(matches!(
+                DateTime::parse(input),
+                Err(DateTimeError::InvalidTimezone)
+            ));
+            let options =
+                ParseOptions::new().allow_extreme_offsets(true);
+            let outcome =
+                DateTime::parse_with_options(input, &options)?;
+            assert_eq!(outcome.datetime.offset().whole_hours(), 20);
+            assert!(
+                DateTime::parse("2024-01-01T12:00:00+14:00").is_ok()
+            );
+            Ok(())
+        }
+    }
+
+    mod validate_components_tests {
+        use dtt::datetime::DateTime;
+
+        #[test]
+        fn test_valid_components() {
+            assert!(DateTime::validate_components(
+                2024, 2, 29, 23, 59, 59
+            )
+            .is_ok());
+            assert!(DateTime::validate_components(
+                -9999, 1, 1, 0, 0, 0
+            )
+            .is_ok());
+        }
+
+        #[test]
+        fn test_reports_every_invalid_component() {
+            let errors = DateTime::validate_components(
+                10_000, 13, 32, 24, 60, 60,
+            )
+            .unwrap_err();
+            let names: Vec<&str> =
+                errors.iter().map(|e| e.component).collect();
+            assert_eq!(
+                names,
+                ["year", "month", "day", "hour", "minute", "second"]
+            );
+            assert_eq!(errors[2].maximum, 31);
+        }
+
+        #[test]
+        fn test_day_range_depends_on_month() {
+            let errors =
+                DateTime::validate_components(2023, 2, 29, 0, 0, 0)
+                    .unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].component, "day");
+            assert_eq!(errors[0].value, 29);
+            assert_eq!(errors[0].minimum, 1);
+            assert_eq!(errors[0].maximum, 28);
+        }
+
+        #[test]
+        fn test_zero_month_and_day() {
+            let errors =
+                DateTime::validate_components(2024, 0, 0, 0, 0, 0)
+                    .unwrap_err();
+            assert_eq!(errors.len(), 2);
+            assert_eq!(
+                errors[0].to_string(),
+                "month must be in the range 1..=12, got 0"
+            );
+        }
+    }
+
+    mod format_validation_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::ValidationIssueKind;
+
+        #[test]
+        fn test_matches_format() {
+            let format = "[year]-[month]-[day] [hour]:[minute]";
+            assert!(DateTime::matches_format(
+                "2024-01-15 09:30",
+                format
+            ));
+            assert!(!DateTime::matches_format("2024-01-15", format));
+            assert!(!DateTime::matches_format(
+                "2024-01-15 24:00",
+                format
+            ));
+            assert!(!DateTime::matches_format(
+                "2024-01-15",
+                "[invalid]"
+            ));
+            assert!(DateTime::matches_format(
+                "2024-01-15T09:30:00+02:00",
+                "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+            ));
+        }
+
+        #[test]
+        fn test_validate_accepts_parseable_input() {
+            for input in [
+                "2024-01-15",
+                "2024-01-15T09:30:00Z",
+                "2024-01-15 09:30:00.123456789-04:00",
+                "2024-12-31t23:59:59z",
+            ] {
+                assert_eq!(
+                    DateTime::validate(input),
+                    Ok(()),
+                    "{input}"
+                );
+                assert!(DateTime::parse(input).is_ok(), "{input}");
+            }
+        }
+
+        #[test]
+        fn test_validate_reports_every_issue() {
+            let issues =
+                DateTime::validate("2023-02-29X25:61:00+15:00")
+                    .unwrap_err();
+            let found: Vec<(&str, usize)> = issues
+                .iter()
+                .map(|issue| (issue.component, issue.position))
+                .collect();
+            assert_eq!(
+                found,
+                [
+                    ("day", 8),
+                    ("date-time separator", 10),
+                    ("hour", 11),
+                    ("minute", 14),
+                    ("offset", 19),
+                ]
+            );
+            assert_eq!(
+                issues[4].kind,
+                ValidationIssueKind::OutOfRange {
+                    value: 54_000,
+                    minimum: -50_400,
+                    maximum: 50_400,
+                }
+            );
+        }
+
+        #[test]
+        fn test_validate_malformed_and_missing() {
+            let issues = DateTime::validate("2024-1-15").unwrap_err();
+            assert_eq!(issues[0].component, "month");
+            assert_eq!(issues[0].kind, ValidationIssueKind::Malformed);
+
+            let issues =
+                DateTime::validate("2024-01-15T10:00:00").unwrap_err();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].component, "offset");
+            assert_eq!(issues[0].kind, ValidationIssueKind::Missing);
+
+            let issues = DateTime::validate("").unwrap_err();
+            assert_eq!(issues[0].component, "year");
+            assert_eq!(issues[0].kind, ValidationIssueKind::Missing);
+
+            let issues = DateTime::validate("2024-01-15T10:00:00.Z")
+                .unwrap_err();
+            assert_eq!(issues[0].component, "fraction");
+        }
+
+        #[test]
+        fn test_validate_trailing_input() {
+            let issues =
+                DateTime::validate("2024-01-15T10:00:00Z junk")
+                    .unwrap_err();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(
+                issues[0].kind,
+                ValidationIssueKind::TrailingInput
+            );
+            assert_eq!(issues[0].position, 20);
+            assert_eq!(
+                issues[0].to_string(),
+                "input at byte 20 is not expected"
+            );
+        }
+    }
+
+    mod numeric_validation_tests {
+        use dtt::datetime::DateTime;
+
+        const VALID_NOON: bool = DateTime::is_valid_hour_u8(12)
+            && DateTime::is_valid_minute_u8(0);
+
+        #[test]
+        fn test_numeric_validators_are_const() {
+            const _: () = assert!(VALID_NOON);
+        }
+
+        #[test]
+        fn test_numeric_validator_bounds() {
+            assert!(!DateTime::is_valid_day_u8(0));
+            assert!(DateTime::is_valid_day_u8(31));
+            assert!(!DateTime::is_valid_day_u8(32));
+            assert!(DateTime::is_valid_hour_u8(23));
+            assert!(!DateTime::is_valid_hour_u8(24));
+            assert!(DateTime::is_valid_minute_u8(59));
+            assert!(!DateTime::is_valid_minute_u8(60));
+            assert!(DateTime::is_valid_second_u8(59));
+            assert!(!DateTime::is_valid_second_u8(60));
+            assert!(!DateTime::is_valid_month_u8(0));
+            assert!(DateTime::is_valid_month_u8(12));
+            assert!(!DateTime::is_valid_month_u8(13));
+            assert!(DateTime::is_valid_microsecond_u32(999_999));
+            assert!(!DateTime::is_valid_microsecond_u32(1_000_000));
+            assert!(!DateTime::is_valid_ordinal_u16(0));
+            assert!(DateTime::is_valid_ordinal_u16(366));
+            assert!(!DateTime::is_valid_ordinal_u16(367));
+            assert!(!DateTime::is_valid_iso_week_u8(0));
+            assert!(DateTime::is_valid_iso_week_u8(53));
+            assert!(!DateTime::is_valid_iso_week_u8(54));
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn test_string_validators_agree_with_numeric() {
+            for n in 0..=u8::MAX {
+                let text = n.to_string();
+                assert_eq!(
+                    DateTime::is_valid_day(&text),
+                    DateTime::is_valid_day_u8(n)
+                );
+                assert_eq!(
+                    DateTime::is_valid_month(&text),
+                    DateTime::is_valid_month_u8(n)
+                );
+                assert_eq!(
+                    DateTime::is_valid_iso_week(&text),
+                    DateTime::is_valid_iso_week_u8(n)
+                );
+            }
+        }
+    }
+
+    mod format_error_position_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::DateTimeError;
+        use dtt::naive::NaiveDateTime;
+
+        const FORMAT: &str = "[year]-[month]-[day] [hour]:[minute]";
+
+        fn error_at(input: &str) -> Option<DateTimeError> {
+            DateTime::parse_custom_format(input, FORMAT).err()
+        }
+
+        #[test]
+        fn test_component_position() {
+            assert_eq!(
+                error_at("2024-01-xx 10:00"),
+                Some(DateTimeError::InvalidFormatAt {
+                    index: 8,
+                    expected: "day"
+                })
+            );
+            assert_eq!(
+                error_at("24-01-01 10:00"),
+                Some(DateTimeError::InvalidFormatAt {
+                    index: 0,
+                    expected: "year"
+                })
+            );
+        }
+
+        #[test]
+        fn test_literal_and_trailing_positions() {
+            assert_eq!(
+                error_at("2024/01/01 10:00"),
+                Some(DateTimeError::InvalidFormatAt {
+                    index: 4,
+                    expected: "literal"
+                })
+            );
+            assert_eq!(
+                error_at("2024-01-01 10:00 UTC"),
+                Some(DateTimeError::InvalidFormatAt {
+                    index: 16,
+                    expected: "end of input"
+                })
+            );
+        }
+
+        #[test]
+        fn test_invalid_values_keep_plain_error() {
+            assert_eq!(
+                error_at("2024-02-30 10:00"),
+                Some(DateTimeError::InvalidFormat)
+            );
+            assert_eq!(
+                DateTime::parse_custom_format("2024", "[invalid]"),
+                Err(DateTimeError::InvalidFormat)
+            );
+        }
+
+        #[test]
+        fn test_naive_reports_position() {
+            assert_eq!(
+                NaiveDateTime::parse_custom_format(
+                    "2024-01-01 1x:00",
+                    FORMAT
+                )
+                .err(),
+                Some(DateTimeError::InvalidFormatAt {
+                    index: 11,
+                    expected: "hour"
+                })
+            );
+        }
+
+        #[test]
+        fn test_invalid_format_at_metadata() {
+            let error = DateTimeError::InvalidFormatAt {
+                index: 3,
+                expected: "month",
+            };
+            assert_eq!(error.code(), "DTT-0008");
+            assert_eq!(
+                error.to_string(),
+                "Invalid date format at index 3: expected month"
+            );
+        }
+    }
+
+    mod unix_nanos_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::DateTimeError;
+
+        #[test]
+        fn test_nanos_beyond_i64_range() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2262-04-11T23:47:16.854775808Z")?;
+            let nanos = dt.unix_timestamp_nanos();
+            assert_eq!(nanos, i128::from(i64::MAX) + 1);
+            assert_eq!(DateTime::from_unix_timestamp_nanos(nanos)?, dt);
+            Ok(())
+        }
+
+        #[test]
+        fn test_nanos_round_trip_at_bounds() -> Result<(), DateTimeError>
+        {
+            use time::macros::datetime;
+
+            for bound in [
+                datetime!(-9999-01-01 0:00 UTC),
+                datetime!(9999-12-31 23:59:59.999_999_999 UTC),
+                datetime!(1970-01-01 0:00 UTC),
+            ] {
+                let dt = DateTime::from_offset_datetime(bound);
+                let nanos = dt.unix_timestamp_nanos();
+                assert_eq!(nanos, bound.unix_timestamp_nanos());
+                assert_eq!(
+                    DateTime::from_unix_timestamp_nanos(nanos)?,
+                    dt
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_nanos_preserve_instant_across_offsets(
+        ) -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2024-01-01T12:00:00.5+05:30")?;
+            let utc = DateTime::from_unix_timestamp_nanos(
+                dt.unix_timestamp_nanos(),
+            )?;
+            assert_eq!(utc.to_string(), "2024-01-01T06:30:00.5Z");
+            assert_eq!(
+                utc.unix_timestamp_nanos(),
+                dt.unix_timestamp_nanos()
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_nanos_round_trip_at_extreme_offsets(
+        ) -> Result<(), DateTimeError> {
+            use time::macros::{datetime, offset};
+
+            for (wall, offset) in [
+                (
+                    datetime!(9999-12-31 23:59:59.999_999_999),
+                    offset!(-14:00),
+                ),
+                (
+                    datetime!(9999-12-31 23:59:59.999_999_999),
+                    offset!(+14:00),
+                ),
+                (datetime!(-9999-01-01 0:00), offset!(+14:00)),
+                (datetime!(-9999-01-01 0:00), offset!(-14:00)),
+            ] {
+                let dt = DateTime::from_primitive(wall, offset);
+                let nanos = dt.unix_timestamp_nanos();
+                assert_eq!(
+                    DateTime::from_unix_timestamp_nanos_in(
+                        nanos, offset
+                    )?,
+                    dt
+                );
+            }
+
+            // Only representable in their own offset: the UTC wall clock
+            // is past 9999 or before -9999.
+            let late = DateTime::from_primitive(
+                datetime!(9999-12-31 23:59:59.999_999_999),
+                offset!(-14:00),
+            );
+            assert_eq!(
+                late.unix_timestamp_nanos(),
+                253_402_351_199_999_999_999
+            );
+            assert!(matches!(
+                DateTime::from_unix_timestamp_nanos(
+                    late.unix_timestamp_nanos()
+                ),
+                Err(DateTimeError::ComponentRange(_))
+            ));
+            let early = DateTime::from_primitive(
+                datetime!(-9999-01-01 0:00),
+                offset!(+14:00),
+            );
+            assert!(DateTime::from_unix_timestamp_nanos(
+                early.unix_timestamp_nanos()
+            )
+            .is_err());
+
+            // The wall clock in the requested offset must be in range.
+            assert!(matches!(
+                DateTime::from_unix_timestamp_nanos_in(
+                    late.unix_timestamp_nanos(),
+                    offset!(+14:00)
+                ),
+                Err(DateTimeError::ComponentRange(_))
+            ));
+            assert!(DateTime::from_unix_timestamp_nanos_in(
+                i128::MAX,
+                offset!(+14:00)
+            )
+            .is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn test_nanos_out_of_range() {
+            assert!(matches!(
+                DateTime::from_unix_timestamp_nanos(i128::MAX),
+                Err(DateTimeError::ComponentRange(_))
+            ));
+            assert!(DateTime::from_unix_timestamp_nanos(
+                253_402_300_800_000_000_000
+            )
+            .is_err());
+        }
+    }
+
+    /// Tests for `iter_iso_weeks`.
+    mod iso_weeks_tests {
+        use super::*;
+        use dtt::datetime::iter_iso_weeks;
+
+        fn weeks(
+            start: &str,
+            end: &str,
+        ) -> Result<Vec<(i32, u8, String, String)>, DateTimeError>
+        {
+            let start = DateTime::parse(start)?;
+            let end = DateTime::parse(end)?;
+            Ok(iter_iso_weeks(&start, &end)
+                .map(|(year, week, (from, to))| {
+                    (year, week, from.to_string(), to.to_string())
+                })
+                .collect())
+        }
+
+        #[test]
+        fn test_year_boundary_uses_iso_year(
+        ) -> Result<(), DateTimeError> {
+            let result =
+                weeks("2018-12-26T00:00:00Z", "2019-01-02T00:00:00Z")?;
+            assert_eq!(result.len(), 2);
+            assert_eq!((result[0].0, result[0].1), (2018, 52));
+            // 2018-12-31 is a Monday and already in 2019-W01.
+            assert_eq!((result[1].0, result[1].1), (2019, 1));
+            assert_eq!(result[1].2, "2018-12-31T00:00:00Z");
+            assert_eq!(result[1].3, "2019-01-02T00:00:00Z");
+            Ok(())
+        }
+
+        #[test]
+        fn test_full_weeks_are_contiguous() -> Result<(), DateTimeError>
+        {
+            let result =
+                weeks("2024-03-04T00:00:00Z", "2024-03-18T00:00:00Z")?;
+            assert_eq!(
+                result,
+                [
+                    (
+                        2024,
+                        10,
+                        "2024-03-04T00:00:00Z".to_string(),
+                        "2024-03-11T00:00:00Z".to_string()
+                    ),
+                    (
+                        2024,
+                        11,
+                        "2024-03-11T00:00:00Z".to_string(),
+                        "2024-03-18T00:00:00Z".to_string()
+                    ),
+                ]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_span_within_one_week() -> Result<(), DateTimeError> {
+            let result =
+                weeks("2024-03-05T10:00:00Z", "2024-03-06T10:00:00Z")?;
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].2, "2024-03-05T10:00:00Z");
+            assert_eq!(result[0].3, "2024-03-06T10:00:00Z");
+            Ok(())
+        }
+
+        #[test]
+        fn test_empty_and_reversed_spans() -> Result<(), DateTimeError>
+        {
+            let dt = DateTime::parse("2024-03-05T10:00:00Z")?;
+            assert_eq!(iter_iso_weeks(&dt, &dt).count(), 0);
+            let earlier = dt.add_days(-3)?;
+            assert_eq!(iter_iso_weeks(&dt, &earlier).count(), 0);
+            Ok(())
+        }
+
+        #[test]
+        fn test_end_is_converted_to_start_offset(
+        ) -> Result<(), DateTimeError> {
+            let result = weeks(
+                "2024-03-10T20:00:00-05:00",
+                "2024-03-11T03:00:00Z",
+            )?;
+            // The end is 22:00 on Sunday in the start's offset.
+            assert_eq!(result.len(), 1);
+            assert_eq!((result[0].0, result[0].1), (2024, 10));
+            assert_eq!(result[0].3, "2024-03-10T22:00:00-05:00");
+            Ok(())
+        }
+    }
+
+    /// Tests for the infallible period boundary methods.
+    mod infallible_boundary_tests {
+        use super::*;
+
+        #[test]
+        fn test_month_boundaries() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2023-02-15T10:30:00+02:00")?;
+            assert_eq!(
+                dt.first_day_of_month().to_string(),
+                "2023-02-01T10:30:00+02:00"
+            );
+            assert_eq!(
+                dt.last_day_of_month().to_string(),
+                "2023-02-28T10:30:00+02:00"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_quarter_boundaries() -> Result<(), DateTimeError> {
+            let cases = [
+                ("2024-01-10T00:00:00Z", "2024-01-01", "2024-03-31"),
+                ("2024-05-10T00:00:00Z", "2024-04-01", "2024-06-30"),
+                ("2024-09-30T00:00:00Z", "2024-07-01", "2024-09-30"),
+                ("2024-12-31T00:00:00Z", "2024-10-01", "2024-12-31"),
+            ];
+            for (input, first, last) in cases {
+                let dt = DateTime::parse(input)?;
+                assert_eq!(
+                    dt.first_day_of_quarter().date_only().to_string(),
+                    first
+                );
+                assert_eq!(
+                    dt.last_day_of_quarter().date_only().to_string(),
+                    last
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_year_boundaries_at_extremes(
+        ) -> Result<(), DateTimeError> {
+            let max = DateTime::parse("9999-06-15T23:59:59Z")?;
+            assert_eq!(
+                max.last_day_of_year().to_string(),
+                "9999-12-31T23:59:59Z"
+            );
+            let min = DateTime::from_components(
+                -9999,
+                6,
+                15,
+                0,
+                0,
+                0,
+                UtcOffset::UTC,
+            )?;
+            assert_eq!(min.first_day_of_year().year(), -9999);
+            assert_eq!(min.first_day_of_year().ordinal(), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn test_result_variants_agree() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2024-08-20T08:00:00Z")?;
+            assert_eq!(dt.start_of_month()?, dt.first_day_of_month());
+            assert_eq!(dt.end_of_month()?, dt.last_day_of_month());
+            assert_eq!(
+                dt.start_of_quarter()?,
+                dt.first_day_of_quarter()
+            );
+            assert_eq!(dt.end_of_quarter()?, dt.last_day_of_quarter());
+            assert_eq!(dt.start_of_year()?, dt.first_day_of_year());
+            assert_eq!(dt.end_of_year()?, dt.last_day_of_year());
+            Ok(())
+        }
+    }
+
+    /// Tests for the age helpers.
+    mod age_tests {
+        use super::*;
+
+        #[test]
+        fn test_age_before_and_on_birthday() -> Result<(), DateTimeError>
+        {
+            let birth = DateTime::parse("1990-07-15T10:00:00Z")?;
+            let eve = DateTime::parse("2024-07-14T23:59:59Z")?;
+            let day = DateTime::parse("2024-07-15T00:00:00Z")?;
+            assert_eq!(birth.age_in_years(&eve), 33);
+            assert_eq!(birth.age_in_years(&day), 34);
+            assert_eq!(birth.age_in_months(&day), 34 * 12);
+            Ok(())
+        }
+
+        #[test]
+        fn test_leap_day_birthday() -> Result<(), DateTimeError> {
+            let birth = DateTime::parse("2004-02-29T00:00:00Z")?;
+            let cases = [
+                ("2005-02-27T00:00:00Z", 0),
+                ("2005-02-28T00:00:00Z", 1),
+                ("2007-03-01T00:00:00Z", 3),
+                ("2008-02-28T00:00:00Z", 3),
+                ("2008-02-29T00:00:00Z", 4),
+            ];
+            for (today, age) in cases {
+                assert_eq!(
+                    birth.age_in_years(&DateTime::parse(today)?),
+                    age,
+                    "{today}"
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_each_value_uses_its_own_wall_clock(
+        ) -> Result<(), DateTimeError> {
+            // Born on March 10 in Tokyo, which was March 9 in UTC.
+            let birth = DateTime::parse("2000-03-10T02:00:00+09:00")?;
+            let utc_eve = DateTime::parse("2024-03-09T20:00:00Z")?;
+            assert_eq!(birth.age_in_years(&utc_eve), 23);
+            let tokyo_day =
+                DateTime::parse("2024-03-10T05:00:00+09:00")?;
+            assert_eq!(birth.age_in_years(&tokyo_day), 24);
+            Ok(())
+        }
+
+        #[test]
+        fn test_future_birth_is_negative() -> Result<(), DateTimeError>
+        {
+            let birth = DateTime::parse("2030-01-01T00:00:00Z")?;
+            let today = DateTime::parse("2024-06-01T00:00:00Z")?;
+            assert_eq!(birth.age_in_years(&today), -5);
+            assert_eq!(birth.age_in_months(&today), -67);
+            Ok(())
+        }
+
+        #[test]
+        fn test_month_end_birthdays() -> Result<(), DateTimeError> {
+            let birth = DateTime::parse("2023-08-31T00:00:00Z")?;
+            let sept_29 = DateTime::parse("2023-09-29T00:00:00Z")?;
+            let sept_30 = DateTime::parse("2023-09-30T00:00:00Z")?;
+            assert_eq!(birth.age_in_months(&sept_29), 0);
+            assert_eq!(birth.age_in_months(&sept_30), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn test_years_until_counts_time_of_day(
+        ) -> Result<(), DateTimeError> {
+            let start = DateTime::parse("2000-01-01T12:00:00+01:00")?;
+            let before = DateTime::parse("2010-01-01T10:59:59Z")?;
+            let after = DateTime::parse("2010-01-01T11:00:00Z")?;
+            assert_eq!(start.years_until(&before), 9);
+            assert_eq!(start.years_until(&after), 10);
+            Ok(())
+        }
+    }
+
+    /// Tests for the month-length and cumulative-day tables.
+    mod month_table_tests {
+        use super::*;
+        use dtt::datetime::{
+            cumulative_days_before_month, days_in_month,
+        };
+
+        #[test]
+        fn test_cumulative_days_match_ordinals(
+        ) -> Result<(), DateTimeError> {
+            for year in [1900, 2000, 2023, 2024] {
+                for month in 1..=12 {
+                    let first = DateTime::from_components(
+                        year,
+                        month,
+                        1,
+                        0,
+                        0,
+                        0,
+                        UtcOffset::UTC,
+                    )?;
+                    assert_eq!(
+                        cumulative_days_before_month(year, month)? + 1,
+                        first.ordinal(),
+                        "{year}-{month}"
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_cumulative_days_sum_month_lengths(
+        ) -> Result<(), DateTimeError> {
+            for year in [2023, 2024] {
+                let mut total = 0_u16;
+                for month in 1..=12 {
+                    assert_eq!(
+                        cumulative_days_before_month(year, month)?,
+                        total
+                    );
+                    total += u16::from(days_in_month(year, month)?);
+                }
+                assert_eq!(total, if year == 2024 { 366 } else { 365 });
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_invalid_month() {
+            assert_eq!(
+                cumulative_days_before_month(2024, 0),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert_eq!(
+                cumulative_days_before_month(2024, 13),
+                Err(DateTimeError::InvalidDate)
+            );
+        }
+
+        #[test]
+        fn test_add_months_across_year_zero(
+        ) -> Result<(), DateTimeError> {
+            let dt = DateTime::from_components(
+                0,
+                1,
+                31,
+                0,
+                0,
+                0,
+                UtcOffset::UTC,
+            )?;
+            let earlier = dt.add_months(-1)?;
+            assert_eq!(
+                (earlier.year(), earlier.month() as u8, earlier.day()),
+                (-1, 12, 31)
+            );
+            let back = earlier.add_months(2)?;
+            assert_eq!(
+                (back.year(), back.month() as u8, back.day()),
+                (0, 2, 29)
+            );
+            Ok(())
+        }
+    }
+
+    mod format_reuse_tests {
+        use super::*;
+
+        #[test]
+        fn test_reuse_matches_allocating_methods(
+        ) -> Result<(), DateTimeError> {
+            let mut output = String::from("stale contents");
+            for input in [
+                "2024-02-29T23:59:59.123456789-05:00",
+                "1970-01-01T00:00:00Z",
+                "9999-12-31T23:59:59+14:00",
+            ] {
+                let dt = DateTime::parse(input)?;
+                dt.format_rfc3339_reuse(&mut output)?;
+                assert_eq!(output, dt.format_rfc3339()?);
+                dt.format_iso8601_reuse(&mut output)?;
+                assert_eq!(output, dt.format_iso8601()?);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_reuse_keeps_output_on_error(
+        ) -> Result<(), DateTimeError> {
+            let dt = DateTime::from_components(
+                -1,
+                1,
+                1,
+                0,
+                0,
+                0,
+                UtcOffset::UTC,
+            )?;
+            let mut output = String::from("unchanged");
+            assert_eq!(
+                dt.format_rfc3339_reuse(&mut output),
+                Err(DateTimeError::InvalidFormat)
+            );
+            assert_eq!(output, "unchanged");
+            Ok(())
+        }
+
+        #[test]
+        fn test_formatting_across_threads() {
+            let handles: Vec<_> = (0..4)
+                .map(|hour| {
+                    std::thread::spawn(move || {
+                        let dt = DateTime::from_components(
+                            2024,
+                            1,
+                            1,
+                            hour,
+                            0,
+                            0,
+                            UtcOffset::UTC,
+                        )
+                        .and_then(|dt| dt.format_rfc3339());
+                        (hour, dt)
+                    })
+                })
+                .collect();
+            for handle in handles {
+                let (hour, formatted) = handle
+                    .join()
+                    .unwrap_or((0, Err(DateTimeError::InvalidDate)));
+                assert_eq!(
+                    formatted,
+                    Ok(format!("2024-01-01T{:02}:00:00Z", hour))
+                );
+            }
+        }
+    }
+
+    mod format_into_tests {
+        use super::*;
+        use dtt::format::CompiledFormat;
+        use std::fmt;
+
+        /// A sink that rejects every write.
+        struct Closed;
+
+        impl fmt::Write for Closed {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+
+        /// Displays a value through `write_rfc3339_into`.
+        struct Stamp(DateTime);
+
+        impl fmt::Display for Stamp {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.write_rfc3339_into(f).map_err(|_| fmt::Error)
+            }
+        }
+
+        #[test]
+        fn test_write_rfc3339_into_appends() -> Result<(), DateTimeError>
+        {
+            let dt = DateTime::parse("2024-02-29T23:59:59.5-05:00")?;
+            let mut output = String::from("at ");
+            dt.write_rfc3339_into(&mut output)?;
+            dt.write_rfc3339_into(&mut output)?;
+            let expected = dt.format_rfc3339()?;
+            assert_eq!(output, format!("at {expected}{expected}"));
+            assert_eq!(Stamp(dt).to_string(), expected);
+            Ok(())
+        }
+
+        #[test]
+        fn test_format_into_matches_format_with(
+        ) -> Result<(), DateTimeError> {
+            let compiled =
+                CompiledFormat::new("[year]/[month]/[day] [hour]h")?;
+            let dt = DateTime::parse("2024-02-15T10:30:05Z")?;
+            let mut output = String::new();
+            let sink: &mut dyn fmt::Write = &mut output;
+            dt.format_into(sink, &compiled)?;
+            assert_eq!(output, dt.format_with(&compiled)?);
+            Ok(())
+        }
+
+        #[test]
+        fn test_errors_leave_output_unchanged(
+        ) -> Result<(), DateTimeError> {
+            let dt = DateTime::from_components(
+                -1,
+                1,
+                1,
+                0,
+                0,
+                0,
+                UtcOffset::UTC,
+            )?;
+            let mut output = String::from("kept");
+            assert_eq!(
+                dt.write_rfc3339_into(&mut output),
+                Err(DateTimeError::InvalidFormat)
+            );
+            assert_eq!(output, "kept");
+
+            let ok = DateTime::parse("2024-01-01T00:00:00Z")?;
+            assert_eq!(
+                ok.write_rfc3339_into(&mut Closed),
+                Err(DateTimeError::InvalidFormat)
+            );
+            let compiled = CompiledFormat::new("[year]")?;
+            assert_eq!(
+                ok.format_into(&mut Closed, &compiled),
+                Err(DateTimeError::InvalidFormat)
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_nested_formatting() -> Result<(), DateTimeError> {
+            // Formatting inside a sink's write reuses the thread's buffer
+            // re-entrantly.
+            struct Nesting(String, DateTime);
+
+            impl fmt::Write for Nesting {
+                fn write_str(&mut self, text: &str) -> fmt::Result {
+                    self.0.push_str(text);
+                    self.0.push(' ');
+                    self.1
+                        .write_rfc3339_into(&mut self.0)
+                        .map_err(|_| fmt::Error)
+                }
+            }
+
+            let outer = DateTime::parse("2024-01-01T00:00:00Z")?;
+            let inner = DateTime::parse("1999-12-31T23:59:59Z")?;
+            let mut sink = Nesting(String::new(), inner);
+            outer.write_rfc3339_into(&mut sink)?;
+            assert_eq!(
+                sink.0,
+                "2024-01-01T00:00:00Z 1999-12-31T23:59:59Z"
+            );
+            Ok(())
+        }
+    }
+
+    mod numeric_timezone_tests {
+        use super::*;
+
+        #[test]
+        fn test_new_with_numeric_tz() -> Result<(), DateTimeError> {
+            for (name, seconds) in [
+                ("UTC+05:30", 19_800),
+                ("utc+01:00", 3_600),
+                ("+0530", 19_800),
+                ("-0800", -28_800),
+                ("GMT-7", -25_200),
+                ("GMT+14", 50_400),
+                ("-09:30", -34_200),
+                ("UTC-0", 0),
+            ] {
+                let dt = DateTime::new_with_tz(name)?;
+                assert_eq!(
+                    dt.offset().whole_seconds(),
+                    seconds,
+                    "{}",
+                    name
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_convert_to_numeric_tz() -> Result<(), DateTimeError> {
+            let utc = DateTime::parse("2024-01-01T12:00:00Z")?;
+            assert_eq!(
+                utc.convert_to_tz("UTC+05:30")?.to_string(),
+                "2024-01-01T17:30:00+05:30"
+            );
+            assert_eq!(
+                utc.convert_to_tz("GMT-7")?.to_string(),
+                "2024-01-01T05:00:00-07:00"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_invalid_numeric_tz() {
+            for name in [
+                "UTC+",
+                "+5:3",
+                "+123",
+                "+05:60",
+                "GMT+15",
+                "-14:01",
+                "UTC 05:00",
+                "05:00",
+                "UTC+05:30:00",
+                "+٥",
+            ] {
+                assert_eq!(
+                    DateTime::new_with_tz(name),
+                    Err(DateTimeError::InvalidTimezone),
+                    "{}",
+                    name
+                );
+            }
+        }
+    }
+
+    mod in_place_mutation_tests {
+        use super::*;
+
+        #[test]
+        fn test_bump_units() -> Result<(), DateTimeError> {
+            let mut dt = DateTime::parse("2023-12-31T23:59:59-05:00")?;
+            dt.bump_second()?;
+            assert_eq!(dt.to_string(), "2024-01-01T00:00:00-05:00");
+            dt.bump_minute()?;
+            dt.bump_hour()?;
+            assert_eq!(dt.to_string(), "2024-01-01T01:01:00-05:00");
+            Ok(())
+        }
+
+        #[test]
+        fn test_bump_day_matches_next_day() -> Result<(), DateTimeError>
+        {
+            let start = DateTime::parse("2023-01-01T12:34:56Z")?;
+            let mut bumped = start;
+            let mut stepped = start;
+            for _ in 0..800 {
+                bumped.bump_day()?;
+                stepped = stepped.next_day()?;
+                assert_eq!(bumped, stepped);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_bump_overflow_leaves_value() -> Result<(), DateTimeError>
+        {
+            let last = DateTime::parse("9999-12-31T23:59:59Z")?;
+            let mut dt = last;
+            assert_eq!(dt.bump_day(), Err(DateTimeError::InvalidDate));
+            assert_eq!(
+                dt.bump_second(),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert_eq!(dt.bump_hour(), Err(DateTimeError::InvalidDate));
+            assert_eq!(dt, last);
+            Ok(())
+        }
+
+        #[test]
+        fn test_make_start_of() -> Result<(), DateTimeError> {
+            let mut dt =
+                DateTime::parse("2024-03-31T18:45:10.5+02:00")?;
+            dt.make_start_of_month();
+            assert_eq!(
+                dt,
+                DateTime::parse("2024-03-31T18:45:10.5+02:00")?
+                    .first_day_of_month()
+            );
+            dt.make_start_of_day();
+            assert_eq!(dt.to_string(), "2024-03-01T00:00:00+02:00");
+            Ok(())
+        }
+    }
+
+    mod operator_tests {
+        use super::*;
+        use std::time::Duration as StdDuration;
+
+        #[test]
+        fn test_sub_datetime() -> Result<(), DateTimeError> {
+            let a = DateTime::parse("2024-03-01T00:00:00Z")?;
+            let b = DateTime::parse("2024-02-28T12:00:00-06:00")?;
+            assert_eq!(a - b, Duration::hours(30));
+            assert_eq!(b - a, Duration::hours(-30));
+            assert_eq!(a - a, Duration::ZERO);
+            assert_eq!(a - b, a.duration_since(&b));
+            Ok(())
+        }
+
+        #[test]
+        fn test_std_duration() -> Result<(), DateTimeError> {
+            let dt = DateTime::parse("2024-02-28T23:59:59.5Z")?;
+            let later = (dt + StdDuration::from_millis(1_500))?;
+            assert_eq!(later.to_string(), "2024-02-29T00:00:01Z");
+            assert_eq!((later - StdDuration::from_millis(1_500))?, dt);
+
+            assert!((dt + StdDuration::MAX).is_err());
+            assert!((dt - StdDuration::MAX).is_err());
+            Ok(())
+        }
+    }
+
+    mod assign_tests {
+        use super::*;
+
+        #[test]
+        fn test_add_sub_assign() -> Result<(), DateTimeError> {
+            let mut dt = DateTime::parse("2024-02-28T22:00:00+01:00")?;
+            dt += Duration::hours(3);
+            assert_eq!(dt.to_string(), "2024-02-29T01:00:00+01:00");
+            dt -= Duration::days(1);
+            assert_eq!(dt.to_string(), "2024-02-28T01:00:00+01:00");
+
+            let mut last = DateTime::parse("9999-12-31T23:00:00Z")?;
+            last += Duration::days(2);
+            assert_eq!(
+                last.to_string(),
+                "9999-12-31T23:59:59.999999999Z"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_advance_methods() -> Result<(), DateTimeError> {
+            let mut dt = DateTime::parse("2024-01-31T08:00:00Z")?;
+            dt.advance_months(1)?;
+            assert_eq!(dt.to_string(), "2024-02-29T08:00:00Z");
+            dt.advance_years(1)?;
+            assert_eq!(dt.to_string(), "2025-02-28T08:00:00Z");
+            dt.advance_days(-28)?;
+            assert_eq!(dt.to_string(), "2025-01-31T08:00:00Z");
+            dt.advance(Duration::minutes(-30))?;
+            assert_eq!(dt.to_string(), "2025-01-31T07:30:00Z");
+
+            let before = dt;
+            assert!(dt.advance(Duration::MAX).is_err());
+            assert!(dt.advance_years(i32::MAX).is_err());
+            assert_eq!(dt, before);
+            Ok(())
+        }
+    }
+
+    mod builder_issue_tests {
+        use super::*;
+        use dtt::datetime::DateTimeBuilder;
+
+        #[test]
+        fn test_build_reports_every_component() {
+            let error = DateTimeBuilder::new()
+                .year(2023)
+                .month(2)
+                .day(29)
+                .hour(24)
+                .second(60)
+                .nanosecond(1_000_000_000)
+                .build()
+                .err();
+            let issues = match error {
+                Some(DateTimeError::InvalidComponents(issues)) => {
+                    issues
+                }
+                other => panic!("unexpected result: {other:?}"),
+            };
+            let errors = issues.errors();
+            let names: Vec<&str> =
+                errors.iter().map(|e| e.component).collect();
+            assert_eq!(names, ["day", "hour", "second", "nanosecond"]);
+            assert_eq!((errors[0].value, errors[0].maximum), (29, 28));
+            assert_eq!(errors[3].maximum, 999_999_999);
+        }
+
+        #[test]
+        fn test_build_valid_components() -> Result<(), DateTimeError> {
+            let dt = DateTimeBuilder::new()
+                .year(2024)
+                .month(2)
+                .day(29)
+                .hour(23)
+                .build()?;
+            assert_eq!(dt.to_string(), "2024-02-29T23:00:00Z");
+            Ok(())
+        }
+    }
+
+    mod carry_tests {
+        use super::*;
+
+        fn dt(input: &str) -> DateTime {
+            DateTime::parse(input).unwrap()
+        }
+
+        #[test]
+        fn test_add_hours_within_day() {
+            let carried =
+                dt("2024-01-01T10:00:00Z").add_hours(5).unwrap();
+            assert_eq!(carried.datetime, dt("2024-01-01T15:00:00Z"));
+            assert_eq!(carried.days, 0);
+        }
+
+        #[test]
+        fn test_add_hours_crosses_several_days() {
+            let carried =
+                dt("2024-01-01T10:00:00Z").add_hours(50).unwrap();
+            assert_eq!(carried.datetime, dt("2024-01-03T12:00:00Z"));
+            assert_eq!(carried.days, 2);
+        }
+
+        #[test]
+        fn test_negative_minutes_carry_backwards() {
+            let carried =
+                dt("2024-01-01T00:30:00Z").add_minutes(-31).unwrap();
+            assert_eq!(carried.datetime, dt("2023-12-31T23:59:00Z"));
+            assert_eq!(carried.days, -1);
+        }
+
+        #[test]
+        fn test_carry_uses_local_date() {
+            // 15 hours later is 23:00 in Tokyo but already the next day
+            // in UTC; the carry follows the local date.
+            let carried =
+                dt("2024-01-01T08:00:00+09:00").add_hours(15).unwrap();
+            assert_eq!(carried.datetime.offset().whole_hours(), 9);
+            assert_eq!(carried.days, 0);
+            let carried =
+                dt("2024-01-01T08:00:00+09:00").add_hours(16).unwrap();
+            assert_eq!(carried.days, 1);
+        }
+
+        #[test]
+        fn test_add_seconds_keeps_nanoseconds() {
+            let carried =
+                dt("2024-01-01T23:59:59.5Z").add_seconds(1).unwrap();
+            assert_eq!(carried.datetime, dt("2024-01-02T00:00:00.5Z"));
+            assert_eq!(carried.days, 1);
+        }
+
+        #[test]
+        fn test_set_time_carrying() {
+            let base = dt("2024-01-31T12:34:56.789Z");
+            let carried = base.set_time_carrying(24, 0, 0).unwrap();
+            assert_eq!(carried.datetime, dt("2024-02-01T00:00:00Z"));
+            assert_eq!(carried.days, 1);
+
+            let carried = base.set_time_carrying(0, -1, 0).unwrap();
+            assert_eq!(carried.datetime, dt("2024-01-30T23:59:00Z"));
+            assert_eq!(carried.days, -1);
+
+            let carried = base.set_time_carrying(9, 75, 90).unwrap();
+            assert_eq!(carried.datetime, dt("2024-01-31T10:16:30Z"));
+            assert_eq!(carried.days, 0);
+        }
+
+        #[test]
+        fn test_overflow_is_an_error() {
+            let base = dt("2024-01-01T00:00:00Z");
+            assert_eq!(
+                base.add_hours(i64::MAX),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert_eq!(
+                base.set_time_carrying(0, 0, i64::MAX),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert!(base.add_seconds(1_000_000_000_000).is_err());
+        }
+    }
+
+    mod diff_in_tests {
+        use super::*;
+        use dtt::datetime::Unit;
+
+        #[test]
+        fn test_diff_in_exact_units_truncate() {
+            let start =
+                DateTime::parse("2024-01-01T00:00:00Z").unwrap();
+            let end =
+                DateTime::parse("2024-01-15T11:59:59+02:00").unwrap();
+            assert_eq!(
+                end.diff_in(&start, Unit::Second),
+                Ok(1_245_599)
+            );
+            assert_eq!(end.diff_in(&start, Unit::Hour), Ok(345));
+            assert_eq!(end.diff_in(&start, Unit::Day), Ok(14));
+            assert_eq!(end.diff_in(&start, Unit::Week), Ok(2));
+            assert_eq!(start.diff_in(&end, Unit::Day), Ok(-14));
+        }
+
+        #[test]
+        fn test_diff_in_calendar_units() {
+            let start =
+                DateTime::parse("2022-11-30T00:00:00Z").unwrap();
+            let end = DateTime::parse("2024-02-29T00:00:00Z").unwrap();
+            assert_eq!(end.diff_in(&start, Unit::Month), Ok(15));
+            assert_eq!(end.diff_in(&start, Unit::Year), Ok(1));
+            assert_eq!(start.diff_in(&end, Unit::Month), Ok(-15));
+        }
+    }
+
+    mod display_round_trip_tests {
+        use super::*;
+        use time::UtcOffset;
+
+        #[test]
+        fn test_display_parses_back_to_equal_value() {
+            let base = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+            let offsets = [
+                (0, 0, 0),
+                (5, 30, 0),
+                (-9, -30, 0),
+                (0, 9, 21),
+                (-4, -56, -2),
+                (20, 0, 0),
+                (-15, -30, 0),
+                (25, 59, 59),
+            ];
+            let instants = [
+                base,
+                base.set_nanosecond(120_000_000).unwrap(),
+                DateTime::from_components(
+                    -44,
+                    3,
+                    15,
+                    12,
+                    0,
+                    0,
+                    UtcOffset::UTC,
+                )
+                .unwrap(),
+                DateTime::parse("0000-01-01T00:00:00.000000001Z")
+                    .unwrap(),
+            ];
+            for instant in instants {
+                for (hours, minutes, seconds) in offsets {
+                    let offset =
+                        UtcOffset::from_hms(hours, minutes, seconds)
+                            .unwrap();
+                    let dt = match instant.try_convert_to_offset(offset)
+                    {
+                        Ok(dt) => dt,
+                        Err(_) => continue,
+                    };
+                    let text = dt.to_string();
+                    assert_eq!(
+                        text.parse::<DateTime>(),
+                        Ok(dt),
+                        "{text}"
+                    );
+                    assert_eq!(dt.offset(), offset);
+                }
+            }
+        }
+
+        #[test]
+        fn test_extreme_offset_round_trip() {
+            let offset = UtcOffset::from_hms(20, 0, 0).unwrap();
+            let dt =
+                DateTime::from_components(2024, 3, 1, 12, 0, 0, offset)
+                    .unwrap();
+            assert_eq!(dt.to_string(), "2024-03-01T12:00:00+20:00");
+            assert_eq!(
+                DateTime::parse_display(&dt.to_string()),
+                Ok(dt)
+            );
+            assert_eq!(
+                DateTime::parse(&dt.to_string()),
+                Err(DateTimeError::InvalidTimezone)
+            );
+        }
+
+        #[test]
+        fn test_extended_display_forms() {
+            let dt = DateTime::from_components(
+                -44,
+                3,
+                15,
+                12,
+                0,
+                0,
+                UtcOffset::UTC,
+            )
+            .unwrap();
+            assert_eq!(dt.to_string(), "-0044-03-15T12:00:00+00:00:00");
+
+            let offset = UtcOffset::from_hms(-4, -56, -2).unwrap();
+            let dt = DateTime::parse("1883-11-18T12:00:00.5Z")
+                .unwrap()
+                .try_convert_to_offset(offset)
+                .unwrap();
+            assert_eq!(
+                dt.to_string(),
+                "1883-11-18T07:03:58.5-04:56:02"
+            );
+            assert_eq!(
+                DateTime::parse_display(
+                    "1883-11-18T07:03:58.5-04:56:02"
+                ),
+                Ok(dt)
+            );
+        }
+
+        #[test]
+        fn test_parse_display_keeps_parse_errors() {
+            assert_eq!(
+                DateTime::parse_display("not a date"),
+                DateTime::parse("not a date")
+            );
+        }
+    }
+
+    mod offset_string_tests {
+        use super::*;
+        use time::UtcOffset;
+
+        #[test]
+        fn test_offset_string() {
+            let cases = [
+                ("2024-01-01T12:00:00Z", "+00:00", 0, 0),
+                ("2024-01-01T12:00:00+05:45", "+05:45", 20_700, 345),
+                ("2024-01-01T12:00:00-09:30", "-09:30", -34_200, -570),
+                ("2024-01-01T12:00:00+14:00", "+14:00", 50_400, 840),
+            ];
+            for (input, text, seconds, minutes) in cases {
+                let dt = DateTime::parse(input).unwrap();
+                assert_eq!(dt.offset_string(), text);
+                assert_eq!(dt.offset_seconds(), seconds);
+                assert_eq!(dt.offset_minutes(), minutes);
+            }
+        }
+
+        #[test]
+        fn test_offset_string_with_seconds() {
+            let offset = UtcOffset::from_hms(-4, -56, -2).unwrap();
+            let dt = DateTime::parse("1883-11-18T12:00:00Z")
+                .unwrap()
+                .try_convert_to_offset(offset)
+                .unwrap();
+            assert_eq!(dt.offset_string(), "-04:56:02");
+            assert_eq!(dt.offset_seconds(), -17_762);
+            assert_eq!(dt.offset_minutes(), -296);
+        }
+    }
+
+    mod instant_comparison_tests {
+        use super::*;
+        use dtt::datetime::InstantOrdered;
+        use std::cmp::Ordering;
+        use std::collections::{BTreeMap, HashSet};
+
+        fn parse(input: &str) -> DateTime {
+            DateTime::parse(input).unwrap()
+        }
+
+        #[test]
+        fn test_same_instant_and_cmp_instant() {
+            let utc = parse("2024-06-01T00:00:00Z");
+            let la = parse("2024-05-31T17:00:00-07:00");
+            let later = parse("2024-06-01T00:00:01+00:00");
+            assert!(utc.same_instant_as(&la));
+            assert!(!utc.same_instant_as(&later));
+            assert_eq!(utc.cmp_instant(&la), Ordering::Equal);
+            assert_eq!(la.cmp_instant(&later), Ordering::Less);
+            assert_eq!(later.cmp_instant(&la), Ordering::Greater);
+            // Wall-clock ordering is unchanged.
+            assert_eq!(la.cmp(&utc), Ordering::Less);
+        }
+
+        #[test]
+        fn test_instant_ordered_keys() {
+            let utc = InstantOrdered(parse("2024-06-01T00:00:00Z"));
+            let la = InstantOrdered::from(parse(
+                "2024-05-31T17:00:00-07:00",
+            ));
+            assert_eq!(utc, la);
+
+            let set: HashSet<InstantOrdered> =
+                [utc, la].into_iter().collect();
+            assert_eq!(set.len(), 1);
+
+            let mut map = BTreeMap::new();
+            let _ = map.insert(la, "first");
+            let _ = map.insert(utc, "second");
+            assert_eq!(map.len(), 1);
+            let (key, value) = map.iter().next().unwrap();
+            assert_eq!(DateTime::from(*key), la.into_inner());
+            assert_eq!(*value, "second");
+            assert_eq!(key.to_string(), "2024-05-31T17:00:00-07:00");
+        }
+    }
+
+    mod offset_change_tests {
+        use super::*;
+        use dtt::offsets;
+
+        #[test]
+        fn test_to_utc() {
+            let dt =
+                DateTime::parse("2024-01-01T01:00:00-08:00").unwrap();
+            let utc = dt.to_utc().unwrap();
+            assert_eq!(utc.to_string(), "2024-01-01T09:00:00Z");
+            assert!(utc.same_instant_as(&dt));
+
+            let late =
+                DateTime::parse("9999-12-31T23:00:00-05:00").unwrap();
+            assert!(matches!(
+                late.to_utc(),
+                Err(DateTimeError::ConversionOverflow { .. })
+            ));
+        }
+
+        #[test]
+        fn test_with_offset_keeps_wall_clock() {
+            let dt = DateTime::parse("2024-03-10T02:30:00Z").unwrap();
+            let shifted = dt.with_offset(offsets::IST);
+            assert_eq!(shifted.to_primitive(), dt.to_primitive());
+            assert_eq!(shifted.offset(), offsets::IST);
+            assert_eq!(
+                dt.unix_timestamp() - shifted.unix_timestamp(),
+                19_800
+            );
+        }
+
+        #[test]
+        fn test_with_offset_same_instant() {
+            let dt = DateTime::parse("2024-03-10T02:30:00Z").unwrap();
+            let converted =
+                dt.with_offset_same_instant(offsets::PST).unwrap();
+            assert_eq!(
+                converted.to_string(),
+                "2024-03-09T18:30:00-08:00"
+            );
+            assert_eq!(
+                converted,
+                dt.try_convert_to_offset(offsets::PST).unwrap()
+            );
+        }
+    }
+
+    mod weekday_of_month_tests {
+        use super::*;
+
+        #[test]
+        fn test_nth_weekday_of_month() {
+            let dt =
+                DateTime::parse("2024-02-20T08:15:00+01:00").unwrap();
+            let cases = [
+                (
+                    1,
+                    Weekday::Thursday,
+                    Some("2024-02-01T08:15:00+01:00"),
+                ),
+                (
+                    1,
+                    Weekday::Wednesday,
+                    Some("2024-02-07T08:15:00+01:00"),
+                ),
+                (
+                    5,
+                    Weekday::Thursday,
+                    Some("2024-02-29T08:15:00+01:00"),
+                ),
+                (5, Weekday::Friday, None),
+                (0, Weekday::Monday, None),
+                (255, Weekday::Monday, None),
+            ];
+            for (n, weekday, expected) in cases {
+                assert_eq!(
+                    dt.nth_weekday_of_month(n, weekday)
+                        .map(|found| found.to_string())
+                        .as_deref(),
+                    expected,
+                    "{n} {weekday}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_last_weekday_of_month() {
+            let dt = DateTime::parse("2023-09-05T12:00:00Z").unwrap();
+            // September 2023 ends on a Saturday.
+            assert_eq!(
+                dt.last_weekday_of_month(Weekday::Saturday).day(),
+                30
+            );
+            assert_eq!(
+                dt.last_weekday_of_month(Weekday::Sunday).day(),
+                24
+            );
+            assert_eq!(
+                dt.last_weekday_of_month(Weekday::Friday).day(),
+                29
+            );
+            assert_eq!(
+                dt.last_weekday_of_month(Weekday::Friday).hour(),
+                12
+            );
+        }
+
+        #[test]
+        fn test_next_occurrence_of() {
+            let sunday =
+                DateTime::parse("2024-12-29T23:59:59Z").unwrap();
+            let monday =
+                sunday.next_occurrence_of(Weekday::Monday).unwrap();
+            assert_eq!(monday.to_string(), "2024-12-30T23:59:59Z");
+            let week_later =
+                sunday.next_occurrence_of(Weekday::Sunday).unwrap();
+            assert_eq!(week_later.to_string(), "2025-01-05T23:59:59Z");
+
+            let last = DateTime::parse("9999-12-31T00:00:00Z").unwrap();
+            assert!(last.next_occurrence_of(Weekday::Monday).is_err());
+        }
+    }
+
+    mod zone_abbreviation_tests {
+        use super::*;
+
+        #[test]
+        fn test_zone_abbreviation_is_tracked() {
+            let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+            assert_eq!(utc.zone_abbreviation(), None);
+
+            let est = utc.convert_to_tz("EST").unwrap();
+            assert_eq!(est.zone_abbreviation(), Some("EST"));
+            assert_eq!(
+                est.add_days(3).unwrap().zone_abbreviation(),
+                Some("EST")
+            );
+            assert_eq!(
+                (est + Duration::hours(2)).unwrap().zone_abbreviation(),
+                Some("EST")
+            );
+            assert_eq!(
+                est.convert_to_tz("UTC-05:00")
+                    .unwrap()
+                    .zone_abbreviation(),
+                None
+            );
+            assert_eq!(
+                est.try_convert_to_offset(UtcOffset::UTC)
+                    .unwrap()
+                    .zone_abbreviation(),
+                None
+            );
+            assert_eq!(
+                est.with_offset(UtcOffset::UTC).zone_abbreviation(),
+                None
+            );
+            assert_eq!(
+                DateTime::new_with_tz("JST")
+                    .unwrap()
+                    .zone_abbreviation(),
+                Some("JST")
+            );
+        }
+
+        #[test]
+        fn test_zone_abbreviation_is_presentation_only() {
+            let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+            let labelled = utc.convert_to_tz("CET").unwrap();
+            let unlabelled =
+                utc.try_convert_to_offset(labelled.offset()).unwrap();
+            assert_eq!(labelled, unlabelled);
+
+            let json = serde_json::to_string(&labelled).unwrap();
+            let restored: DateTime =
+                serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, labelled);
+            assert_eq!(restored.zone_abbreviation(), None);
+        }
+
+        #[test]
+        fn test_format_zoned() {
+            let utc = DateTime::parse("2024-01-01T12:00:00Z").unwrap();
+            assert_eq!(
+                utc.convert_to_tz("IST")
+                    .unwrap()
+                    .format_zoned()
+                    .unwrap(),
+                "2024-01-01 17:30:00 IST"
+            );
+            assert_eq!(
+                utc.convert_to_tz("+05:30")
+                    .unwrap()
+                    .format_zoned()
+                    .unwrap(),
+                "2024-01-01 17:30:00 +05:30"
+            );
+        }
+    }
 }
@@ -0,0 +1,123 @@
+// test_delta.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `delta` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::delta::DateDelta;
+
+    fn dt(input: &str) -> DateTime {
+        DateTime::parse(input).unwrap_or_default()
+    }
+
+    /// Tests a simple forward difference.
+    #[test]
+    fn test_forward_difference() {
+        let delta = dt("2024-05-20T13:45:30Z")
+            .difference(&dt("2022-03-10T12:00:00Z"));
+        assert_eq!(
+            delta,
+            DateDelta {
+                years: 2,
+                months: 2,
+                days: 10,
+                hours: 1,
+                minutes: 45,
+                seconds: 30,
+                nanoseconds: 0,
+            }
+        );
+        assert!(!delta.is_negative());
+        assert_eq!(
+            delta.to_string(),
+            "2 years, 2 months, 10 days, 1 hour, 45 minutes, 30 seconds"
+        );
+    }
+
+    /// Tests that reversing the operands negates every field.
+    #[test]
+    fn test_sign_handling() {
+        let a = dt("2024-05-20T13:45:30Z");
+        let b = dt("2022-03-10T12:00:00Z");
+        assert_eq!(b.difference(&a), a.difference(&b).negate());
+        assert!(b.difference(&a).is_negative());
+        assert!(b.difference(&a).to_string().starts_with("-2 years"));
+    }
+
+    /// Tests month-end clamping across month boundaries.
+    #[test]
+    fn test_month_end_boundaries() {
+        let delta = dt("2023-02-28").difference(&dt("2023-01-31"));
+        assert_eq!((delta.months, delta.days), (1, 0));
+
+        let delta = dt("2023-03-01").difference(&dt("2023-01-31"));
+        assert_eq!((delta.months, delta.days), (1, 1));
+
+        let delta = dt("2023-02-27").difference(&dt("2023-01-31"));
+        assert_eq!((delta.months, delta.days), (0, 27));
+    }
+
+    /// Tests differences spanning leap days.
+    ///
+    /// Adding a year to February 29 clamps to February 28, so that date is
+    /// exactly one year later.
+    #[test]
+    fn test_leap_year_boundaries() {
+        let delta = dt("2025-02-28").difference(&dt("2024-02-29"));
+        assert_eq!((delta.years, delta.months, delta.days), (1, 0, 0));
+
+        let delta = dt("2025-02-27").difference(&dt("2024-02-29"));
+        assert_eq!(
+            (delta.years, delta.months, delta.days),
+            (0, 11, 29)
+        );
+
+        let delta = dt("2028-02-29").difference(&dt("2024-02-29"));
+        assert_eq!((delta.years, delta.months, delta.days), (4, 0, 0));
+
+        let delta = dt("2024-03-01").difference(&dt("2024-02-28"));
+        assert_eq!(delta.days, 2);
+    }
+
+    /// Tests that time components borrow from days correctly.
+    #[test]
+    fn test_time_borrow() {
+        let delta = dt("2024-02-01T01:00:00Z")
+            .difference(&dt("2024-01-01T23:00:00Z"));
+        assert_eq!((delta.months, delta.days, delta.hours), (0, 30, 2));
+    }
+
+    /// Tests that offsets are normalized before computing the breakdown.
+    #[test]
+    fn test_offset_normalization(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let utc = dt("2024-01-01T12:00:00Z");
+        let jst = utc.convert_to_tz("JST")?;
+        let delta = jst.difference(&utc);
+        assert!(delta.is_zero());
+        assert_eq!(delta.to_string(), "0 seconds");
+        Ok(())
+    }
+
+    /// Tests that values beyond the range in the other offset fall back
+    /// to UTC instead of panicking.
+    #[test]
+    fn test_range_edges() {
+        let last = dt("9999-12-31T23:59:59-05:00");
+        let now = dt("2024-01-01T00:00:00Z");
+        let delta = DateDelta::between(&last, &now);
+        assert_eq!(
+            (delta.years, delta.months, delta.days),
+            (-7975, -11, -30)
+        );
+        assert_eq!(now.difference(&last), delta);
+
+        // `now` can be shown in the offset of `last`, so no fallback.
+        let delta = DateDelta::between(&now, &last);
+        assert_eq!((delta.years, delta.hours), (7976, 4));
+    }
+}
@@ -0,0 +1,82 @@
+// test_diff.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `diff` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::diff::DiffComponent;
+    use time::{Duration, UtcOffset};
+
+    /// Tests that identical values produce an empty report.
+    #[test]
+    fn test_identical() -> Result<(), Box<dyn std::error::Error>> {
+        let dt = DateTime::parse("2024-01-01T12:00:00Z")?;
+        let report = dt.diff_report(&dt);
+        assert!(report.is_identical());
+        assert!(report.is_same_instant());
+        assert!(report.to_string().ends_with("no differences"));
+        Ok(())
+    }
+
+    /// Tests that each differing component is listed in order.
+    #[test]
+    fn test_component_differences(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let a = DateTime::from_components(
+            2024,
+            1,
+            1,
+            12,
+            0,
+            0,
+            UtcOffset::UTC,
+        )?;
+        let b = DateTime::from_components(
+            2024,
+            1,
+            2,
+            13,
+            0,
+            0,
+            UtcOffset::UTC,
+        )?;
+        let report = a.diff_report(&b);
+        let components: Vec<DiffComponent> =
+            report.differences().iter().map(|d| d.component).collect();
+        assert_eq!(
+            components,
+            vec![DiffComponent::Day, DiffComponent::Hour]
+        );
+        assert_eq!(report.delta(), Duration::hours(25));
+        Ok(())
+    }
+
+    /// Tests that the same instant in different offsets is reported as such.
+    #[test]
+    fn test_same_instant_different_offset(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let utc = DateTime::from_components(
+            2024,
+            1,
+            1,
+            12,
+            0,
+            0,
+            UtcOffset::UTC,
+        )?;
+        let cet = utc.convert_to_tz("CET")?;
+        let report = utc.diff_report(&cet);
+        assert!(!report.is_identical());
+        assert!(report.is_same_instant());
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("offset     +00:00 != +01:00"));
+        assert!(rendered.contains("hour       12 != 13"));
+        assert!(rendered.ends_with("delta: 0s (same instant)"));
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+// test_duration.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `duration` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::error::DateTimeError;
+    use dtt::parse_duration;
+    use time::Duration;
+
+    mod human_tests {
+        use super::*;
+
+        #[test]
+        fn test_units() {
+            let cases = [
+                ("90s", Duration::seconds(90)),
+                ("1h30m", Duration::minutes(90)),
+                ("1d 2h 30m", Duration::minutes(1_590)),
+                ("2w", Duration::weeks(2)),
+                ("250ms", Duration::milliseconds(250)),
+                ("5us", Duration::microseconds(5)),
+                ("5µs", Duration::microseconds(5)),
+                ("7ns", Duration::nanoseconds(7)),
+                ("3 hours 4 minutes", Duration::minutes(184)),
+                ("1 day", Duration::days(1)),
+                ("10 sec", Duration::seconds(10)),
+                ("0s", Duration::ZERO),
+            ];
+            for (input, expected) in cases {
+                assert_eq!(
+                    parse_duration(input),
+                    Ok(expected),
+                    "{input}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_fractions_and_signs() {
+            assert_eq!(
+                parse_duration("1.5h"),
+                Ok(Duration::minutes(90))
+            );
+            assert_eq!(
+                parse_duration("0.25s"),
+                Ok(Duration::milliseconds(250))
+            );
+            assert_eq!(
+                parse_duration("-1.5h"),
+                Ok(Duration::minutes(-90))
+            );
+            assert_eq!(parse_duration("+2m"), Ok(Duration::minutes(2)));
+            assert_eq!(
+                parse_duration("-1m 30s"),
+                Ok(Duration::seconds(-90))
+            );
+        }
+
+        #[test]
+        fn test_errors() {
+            assert_eq!(
+                parse_duration(""),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 0,
+                    expected: "number"
+                })
+            );
+            assert_eq!(
+                parse_duration("15"),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 2,
+                    expected: "unit"
+                })
+            );
+            assert_eq!(
+                parse_duration("2h 15x"),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 5,
+                    expected: "unit"
+                })
+            );
+            assert_eq!(
+                parse_duration("h"),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 0,
+                    expected: "number"
+                })
+            );
+            assert!(parse_duration("1.h").is_err());
+            assert_eq!(
+                parse_duration("99999999999999999999w"),
+                Err(DateTimeError::InvalidFormat)
+            );
+        }
+    }
+
+    mod iso8601_tests {
+        use super::*;
+
+        #[test]
+        fn test_components() {
+            let cases = [
+                ("PT90S", Duration::seconds(90)),
+                ("P1DT2H", Duration::hours(26)),
+                ("P2W", Duration::weeks(2)),
+                ("P1D", Duration::days(1)),
+                ("PT1H2M3S", Duration::seconds(3_723)),
+                ("PT0.5S", Duration::milliseconds(500)),
+                ("PT1,5M", Duration::seconds(90)),
+                ("-PT1M", Duration::minutes(-1)),
+            ];
+            for (input, expected) in cases {
+                assert_eq!(
+                    parse_duration(input),
+                    Ok(expected),
+                    "{input}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_errors() {
+            for (input, index, expected) in [
+                ("P1Y", 2, "designator"),
+                ("P1M", 2, "designator"),
+                ("PT1D", 3, "designator"),
+                ("PT1S2M", 5, "designator"),
+                ("P", 1, "number"),
+                ("PT", 2, "number"),
+                ("PT0.5M1S", 6, "end of input"),
+            ] {
+                assert_eq!(
+                    parse_duration(input),
+                    Err(DateTimeError::InvalidFormatAt {
+                        index,
+                        expected
+                    }),
+                    "{input}"
+                );
+            }
+        }
+    }
+
+    mod format_tests {
+        use super::*;
+        use dtt::duration::{
+            format_duration_iso8601, parse_duration_iso8601,
+        };
+
+        #[test]
+        fn test_format() {
+            let cases = [
+                (Duration::ZERO, "PT0S"),
+                (Duration::seconds(90), "PT1M30S"),
+                (Duration::hours(26), "P1DT2H"),
+                (Duration::days(3), "P3D"),
+                (
+                    Duration::days(400) + Duration::minutes(5),
+                    "P400DT5M",
+                ),
+                (Duration::milliseconds(500), "PT0.5S"),
+                (Duration::nanoseconds(1), "PT0.000000001S"),
+                (Duration::seconds(-3_723), "-PT1H2M3S"),
+                (
+                    Duration::days(-1) - Duration::milliseconds(250),
+                    "-P1DT0.25S",
+                ),
+            ];
+            for (duration, expected) in cases {
+                assert_eq!(format_duration_iso8601(duration), expected);
+            }
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let durations = [
+                Duration::ZERO,
+                Duration::nanoseconds(-7),
+                Duration::new(86_400 * 365 + 59, 123_456_789),
+                Duration::MAX,
+                Duration::MIN + Duration::nanoseconds(1),
+            ];
+            for duration in durations {
+                let text = format_duration_iso8601(duration);
+                assert_eq!(
+                    parse_duration_iso8601(&text),
+                    Ok(duration),
+                    "{text}"
+                );
+                assert_eq!(
+                    parse_duration(&text),
+                    Ok(duration),
+                    "{text}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_strict_parser() {
+            assert_eq!(
+                parse_duration_iso8601("PT15M"),
+                Ok(Duration::minutes(15))
+            );
+            assert_eq!(
+                parse_duration_iso8601("15m"),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 0,
+                    expected: "designator"
+                })
+            );
+            assert!(parse_duration_iso8601("P1Y2M3DT4H5M6S").is_err());
+            assert!(parse_duration_iso8601("").is_err());
+        }
+    }
+}
@@ -134,6 +134,7 @@ mod tests {
     }
 
     mod serialization_tests {
+        use dtt::datetime::{DateTime, DateTimeBuilder};
         use dtt::error::DateTimeError;
         use time::error::{Parse, TryFromParsed};
 
@@ -205,19 +206,20 @@ mod tests {
                 serde_json::from_str(&serialized_format)?
             );
 
-            // Test with ParseError variant using a mock string, because we cannot directly deserialize ParseError
+            // ParseError carries which `time` error it wraps
             let parse_error =
                 DateTimeError::ParseError(Parse::TryFromParsed(
                     TryFromParsed::InsufficientInformation,
                 ));
             let serialized_parse = serde_json::to_string(&parse_error)?;
-
-            // Deserialize into a string and assert that it matches the known string for ParseError
-            assert_eq!(serialized_parse, "\"ParseError\"");
-            assert!(serde_json::from_str::<DateTimeError>(
-                &serialized_parse
-            )
-            .is_err());
+            assert_eq!(
+                serialized_parse,
+                r#"{"ParseError":"InsufficientInformation"}"#
+            );
+            assert_eq!(
+                parse_error,
+                serde_json::from_str(&serialized_parse)?
+            );
 
             Ok(())
         }
@@ -245,6 +247,193 @@ mod tests {
             Ok(())
         }
 
+        /// Tests that every variant round-trips through JSON to an equal
+        /// value.
+        #[test]
+        fn test_lossless_round_trip(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let parse_failures =
+                ["2024-13-01", "2024-01-01x", "2024/01/01", "[year]"]
+                    .iter()
+                    .filter_map(|input| {
+                        time::Date::parse(
+                            input,
+                            time::macros::format_description!(
+                                "[year]-[month]-[day]"
+                            ),
+                        )
+                        .err()
+                    });
+            let builder_error = DateTimeBuilder::new()
+                .month(13)
+                .day(32)
+                .build()
+                .unwrap_err();
+            let instant = DateTime::parse("9999-12-31T23:00:00Z")?;
+            let leap_second = time::OffsetDateTime::parse(
+                "2016-06-30T12:59:60Z",
+                &time::format_description::well_known::Rfc3339,
+            )
+            .unwrap_err();
+            let mut errors = vec![
+                DateTimeError::InvalidFormat,
+                DateTimeError::InvalidTimezone,
+                DateTimeError::InvalidDate,
+                DateTimeError::InvalidTime,
+                DateTimeError::ParseError(Parse::TryFromParsed(
+                    TryFromParsed::InsufficientInformation,
+                )),
+                DateTimeError::ParseError(leap_second),
+                DateTimeError::InvalidFormatAt {
+                    index: 5,
+                    expected: "month",
+                },
+                DateTimeError::ConversionOverflow {
+                    instant,
+                    target: time::UtcOffset::from_hms(2, 0, 0)?,
+                },
+                builder_error,
+            ];
+            errors
+                .extend(parse_failures.map(DateTimeError::ParseError));
+            errors.extend(
+                [
+                    time::Date::from_calendar_date(
+                        -10_000,
+                        time::Month::May,
+                        1,
+                    )
+                    .err(),
+                    time::Month::try_from(0).err(),
+                    time::Date::from_calendar_date(
+                        2023,
+                        time::Month::February,
+                        29,
+                    )
+                    .err(),
+                    time::Date::from_calendar_date(
+                        2024,
+                        time::Month::June,
+                        31,
+                    )
+                    .err(),
+                    // Rejected by February 2023 too, with another range.
+                    time::Date::from_calendar_date(
+                        2024,
+                        time::Month::February,
+                        30,
+                    )
+                    .err(),
+                    time::Date::from_ordinal_date(2024, 400).err(),
+                    time::Date::from_iso_week_date(
+                        2024,
+                        53,
+                        time::Weekday::Friday,
+                    )
+                    .err(),
+                    time::Time::from_hms(24, 0, 0).err(),
+                    time::Time::from_hms(1, 60, 0).err(),
+                    time::Time::from_hms(1, 2, 61).err(),
+                    time::Time::from_hms_milli(1, 2, 3, 1000).err(),
+                    time::Time::from_hms_nano(1, 2, 3, 1_000_000_000)
+                        .err(),
+                    time::UtcOffset::from_hms(26, 0, 0).err(),
+                    time::UtcOffset::from_whole_seconds(100_000).err(),
+                    time::OffsetDateTime::from_unix_timestamp(i64::MAX)
+                        .err(),
+                ]
+                .into_iter()
+                .flatten()
+                .map(DateTimeError::ComponentRange),
+            );
+            assert_eq!(errors.len(), 28);
+
+            for error in errors {
+                let json = serde_json::to_string(&error)?;
+                let back: DateTimeError = serde_json::from_str(&json)?;
+                assert_eq!(back, error, "{json}");
+            }
+            Ok(())
+        }
+
+        /// Tests the externally tagged representation of payloads.
+        #[test]
+        fn test_tagged_representation() -> Result<(), serde_json::Error>
+        {
+            let error = DateTimeError::InvalidFormatAt {
+                index: 5,
+                expected: "month",
+            };
+            assert_eq!(
+                serde_json::to_string(&error)?,
+                r#"{"InvalidFormatAt":{"index":5,"expected":"month"}}"#
+            );
+            assert!(serde_json::from_str::<DateTimeError>(
+                r#"{"InvalidFormatAt":{"index":5,"expected":"moon"}}"#
+            )
+            .is_err());
+            Ok(())
+        }
+
+        /// Tests the representation of `time` range errors, and that
+        /// one no `time` call reproduces fails instead of changing.
+        #[test]
+        fn test_component_range_representation() {
+            let error = DateTimeError::from(
+                time::Date::from_ordinal_date(2024, 400).unwrap_err(),
+            );
+            assert_eq!(
+                serde_json::to_string(&error).unwrap(),
+                r#"{"ComponentRange":{"name":"ordinal","value":400,"conditional":true,"message":"ordinal must be in the range 1..=366, given values of other parameters"}}"#
+            );
+
+            // An unrecognised message still rebuilds from the value.
+            let reworded = r#"{"ComponentRange":{"name":"hour","value":24,"conditional":false,"message":"?"}}"#;
+            assert_eq!(
+                serde_json::from_str::<DateTimeError>(reworded)
+                    .unwrap(),
+                DateTimeError::from(
+                    time::Time::from_hms(24, 0, 0).unwrap_err()
+                )
+            );
+
+            let forged = r#"{"ComponentRange":{"name":"ordinal","value":100,"conditional":true,"message":""}}"#;
+            assert!(
+                serde_json::from_str::<DateTimeError>(forged).is_err()
+            );
+        }
+
+        /// Tests that the rejected value is read from every kind of
+        /// `time` range error. This fails if a `time` release changes
+        /// the `Debug` output the value is read from.
+        #[test]
+        fn test_component_range_value_is_recorded() {
+            let cases = [
+                (time::Time::from_hms(24, 0, 0).unwrap_err(), 24),
+                (time::Month::try_from(0).unwrap_err(), 0),
+                (
+                    time::UtcOffset::from_hms(-26, 0, 0).unwrap_err(),
+                    -26,
+                ),
+                (
+                    time::OffsetDateTime::from_unix_timestamp(i64::MAX)
+                        .unwrap_err(),
+                    i64::MAX,
+                ),
+            ];
+            for (range, value) in cases {
+                let json = serde_json::to_value(
+                    DateTimeError::ComponentRange(range),
+                )
+                .unwrap();
+                assert_eq!(
+                    json["ComponentRange"]["value"],
+                    serde_json::json!(value),
+                    "{range:?}"
+                );
+            }
+        }
+
         /// Tests deserialization from invalid JSON strings.
         ///
         /// This test ensures that attempting to deserialize `DateTimeError` from
@@ -436,6 +625,21 @@ mod tests {
                             .to_string()
                             .contains("Component range error"));
                     }
+                    DateTimeError::ConversionOverflow { .. } => {
+                        assert!(variant
+                            .to_string()
+                            .contains("overflows"));
+                    }
+                    DateTimeError::InvalidFormatAt { .. } => {
+                        assert!(variant.to_string().starts_with(
+                            "Invalid date format at index"
+                        ));
+                    }
+                    DateTimeError::InvalidComponents(_) => {
+                        assert!(variant
+                            .to_string()
+                            .starts_with("Invalid components"));
+                    }
                 }
             }
         }
@@ -487,4 +691,535 @@ mod tests {
             assert_eq!(default_error, DateTimeError::InvalidFormat);
         }
     }
+
+    mod classification_tests {
+        use dtt::error::{DateTimeError, ErrorCategory};
+        use std::collections::HashSet;
+
+        fn all_variants() -> Vec<DateTimeError> {
+            let range = time::Date::from_calendar_date(
+                2024,
+                time::Month::February,
+                30,
+            )
+            .err();
+            let parse = time::Date::parse(
+                "x",
+                time::macros::format_description!("[year]"),
+            )
+            .err();
+            let mut variants = vec![
+                DateTimeError::InvalidFormat,
+                DateTimeError::InvalidTimezone,
+                DateTimeError::InvalidDate,
+                DateTimeError::InvalidTime,
+            ];
+            variants.extend(parse.map(DateTimeError::ParseError));
+            variants.extend(range.map(DateTimeError::ComponentRange));
+            variants.push(DateTimeError::ConversionOverflow {
+                instant: dtt::datetime::DateTime::default(),
+                target: time::UtcOffset::UTC,
+            });
+            variants.push(DateTimeError::InvalidFormatAt {
+                index: 0,
+                expected: "year",
+            });
+            variants.extend(
+                dtt::datetime::DateTimeBuilder::new()
+                    .month(0)
+                    .build()
+                    .err(),
+            );
+            variants
+        }
+
+        /// Tests that every variant has a unique, well-formed code.
+        #[test]
+        fn test_codes_are_unique() {
+            let variants = all_variants();
+            assert_eq!(variants.len(), 9);
+            let codes: HashSet<&str> =
+                variants.iter().map(DateTimeError::code).collect();
+            assert_eq!(codes.len(), variants.len());
+            assert!(codes.iter().all(
+                |code| code.starts_with("DTT-") && code.len() == 8
+            ));
+            assert_eq!(DateTimeError::InvalidTime.code(), "DTT-0004");
+        }
+
+        /// Tests the category of each variant.
+        #[test]
+        fn test_categories() {
+            let categories: Vec<ErrorCategory> = all_variants()
+                .iter()
+                .map(DateTimeError::category)
+                .collect();
+            assert_eq!(
+                categories,
+                vec![
+                    ErrorCategory::Format,
+                    ErrorCategory::Timezone,
+                    ErrorCategory::Range,
+                    ErrorCategory::Range,
+                    ErrorCategory::Parse,
+                    ErrorCategory::Range,
+                    ErrorCategory::Arithmetic,
+                    ErrorCategory::Format,
+                    ErrorCategory::Range,
+                ]
+            );
+        }
+
+        /// Tests that no current variant is retryable.
+        #[test]
+        fn test_is_retryable() {
+            assert!(all_variants().iter().all(|e| !e.is_retryable()));
+        }
+
+        /// Tests that categories serialize by name.
+        #[test]
+        fn test_category_serialization(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let json = serde_json::to_string(&ErrorCategory::Timezone)?;
+            assert_eq!(json, "\"Timezone\"");
+            let category: ErrorCategory = serde_json::from_str(&json)?;
+            assert_eq!(category, ErrorCategory::Timezone);
+            Ok(())
+        }
+    }
+
+    mod serializable_error_tests {
+        use dtt::error::{
+            DateTimeError, ErrorCategory, SerializableError,
+        };
+
+        fn round_trip(
+            error: &DateTimeError,
+        ) -> Result<SerializableError, serde_json::Error> {
+            let snapshot = SerializableError::from(error);
+            let json = serde_json::to_string(&snapshot)?;
+            let back: SerializableError = serde_json::from_str(&json)?;
+            assert_eq!(back, snapshot);
+            Ok(back)
+        }
+
+        /// Tests that every kind of variant round-trips back into an
+        /// equal error, including the `time` errors.
+        #[test]
+        fn test_variants_round_trip() -> Result<(), serde_json::Error> {
+            let range = time::Date::from_calendar_date(
+                2023,
+                time::Month::February,
+                29,
+            )
+            .map_or_else(DateTimeError::ComponentRange, |_| {
+                unreachable!("2023-02-29 is not a valid date")
+            });
+            let parse = time::Date::parse(
+                "2024-xx-01",
+                time::macros::format_description!(
+                    "[year]-[month]-[day]"
+                ),
+            )
+            .map_or_else(DateTimeError::ParseError, |_| {
+                unreachable!("the input is not a valid date")
+            });
+            for error in [
+                DateTimeError::InvalidFormat,
+                DateTimeError::InvalidTimezone,
+                DateTimeError::InvalidDate,
+                DateTimeError::InvalidTime,
+                DateTimeError::InvalidFormatAt {
+                    index: 5,
+                    expected: "month",
+                },
+                range,
+                parse,
+            ] {
+                let back = round_trip(&error)?;
+                assert_eq!(back.to_error(), error);
+                assert_eq!(back.code, error.code());
+                assert_eq!(back.category, error.category());
+                assert_eq!(back.message, error.to_string());
+            }
+            assert_eq!(
+                round_trip(&range)?.category,
+                ErrorCategory::Range
+            );
+            Ok(())
+        }
+
+        /// Tests that the wrapped error uses `DateTimeError`'s own form.
+        #[test]
+        fn test_error_uses_its_own_form(
+        ) -> Result<(), serde_json::Error> {
+            let error = DateTimeError::InvalidFormatAt {
+                index: 5,
+                expected: "month",
+            };
+            let snapshot =
+                serde_json::to_value(SerializableError::from(error))?;
+            assert_eq!(snapshot["error"], serde_json::to_value(error)?);
+            assert_eq!(snapshot["code"], error.code());
+            Ok(())
+        }
+    }
+
+    mod conversion_overflow_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::{DateTimeError, SerializableError};
+        use time::UtcOffset;
+
+        /// Tests the message, code, and serialization of the variant.
+        #[test]
+        fn test_conversion_overflow(
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let error = DateTimeError::ConversionOverflow {
+                instant: DateTime::parse("9999-12-31T23:00:00Z")?,
+                target: UtcOffset::from_hms(10, 0, 0)?,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Converting 9999-12-31 23:00:00.0 +00:00:00 to offset \
+                 +10:00:00 overflows the supported date range"
+            );
+            assert_eq!(error.code(), "DTT-0007");
+            let json = serde_json::to_string(&error)?;
+            assert!(json.starts_with("{\"ConversionOverflow\":"));
+            assert_eq!(
+                serde_json::from_str::<DateTimeError>(&json)?,
+                error
+            );
+
+            let snapshot = SerializableError::from(error);
+            assert_eq!(snapshot.code, "DTT-0007");
+            let json = serde_json::to_string(&snapshot)?;
+            assert_eq!(
+                serde_json::from_str::<SerializableError>(&json)?
+                    .to_error(),
+                error
+            );
+            Ok(())
+        }
+    }
+
+    mod contextual_error_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::{ContextualError, DateTimeError};
+        use std::error::Error;
+
+        /// Tests rendering with a non-UTC instant.
+        #[test]
+        fn test_rendering() -> Result<(), DateTimeError> {
+            let instant = DateTime::parse("2024-01-01T12:00:00Z")?
+                .convert_to_tz("JST")?;
+            let error = DateTimeError::InvalidTime.at(&instant);
+            assert_eq!(
+                error.to_string(),
+                "[2024-01-01T21:00:00+09:00] Invalid time"
+            );
+            assert_eq!(
+                error.to_logfmt(),
+                "at=2024-01-01T21:00:00+09:00 at_utc=2024-01-01T12:00:00Z \
+                 code=DTT-0004 category=Range message=\"Invalid time\""
+            );
+            Ok(())
+        }
+
+        /// Tests the error trait and conversions.
+        #[test]
+        fn test_source_and_conversion() {
+            let instant = DateTime::default();
+            let error: ContextualError =
+                DateTimeError::InvalidFormat.at(&instant);
+            assert_eq!(
+                error.source().map(ToString::to_string),
+                Some("Invalid date format".to_string())
+            );
+            assert_eq!(
+                DateTimeError::from(error),
+                DateTimeError::InvalidFormat
+            );
+        }
+
+        /// Tests rendering of instants outside the RFC 3339 year range.
+        #[test]
+        fn test_out_of_range_instant() -> Result<(), DateTimeError> {
+            let instant = DateTime::from_components(
+                -50,
+                1,
+                1,
+                0,
+                0,
+                0,
+                time::UtcOffset::UTC,
+            )?;
+            let error = DateTimeError::InvalidDate.at(&instant);
+            assert!(error.to_string().starts_with("[-0050-01-01"));
+            Ok(())
+        }
+    }
+
+    mod app_error_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::{AppError, DateTimeError};
+        use std::error::Error as _;
+
+        fn parse(input: &str) -> Result<DateTime, AppError> {
+            Ok(DateTime::parse(input)?)
+        }
+
+        #[test]
+        fn test_question_mark_conversions() {
+            let error = parse("nope").unwrap_err();
+            assert!(matches!(error, AppError::DateTimeError(_)));
+            assert_eq!(
+                error.datetime_error(),
+                Some(DateTimeError::InvalidFormat)
+            );
+
+            let io: AppError = std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "gone",
+            )
+            .into();
+            assert_eq!(io.exit_code(), 74);
+            assert!(io.source().is_some());
+            assert_eq!(io.datetime_error(), None);
+        }
+
+        #[test]
+        fn test_contextual_conversion() {
+            let now = DateTime::default();
+            let error: AppError =
+                DateTimeError::InvalidDate.at(&now).into();
+            assert_eq!(
+                error.datetime_error(),
+                Some(DateTimeError::InvalidDate)
+            );
+            assert_eq!(error.exit_code(), 65);
+        }
+
+        #[test]
+        fn test_config_error() {
+            let error =
+                AppError::config("timezone", "unknown zone `XYZ`");
+            assert_eq!(
+                error.to_string(),
+                "Configuration error for `timezone`: unknown zone `XYZ`"
+            );
+            assert_eq!(error.exit_code(), 78);
+        }
+
+        #[test]
+        fn test_usage_error() {
+            let error = AppError::usage("missing <DATE>");
+            assert_eq!(
+                error.to_string(),
+                "Usage error: missing <DATE>"
+            );
+            assert_eq!(error.exit_code(), 64);
+        }
+
+        #[test]
+        fn test_other_exit_codes() {
+            assert_eq!(AppError::SimulatedError.exit_code(), 1);
+            assert_eq!(AppError::Other("x".into()).exit_code(), 1);
+            assert_eq!(
+                AppError::from(std::env::VarError::NotPresent)
+                    .exit_code(),
+                78
+            );
+        }
+    }
+
+    mod input_error_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::{AppError, DateTimeError};
+
+        /// Tests that `with_input` keeps the error and carries over the
+        /// position of `InvalidFormatAt`.
+        #[test]
+        fn test_with_input() {
+            let error = DateTimeError::InvalidFormatAt {
+                index: 5,
+                expected: "month",
+            }
+            .with_input("2024-xx-01");
+            assert_eq!(error.position(), Some(5));
+            assert_eq!(error.expected(), Some("month"));
+            assert_eq!(
+                error.to_string(),
+                "Invalid date format at index 5: expected month in \"2024-xx-01\""
+            );
+            assert_eq!(
+                DateTimeError::from(error.clone()),
+                error.error()
+            );
+
+            let plain =
+                DateTimeError::InvalidTimezone.with_input("XYZ");
+            assert_eq!(plain.position(), None);
+            assert_eq!(plain.to_string(), "Invalid or unsupported timezone; DST not supported in \"XYZ\"");
+        }
+
+        /// Tests that `parse_detailed` locates the offending component.
+        #[test]
+        fn test_parse_detailed() {
+            let error =
+                DateTime::parse_detailed("2024-01-01T25:00:00Z").err();
+            let error = error.as_ref();
+            assert_eq!(error.and_then(|e| e.position()), Some(11));
+            assert_eq!(error.and_then(|e| e.expected()), Some("hour"));
+
+            let unknown = DateTime::parse_detailed("garbage").err();
+            assert_eq!(
+                unknown.as_ref().map(|e| e.input()),
+                Some("garbage")
+            );
+            assert!(DateTime::parse_detailed("2024-01-01").is_ok());
+        }
+
+        /// Tests that `AppError` wraps input errors as invalid data.
+        #[test]
+        fn test_app_error_input() {
+            let error = AppError::from(
+                DateTimeError::InvalidDate.with_input("2023-02-29"),
+            );
+            assert_eq!(error.exit_code(), 65);
+            assert_eq!(
+                error.datetime_error(),
+                Some(DateTimeError::InvalidDate)
+            );
+        }
+    }
+
+    mod kind_tests {
+        use dtt::datetime::{DateTime, DateTimeBuilder};
+        use dtt::error::{DateTimeError, ErrorKind};
+
+        /// Tests the kind of each plain variant and of component errors.
+        #[test]
+        fn test_kinds() {
+            assert_eq!(
+                DateTimeError::InvalidFormat.kind(),
+                ErrorKind::Format
+            );
+            assert_eq!(
+                DateTimeError::InvalidTimezone.kind(),
+                ErrorKind::Timezone
+            );
+            assert_eq!(
+                DateTimeError::InvalidDate.kind(),
+                ErrorKind::Date
+            );
+            assert_eq!(
+                DateTimeError::InvalidTime.kind(),
+                ErrorKind::Time
+            );
+            assert_eq!(
+                DateTimeError::InvalidFormatAt {
+                    index: 0,
+                    expected: "literal"
+                }
+                .kind(),
+                ErrorKind::Format
+            );
+
+            let built = DateTimeBuilder::new()
+                .minute(60)
+                .second(60)
+                .build()
+                .err();
+            assert_eq!(
+                built.map(|e| e.kind()),
+                Some(ErrorKind::Minute)
+            );
+
+            let parsed = DateTime::parse_custom_format(
+                "2024-01-01 00:00 +xx",
+                "[year]-[month]-[day] [hour]:[minute] [offset_hour]",
+            )
+            .err();
+            assert_eq!(
+                parsed.map(|e| e.kind()),
+                Some(ErrorKind::Offset)
+            );
+
+            let overflow = DateTime::parse("9999-12-31T23:00:00Z")
+                .ok()
+                .and_then(|dt| dt.convert_to_tz("JST").err());
+            assert_eq!(
+                overflow.map(|e| e.kind()),
+                Some(ErrorKind::Overflow)
+            );
+        }
+
+        /// Tests that input errors narrow the kind and give hints.
+        #[test]
+        fn test_input_kinds_and_suggestions() {
+            let day = DateTime::parse_detailed("2023-02-29").err();
+            assert_eq!(
+                day.as_ref().map(|e| e.kind()),
+                Some(ErrorKind::Day)
+            );
+            assert!(day
+                .and_then(|e| e.suggestion())
+                .unwrap_or_default()
+                .contains("leap years"));
+
+            let garbage = DateTime::parse_detailed("soon").err();
+            assert_eq!(
+                garbage.map(|e| e.kind()),
+                Some(ErrorKind::Year)
+            );
+            assert_eq!(
+                DateTimeError::InvalidTimezone.suggestion(),
+                None
+            );
+            assert_eq!(
+                DateTimeError::InvalidTimezone
+                    .with_input("ETS")
+                    .suggestion()
+                    .as_deref(),
+                Some("did you mean EST?")
+            );
+            assert_eq!(
+                DateTimeError::InvalidTimezone
+                    .with_input("Mars/Olympus")
+                    .suggestion(),
+                None
+            );
+        }
+
+        /// Tests that suggestions come from the error's payload.
+        #[test]
+        fn test_specific_suggestions() {
+            assert_eq!(DateTimeError::InvalidFormat.suggestion(), None);
+            assert_eq!(
+                DateTimeError::InvalidFormatAt {
+                    index: 5,
+                    expected: "month",
+                }
+                .suggestion()
+                .as_deref(),
+                Some("months run from 01 (January) to 12 (December)")
+            );
+            assert_eq!(
+                DateTimeError::InvalidFormatAt {
+                    index: 10,
+                    expected: "end of input",
+                }
+                .suggestion()
+                .as_deref(),
+                Some("expected end of input")
+            );
+
+            let built = DateTimeBuilder::new().minute(60).build().err();
+            assert_eq!(
+                built.and_then(|e| e.suggestion()).as_deref(),
+                Some("minute must be between 0 and 59")
+            );
+        }
+    }
 }
@@ -0,0 +1,150 @@
+// test_flexible.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `flexible` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::flexible::{parse_flexible, DateOrder, FlexibleOptions};
+
+    fn parse(input: &str, options: &FlexibleOptions) -> String {
+        match parse_flexible(input, options) {
+            Ok(dt) => dt.to_string(),
+            Err(e) => format!("error: {e:?}"),
+        }
+    }
+
+    /// Tests numeric dates in both day/month orders.
+    #[test]
+    fn test_numeric_dates() {
+        let us = FlexibleOptions::new();
+        let eu = FlexibleOptions::new().order(DateOrder::DayFirst);
+
+        assert_eq!(parse("01/02/2024", &us), "2024-01-02T00:00:00Z");
+        assert_eq!(parse("01/02/2024", &eu), "2024-02-01T00:00:00Z");
+        assert_eq!(parse("1-2-2024", &us), "2024-01-02T00:00:00Z");
+        assert_eq!(parse("2024.02.01", &us), "2024-02-01T00:00:00Z");
+        assert_eq!(parse("2024.02.01", &eu), "2024-02-01T00:00:00Z");
+        assert_eq!(parse("2024/2/1", &eu), "2024-02-01T00:00:00Z");
+    }
+
+    /// Tests the two-digit year pivot.
+    #[test]
+    fn test_two_digit_years() {
+        let us = FlexibleOptions::new();
+        assert_eq!(parse("3/4/00", &us), "2000-03-04T00:00:00Z");
+        assert_eq!(parse("3/4/68", &us), "2068-03-04T00:00:00Z");
+        assert_eq!(parse("3/4/69", &us), "1969-03-04T00:00:00Z");
+        assert_eq!(parse("3/4/99", &us), "1999-03-04T00:00:00Z");
+        assert!(parse_flexible("3/4/999", &us).is_err());
+    }
+
+    /// Tests dates with month names.
+    #[test]
+    fn test_named_months() {
+        let options = FlexibleOptions::new();
+        for input in [
+            "Jan 2 2024",
+            "jan 2, 2024",
+            "January 2nd, 2024",
+            "2 Jan 2024",
+            "2nd January 2024",
+            "Jan. 2 24",
+        ] {
+            assert_eq!(
+                parse(input, &options),
+                "2024-01-02T00:00:00Z",
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse("Sept 30 2024", &options),
+            "2024-09-30T00:00:00Z"
+        );
+    }
+
+    /// Tests relative words against a fixed reference time.
+    #[test]
+    fn test_relative_words() -> Result<(), DateTimeError> {
+        // 2024-03-13 is a Wednesday.
+        let reference = DateTime::parse("2024-03-13T15:45:00+02:00")?;
+        let options = FlexibleOptions::new().relative_to(reference);
+
+        assert_eq!(
+            parse("today", &options),
+            "2024-03-13T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("Tomorrow", &options),
+            "2024-03-14T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("yesterday", &options),
+            "2024-03-12T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("next monday", &options),
+            "2024-03-18T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("next wed", &options),
+            "2024-03-20T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("next thursday", &options),
+            "2024-03-14T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("last friday", &options),
+            "2024-03-08T00:00:00+02:00"
+        );
+        assert_eq!(
+            parse("last wednesday", &options),
+            "2024-03-06T00:00:00+02:00"
+        );
+        Ok(())
+    }
+
+    /// Tests that strict formats still parse unchanged.
+    #[test]
+    fn test_strict_formats_pass_through() -> Result<(), DateTimeError> {
+        let options = FlexibleOptions::new();
+        let input = "2024-01-15T10:30:00+05:30";
+        assert_eq!(
+            parse_flexible(input, &options)?,
+            DateTime::parse(input)?
+        );
+        assert_eq!(
+            parse(" 2024-01-15 ", &options),
+            "2024-01-15T00:00:00Z"
+        );
+        Ok(())
+    }
+
+    /// Tests rejected inputs.
+    #[test]
+    fn test_invalid_inputs() {
+        let options = FlexibleOptions::new();
+        assert_eq!(
+            parse_flexible("Feb 30 2024", &options),
+            Err(DateTimeError::InvalidDate)
+        );
+        assert_eq!(
+            parse_flexible("13/01/2024", &options),
+            Err(DateTimeError::InvalidDate)
+        );
+        for input in
+            ["", "next", "next month", "Foo 2 2024", "1/2", "a/b/c"]
+        {
+            assert_eq!(
+                parse_flexible(input, &options),
+                Err(DateTimeError::InvalidFormat),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,437 @@
+// test_format.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `format` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::error::DateTimeError;
+    use dtt::format::{Format, FormatableDateTime};
+    use dtt::local::TomlDateTime;
+    use dtt::naive::NaiveDateTime;
+    use dtt::{Date, DateTime, Time};
+
+    fn render_all(
+        values: &[&dyn FormatableDateTime],
+        format: Format,
+    ) -> Vec<Result<String, DateTimeError>> {
+        values
+            .iter()
+            .map(|value| value.format_preset(format))
+            .collect()
+    }
+
+    #[test]
+    fn test_datetime_presets() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-02-15T10:30:00+01:00")?;
+        assert_eq!(
+            dt.format_preset(Format::Rfc3339)?,
+            "2024-02-15T10:30:00+01:00"
+        );
+        assert_eq!(
+            dt.format_preset(Format::Iso8601)?,
+            "2024-02-15T10:30:00"
+        );
+        assert_eq!(
+            dt.format_preset(Format::HttpDate)?,
+            "Thu, 15 Feb 2024 09:30:00 GMT"
+        );
+        assert_eq!(
+            dt.format_preset(Format::CalendarDate)?,
+            "2024-02-15"
+        );
+        assert_eq!(dt.format_preset(Format::OrdinalDate)?, "2024-046");
+        assert_eq!(dt.format_preset(Format::TimeOfDay)?, "10:30:00");
+        assert_eq!(
+            dt.format_preset(Format::Zoned)?,
+            "2024-02-15 10:30:00 +01:00"
+        );
+        assert_eq!(
+            dt.convert_to_tz("EST")?.format_preset(Format::Zoned)?,
+            "2024-02-15 04:30:00 EST"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_naive_presets() -> Result<(), DateTimeError> {
+        let naive: NaiveDateTime =
+            Date::from_calendar_date(2024, 2, 15)?
+                .at(Time::from_hms(10, 30, 0)?);
+        assert_eq!(
+            naive.format_preset(Format::Iso8601)?,
+            "2024-02-15T10:30:00"
+        );
+        assert_eq!(
+            naive.format_preset(Format::OrdinalDate)?,
+            "2024-046"
+        );
+        assert_eq!(naive.format_preset(Format::TimeOfDay)?, "10:30:00");
+        assert!(matches!(
+            naive.format_preset(Format::Rfc3339),
+            Err(DateTimeError::InvalidFormat)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_drops_fraction_only_for_time_of_day(
+    ) -> Result<(), DateTimeError> {
+        let time: Time = "10:30:00.25".parse()?;
+        assert_eq!(
+            time.format_preset(Format::Iso8601)?,
+            "10:30:00.250000000"
+        );
+        assert_eq!(time.format_preset(Format::TimeOfDay)?, "10:30:00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_uniform_calendar_date() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-02-15T10:30:00Z")?;
+        let date = Date::from_calendar_date(2024, 2, 15)?;
+        let toml: TomlDateTime = "2024-02-15T10:30:00".parse()?;
+        let results =
+            render_all(&[&dt, &date, &toml], Format::CalendarDate);
+        for result in results {
+            assert_eq!(result?, "2024-02-15");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_presets() -> Result<(), DateTimeError> {
+        let date = Date::from_calendar_date(2024, 2, 15)?;
+        let time = Time::from_hms(10, 30, 0)?;
+        for format in [
+            Format::Rfc3339,
+            Format::HttpDate,
+            Format::TimeOfDay,
+            Format::Zoned,
+        ] {
+            assert!(date.format_preset(format).is_err());
+        }
+        for format in [
+            Format::Rfc3339,
+            Format::HttpDate,
+            Format::CalendarDate,
+            Format::OrdinalDate,
+            Format::Zoned,
+        ] {
+            assert!(time.format_preset(format).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_references_are_formatable() -> Result<(), DateTimeError> {
+        fn iso(value: impl FormatableDateTime) -> String {
+            value.format_preset(Format::Iso8601).unwrap_or_default()
+        }
+        let date = Date::from_calendar_date(2024, 2, 15)?;
+        let dates = [date];
+        let rendered: Vec<String> = dates.iter().map(iso).collect();
+        assert_eq!(rendered, ["2024-02-15"]);
+        assert_eq!(iso(date), "2024-02-15");
+        Ok(())
+    }
+
+    mod registry_tests {
+        use super::*;
+        use dtt::format::FormatRegistry;
+
+        #[test]
+        fn test_register_lookup_unregister() {
+            let registry = FormatRegistry::new();
+            assert!(registry.lookup("audit").is_none());
+            registry.register("audit", "[year][month][day]").unwrap();
+            registry.register("audit", "[year]-[month]-[day]").unwrap();
+            registry.register("alpha", "[hour]").unwrap();
+            assert_eq!(
+                registry.lookup("audit").as_deref(),
+                Some("[year]-[month]-[day]")
+            );
+            let names: Vec<String> = registry
+                .registered()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            assert_eq!(names, ["alpha", "audit"]);
+            assert_eq!(
+                registry.unregister("audit").as_deref(),
+                Some("[year]-[month]-[day]")
+            );
+            assert!(registry.unregister("audit").is_none());
+        }
+
+        #[test]
+        fn test_register_rejects_bad_input() {
+            let registry = FormatRegistry::new();
+            for (name, description) in
+                [("", "[year]"), ("a b", "[year]"), ("bad", "[year")]
+            {
+                assert_eq!(
+                    registry.register(name, description),
+                    Err(DateTimeError::InvalidFormat)
+                );
+            }
+            assert!(registry.registered().is_empty());
+        }
+
+        #[test]
+        fn test_named_format_and_parse() -> Result<(), DateTimeError> {
+            FormatRegistry::global().register(
+                "test-audit",
+                "[year][month][day]T[hour][minute][second]",
+            )?;
+            let dt = DateTime::parse("2024-02-15T10:30:05Z")?;
+            let text = dt.format_named("test-audit")?;
+            assert_eq!(text, "20240215T103005");
+            assert_eq!(DateTime::parse_named(&text, "test-audit")?, dt);
+            assert_eq!(
+                dt.format_named("test-missing"),
+                Err(DateTimeError::InvalidFormat)
+            );
+            assert_eq!(
+                DateTime::parse_named(&text, "test-missing"),
+                Err(DateTimeError::InvalidFormat)
+            );
+            Ok(())
+        }
+    }
+
+    mod spec_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::DateTimeError;
+        use dtt::format::{CompiledFormat, FormatSpec};
+
+        #[test]
+        fn test_new_rejects_invalid_descriptions() {
+            assert_eq!(
+                FormatSpec::new("[year"),
+                Err(DateTimeError::InvalidFormat)
+            );
+            assert_eq!(
+                "[bogus]".parse::<FormatSpec>(),
+                Err(DateTimeError::InvalidFormat)
+            );
+        }
+
+        #[test]
+        fn test_round_trips_description() {
+            let spec: FormatSpec = "[hour]:[minute]".parse().unwrap();
+            assert_eq!(spec.as_str(), "[hour]:[minute]");
+            assert_eq!(spec.to_string(), "[hour]:[minute]");
+            assert_eq!(spec.clone(), spec);
+        }
+
+        #[test]
+        fn test_parse_with_spec_reports_position() {
+            let format = "[year]/[month]/[day] [hour]h";
+            let spec = FormatSpec::new(format).unwrap();
+            let dt = DateTime::parse_with_spec("2024/02/29 07h", &spec)
+                .unwrap();
+            assert_eq!(dt.to_string(), "2024-02-29T07:00:00Z");
+            assert_eq!(
+                DateTime::parse_with_spec("2024-02-29 07h", &spec),
+                Err(DateTimeError::InvalidFormatAt {
+                    index: 4,
+                    expected: "literal",
+                })
+            );
+            assert_eq!(
+                DateTime::parse_with_spec(
+                    "2024/02/29 07h extra",
+                    &spec
+                ),
+                DateTime::parse_custom_format(
+                    "2024/02/29 07h extra",
+                    format
+                )
+            );
+        }
+
+        #[test]
+        fn test_format_with_matches_format() {
+            let dt = DateTime::parse("2024-02-15T10:30:05.25+01:00")
+                .unwrap();
+            for description in [
+                "[year]-[month]-[day]",
+                "[hour]:[minute]:[second].[subsecond digits:2]",
+                "[offset_hour sign:mandatory][offset_minute]",
+            ] {
+                let compiled =
+                    CompiledFormat::new(description).unwrap();
+                assert_eq!(
+                    dt.format_with(&compiled),
+                    dt.format(description)
+                );
+            }
+        }
+
+        #[test]
+        fn test_format_with_reports_missing_components() {
+            let compiled = CompiledFormat::new("[weekday]").unwrap();
+            let dt = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+            assert_eq!(dt.format_with(&compiled).unwrap(), "Thursday");
+            assert_eq!(
+                dt.format("[year"),
+                Err(DateTimeError::InvalidFormat)
+            );
+        }
+
+        #[test]
+        fn test_cached_formats_stay_correct() {
+            // More distinct descriptions than the cache holds, revisited
+            // in a different order, from several threads.
+            let dt = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+            let descriptions: Vec<String> = (0..80)
+                .map(|i| format!("{i}:[year]-[month]-[day]"))
+                .collect();
+            let handles: Vec<_> = (0..4)
+                .map(|thread| {
+                    let descriptions = descriptions.clone();
+                    std::thread::spawn(move || {
+                        for (i, description) in
+                            descriptions.iter().enumerate().rev()
+                        {
+                            if i % 4 != thread {
+                                assert_eq!(
+                                    dt.format(description).unwrap(),
+                                    format!("{i}:2024-02-15")
+                                );
+                            }
+                        }
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            for _ in 0..3 {
+                assert_eq!(
+                    dt.format(&descriptions[0]).unwrap(),
+                    "0:2024-02-15"
+                );
+            }
+        }
+    }
+
+    mod display_tests {
+        use dtt::datetime::DateTime;
+        use dtt::format::{CompiledFormat, DisplayStyle};
+
+        fn dt(input: &str) -> DateTime {
+            DateTime::parse(input).unwrap()
+        }
+
+        #[test]
+        fn test_default_style_matches_display() {
+            let value = dt("2024-02-15T10:30:05.5-03:30");
+            assert_eq!(DisplayStyle::default(), DisplayStyle::Rfc3339);
+            assert_eq!(
+                value.display_as(DisplayStyle::default()).to_string(),
+                value.to_string()
+            );
+        }
+
+        #[test]
+        fn test_iso8601_basic() {
+            for (input, expected) in [
+                ("2024-02-15T10:30:05Z", "20240215T103005Z"),
+                (
+                    "2024-02-15T10:30:05.250+05:45",
+                    "20240215T103005.25+0545",
+                ),
+                ("0001-01-01T00:00:00-12:00", "00010101T000000-1200"),
+            ] {
+                assert_eq!(
+                    dt(input)
+                        .display_as(DisplayStyle::Iso8601Basic)
+                        .to_string(),
+                    expected
+                );
+            }
+        }
+
+        #[test]
+        fn test_date_only_uses_local_date() {
+            let value = dt("2024-12-31T23:30:00-05:00");
+            assert_eq!(
+                value.display_as(DisplayStyle::DateOnly).to_string(),
+                "2024-12-31"
+            );
+        }
+
+        #[test]
+        fn test_custom_and_padding_free_writes() {
+            let compiled =
+                CompiledFormat::new("[weekday repr:short] [hour]h")
+                    .unwrap();
+            let value = dt("2024-02-15T10:30:05Z");
+            let style = DisplayStyle::Custom(&compiled);
+            assert_eq!(
+                format!("<{}>", value.display_as(style)),
+                "<Thu 10h>"
+            );
+            assert_eq!(style, DisplayStyle::Custom(&compiled));
+        }
+
+        #[test]
+        fn test_values_outside_rfc3339_are_written() {
+            let ancient = DateTime::from_components(
+                -50,
+                1,
+                1,
+                0,
+                0,
+                0,
+                time::UtcOffset::UTC,
+            )
+            .unwrap();
+            let odd_offset = DateTime::from_components(
+                2024,
+                1,
+                1,
+                0,
+                0,
+                0,
+                time::UtcOffset::from_hms(5, 30, 15).unwrap(),
+            )
+            .unwrap();
+            for value in [ancient, odd_offset] {
+                assert_eq!(
+                    value.display_as(DisplayStyle::Rfc3339).to_string(),
+                    value.to_string()
+                );
+            }
+            assert_eq!(
+                ancient.display_as(DisplayStyle::Rfc3339).to_string(),
+                "-0050-01-01T00:00:00+00:00:00"
+            );
+            assert_eq!(
+                ancient
+                    .display_as(DisplayStyle::Iso8601Basic)
+                    .to_string(),
+                "-00500101T000000Z"
+            );
+        }
+
+        #[test]
+        fn test_custom_offset_components() {
+            let compiled = CompiledFormat::new(
+                "[hour]:[minute] [offset_hour sign:mandatory]",
+            )
+            .unwrap();
+            let value = dt("2024-02-15T10:30:05+05:30");
+            assert_eq!(
+                value
+                    .display_as(DisplayStyle::Custom(&compiled))
+                    .to_string(),
+                "10:30 +05"
+            );
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// test_holidays.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `holidays` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::holidays::{
+        add_business_days, is_business_day, Holiday, HolidayProvider,
+        Target2, UkBankHolidays, UsFederal,
+    };
+    use dtt::Date;
+    use time::Weekday;
+
+    fn date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date, DateTimeError> {
+        Date::from_calendar_date(year, month, day)
+    }
+
+    fn dates<P: HolidayProvider>(
+        provider: &P,
+        year: i32,
+    ) -> Vec<String> {
+        provider
+            .holidays(year)
+            .iter()
+            .map(|holiday| holiday.date.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_us_federal_2024() {
+        assert_eq!(
+            dates(&UsFederal, 2024),
+            [
+                "2024-01-01",
+                "2024-01-15",
+                "2024-02-19",
+                "2024-05-27",
+                "2024-06-19",
+                "2024-07-04",
+                "2024-09-02",
+                "2024-10-14",
+                "2024-11-11",
+                "2024-11-28",
+                "2024-12-25",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_us_federal_observance() -> Result<(), DateTimeError> {
+        // July 4, 2026 is a Saturday.
+        assert_eq!(
+            UsFederal.holiday_name(date(2026, 7, 3)?).as_deref(),
+            Some("Independence Day")
+        );
+        // Juneteenth 2022 is a Sunday.
+        assert!(UsFederal.is_holiday(date(2022, 6, 20)?));
+        assert!(!UsFederal.is_holiday(date(2022, 6, 19)?));
+        // January 1, 2022 is a Saturday, observed in the previous year.
+        assert!(UsFederal.is_holiday(date(2021, 12, 31)?));
+        assert_eq!(UsFederal.holidays(2022).len(), 10);
+        // Juneteenth only from 2021.
+        assert!(!UsFederal.is_holiday(date(2020, 6, 19)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_uk_bank_holidays_2024() {
+        assert_eq!(
+            dates(&UkBankHolidays, 2024),
+            [
+                "2024-01-01",
+                "2024-03-29",
+                "2024-04-01",
+                "2024-05-06",
+                "2024-05-27",
+                "2024-08-26",
+                "2024-12-25",
+                "2024-12-26",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_uk_christmas_substitutes() {
+        // Christmas on a Saturday.
+        assert!(dates(&UkBankHolidays, 2021).ends_with(&[
+            "2021-12-27".to_string(),
+            "2021-12-28".to_string()
+        ]));
+        // Christmas on a Sunday.
+        assert!(dates(&UkBankHolidays, 2022).ends_with(&[
+            "2022-12-26".to_string(),
+            "2022-12-27".to_string()
+        ]));
+        // Boxing Day on a Saturday.
+        assert!(dates(&UkBankHolidays, 2026).ends_with(&[
+            "2026-12-25".to_string(),
+            "2026-12-28".to_string()
+        ]));
+        // New Year's Day on a Sunday.
+        assert_eq!(dates(&UkBankHolidays, 2023)[0], "2023-01-02");
+    }
+
+    #[test]
+    fn test_target2_easter() -> Result<(), DateTimeError> {
+        assert_eq!(
+            Target2.holiday_name(date(2024, 3, 29)?).as_deref(),
+            Some("Good Friday")
+        );
+        assert!(Target2.is_holiday(date(2025, 4, 21)?));
+        assert!(Target2.is_holiday(date(2038, 4, 26)?));
+        assert!(Target2.is_holiday(date(2285, 3, 20)?));
+        // No weekend substitutes.
+        assert!(Target2.is_holiday(date(2022, 1, 1)?));
+        assert!(!Target2.is_holiday(date(2022, 1, 3)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_business_day() -> Result<(), DateTimeError> {
+        assert!(is_business_day(date(2024, 11, 27)?, &UsFederal));
+        assert!(!is_business_day(date(2024, 11, 28)?, &UsFederal));
+        assert!(!is_business_day(date(2024, 11, 30)?, &UsFederal));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_business_days() -> Result<(), DateTimeError> {
+        let start = date(2024, 12, 20)?;
+        assert_eq!(
+            add_business_days(start, 3, &UkBankHolidays)?.to_string(),
+            "2024-12-27"
+        );
+        assert_eq!(
+            add_business_days(
+                date(2024, 12, 27)?,
+                -3,
+                &UkBankHolidays
+            )?
+            .to_string(),
+            "2024-12-20"
+        );
+        assert_eq!(
+            add_business_days(start, 0, &UkBankHolidays)?,
+            start
+        );
+        // Crosses a year boundary.
+        assert_eq!(
+            add_business_days(date(2024, 12, 31)?, 1, &UsFederal)?
+                .to_string(),
+            "2025-01-02"
+        );
+        Ok(())
+    }
+
+    struct Closed;
+
+    impl HolidayProvider for Closed {
+        fn holidays(&self, _year: i32) -> Vec<Holiday> {
+            Vec::new()
+        }
+
+        fn is_weekend(&self, _weekday: Weekday) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_custom_provider() -> Result<(), DateTimeError> {
+        assert!(matches!(
+            add_business_days(date(2024, 1, 1)?, 1, &Closed),
+            Err(DateTimeError::InvalidDate)
+        ));
+        let provider: &dyn HolidayProvider = &UsFederal;
+        assert!(!is_business_day(date(2024, 1, 1)?, provider));
+        Ok(())
+    }
+
+    #[test]
+    fn test_datetime_methods() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-07-03T17:30:00-04:00")?;
+        assert!(!dt.is_holiday(&UsFederal));
+        assert!(dt.is_business_day(&UsFederal));
+
+        let next = dt.add_business_days(1, &UsFederal)?;
+        assert_eq!(next.to_string(), "2024-07-05T17:30:00-04:00");
+
+        let back = next.add_business_days(-1, &UsFederal)?;
+        assert_eq!(back, dt);
+        Ok(())
+    }
+}
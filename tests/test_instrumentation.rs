@@ -0,0 +1,104 @@
+// test_instrumentation.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the spans and events emitted with the `tracing` feature.
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use dtt::datetime::DateTime;
+    use std::io;
+    use std::sync::{Arc, Mutex, PoisonError};
+    use tracing::Level;
+
+    /// A writer collecting everything the subscriber prints.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Runs `f` under a debug-level subscriber and returns its output.
+    fn capture(f: impl FnOnce()) -> String {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Level::DEBUG)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        let bytes = buffer.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_parse_reports_branch_and_length() {
+        let output = capture(|| {
+            let _ = DateTime::parse("2024-02-15").unwrap();
+        });
+        assert!(output.contains("dtt::parse"), "{output}");
+        assert!(output.contains("input_len=10"), "{output}");
+        assert!(output.contains("branch=\"iso8601_date\""), "{output}");
+    }
+
+    #[test]
+    fn test_parse_failure_reports_cause() {
+        let output = capture(|| {
+            assert!(DateTime::parse("not a date").is_err());
+        });
+        assert!(
+            output.contains("no parser branch matched"),
+            "{output}"
+        );
+        assert!(output.contains("operation=\"parse\""), "{output}");
+        assert!(output.contains("Invalid date format"), "{output}");
+    }
+
+    #[test]
+    fn test_format_failure_reports_cause() {
+        let dt = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+        let output = capture(|| {
+            assert!(dt.format("[year").is_err());
+        });
+        assert!(output.contains("dtt::format"), "{output}");
+        assert!(
+            output.contains("invalid format description"),
+            "{output}"
+        );
+        assert!(output.contains("operation=\"format\""), "{output}");
+    }
+
+    #[test]
+    fn test_conversion_failure_names_timezone() {
+        let dt = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+        let output = capture(|| {
+            assert!(dt.convert_to_tz("Nowhere").is_err());
+        });
+        assert!(output.contains("timezone=\"Nowhere\""), "{output}");
+        assert!(
+            output.contains("operation=\"convert_to_tz\""),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_success_emits_no_failure_event() {
+        let output = capture(|| {
+            let dt = DateTime::parse("2024-02-15T10:30:05Z").unwrap();
+            let _ = dt.convert_to_tz("JST").unwrap();
+        });
+        assert!(!output.contains("dtt operation failed"), "{output}");
+    }
+}
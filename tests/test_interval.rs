@@ -0,0 +1,205 @@
+// test_interval.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `interval` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::interval::{DateTimeInterval, IntervalSet};
+    use time::Duration;
+
+    fn interval(
+        start: &str,
+        end: &str,
+    ) -> Result<DateTimeInterval, DateTimeError> {
+        DateTimeInterval::new(
+            DateTime::parse(start)?,
+            DateTime::parse(end)?,
+        )
+    }
+
+    #[test]
+    fn test_new_rejects_reversed_endpoints() {
+        assert!(matches!(
+            interval("2024-01-02T00:00:00Z", "2024-01-01T00:00:00Z"),
+            Err(DateTimeError::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn test_endpoints_compare_instants() -> Result<(), DateTimeError> {
+        // 23:00 at -05:00 is 04:00 UTC the next day, after the end.
+        assert!(interval(
+            "2024-01-01T23:00:00-05:00",
+            "2024-01-02T03:00:00Z"
+        )
+        .is_err());
+        let span = interval(
+            "2024-01-01T23:00:00-05:00",
+            "2024-01-02T06:00:00Z",
+        )?;
+        assert_eq!(span.duration(), Duration::hours(2));
+        assert!(
+            span.contains(&DateTime::parse("2024-01-02T05:00:00Z")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_duration() -> Result<(), DateTimeError> {
+        let start = DateTime::parse("2024-01-01T09:00:00Z")?;
+        let span =
+            DateTimeInterval::with_duration(start, Duration::HOUR)?;
+        assert_eq!(span.end().to_string(), "2024-01-01T10:00:00Z");
+        assert!(DateTimeInterval::with_duration(
+            start,
+            -Duration::HOUR
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains_is_half_open() -> Result<(), DateTimeError> {
+        let span =
+            interval("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z")?;
+        assert!(span.contains(&span.start()));
+        assert!(!span.contains(&span.end()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_touching_intervals() -> Result<(), DateTimeError> {
+        let a =
+            interval("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z")?;
+        let b =
+            interval("2024-01-02T00:00:00Z", "2024-01-03T00:00:00Z")?;
+        assert!(!a.overlaps(&b));
+        assert_eq!(a.intersection(&b), None);
+        let joined = a.union(&b).ok_or(DateTimeError::InvalidDate)?;
+        assert_eq!(joined.duration(), Duration::days(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_disjoint_union_is_none() -> Result<(), DateTimeError> {
+        let a =
+            interval("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z")?;
+        let b =
+            interval("2024-01-03T00:00:00Z", "2024-01-04T00:00:00Z")?;
+        assert_eq!(a.union(&b), None);
+        assert_eq!(b.union(&a), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_intersection() -> Result<(), DateTimeError> {
+        let outer =
+            interval("2024-01-01T00:00:00Z", "2024-01-10T00:00:00Z")?;
+        let inner =
+            interval("2024-01-03T00:00:00Z", "2024-01-04T00:00:00Z")?;
+        assert_eq!(outer.intersection(&inner), Some(inner));
+        assert_eq!(outer.union(&inner), Some(outer));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_steps() -> Result<(), DateTimeError> {
+        let span =
+            interval("2024-01-01T09:00:00Z", "2024-01-01T10:00:00Z")?;
+        let slots: Vec<String> = span
+            .iter(Duration::minutes(25))
+            .map(|dt| dt.to_string())
+            .collect();
+        assert_eq!(
+            slots,
+            [
+                "2024-01-01T09:00:00Z",
+                "2024-01-01T09:25:00Z",
+                "2024-01-01T09:50:00Z"
+            ]
+        );
+        assert_eq!(span.iter(Duration::ZERO).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<(), DateTimeError> {
+        let span = interval(
+            "2024-01-01T00:00:00Z",
+            "2024-01-02T00:00:00+01:00",
+        )?;
+        assert_eq!(
+            span.to_string(),
+            "2024-01-01T00:00:00Z/2024-01-02T00:00:00+01:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_merges_overlapping_intervals(
+    ) -> Result<(), DateTimeError> {
+        let set: IntervalSet = [
+            interval("2024-01-01T12:00:00Z", "2024-01-01T13:00:00Z")?,
+            interval("2024-01-01T08:00:00Z", "2024-01-01T09:00:00Z")?,
+            interval("2024-01-01T10:00:00Z", "2024-01-01T11:00:00Z")?,
+            interval("2024-01-01T08:30:00Z", "2024-01-01T10:30:00Z")?,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.intervals(),
+            [
+                interval(
+                    "2024-01-01T08:00:00Z",
+                    "2024-01-01T11:00:00Z"
+                )?,
+                interval(
+                    "2024-01-01T12:00:00Z",
+                    "2024-01-01T13:00:00Z"
+                )?,
+            ]
+        );
+        assert_eq!(set.total_duration(), Duration::hours(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_ignores_empty_intervals() -> Result<(), DateTimeError> {
+        let mut set = IntervalSet::new();
+        set.insert(interval(
+            "2024-01-01T08:00:00Z",
+            "2024-01-01T08:00:00Z",
+        )?);
+        assert!(set.is_empty());
+        assert_eq!(set.total_duration(), Duration::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_queries() -> Result<(), DateTimeError> {
+        let mut set = IntervalSet::new();
+        set.extend([
+            interval("2024-01-01T08:00:00Z", "2024-01-01T09:00:00Z")?,
+            interval("2024-01-01T10:00:00Z", "2024-01-01T11:00:00Z")?,
+        ]);
+        assert_eq!(set.len(), 2);
+        assert!(
+            !set.contains(&DateTime::parse("2024-01-01T09:30:00Z")?)
+        );
+        assert!(set.overlaps(&interval(
+            "2024-01-01T09:30:00Z",
+            "2024-01-01T10:30:00Z"
+        )?));
+        assert!(!set.overlaps(&interval(
+            "2024-01-01T09:00:00Z",
+            "2024-01-01T10:00:00Z"
+        )?));
+        assert_eq!((&set).into_iter().count(), 2);
+        Ok(())
+    }
+}
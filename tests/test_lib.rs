@@ -18,29 +18,59 @@ mod tests {
 
     /// Tests the main `run` function of the library.
     ///
-    /// This test ensures that the `run` function executes correctly when not in test mode.
+    /// This test ensures that the self-test passes and reports the
+    /// library version and timezone data.
     #[test]
     fn test_run_success() {
-        std::env::set_var("DTT_TEST_MODE", "0");
         let result = run();
         assert!(result.is_ok());
+        if let Ok(report) = result {
+            assert_eq!(report.version, env!("CARGO_PKG_VERSION"));
+            assert!(report.timezones.contains(&"UTC"));
+            assert!(report.clock.is_ok());
+        }
     }
 
-    /// Tests the main `run` function of the library in test mode.
+    /// Tests that `run` does not depend on `DTT_TEST_MODE`.
     ///
-    /// This test ensures that the `run` function returns an error when in test mode.
+    /// Test mode is a feature of the binary; the library must not read
+    /// environment variables.
     #[test]
-    fn test_run_test_mode_error() {
+    fn test_run_ignores_test_mode() {
         std::env::set_var("DTT_TEST_MODE", "1");
-        let result = run();
+        assert!(run().is_ok());
+    }
+
+    /// Tests the structured self-test report.
+    #[test]
+    fn test_self_test_report() {
+        use dtt::selftest::self_test;
+        let report = self_test();
+        assert!(report.is_healthy());
+        assert_eq!(report.failure(), None);
+        let text = report.to_string();
+        assert!(text.contains("version: "));
+        assert!(text.contains("clock monotonic: ok"));
+    }
+
+    /// Tests that the clock check reads the given clock.
+    #[test]
+    fn test_clock_check_with_clock() {
+        use dtt::clock::FixedClock;
+        use dtt::datetime::DateTime;
+        use dtt::selftest::ClockCheck;
+
+        let unset = DateTime::parse("1970-01-01T00:00:00Z").unwrap();
+        let check = ClockCheck::run_with_clock(&FixedClock::new(unset));
+        assert_eq!(check.now, unset);
+        assert!(!check.plausible);
+        assert!(check.monotonic);
+        assert!(!check.is_ok());
+
+        let set = DateTime::parse("2025-06-01T00:00:00Z").unwrap();
         assert!(
-            result.is_err(),
-            "Expected `run` to return an error in test mode"
+            ClockCheck::run_with_clock(&FixedClock::new(set)).is_ok()
         );
-
-        if let Err(err) = result {
-            assert_eq!(err.to_string(), "Simulated error");
-        }
     }
 
     /// Tests the `datetime` module's functionality.
@@ -76,16 +106,6 @@ mod tests {
     //     assert_eq!(date, "2023-01-01");
     // }
 
-    /// Tests the environment variable behavior in `run`.
-    ///
-    /// This test ensures that the environment variable `DTT_TEST_MODE` is correctly read and used by the `run` function.
-    #[test]
-    fn test_env_var_handling() {
-        std::env::set_var("DTT_TEST_MODE", "0");
-        let result = run();
-        assert!(result.is_ok());
-    }
-
     /// Tests that the library's metadata is correct.
     ///
     /// This test checks that the library's crate name, type, and documentation URLs are correctly set.
@@ -0,0 +1,80 @@
+// test_limits.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `limits` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::{DateTime, MAX_REAL_OFFSET_SECONDS};
+    use dtt::limits::{self, is_representable};
+    use time::UtcOffset;
+
+    /// Tests that the limits agree with the validators.
+    #[test]
+    fn test_limits_match_validators() {
+        assert!(DateTime::is_valid_hour_u8(limits::MAX_HOUR));
+        assert!(!DateTime::is_valid_hour_u8(limits::MAX_HOUR + 1));
+        assert!(DateTime::is_valid_minute_u8(limits::MAX_MINUTE));
+        assert!(!DateTime::is_valid_second_u8(limits::MAX_SECOND + 1));
+        assert!(DateTime::is_valid_month_u8(limits::MIN_MONTH));
+        assert!(!DateTime::is_valid_month_u8(limits::MIN_MONTH - 1));
+        assert!(DateTime::is_valid_ordinal_u16(
+            limits::MAX_ORDINAL_DAY
+        ));
+        assert!(!DateTime::is_valid_iso_week_u8(
+            limits::MAX_ISO_WEEK + 1
+        ));
+        assert!(DateTime::is_valid_microsecond_u32(
+            limits::MAX_MICROSECOND
+        ));
+        assert_eq!(limits::MAX_OFFSET_SECONDS, MAX_REAL_OFFSET_SECONDS);
+    }
+
+    /// Tests that the year limits match the parser.
+    #[test]
+    fn test_year_limits_match_parser() {
+        assert!(DateTime::from_components(
+            limits::MAX_YEAR,
+            12,
+            31,
+            0,
+            0,
+            0,
+            UtcOffset::UTC
+        )
+        .is_ok());
+        assert!(DateTime::from_components(
+            limits::MAX_YEAR + 1,
+            1,
+            1,
+            0,
+            0,
+            0,
+            UtcOffset::UTC
+        )
+        .is_err());
+        assert!(DateTime::validate_components(
+            limits::MIN_YEAR - 1,
+            1,
+            1,
+            0,
+            0,
+            0
+        )
+        .is_err());
+    }
+
+    /// Tests whole-date representability.
+    #[test]
+    fn test_is_representable() {
+        assert!(is_representable(2024, 2, 29));
+        assert!(!is_representable(2023, 2, 29));
+        assert!(!is_representable(2024, 0, 1));
+        assert!(!is_representable(2024, 1, 0));
+        assert!(!is_representable(2024, 13, 1));
+        assert!(is_representable(limits::MIN_YEAR, 1, 1));
+        assert!(!is_representable(limits::MAX_YEAR + 1, 1, 1));
+    }
+}
@@ -0,0 +1,136 @@
+// test_local.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `local` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::local::{LocalDate, LocalTime, TomlDateTime};
+    use serde::{Deserialize, Serialize};
+    use time::macros::{date, time};
+
+    /// A config struct mixing every datetime kind.
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "dtt::local::rfc3339")]
+        released: DateTime,
+        birthday: LocalDate,
+        alarm: LocalTime,
+        any: TomlDateTime,
+    }
+
+    /// Tests parsing each of the four TOML datetime kinds.
+    #[test]
+    fn test_parse_kinds() -> Result<(), DateTimeError> {
+        let offset: TomlDateTime =
+            "1979-05-27T00:32:00.999999-07:00".parse()?;
+        match offset {
+            TomlDateTime::OffsetDateTime(dt) => {
+                assert_eq!(dt.offset().whole_hours(), -7);
+                assert_eq!(dt.microsecond(), 999_999);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        let local: TomlDateTime = "1979-05-27 07:32:00".parse()?;
+        assert_eq!(local.to_string(), "1979-05-27T07:32:00");
+        assert!(matches!(local, TomlDateTime::LocalDateTime(_)));
+
+        let date: TomlDateTime = "1979-05-27".parse()?;
+        assert_eq!(
+            date,
+            TomlDateTime::LocalDate(LocalDate::new(date!(
+                1979 - 05 - 27
+            )))
+        );
+
+        let time: TomlDateTime = "00:32:00.5".parse()?;
+        assert_eq!(
+            time,
+            TomlDateTime::LocalTime(LocalTime::new(time!(00:32:00.5)))
+        );
+        assert_eq!(time.to_string(), "00:32:00.500000000");
+        Ok(())
+    }
+
+    /// Tests rejected inputs.
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            "1979-02-30".parse::<TomlDateTime>(),
+            Err(DateTimeError::InvalidDate)
+        );
+        assert_eq!(
+            "25:00:00".parse::<TomlDateTime>(),
+            Err(DateTimeError::InvalidTime)
+        );
+        assert!("1979-05-27T07:32".parse::<TomlDateTime>().is_err());
+        assert!("".parse::<TomlDateTime>().is_err());
+    }
+
+    /// Tests a config struct round trip through serde.
+    #[test]
+    fn test_config_round_trip() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let json = r#"{
+            "released": "1979-05-27T07:32:00-08:00",
+            "birthday": "1979-05-27",
+            "alarm": "07:30:00",
+            "any": "1979-05-27T07:32:00"
+        }"#;
+        let config: Config = serde_json::from_str(json)?;
+        assert_eq!(config.released.hour(), 7);
+        assert_eq!(config.birthday.to_date(), date!(1979 - 05 - 27));
+        assert_eq!(config.alarm.to_time(), time!(07:30));
+
+        let back: Config =
+            serde_json::from_str(&serde_json::to_string(&config)?)?;
+        assert_eq!(back, config);
+        assert_eq!(
+            serde_json::to_value(&config)?["released"],
+            "1979-05-27T07:32:00-08:00"
+        );
+        Ok(())
+    }
+
+    /// Tests the map form `toml` uses to mark datetime values.
+    #[test]
+    fn test_deserialize_toml_marker_map(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let value: TomlDateTime = serde_json::from_str(
+            r#"{"$__toml_private_datetime": "1979-05-27T07:32:00Z"}"#,
+        )?;
+        assert_eq!(value.to_string(), "1979-05-27T07:32:00Z");
+        Ok(())
+    }
+
+    /// Tests that the `rfc3339` adapter requires an offset.
+    #[test]
+    fn test_rfc3339_requires_offset() {
+        #[derive(Debug, Deserialize)]
+        struct Event {
+            #[serde(with = "dtt::local::rfc3339")]
+            #[allow(dead_code)]
+            at: DateTime,
+        }
+        let error = serde_json::from_str::<Event>(
+            r#"{"at": "1979-05-27T07:32:00"}"#,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected a datetime with an offset"));
+    }
+
+    /// Tests combining a date and a time.
+    #[test]
+    fn test_and_time() {
+        let naive = LocalDate::from(date!(2024 - 01 - 15))
+            .at(LocalTime::from(time!(12:00)));
+        assert_eq!(naive.to_string(), "2024-01-15T12:00:00");
+    }
+}
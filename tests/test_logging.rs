@@ -0,0 +1,108 @@
+// test_logging.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `logging` module, run with the `tracing` feature.
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::format::{Format, FormatRegistry};
+    use dtt::logging::DttTimer;
+    use time::UtcOffset;
+    use tracing_subscriber::fmt::{format::Writer, time::FormatTime};
+
+    fn sample() -> DateTime {
+        DateTime::parse("2024-02-15T10:30:05Z").unwrap()
+    }
+
+    #[test]
+    fn test_default_is_rfc3339_utc() {
+        let timer = DttTimer::default();
+        assert_eq!(timer, DttTimer::new());
+        assert_eq!(
+            timer.render(&sample()).unwrap(),
+            "2024-02-15T10:30:05Z"
+        );
+    }
+
+    #[test]
+    fn test_presets_and_zones() -> Result<(), DateTimeError> {
+        let timer = DttTimer::new()
+            .with_format(Format::TimeOfDay)
+            .with_timezone("JST")?;
+        assert_eq!(timer.render(&sample())?, "19:30:05");
+
+        let timer = timer.with_format(Format::Zoned);
+        assert_eq!(timer.render(&sample())?, "2024-02-15 19:30:05 JST");
+        assert_eq!(
+            timer.with_offset(UtcOffset::UTC).render(&sample())?,
+            "2024-02-15 10:30:05 +00:00"
+        );
+
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let timer = DttTimer::new().with_offset(offset);
+        assert_eq!(
+            timer.render(&sample())?,
+            "2024-02-15T05:30:05-05:00"
+        );
+
+        assert_eq!(
+            DttTimer::new().with_timezone("Nowhere"),
+            Err(DateTimeError::InvalidTimezone)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_descriptions() -> Result<(), DateTimeError> {
+        let timer = DttTimer::new()
+            .with_description("[day]/[month] [hour]h")?;
+        assert_eq!(timer.render(&sample())?, "15/02 10h");
+        assert_eq!(
+            DttTimer::new().with_description("[day"),
+            Err(DateTimeError::InvalidFormat)
+        );
+
+        FormatRegistry::global()
+            .register("log-test", "[year][month][day]")?;
+        let timer = DttTimer::new().with_named_format("log-test")?;
+        assert_eq!(timer.render(&sample())?, "20240215");
+        assert_eq!(
+            DttTimer::new().with_named_format("log-missing"),
+            Err(DateTimeError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_time_writes_current_time() {
+        let timer = DttTimer::new().with_format(Format::CalendarDate);
+        let mut output = String::new();
+        timer.format_time(&mut Writer::new(&mut output)).unwrap();
+        let today = DateTime::now_utc().date_only().to_string();
+        assert_eq!(output, today);
+    }
+
+    #[test]
+    fn test_format_time_reads_the_clock() -> Result<(), DateTimeError> {
+        use dtt::clock::MockClock;
+
+        let clock = MockClock::new(sample());
+        let timer = DttTimer::new()
+            .with_format(Format::Zoned)
+            .with_timezone("JST")?
+            .with_clock(&clock);
+        let mut output = String::new();
+        timer.format_time(&mut Writer::new(&mut output)).unwrap();
+        assert_eq!(output, "2024-02-15 19:30:05 JST");
+
+        let _ = clock.advance(time::Duration::minutes(90))?;
+        output.clear();
+        timer.format_time(&mut Writer::new(&mut output)).unwrap();
+        assert_eq!(output, "2024-02-15 21:00:05 JST");
+        Ok(())
+    }
+}
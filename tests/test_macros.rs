@@ -11,8 +11,7 @@
 #[cfg(test)]
 mod tests {
     use dtt::*;
-    use paste::paste;
-    use std::{collections::HashMap, panic};
+    use std::collections::HashMap;
     use time::Month;
 
     #[test]
@@ -104,6 +103,41 @@ mod tests {
         assert_eq!(dt.offset().to_string(), "+01:00:00");
     }
 
+    #[test]
+    fn test_dtt_now_tz() {
+        let dt = dtt_now_tz!("JST").unwrap();
+        assert_eq!(dt.offset().whole_hours(), 9);
+        assert_eq!(
+            dtt_now_tz!("Nowhere"),
+            Err(error::DateTimeError::InvalidTimezone)
+        );
+    }
+
+    #[test]
+    fn test_dtt_today() {
+        let today = dtt_today!();
+        let now = dtt_now!();
+        assert_eq!(today.offset(), time::UtcOffset::UTC);
+        assert_eq!(
+            (today.hour(), today.minute(), today.second()),
+            (0, 0, 0)
+        );
+        assert_eq!(today.nanosecond(), 0);
+        assert!(today <= now);
+        assert!(now.duration_since(&today) < time::Duration::days(1));
+    }
+
+    #[test]
+    fn test_dtt_today_with_clock() {
+        let clock = clock::FixedClock::new(
+            DateTime::parse("2024-03-10T23:45:00-05:00").unwrap(),
+        );
+        assert_eq!(
+            dtt_today!(&clock).to_string(),
+            "2024-03-10T00:00:00-05:00"
+        );
+    }
+
     #[test]
     fn test_dtt_add_days() {
         let dt = dtt_parse!("2023-01-01T12:00:00+00:00")
@@ -124,18 +158,28 @@ mod tests {
 
     #[test]
     fn test_dtt_diff_seconds() {
-        let dt1 = "1609459200"; // 2021-01-01 00:00:00 UTC
-        let dt2 = "1609459230"; // 2021-01-01 00:00:30 UTC
-        let seconds_difference = dtt_diff_seconds!(dt1, dt2);
-        assert_eq!(seconds_difference, 30i64);
+        let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+        let dt2 = dtt_parse!("2021-01-01T00:00:30Z").unwrap();
+        assert_eq!(dtt_diff_seconds!(dt1, dt2), Ok(30));
+        assert_eq!(dtt_diff_seconds!(dt2, dt1), Ok(30));
     }
 
     #[test]
     fn test_dtt_diff_days() {
-        let dt1 = "1609459200"; // 2021-01-01 00:00:00 UTC
-        let dt2 = "1609545600"; // 2021-01-02 00:00:00 UTC
-        let days_difference = dtt_diff_days!(dt1, dt2);
-        assert_eq!(days_difference, 1i64);
+        let dt1 = dtt_parse!("2021-01-01T00:00:00Z").unwrap();
+        let dt2 = dtt_parse!("2021-01-02T23:59:59Z").unwrap();
+        assert_eq!(dtt_diff_days!(dt1, dt2), Ok(1));
+    }
+
+    #[test]
+    fn test_dtt_diff_units() {
+        let dt1 = dtt_parse!("2024-01-01T00:00:00Z").unwrap();
+        let dt2 = dtt_parse!("2024-03-15T13:45:00+01:00").unwrap();
+        assert_eq!(dtt_diff_minutes!(dt1, dt2), Ok(107_325));
+        assert_eq!(dtt_diff_hours!(dt1, dt2), Ok(1788));
+        assert_eq!(dtt_diff_weeks!(dt1, dt2), Ok(10));
+        assert_eq!(dtt_diff!(dt1, dt2, months), Ok(2));
+        assert_eq!(dtt_diff!(dt2, dt1, years), Ok(0));
     }
 
     #[test]
@@ -177,14 +221,38 @@ mod tests {
         dtt_is_valid_function!(minute, u8);
         assert!(is_valid_minute("59"));
         assert!(!is_valid_minute("60"));
+
+        dtt_is_valid_function!(iso_week);
+        assert!(is_valid_iso_week("53"));
+        assert!(!is_valid_iso_week("0"));
+
+        dtt_is_valid_function!(ordinal, u16);
+        assert!(is_valid_ordinal("366"));
+        assert!(!is_valid_ordinal("367"));
+    }
+
+    #[test]
+    fn test_is_valid_expression() {
+        assert!(is_valid!(microsecond, "999999"));
+        assert!(!is_valid!(nanosecond, "1000000000"));
+        assert!(is_valid!(time, "00:00:00"));
+        assert!(!is_valid!(offset, "+14:30"));
+        assert!(is_valid!(year, "-9999"));
+    }
+
+    #[test]
+    fn test_is_valid_generated_function() {
+        is_valid!(fn is_valid_offset);
+        assert!(is_valid_offset("Z"));
+        assert!(!is_valid_offset("ZZ"));
     }
 
     #[test]
     fn test_dtt_clone() {
         let dt = dtt_parse!("2023-01-01T12:00:00+00:00").unwrap();
         let cloned = dtt_clone!(dt);
-        assert_eq!(dt.datetime, cloned.datetime);
-        assert_eq!(dt.offset, cloned.offset);
+        assert_eq!(dt.to_primitive(), cloned.to_primitive());
+        assert_eq!(dt.offset(), cloned.offset());
 
         // We can still test the individual components
         assert_eq!(dt.year(), cloned.year());
@@ -195,14 +263,6 @@ mod tests {
         assert_eq!(dt.second(), cloned.second());
     }
 
-    #[test]
-    #[should_panic(expected = "Error: Invalid input")]
-    fn test_dtt_diff_days_error() {
-        let dt1 = "invalid";
-        let dt2 = "1641081600";
-        let _ = dtt_diff_days!(dt1, dt2);
-    }
-
     #[test]
     fn test_dtt_format_complex() {
         let dt =
@@ -234,4 +294,96 @@ mod tests {
         assert_eq!(dt.month(), Month::January);
         assert_eq!(dt.day(), 1);
     }
+
+    #[test]
+    fn test_dtt_duration_macro() {
+        assert_eq!(
+            dtt_duration!("2h 15m"),
+            Ok(time::Duration::minutes(135))
+        );
+        assert_eq!(
+            dtt_duration!("P1DT2H"),
+            Ok(time::Duration::hours(26))
+        );
+        assert!(dtt_duration!("2 fortnights").is_err());
+    }
+
+    #[test]
+    fn test_dtt_shift_macro() {
+        let dt = dtt_parse!("2024-01-31T17:45:00Z").unwrap();
+        assert_eq!(dtt_shift!(dt), Ok(dt));
+        assert_eq!(
+            dtt_shift!(dt, +1 month, -2 days, at 09:00)
+                .unwrap()
+                .to_string(),
+            "2024-02-27T09:00:00Z"
+        );
+        assert_eq!(
+            dtt_shift!(dt, -1 year, +1 week, at 23:59:30, +30 seconds)
+                .unwrap()
+                .to_string(),
+            "2023-02-08T00:00:00Z"
+        );
+        assert_eq!(
+            dtt_shift!(dt, +6 hours, -15 minutes, +1 day)
+                .unwrap()
+                .to_string(),
+            "2024-02-01T23:30:00Z"
+        );
+
+        let months = 2;
+        assert_eq!(
+            dtt_shift!(dt, +(months) months, -(months) months,),
+            dt.add_months(2).and_then(|dt| dt.add_months(-2))
+        );
+        assert!(dtt_shift!(dt, at 24:00).is_err());
+        assert!(dtt_shift!(dt, +8000 years).is_err());
+    }
+
+    #[test]
+    fn test_dtt_interval_macro() {
+        use dtt::interval::DateTimeInterval;
+
+        let expected = DateTimeInterval::new(
+            dtt_parse!("2024-01-01").unwrap(),
+            dtt_parse!("2024-02-01").unwrap(),
+        );
+        assert_eq!(dtt_interval!("2024-01-01".."2024-02-01"), expected);
+        assert_eq!(dtt_interval!((2024, 1, 1)..(2024, 2, 1)), expected);
+        assert_eq!(
+            dtt_interval!("2024-01-01", &String::from("2024-02-01"),),
+            expected
+        );
+
+        let shift = dtt_interval!(
+            (2024, 3, 1, 9, 0, 0).."2024-03-01T17:00:00Z"
+        )
+        .unwrap();
+        assert_eq!(shift.duration(), time::Duration::hours(8));
+
+        assert_eq!(
+            dtt_interval!("2024-02-01".."2024-01-01"),
+            Err(error::DateTimeError::InvalidDate)
+        );
+        assert!(dtt_interval!((2023, 2, 29)..(2023, 3, 1)).is_err());
+        assert!(dtt_interval!("soon".."later").is_err());
+    }
+
+    #[test]
+    fn test_dtt_range_macro() {
+        let start = dtt_parse!("2024-01-01").unwrap();
+        let end = dtt_parse!("2024-01-31T23:59:59Z").unwrap();
+        assert_eq!(
+            dtt_range!((2024, 1, 1)..=(2024, 1, 31, 23, 59, 59)),
+            Ok(start..=end)
+        );
+        assert_eq!(
+            dtt_range!("2024-01-01".."2024-01-31T23:59:59Z"),
+            Ok(start..end)
+        );
+        assert!(dtt_range!("2024-01-31".."2024-01-01")
+            .unwrap()
+            .is_empty());
+        assert!(dtt_range!("2024-01-01"..="never").is_err());
+    }
 }
@@ -95,4 +95,415 @@ mod tests {
         run_and_verify_test_mode(true, true)?;
         Ok(())
     }
+
+    /// Runs the `dtt` binary with arguments and returns its exit code,
+    /// stdout, and stderr.
+    fn dtt(
+        args: &[&str],
+    ) -> Result<(i32, String, String), Box<dyn std::error::Error>> {
+        let output = Command::cargo_bin("dtt")?
+            .args(args)
+            .env_remove("DTT_TEST_MODE")
+            .output()?;
+        Ok((
+            output.status.code().unwrap_or(-1),
+            String::from_utf8(output.stdout)?,
+            String::from_utf8(output.stderr)?,
+        ))
+    }
+
+    #[test]
+    fn test_parse_command() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&["parse", "Jan 2 2024"])?;
+        assert_eq!(code, 0);
+        assert_eq!(stdout, "2024-01-02T00:00:00Z\n");
+
+        let (_, stdout, _) =
+            dtt(&["parse", "2024-01-02T12:00:00Z", "--tz", "CET"])?;
+        assert_eq!(stdout, "2024-01-02T13:00:00+01:00\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_command() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, stdout, _) =
+            dtt(&["add", "2024-01-31T00:00:00Z", "1mo"])?;
+        assert_eq!(stdout, "2024-02-29T00:00:00Z\n");
+
+        let (_, stdout, _) =
+            dtt(&["add", "2024-01-01T00:00:00Z", "-2h"])?;
+        assert_eq!(stdout, "2023-12-31T22:00:00Z\n");
+
+        let (code, _, stderr) =
+            dtt(&["add", "2024-01-01T00:00:00Z", "3x"])?;
+        assert_eq!(code, 64);
+        assert!(stderr.contains("unknown unit"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_command() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, stdout, _) = dtt(&[
+            "diff",
+            "2024-01-01T00:00:00Z",
+            "2024-02-02T03:00:00Z",
+        ])?;
+        assert_eq!(stdout, "1 month, 1 day, 3 hours\n");
+
+        let (_, stdout, _) = dtt(&[
+            "diff",
+            "2024-01-01T00:00:00Z",
+            "2024-01-01T01:00:00Z",
+            "--json",
+        ])?;
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert_eq!(value["seconds"], 3600);
+        assert_eq!(value["delta"]["hours"], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_command() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, stdout, _) = dtt(&[
+            "fmt",
+            "2024-02-15T10:30:00+01:00",
+            "--format",
+            "http",
+        ])?;
+        assert_eq!(stdout, "Thu, 15 Feb 2024 09:30:00 GMT\n");
+
+        let (_, stdout, _) = dtt(&[
+            "fmt",
+            "2024-02-15T10:30:00Z",
+            "--format=[day]/[month]/[year]",
+        ])?;
+        assert_eq!(stdout, "15/02/2024\n");
+
+        let (_, stdout, _) = dtt(&[
+            "fmt",
+            "2024-02-15T10:30:00Z",
+            "--tz",
+            "PST",
+            "--format",
+            "zoned",
+        ])?;
+        assert_eq!(stdout, "2024-02-15 02:30:00 PST\n");
+
+        let (code, _, _) = dtt(&["fmt", "2024-02-15"])?;
+        assert_eq!(code, 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_now_json() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&["now", "--tz", "JST", "--json"])?;
+        assert_eq!(code, 0);
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert_eq!(value["offset"], "+09:00:00");
+        assert!(value["unix_timestamp"].is_i64());
+        Ok(())
+    }
+
+    #[test]
+    fn test_usage_errors() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(dtt(&["frobnicate"])?.0, 64);
+        assert_eq!(dtt(&["now", "--bogus"])?.0, 64);
+        assert_eq!(dtt(&["parse"])?.0, 64);
+        assert_eq!(dtt(&["now", "extra"])?.0, 64);
+        assert_eq!(dtt(&["parse", "not a date"])?.0, 65);
+
+        let (code, stdout, _) = dtt(&["--help"])?;
+        assert_eq!(code, 0);
+        assert!(stdout.starts_with("Usage: dtt"));
+        let (_, stdout, _) = dtt(&["--version"])?;
+        assert!(stdout.starts_with("dtt "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) =
+            dtt(&["convert", "2024-07-01T09:00:00Z", "--to", "PST"])?;
+        assert_eq!(code, 0);
+        assert_eq!(stdout, "2024-07-01T01:00:00-08:00\n");
+
+        let (_, stdout, _) = dtt(&[
+            "convert",
+            "2024-07-01T09:00:00Z",
+            "--to=UTC+05:30",
+            "--format",
+            "time",
+        ])?;
+        assert_eq!(stdout, "14:30:00\n");
+
+        assert_eq!(dtt(&["convert", "2024-07-01"])?.0, 64);
+        assert_eq!(dtt(&["now", "--to", "PST"])?.0, 64);
+        assert_eq!(
+            dtt(&[
+                "convert",
+                "2024-07-01",
+                "--to",
+                "PST",
+                "--tz",
+                "EST"
+            ])?
+            .0,
+            64
+        );
+        assert_eq!(
+            dtt(&["convert", "2024-07-01", "--to", "Nowhere"])?.0,
+            65
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_json_components(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&[
+            "parse",
+            "2024-12-30T08:15:30.5+02:00",
+            "--output",
+            "json",
+        ])?;
+        assert_eq!(code, 0);
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert_eq!(value["year"], 2024);
+        assert_eq!(value["month"], 12);
+        assert_eq!(value["day"], 30);
+        assert_eq!(value["hour"], 8);
+        assert_eq!(value["second"], 30);
+        assert_eq!(value["nanosecond"], 500_000_000);
+        assert_eq!(value["weekday"], "Monday");
+        assert_eq!(value["iso_week"], 1);
+        assert_eq!(value["ordinal"], 365);
+        assert_eq!(value["unix_timestamp"], 1_735_539_330);
+        assert_eq!(value["offset"], "+02:00:00");
+
+        let (_, stdout, _) =
+            dtt(&["parse", "2024-01-01", "--output=text"])?;
+        assert!(stdout.starts_with("2024-01-01T00:00:00"));
+        assert_eq!(dtt(&["now", "--output", "xml"])?.0, 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_epoch() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&["epoch", "1700000000"])?;
+        assert_eq!(code, 0);
+        assert_eq!(stdout, "2023-11-14T22:13:20Z\n");
+
+        let (_, stdout, _) =
+            dtt(&["epoch", "-1500", "--unit", "ms", "--tz", "CET"])?;
+        assert_eq!(stdout, "1970-01-01T00:59:58.5+01:00\n");
+
+        let (_, stdout, _) = dtt(&["epoch", "2023-11-14T22:13:20Z"])?;
+        assert_eq!(stdout, "1700000000\n");
+        let (_, stdout, _) = dtt(&[
+            "epoch",
+            "1969-12-31T23:59:59.9999995Z",
+            "--unit=us",
+        ])?;
+        assert_eq!(stdout, "-1\n");
+
+        let (_, stdout, _) = dtt(&[
+            "epoch",
+            "9999-12-31T00:00:00Z",
+            "--unit",
+            "ns",
+            "--json",
+        ])?;
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert_eq!(value["epoch"], "253402214400000000000");
+        assert_eq!(value["unit"], "ns");
+
+        assert_eq!(dtt(&["epoch", "1", "--unit", "d"])?.0, 64);
+        assert_eq!(dtt(&["parse", "2024-01-01", "--unit", "s"])?.0, 64);
+        assert_eq!(dtt(&["epoch", "99999999999999999"])?.0, 65);
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_until() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) =
+            dtt(&["since", "2000-01-01T00:00:00Z"])?;
+        assert_eq!(code, 0);
+        assert!(stdout.contains(" years"));
+        assert!(!stdout.starts_with('-'));
+
+        let (_, stdout, _) = dtt(&["until", "2000-01-01T00:00:00Z"])?;
+        assert!(stdout.starts_with('-'));
+
+        let (_, stdout, _) = dtt(&[
+            "until",
+            "9000-06-15T00:00:00Z",
+            "--tz",
+            "JST",
+            "--format",
+            "[day]/[month]/[year]",
+        ])?;
+        assert!(stdout.ends_with(" until 15/06/9000\n"));
+
+        let (_, stdout, _) =
+            dtt(&["since", "yesterday", "--tz", "CET", "--json"])?;
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert!(value["date"]
+            .as_str()
+            .unwrap_or("")
+            .ends_with("+01:00"));
+        assert!(value["seconds"].as_i64().unwrap_or(0) > 0);
+        assert!(value["formatted"].is_null());
+
+        let (code, stdout, _) =
+            dtt(&["since", "9999-12-31T23:59:59-05:00"])?;
+        assert_eq!(code, 0);
+        assert!(stdout.starts_with('-'));
+
+        assert_eq!(dtt(&["since"])?.0, 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zones_and_convert_from(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&["zones"])?;
+        assert_eq!(code, 0);
+        assert!(stdout
+            .lines()
+            .any(|line| line.starts_with("JST")
+                && line.ends_with("+09:00")));
+        assert!(stdout.lines().any(|line| line.ends_with("-05:00")));
+
+        let (_, stdout, _) = dtt(&["zones", "--json"])?;
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        let zones = value.as_array().cloned().unwrap_or_default();
+        assert!(zones.iter().any(|zone| zone["name"] == "UTC"
+            && zone["offset"] == "+00:00"
+            && zone["seconds"] == 0));
+
+        let (code, stdout, _) = dtt(&[
+            "convert",
+            "2024-01-01T12:00:00Z",
+            "--from",
+            "EST",
+            "--to",
+            "JST",
+        ])?;
+        assert_eq!(code, 0);
+        assert_eq!(stdout, "2024-01-02T02:00:00+09:00\n");
+
+        assert_eq!(dtt(&["zones", "extra"])?.0, 64);
+        assert_eq!(dtt(&["now", "--from", "UTC"])?.0, 64);
+        assert_eq!(
+            dtt(&[
+                "convert",
+                "2024-01-01",
+                "--from",
+                "Nowhere",
+                "--to",
+                "UTC"
+            ])?
+            .0,
+            65
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cal() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) =
+            dtt(&["cal", "2", "2024", "--start-of-week", "sunday"])?;
+        assert_eq!(code, 0);
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines[0], "   February 2024");
+        assert_eq!(lines[1], "Su Mo Tu We Th Fr Sa");
+        assert_eq!(lines[2], "             1  2  3");
+        assert_eq!(lines.last(), Some(&"25 26 27 28 29"));
+
+        let (_, stdout, _) = dtt(&["cal", "sep", "2024"])?;
+        assert!(stdout.starts_with("   September 2024\nMo Tu"));
+
+        let (_, stdout, _) = dtt(&["cal", "2024"])?;
+        assert!(stdout.contains("January"));
+        assert!(stdout.contains("December"));
+
+        let (code, stdout, _) = dtt(&["cal"])?;
+        assert_eq!(code, 0);
+        assert!(stdout.lines().nth(1).unwrap_or("").starts_with("Mo"));
+
+        assert_eq!(dtt(&["cal", "13", "2024"])?.0, 64);
+        assert_eq!(dtt(&["cal", "--start-of-week", "x"])?.0, 64);
+        assert_eq!(dtt(&["now", "--start-of-week", "sunday"])?.0, 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_watch() -> Result<(), Box<dyn std::error::Error>> {
+        let (code, stdout, _) = dtt(&[
+            "watch",
+            "--tz",
+            "UTC",
+            "--tz",
+            "JST",
+            "--interval",
+            "10ms",
+            "--count",
+            "3",
+            "--format",
+            "time",
+        ])?;
+        assert_eq!(code, 0);
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in lines {
+            let parts: Vec<&str> = line.split("  ").collect();
+            assert_eq!(parts.len(), 2);
+            assert!(parts[0].starts_with("UTC "));
+            assert!(parts[1].starts_with("JST "));
+        }
+
+        let (_, stdout, _) =
+            dtt(&["watch", "--count=1", "--json", "--tz", "CET"])?;
+        let value: serde_json::Value = serde_json::from_str(&stdout)?;
+        assert_eq!(value[0]["zone"], "CET");
+        assert!(value[0]["datetime"]
+            .as_str()
+            .unwrap_or("")
+            .ends_with("+01:00"));
+
+        assert_eq!(dtt(&["watch", "--interval", "0s"])?.0, 64);
+        assert_eq!(dtt(&["watch", "--interval", "1h"])?.0, 64);
+        assert_eq!(dtt(&["watch", "--tz", "Nowhere"])?.0, 65);
+        assert_eq!(dtt(&["now", "--tz", "UTC", "--tz", "JST"])?.0, 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_formats_from_environment(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let output = Command::cargo_bin("dtt")?
+            .args(["fmt", "2024-02-15T10:30:05Z", "--format", "audit"])
+            .env_remove("DTT_TEST_MODE")
+            .env(
+                "DTT_FORMATS",
+                "audit=[year][month][day]T[hour][minute][second]; \
+                 day=[day]",
+            )
+            .output()?;
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(
+            String::from_utf8(output.stdout)?,
+            "20240215T103005\n"
+        );
+
+        let output = Command::cargo_bin("dtt")?
+            .args(["now"])
+            .env_remove("DTT_TEST_MODE")
+            .env("DTT_FORMATS", "audit")
+            .output()?;
+        assert_eq!(output.status.code(), Some(64));
+        assert!(String::from_utf8(output.stderr)?
+            .contains("invalid DTT_FORMATS entry `audit`"));
+        Ok(())
+    }
 }
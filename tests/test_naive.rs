@@ -0,0 +1,71 @@
+// test_naive.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `naive` module and the explicit `now_*` constructors.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::naive::NaiveDateTime;
+    use time::{macros::datetime, UtcOffset};
+
+    /// Tests that `now_utc` and `now_fixed` carry the expected offsets and
+    /// represent the same instant.
+    #[test]
+    fn test_now_constructors() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let offset = UtcOffset::from_hms(-3, -30, 0)?;
+        let utc = DateTime::now_utc();
+        let fixed = DateTime::now_fixed(offset);
+        assert_eq!(utc.offset(), UtcOffset::UTC);
+        assert_eq!(fixed.offset(), offset);
+        assert!(fixed.duration_since(&utc).whole_seconds().abs() < 5);
+        Ok(())
+    }
+
+    /// Tests that a naive value can be anchored to different offsets.
+    #[test]
+    fn test_assume_offset() -> Result<(), Box<dyn std::error::Error>> {
+        let naive = NaiveDateTime::new(datetime!(2024-06-01 08:00));
+        let utc = naive.assume_utc();
+        let est = naive.assume_offset(UtcOffset::from_hms(-5, 0, 0)?);
+        assert_eq!(utc.hour(), 8);
+        assert_eq!(est.hour(), 8);
+        assert_eq!(est.unix_timestamp() - utc.unix_timestamp(), 18_000);
+        Ok(())
+    }
+
+    /// Tests conversions from `DateTime` back to naive values.
+    #[test]
+    fn test_to_naive() -> Result<(), Box<dyn std::error::Error>> {
+        let utc = DateTime::parse("2024-06-01T23:30:00Z")?;
+        let ist = utc.convert_to_tz("IST")?;
+        assert_eq!(ist.to_naive().to_string(), "2024-06-02T05:00:00");
+        assert_eq!(ist.to_naive_utc()?, utc.to_naive());
+        assert_eq!(ist.to_naive().assume_offset(ist.offset()), ist);
+        Ok(())
+    }
+
+    /// Tests parsing and display, including sub-second precision.
+    #[test]
+    fn test_parse_and_display() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let naive = NaiveDateTime::parse_custom_format(
+            "01/02/2024 03:04:05",
+            "[day]/[month]/[year] [hour]:[minute]:[second]",
+        )?;
+        assert_eq!(naive.to_string(), "2024-02-01T03:04:05");
+        assert!(NaiveDateTime::parse_custom_format("nope", "[year]")
+            .is_err());
+
+        let precise =
+            NaiveDateTime::from(datetime!(2024-02-01 03:04:05.25));
+        assert_eq!(
+            precise.to_string(),
+            "2024-02-01T03:04:05.250000000"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+// test_offsets.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `offsets` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::{supported_timezones, DateTime};
+    use dtt::offsets;
+    use dtt::timezone::TimezoneRegistry;
+
+    #[test]
+    fn test_constants_match_the_registry() {
+        let constants = [
+            ("CET", offsets::CET),
+            ("EST", offsets::EST),
+            ("GMT", offsets::GMT),
+            ("IST", offsets::IST),
+            ("JST", offsets::JST),
+            ("PST", offsets::PST),
+            ("UTC", offsets::UTC),
+            ("WADT", offsets::WADT),
+        ];
+        for (name, offset) in constants {
+            assert_eq!(
+                TimezoneRegistry::global().lookup(name),
+                Some(offset),
+                "{name}"
+            );
+        }
+        assert_eq!(supported_timezones().len(), 20);
+    }
+
+    #[test]
+    fn test_constant_values() {
+        assert_eq!(offsets::IST.as_hms(), (5, 30, 0));
+        assert_eq!(offsets::EST.whole_hours(), -5);
+        assert_eq!(offsets::WADT.whole_minutes(), 525);
+        assert!(offsets::UTC.is_utc());
+    }
+
+    #[test]
+    fn test_convert_with_constant() {
+        let dt = DateTime::parse("2024-07-01T12:00:00Z").unwrap();
+        let converted = dt.try_convert_to_offset(offsets::IST).unwrap();
+        assert_eq!(converted, dt.convert_to_tz("IST").unwrap());
+    }
+}
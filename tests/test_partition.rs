@@ -0,0 +1,145 @@
+// test_partition.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `partition` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::{DateTime, Unit};
+    use dtt::error::DateTimeError;
+    use dtt::partition::{
+        parse_partition_path, partition_path, PartitionScheme,
+    };
+
+    /// Tests rendering at every supported granularity.
+    #[test]
+    fn test_partition_path() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-01-15T12:34:56Z")?;
+        let cases = [
+            (PartitionScheme::hive(Unit::Year), "year=2024"),
+            (PartitionScheme::hive(Unit::Month), "year=2024/month=01"),
+            (
+                PartitionScheme::hive(Unit::Minute),
+                "year=2024/month=01/day=15/hour=12/minute=34",
+            ),
+            (PartitionScheme::plain(Unit::Day), "2024/01/15"),
+            (PartitionScheme::plain(Unit::Hour), "2024/01/15/12"),
+        ];
+        for (scheme, expected) in cases {
+            assert_eq!(partition_path(&dt, scheme)?, expected);
+        }
+        Ok(())
+    }
+
+    /// Tests that values are partitioned on the UTC wall clock.
+    #[test]
+    fn test_partition_path_normalizes_offset(
+    ) -> Result<(), DateTimeError> {
+        let utc = DateTime::parse("2024-01-15T20:00:00Z")?;
+        let jst = utc.convert_to_tz("JST")?;
+        assert_eq!(jst.day(), 16);
+        assert_eq!(
+            partition_path(&jst, PartitionScheme::plain(Unit::Day))?,
+            "2024/01/15"
+        );
+        Ok(())
+    }
+
+    /// Tests that values out of range in UTC are errors.
+    #[test]
+    fn test_partition_path_out_of_range() -> Result<(), DateTimeError> {
+        let last = DateTime::parse("9999-12-31T23:00:00-05:00")?;
+        assert!(matches!(
+            partition_path(&last, PartitionScheme::plain(Unit::Day)),
+            Err(DateTimeError::ConversionOverflow { .. })
+        ));
+        Ok(())
+    }
+
+    /// Tests that unsupported granularities are rejected.
+    #[test]
+    fn test_unsupported_granularity() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-01-15T12:34:56Z")?;
+        for unit in [Unit::Second, Unit::Week] {
+            assert!(matches!(
+                partition_path(&dt, PartitionScheme::hive(unit)),
+                Err(DateTimeError::InvalidFormat)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Tests recovering the period covered by a path.
+    #[test]
+    fn test_parse_partition_path() -> Result<(), DateTimeError> {
+        let cases = [
+            ("2024", "2024-01-01T00:00:00Z", "2025-01-01T00:00:00Z"),
+            (
+                "/2024/02/",
+                "2024-02-01T00:00:00Z",
+                "2024-03-01T00:00:00Z",
+            ),
+            (
+                "2024/12/31",
+                "2024-12-31T00:00:00Z",
+                "2025-01-01T00:00:00Z",
+            ),
+            (
+                "year=2024/month=01/day=15/hour=23",
+                "2024-01-15T23:00:00Z",
+                "2024-01-16T00:00:00Z",
+            ),
+            (
+                "bucket/events/year=2024/month=01/day=15/data.csv",
+                "2024-01-15T00:00:00Z",
+                "2024-01-16T00:00:00Z",
+            ),
+        ];
+        for (path, start, end) in cases {
+            let (s, e) = parse_partition_path(path)?;
+            assert_eq!(
+                (s.to_string(), e.to_string()),
+                (start.into(), end.into())
+            );
+        }
+        Ok(())
+    }
+
+    /// Tests that rendered paths parse back to a period containing the
+    /// original value.
+    #[test]
+    fn test_round_trip() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-07-04T08:09:10Z")?;
+        for scheme in [
+            PartitionScheme::hive(Unit::Hour),
+            PartitionScheme::plain(Unit::Month),
+        ] {
+            let (start, end) =
+                parse_partition_path(&partition_path(&dt, scheme)?)?;
+            assert!(start <= dt && dt < end);
+        }
+        Ok(())
+    }
+
+    /// Tests malformed paths.
+    #[test]
+    fn test_parse_invalid() {
+        for path in [
+            "",
+            "2024/01/15/12/30/00",
+            "month=01/year=2024",
+            "year=2024/day=15",
+            "2024/x1",
+            "2024/+1",
+        ] {
+            assert!(matches!(
+                parse_partition_path(path),
+                Err(DateTimeError::InvalidFormat)
+            ));
+        }
+        assert!(parse_partition_path("2023/02/29").is_err());
+        assert!(parse_partition_path("2024/13").is_err());
+    }
+}
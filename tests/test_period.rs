@@ -0,0 +1,162 @@
+// test_period.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `period` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::period::Period;
+
+    fn dt(input: &str) -> DateTime {
+        DateTime::parse(input).unwrap()
+    }
+
+    mod add_tests {
+        use super::*;
+
+        #[test]
+        fn test_month_end_clamping() {
+            let start = dt("2024-01-31T08:15:00+02:00");
+            let cases = [
+                (Period::months(1), "2024-02-29T08:15:00+02:00"),
+                (Period::months(2), "2024-03-31T08:15:00+02:00"),
+                (Period::new(0, 1, 1), "2024-03-01T08:15:00+02:00"),
+                (Period::years(1), "2025-01-31T08:15:00+02:00"),
+                (Period::new(1, 1, 0), "2025-02-28T08:15:00+02:00"),
+                (Period::days(-31), "2023-12-31T08:15:00+02:00"),
+                (Period::ZERO, "2024-01-31T08:15:00+02:00"),
+            ];
+            for (period, expected) in cases {
+                assert_eq!(
+                    start.add_period(&period).unwrap().to_string(),
+                    expected,
+                    "{period}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_mixed_signs_apply_in_order() {
+            let start = dt("2024-03-31T00:00:00Z");
+            let result =
+                start.add_period(&Period::new(0, -1, 1)).unwrap();
+            assert_eq!(result.to_string(), "2024-03-01T00:00:00Z");
+        }
+
+        #[test]
+        fn test_overflow() {
+            let start = dt("2024-01-01T00:00:00Z");
+            assert_eq!(
+                start.add_period(&Period::years(i32::MAX)),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert!(start.add_period(&Period::days(i32::MAX)).is_err());
+        }
+    }
+
+    mod between_tests {
+        use super::*;
+
+        #[test]
+        fn test_between_ignores_time_of_day() {
+            let a = dt("2024-01-01T23:59:00Z");
+            let b = dt("2024-01-02T00:01:00Z");
+            assert_eq!(Period::between(&a, &b), Period::days(1));
+            assert_eq!(Period::between(&b, &a), Period::days(-1));
+            assert_eq!(Period::between(&a, &a), Period::ZERO);
+        }
+
+        #[test]
+        fn test_between_uses_end_offset() {
+            let a = dt("2024-01-01T23:00:00Z");
+            let b = dt("2024-03-02T10:00:00+09:00");
+            // In +09:00, `a` falls on January 2nd.
+            assert_eq!(Period::between(&a, &b), Period::new(0, 2, 0));
+        }
+
+        #[test]
+        fn test_between_round_trips() {
+            let start = dt("2023-05-17T10:00:00Z");
+            for end in [
+                "2023-05-18T00:00:00Z",
+                "2024-02-29T00:00:00Z",
+                "2030-12-01T00:00:00Z",
+                "2020-01-31T00:00:00Z",
+            ] {
+                let end = dt(end);
+                let period = Period::between(&start, &end);
+                let reached = start.add_period(&period).unwrap();
+                assert_eq!(
+                    reached.date_only(),
+                    end.date_only(),
+                    "{period}"
+                );
+            }
+        }
+    }
+
+    mod text_tests {
+        use super::*;
+
+        #[test]
+        fn test_display() {
+            assert_eq!(Period::new(1, 2, 3).to_string(), "P1Y2M3D");
+            assert_eq!(Period::months(-1).to_string(), "P-1M");
+            assert_eq!(Period::ZERO.to_string(), "P0D");
+            assert_eq!(Period::days(10).to_string(), "P10D");
+        }
+
+        #[test]
+        fn test_parse() {
+            let cases = [
+                ("P1Y2M3D", Period::new(1, 2, 3)),
+                ("P2W", Period::days(14)),
+                ("P1W2D", Period::days(9)),
+                ("P0D", Period::ZERO),
+                ("-P1Y2D", Period::new(-1, 0, -2)),
+                ("P-1M", Period::months(-1)),
+                ("+P6M", Period::months(6)),
+            ];
+            for (input, expected) in cases {
+                assert_eq!(
+                    input.parse::<Period>(),
+                    Ok(expected),
+                    "{input}"
+                );
+                assert_eq!(
+                    expected.to_string().parse::<Period>(),
+                    Ok(expected)
+                );
+            }
+        }
+
+        #[test]
+        fn test_parse_errors() {
+            for input in [
+                "", "P", "1Y", "P1D2M", "P1Y1Y", "PT1H", "P1", "PxY",
+                "P1.5D",
+            ] {
+                assert_eq!(
+                    input.parse::<Period>(),
+                    Err(DateTimeError::InvalidFormat),
+                    "{input}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_serde() {
+            let period = Period::new(1, -2, 3);
+            let json = serde_json::to_string(&period).unwrap();
+            assert_eq!(json, r#"{"years":1,"months":-2,"days":3}"#);
+            assert_eq!(
+                serde_json::from_str::<Period>(&json).unwrap(),
+                period
+            );
+        }
+    }
+}
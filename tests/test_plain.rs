@@ -0,0 +1,218 @@
+// test_plain.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `plain` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::{Date, Time};
+    use time::{Duration, Month, Weekday};
+
+    /// Tests construction, accessors, and parsing of dates.
+    #[test]
+    fn test_date_basics() -> Result<(), DateTimeError> {
+        let date = Date::from_calendar_date(2024, 2, 29)?;
+        assert_eq!(date.year(), 2024);
+        assert_eq!(date.month(), Month::February);
+        assert_eq!(date.day(), 29);
+        assert_eq!(date.ordinal(), 60);
+        assert_eq!(date.weekday(), Weekday::Thursday);
+        assert_eq!(date, "2024-02-29".parse()?);
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(
+            Date::from_calendar_date(2023, 2, 29),
+            Err(DateTimeError::InvalidDate)
+        );
+        assert_eq!(
+            Date::parse_custom_format(
+                "29/02/2024",
+                "[day]/[month]/[year]"
+            )?,
+            date
+        );
+        assert_eq!(
+            date.format("[weekday repr:short] [day]")?,
+            "Thu 29"
+        );
+        Ok(())
+    }
+
+    /// Tests date arithmetic.
+    #[test]
+    fn test_date_arithmetic() -> Result<(), DateTimeError> {
+        let date = Date::from_calendar_date(2024, 2, 28)?;
+        let later = date.add_days(2)?;
+        assert_eq!(later.to_string(), "2024-03-01");
+        assert_eq!(date.days_until(later), 2);
+        assert_eq!(later.days_until(date), -2);
+        assert_eq!(later - date, Duration::days(2));
+        assert!(Date::from_calendar_date(9999, 12, 31)?
+            .add_days(1)
+            .is_err());
+        Ok(())
+    }
+
+    /// Tests construction, arithmetic, and parsing of times.
+    #[test]
+    fn test_time_basics() -> Result<(), DateTimeError> {
+        let time = Time::from_hms(23, 30, 0)?;
+        assert_eq!(
+            (time.hour(), time.minute(), time.second()),
+            (23, 30, 0)
+        );
+        assert_eq!(time, "23:30:00".parse()?);
+        assert_eq!((time + Duration::hours(1)).to_string(), "00:30:00");
+        assert_eq!(
+            (Time::MIDNIGHT - Duration::SECOND).to_string(),
+            "23:59:59"
+        );
+        assert_eq!(
+            time - Time::MIDNIGHT,
+            Duration::minutes(23 * 60 + 30)
+        );
+        assert_eq!(
+            "12:00:00.25".parse::<Time>()?.nanosecond(),
+            250_000_000
+        );
+        assert_eq!(
+            Time::from_hms(24, 0, 0),
+            Err(DateTimeError::InvalidTime)
+        );
+        assert_eq!(
+            Time::parse_custom_format(
+                "9.05",
+                "[hour padding:none].[minute]"
+            )?
+            .format("[hour]:[minute]")?,
+            "09:05"
+        );
+        Ok(())
+    }
+
+    /// Tests conversions to and from `DateTime`.
+    #[test]
+    fn test_datetime_conversions() -> Result<(), DateTimeError> {
+        let dt = DateTime::parse("2024-06-01T23:15:30+10:00")?;
+        let date = dt.date_only();
+        let time = dt.time_only();
+        assert_eq!(date.to_string(), "2024-06-01");
+        assert_eq!(time.to_string(), "23:15:30");
+        assert_eq!(date.at(time).assume_offset(dt.offset()), dt);
+        assert_eq!(
+            DateTime::from(date).to_string(),
+            "2024-06-01T00:00:00Z"
+        );
+        assert_eq!(time::Date::from(date), date.to_date());
+        Ok(())
+    }
+
+    /// Tests that dates compare by calendar order.
+    #[test]
+    fn test_ordering() -> Result<(), DateTimeError> {
+        let a: Date = "2023-12-31".parse()?;
+        let b: Date = "2024-01-01".parse()?;
+        assert!(a < b);
+        assert!(Time::MIDNIGHT < "00:00:01".parse()?);
+        Ok(())
+    }
+
+    mod partial_tests {
+        use dtt::error::DateTimeError;
+        use dtt::plain::{MonthDay, YearMonth};
+        use dtt::Date;
+        use time::Month;
+
+        /// Tests `YearMonth` construction, parsing, and accessors.
+        #[test]
+        fn test_year_month_basics() -> Result<(), DateTimeError> {
+            let ym: YearMonth = "2024-02".parse()?;
+            assert_eq!(ym, YearMonth::new(2024, 2)?);
+            assert_eq!(ym.year(), 2024);
+            assert_eq!(ym.month(), Month::February);
+            assert_eq!(ym.length_of_month(), 29);
+            assert!(ym.is_valid_day(29));
+            assert!(!ym.is_valid_day(30));
+            assert!(!ym.is_valid_day(0));
+            assert!("2024-2".parse::<YearMonth>().is_err());
+            assert_eq!(
+                YearMonth::new(2024, 0),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert!(YearMonth::new(10_000, 1).is_err());
+            Ok(())
+        }
+
+        /// Tests month arithmetic across year boundaries.
+        #[test]
+        fn test_year_month_arithmetic() -> Result<(), DateTimeError> {
+            let ym = YearMonth::new(2024, 1)?;
+            assert_eq!(ym.plus_months(12)?.to_string(), "2025-01");
+            assert_eq!(ym.plus_months(-1)?.to_string(), "2023-12");
+            assert_eq!(ym.minus_months(25)?.to_string(), "2021-12");
+            assert_eq!(ym.plus_months(0)?, ym);
+            assert!(YearMonth::new(9999, 12)?.plus_months(1).is_err());
+            assert!(ym.minus_months(i32::MIN).is_err());
+            Ok(())
+        }
+
+        /// Tests conversion to dates, e.g. card expiry checks.
+        #[test]
+        fn test_year_month_dates() -> Result<(), DateTimeError> {
+            let expiry = YearMonth::new(2026, 2)?;
+            let last = expiry.at_day(expiry.length_of_month())?;
+            assert_eq!(last.to_string(), "2026-02-28");
+            assert!(expiry.at_day(29).is_err());
+            assert!(expiry.contains(last));
+            assert!(!expiry.contains(last.add_days(1)?));
+            assert_eq!(last.year_month(), expiry);
+            Ok(())
+        }
+
+        /// Tests `MonthDay` validity and conversion.
+        #[test]
+        fn test_month_day() -> Result<(), DateTimeError> {
+            let leap_day: MonthDay = "--02-29".parse()?;
+            assert_eq!(leap_day, "02-29".parse()?);
+            assert_eq!(leap_day.to_string(), "--02-29");
+            assert_eq!(leap_day.month(), Month::February);
+            assert_eq!(leap_day.day(), 29);
+            assert!(leap_day.is_valid_in_year(2000));
+            assert!(!leap_day.is_valid_in_year(1900));
+            assert_eq!(
+                leap_day.in_year(2028)?.to_string(),
+                "2028-02-29"
+            );
+            assert_eq!(
+                leap_day.in_year(2027),
+                Err(DateTimeError::InvalidDate)
+            );
+            assert!(MonthDay::new(2, 30).is_err());
+            assert!(MonthDay::new(13, 1).is_err());
+
+            let date: Date = "2024-12-25".parse()?;
+            assert_eq!(date.month_day(), MonthDay::new(12, 25)?);
+            Ok(())
+        }
+
+        /// Tests that partial types serialize as strings.
+        #[test]
+        fn test_partial_serde() -> Result<(), Box<dyn std::error::Error>>
+        {
+            let ym = YearMonth::new(2026, 3)?;
+            assert_eq!(serde_json::to_string(&ym)?, r#""2026-03""#);
+            assert_eq!(
+                serde_json::from_str::<YearMonth>(r#""2026-03""#)?,
+                ym
+            );
+            let md = MonthDay::new(7, 4)?;
+            assert_eq!(serde_json::to_string(&md)?, r#""--07-04""#);
+            assert!(serde_json::from_str::<MonthDay>(r#""--02-30""#)
+                .is_err());
+            Ok(())
+        }
+    }
+}
@@ -32,6 +32,7 @@ mod tests {
     /// This test checks the validity of various components such as microsecond, second, minute,
     /// hour, month, ordinal, and ISO 8601 formatted strings.
     #[test]
+    #[allow(deprecated)]
     fn example_2() {
         // Validate microseconds
         assert!(DateTime::is_valid_microsecond("999999"));
@@ -0,0 +1,121 @@
+// test_retention.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `retention` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::retention::{RetentionDecision, RetentionPolicy};
+    use time::Duration;
+
+    /// Returns one snapshot per day at 02:00 UTC, newest first, for the
+    /// given number of days ending on 2024-03-31.
+    fn daily_snapshots(days: i64) -> Vec<DateTime> {
+        let last =
+            DateTime::parse("2024-03-31T02:00:00Z").unwrap_or_default();
+        (0..days)
+            .map(|i| (last - Duration::days(i)).unwrap_or(last))
+            .collect()
+    }
+
+    /// Tests that an empty policy deletes everything.
+    #[test]
+    fn test_empty_policy() {
+        let snapshots = daily_snapshots(3);
+        let decision = RetentionPolicy::new().evaluate(&snapshots);
+        assert!(decision.keep.is_empty());
+        assert_eq!(decision.delete, vec![0, 1, 2]);
+        assert_eq!(
+            RetentionPolicy::default().evaluate(&[]),
+            RetentionDecision::default()
+        );
+    }
+
+    /// Tests a combined daily, weekly, and monthly policy.
+    #[test]
+    fn test_grandfather_father_son() {
+        let snapshots = daily_snapshots(90);
+        let policy = RetentionPolicy::new()
+            .keep_daily(7)
+            .keep_weekly(4)
+            .keep_monthly(3);
+        let decision = policy.evaluate(&snapshots);
+
+        let kept: Vec<String> = decision
+            .keep
+            .iter()
+            .map(|i| snapshots[*i].to_string()[..10].to_string())
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                // Daily: the last seven days (the first is also the
+                // newest of its week and month).
+                "2024-03-31",
+                "2024-03-30",
+                "2024-03-29",
+                "2024-03-28",
+                "2024-03-27",
+                "2024-03-26",
+                "2024-03-25",
+                // Weekly: newest of each previous ISO week (Sundays).
+                "2024-03-24",
+                "2024-03-17",
+                "2024-03-10",
+                // Monthly: newest of each previous month.
+                "2024-02-29",
+                "2024-01-31",
+            ]
+        );
+        assert_eq!(decision.keep.len() + decision.delete.len(), 90);
+    }
+
+    /// Tests that only the newest item in a bucket is kept and that input
+    /// order does not matter.
+    #[test]
+    fn test_unsorted_and_same_bucket(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let snapshots = vec![
+            DateTime::parse("2024-03-09T02:00:00Z")?,
+            DateTime::parse("2024-03-10T01:00:00Z")?,
+            DateTime::parse("2024-03-10T23:00:00Z")?,
+            DateTime::parse("2024-03-08T02:00:00Z")?,
+        ];
+        let decision =
+            RetentionPolicy::new().keep_daily(2).evaluate(&snapshots);
+        assert_eq!(decision.keep, vec![0, 2]);
+        assert_eq!(decision.delete, vec![1, 3]);
+        Ok(())
+    }
+
+    /// Tests that buckets are computed in UTC.
+    #[test]
+    fn test_offsets_normalized(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let late = DateTime::parse("2024-03-10T20:00:00Z")?;
+        let early = DateTime::parse("2024-03-10T10:00:00Z")?;
+        // 2024-03-11T05:00:00+09:00 is still March 10 in UTC.
+        let late_jst = late.convert_to_tz("JST")?;
+        let decision = RetentionPolicy::new()
+            .keep_daily(1)
+            .evaluate(&[early, late_jst]);
+        assert_eq!(decision.keep, vec![1]);
+        Ok(())
+    }
+
+    /// Tests that a timestamp out of range in UTC is clamped rather
+    /// than aborting the evaluation.
+    #[test]
+    fn test_range_edge() -> Result<(), Box<dyn std::error::Error>> {
+        let last = DateTime::parse("9999-12-31T23:00:00-05:00")?;
+        let decision = RetentionPolicy::new()
+            .keep_daily(1)
+            .evaluate(&[daily_snapshots(1)[0], last]);
+        assert_eq!(decision.keep, vec![1]);
+        assert_eq!(decision.delete, vec![0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+// test_rollout.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `rollout` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::rollout::RolloutWindow;
+
+    fn window(
+        start: &str,
+        end: &str,
+    ) -> Result<RolloutWindow, DateTimeError> {
+        RolloutWindow::new(
+            DateTime::parse(start)?,
+            DateTime::parse(end)?,
+        )
+    }
+
+    #[test]
+    fn test_new_rejects_reversed_window() {
+        assert!(matches!(
+            window("2024-03-08T00:00:00Z", "2024-03-01T00:00:00Z"),
+            Err(DateTimeError::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn test_activity_boundaries() -> Result<(), DateTimeError> {
+        let w = window("2024-03-01T00:00:00Z", "2024-03-08T00:00:00Z")?;
+        let before = DateTime::parse("2024-02-29T23:59:59Z")?;
+        assert!(!w.is_active(&before));
+        assert!(!w.has_started(&before));
+        assert!(w.is_active(&w.start()));
+        assert!(w.has_started(&w.start()));
+        assert!(!w.is_active(&w.end()));
+        assert!(w.has_ended(&w.end()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fraction_elapsed() -> Result<(), DateTimeError> {
+        let w = window("2024-03-01T00:00:00Z", "2024-03-01T10:00:00Z")?;
+        let cases = [
+            ("2024-02-28T00:00:00Z", 0.0),
+            ("2024-03-01T00:00:00Z", 0.0),
+            ("2024-03-01T02:30:00Z", 0.25),
+            ("2024-03-01T10:00:00Z", 1.0),
+            ("2024-04-01T00:00:00Z", 1.0),
+        ];
+        for (now, expected) in cases {
+            let fraction = w.fraction_elapsed(&DateTime::parse(now)?);
+            assert!(
+                (fraction - expected).abs() < f64::EPSILON,
+                "{now}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fraction_uses_instants() -> Result<(), DateTimeError> {
+        let w = window("2024-03-01T00:00:00Z", "2024-03-01T10:00:00Z")?;
+        let now = DateTime::parse("2024-03-01T07:00:00+02:00")?;
+        assert!((w.fraction_elapsed(&now) - 0.5).abs() < f64::EPSILON);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_window() -> Result<(), DateTimeError> {
+        let w = window("2024-03-01T00:00:00Z", "2024-03-01T00:00:00Z")?;
+        let before = DateTime::parse("2024-02-29T00:00:00Z")?;
+        assert!(w.fraction_elapsed(&before).abs() < f64::EPSILON);
+        assert!(
+            (w.fraction_elapsed(&w.end()) - 1.0).abs() < f64::EPSILON
+        );
+        assert!(!w.is_active(&w.start()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_rolled_out() -> Result<(), DateTimeError> {
+        let w = window("2024-03-01T00:00:00Z", "2024-03-05T00:00:00Z")?;
+        let start = w.start();
+        assert!(!w.is_rolled_out(&start, 0.0));
+        let midway = DateTime::parse("2024-03-03T00:00:00Z")?;
+        assert!(w.is_rolled_out(&midway, 0.49));
+        assert!(!w.is_rolled_out(&midway, 0.5));
+        assert!(w.is_rolled_out(&w.end(), 0.999));
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_round_trip() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let w = window(
+            "2024-03-01T00:00:00Z",
+            "2024-03-08T00:00:00+01:00",
+        )?;
+        let json = serde_json::to_string(&w)?;
+        assert_eq!(
+            json,
+            r#"{"start":"2024-03-01T00:00:00Z","end":"2024-03-08T00:00:00+01:00"}"#
+        );
+        let back: RolloutWindow = serde_json::from_str(&json)?;
+        assert_eq!(back, w);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_rejects_invalid_windows() {
+        let reversed = r#"{"start":"2024-03-08T00:00:00Z","end":"2024-03-01T00:00:00Z"}"#;
+        assert!(
+            serde_json::from_str::<RolloutWindow>(reversed).is_err()
+        );
+        let local = r#"{"start":"2024-03-01T00:00:00","end":"2024-03-08T00:00:00Z"}"#;
+        assert!(serde_json::from_str::<RolloutWindow>(local).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+// test_testvectors.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Runs the conformance vectors against `DateTime`.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::{is_leap_year, DateTime};
+    use dtt::testvectors::{
+        check_arithmetic, check_format, check_leap_years, check_parse,
+        Mismatch, Operation, ARITHMETIC, FORMAT, PARSE,
+    };
+    use time::{Duration, UtcOffset};
+
+    fn apply(start: &str, operation: Operation) -> Option<String> {
+        let dt = DateTime::parse(start).ok()?;
+        let result = match operation {
+            Operation::AddSeconds(n) => dt + Duration::seconds(n),
+            Operation::AddDays(n) => dt.add_days(n),
+            Operation::AddMonths(n) => dt.add_months(n),
+            Operation::AddYears(n) => dt.add_years(n),
+            Operation::ToOffset(n) => UtcOffset::from_whole_seconds(n)
+                .map_err(Into::into)
+                .and_then(|offset| dt.try_convert_to_offset(offset)),
+        };
+        result.ok().map(|dt| dt.to_string())
+    }
+
+    #[test]
+    fn test_parse_vectors() {
+        let mismatches = check_parse(|input| {
+            DateTime::parse(input).ok().map(|dt| dt.to_string())
+        });
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    #[test]
+    fn test_format_vectors() {
+        let mismatches = check_format(|input, format| {
+            DateTime::parse(input).ok()?.format(format).ok()
+        });
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    #[test]
+    fn test_arithmetic_vectors() {
+        let mismatches = check_arithmetic(apply);
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    #[test]
+    fn test_leap_year_vectors() {
+        let mismatches = check_leap_years(is_leap_year);
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    #[test]
+    fn test_vectors_are_not_empty() {
+        assert!(PARSE.iter().any(|v| v.expected.is_none()));
+        assert!(PARSE.iter().any(|v| v.expected.is_some()));
+        assert!(!FORMAT.is_empty());
+        assert!(ARITHMETIC.iter().any(|v| v.expected.is_none()));
+    }
+
+    #[test]
+    fn test_broken_backend_is_reported() {
+        let mismatches = check_parse(|_| None);
+        let accepted = PARSE.iter().filter(|v| v.expected.is_some());
+        assert_eq!(mismatches.len(), accepted.count());
+
+        let first: &Mismatch = &mismatches[0];
+        assert_eq!(first.actual, None);
+        assert!(first.to_string().ends_with("got <error>"));
+    }
+}
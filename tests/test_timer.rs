@@ -0,0 +1,171 @@
+// test_timer.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `timer` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::clock::MockClock;
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::timer::{Deadline, Stopwatch};
+    use std::thread::sleep;
+    use time::Duration;
+
+    #[test]
+    fn test_new_stopwatch_is_stopped() {
+        let stopwatch = Stopwatch::new();
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+        assert_eq!(stopwatch.started_at(), None);
+        assert!(stopwatch.laps().is_empty());
+    }
+
+    #[test]
+    fn test_elapsed_only_counts_running_time() {
+        let mut stopwatch = Stopwatch::start_new();
+        assert!(stopwatch.is_running());
+        sleep(std::time::Duration::from_millis(5));
+        let first = stopwatch.stop();
+        assert!(first >= Duration::milliseconds(5));
+
+        sleep(std::time::Duration::from_millis(5));
+        assert_eq!(stopwatch.elapsed(), first);
+
+        stopwatch.start();
+        assert!(stopwatch.elapsed() >= first);
+    }
+
+    #[test]
+    fn test_laps_sum_to_elapsed() {
+        let mut stopwatch = Stopwatch::start_new();
+        let _ = stopwatch.lap();
+        sleep(std::time::Duration::from_millis(2));
+        let _ = stopwatch.lap();
+        let total = stopwatch.stop();
+        let sum = stopwatch
+            .laps()
+            .iter()
+            .fold(Duration::ZERO, |acc, lap| acc + *lap);
+        assert_eq!(stopwatch.laps().len(), 2);
+        assert!(sum <= total);
+        assert!(stopwatch.laps()[1] >= Duration::milliseconds(2));
+    }
+
+    #[test]
+    fn test_started_at_is_kept_across_pauses() {
+        let mut stopwatch = Stopwatch::start_new();
+        let started = stopwatch.started_at();
+        assert!(started.is_some());
+        let _ = stopwatch.stop();
+        stopwatch.start();
+        assert_eq!(stopwatch.started_at(), started);
+    }
+
+    #[test]
+    fn test_reset_and_restart() {
+        let mut stopwatch = Stopwatch::start_new();
+        let _ = stopwatch.lap();
+        stopwatch.reset();
+        assert!(!stopwatch.is_running());
+        assert!(stopwatch.laps().is_empty());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+
+        stopwatch.restart();
+        assert!(stopwatch.is_running());
+        assert!(stopwatch.started_at().is_some());
+    }
+
+    #[test]
+    fn test_deadline_compares_instants() -> Result<(), DateTimeError> {
+        let deadline =
+            Deadline::at(DateTime::parse("2024-01-01T12:00:00+01:00")?);
+        let now = DateTime::parse("2024-01-01T10:30:00Z")?;
+        assert_eq!(deadline.remaining_at(&now), Duration::minutes(30));
+        assert!(!deadline.is_expired_at(&now));
+
+        let past = DateTime::parse("2024-01-01T11:00:01Z")?;
+        assert_eq!(deadline.remaining_at(&past), Duration::ZERO);
+        assert!(deadline.is_expired_at(&past));
+        Ok(())
+    }
+
+    #[test]
+    fn test_deadline_after() -> Result<(), DateTimeError> {
+        let deadline = Deadline::after(Duration::hours(1))?;
+        assert!(!deadline.is_expired());
+        assert!(deadline.remaining() > Duration::minutes(59));
+
+        let expired = Deadline::after(-Duration::SECOND)?;
+        assert!(expired.is_expired());
+        assert_eq!(expired.remaining(), Duration::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stopwatch_with_mock_clock() -> Result<(), DateTimeError> {
+        let clock =
+            MockClock::new(DateTime::parse("2024-01-01T09:00:00Z")?);
+        let mut stopwatch = Stopwatch::with_source(&clock);
+        let _ = clock.advance(Duration::seconds(10))?;
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+
+        stopwatch.start();
+        assert_eq!(
+            stopwatch.started_at(),
+            Some(DateTime::parse("2024-01-01T09:00:10Z")?)
+        );
+        let _ = clock.advance(Duration::seconds(3))?;
+        assert_eq!(stopwatch.lap(), Duration::seconds(3));
+        let _ = clock.advance(Duration::seconds(4))?;
+        assert_eq!(stopwatch.lap(), Duration::seconds(4));
+
+        // Moving the wall clock back does not affect elapsed time.
+        let _ = clock.advance(-Duration::hours(1))?;
+        assert_eq!(stopwatch.stop(), Duration::seconds(7));
+        let _ = clock.advance(Duration::seconds(30))?;
+        assert_eq!(stopwatch.elapsed(), Duration::seconds(7));
+
+        stopwatch.restart();
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+        let _ = clock.advance(Duration::seconds(2))?;
+        assert_eq!(stopwatch.elapsed(), Duration::seconds(2));
+        assert!(stopwatch.laps().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deadline_with_mock_clock() -> Result<(), DateTimeError> {
+        let clock =
+            MockClock::new(DateTime::parse("2024-01-01T09:00:00Z")?);
+        let deadline =
+            Deadline::after_with_clock(Duration::minutes(5), &clock)?;
+        assert_eq!(
+            deadline.due(),
+            DateTime::parse("2024-01-01T09:05:00Z")?
+        );
+        assert_eq!(
+            deadline.remaining_with_clock(&clock),
+            Duration::minutes(5)
+        );
+        assert!(!deadline.is_expired_with_clock(&clock));
+
+        let _ = clock.advance(Duration::minutes(5))?;
+        assert_eq!(
+            deadline.remaining_with_clock(&clock),
+            Duration::ZERO
+        );
+        assert!(deadline.is_expired_with_clock(&clock));
+        Ok(())
+    }
+
+    #[test]
+    fn test_deadline_out_of_range() {
+        assert!(matches!(
+            Deadline::after(Duration::MAX),
+            Err(DateTimeError::InvalidDate)
+        ));
+    }
+}
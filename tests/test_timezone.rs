@@ -0,0 +1,261 @@
+// test_timezone.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `timezone` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::error::DateTimeError;
+    use dtt::timezone::TimezoneRegistry;
+    use std::sync::Arc;
+    use time::UtcOffset;
+
+    #[test]
+    fn test_global_registration() -> Result<(), DateTimeError> {
+        let registry = TimezoneRegistry::global();
+        assert!(DateTime::new_with_tz("TESTCHAT").is_err());
+
+        registry.register("TESTCHAT", 12, 45)?;
+        let dt = DateTime::new_with_tz("TESTCHAT")?;
+        assert_eq!(dt.offset().whole_minutes(), 765);
+
+        let utc = DateTime::parse("2024-01-01T00:00:00Z")?;
+        assert_eq!(
+            utc.convert_to_tz("TESTCHAT")?.to_string(),
+            "2024-01-01T12:45:00+12:45"
+        );
+
+        assert_eq!(
+            registry.unregister("TESTCHAT"),
+            UtcOffset::from_hms(12, 45, 0).ok()
+        );
+        assert!(DateTime::new_with_tz("TESTCHAT").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_registered_zone_abbreviation() -> Result<(), DateTimeError>
+    {
+        let registry = TimezoneRegistry::global();
+        registry.register("TESTLABEL", 13, 0)?;
+
+        let utc = DateTime::parse("2024-01-01T00:00:00Z")?;
+        let converted = utc.convert_to_tz("TESTLABEL")?;
+        assert_eq!(converted.zone_abbreviation(), Some("TESTLABEL"));
+        assert_eq!(
+            DateTime::new_with_tz("TESTLABEL")?.zone_abbreviation(),
+            Some("TESTLABEL")
+        );
+        assert_eq!(
+            converted.format_zoned()?,
+            "2024-01-01 13:00:00 TESTLABEL"
+        );
+
+        // Values keep the label after the name is unregistered, and
+        // registering it again reuses it.
+        let _ = registry.unregister("TESTLABEL");
+        assert_eq!(converted.zone_abbreviation(), Some("TESTLABEL"));
+        registry.register("TESTLABEL", 12, 0)?;
+        assert_eq!(
+            utc.convert_to_tz("TESTLABEL")?.zone_abbreviation(),
+            Some("TESTLABEL")
+        );
+        let _ = registry.unregister("TESTLABEL");
+        Ok(())
+    }
+
+    #[test]
+    fn test_registered_zones_are_listed_and_suggested(
+    ) -> Result<(), DateTimeError> {
+        use dtt::datetime::{suggest_timezone, supported_timezones};
+
+        let registry = TimezoneRegistry::global();
+        registry.register("TESTSUGGEST", 4, 0)?;
+        let zones = supported_timezones();
+        assert!(zones.contains(&"TESTSUGGEST"));
+        assert!(zones.contains(&"UTC"));
+        assert!(zones.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(suggest_timezone("testsugest"), Some("TESTSUGGEST"));
+
+        let _ = registry.unregister("TESTSUGGEST");
+        assert!(!supported_timezones().contains(&"TESTSUGGEST"));
+        assert_eq!(suggest_timezone("testsugest"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_order() -> Result<(), DateTimeError> {
+        let registry = TimezoneRegistry::new();
+        let india = registry.lookup("IST");
+        assert_eq!(india.map(UtcOffset::whole_minutes), Some(330));
+
+        registry.register("IST", 2, 0)?;
+        assert_eq!(
+            registry.lookup("IST").map(UtcOffset::whole_hours),
+            Some(2)
+        );
+        assert_eq!(
+            registry.unregister("IST").map(UtcOffset::whole_hours),
+            Some(2)
+        );
+        assert_eq!(registry.lookup("IST"), india);
+        assert_eq!(registry.unregister("IST"), None);
+
+        assert_eq!(
+            registry.lookup("UTC+03:00").map(UtcOffset::whole_hours),
+            Some(3)
+        );
+        assert_eq!(registry.lookup("NOPE"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_replaces_and_lists() -> Result<(), DateTimeError> {
+        let registry = TimezoneRegistry::default();
+        registry.register("ZZZ", 1, 0)?;
+        registry.register("AAA", -1, 0)?;
+        registry.register("ZZZ", 4, 0)?;
+        let names: Vec<(String, i8)> = registry
+            .registered()
+            .into_iter()
+            .map(|(name, offset)| (name, offset.whole_hours()))
+            .collect();
+        assert_eq!(
+            names,
+            [("AAA".to_string(), -1), ("ZZZ".to_string(), 4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_rejects_invalid() {
+        let registry = TimezoneRegistry::new();
+        for (name, hours, minutes) in [
+            ("", 1, 0),
+            ("TWO WORDS", 1, 0),
+            ("FAR", 15, 0),
+            ("FAR", -14, -30),
+        ] {
+            assert_eq!(
+                registry.register(name, hours, minutes),
+                Err(DateTimeError::InvalidTimezone),
+                "{}",
+                name
+            );
+        }
+        assert!(registry.registered().is_empty());
+    }
+
+    #[test]
+    fn test_concurrent_access() {
+        let registry = Arc::new(TimezoneRegistry::new());
+        let handles: Vec<_> = (0..8_i8)
+            .map(|i| {
+                let registry = Arc::clone(&registry);
+                std::thread::spawn(move || {
+                    let name = format!("T{}", i);
+                    registry.register(&name, i, 0).is_ok()
+                        && registry
+                            .lookup(&name)
+                            .map(UtcOffset::whole_hours)
+                            == Some(i)
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().ok(), Some(true));
+        }
+        assert_eq!(registry.registered().len(), 8);
+    }
+
+    mod resolver_tests {
+        use dtt::datetime::DateTime;
+        use dtt::error::DateTimeError;
+        use dtt::timezone::{TimezoneRegistry, ZoneResolver};
+
+        #[test]
+        fn test_resolver_keeps_zone_label() -> Result<(), DateTimeError>
+        {
+            let dt = DateTime::parse("2024-01-01T12:00:00Z")?;
+            let resolver = ZoneResolver::new("EST")?;
+            let resolved = resolver.convert(&dt)?;
+            let converted = dt.convert_to_tz("EST")?;
+            assert_eq!(resolved.zone_abbreviation(), Some("EST"));
+            assert_eq!(
+                resolved.format_zoned()?,
+                converted.format_zoned()?
+            );
+            assert_eq!(
+                resolved.format_zoned()?,
+                "2024-01-01 07:00:00 EST"
+            );
+            assert_eq!(resolver.now().zone_abbreviation(), Some("EST"));
+
+            let clock = dtt::clock::MockClock::new(dt);
+            let _ = clock.advance(time::Duration::hours(1))?;
+            let now = resolver.now_with_clock(&clock);
+            assert_eq!(now.format_zoned()?, "2024-01-01 08:00:00 EST");
+
+            let registry = TimezoneRegistry::new();
+            registry.register("RESOLVED", 2, 0)?;
+            let resolver =
+                ZoneResolver::with_registry(&registry, "RESOLVED")?;
+            assert_eq!(
+                resolver.convert(&dt)?.format_zoned()?,
+                "2024-01-01 14:00:00 RESOLVED"
+            );
+
+            let numeric = ZoneResolver::new("UTC-05:00")?;
+            assert_eq!(numeric.convert(&dt)?.zone_abbreviation(), None);
+            Ok(())
+        }
+
+        #[test]
+        fn test_resolver_converts() -> Result<(), DateTimeError> {
+            let resolver = ZoneResolver::new("UTC+05:30")?;
+            assert_eq!(resolver.name(), "UTC+05:30");
+            assert_eq!(resolver.offset().whole_minutes(), 330);
+
+            let dt = DateTime::parse("2024-03-01T00:00:00-08:00")?;
+            let converted = resolver.convert(&dt)?;
+            assert_eq!(converted, dt.convert_to_tz("UTC+05:30")?);
+            assert_eq!(
+                converted.to_string(),
+                "2024-03-01T13:30:00+05:30"
+            );
+
+            assert_eq!(resolver.now().offset(), resolver.offset());
+            Ok(())
+        }
+
+        #[test]
+        fn test_resolver_snapshots_registry(
+        ) -> Result<(), DateTimeError> {
+            let registry = TimezoneRegistry::new();
+            registry.register("OFFICE", 3, 0)?;
+            let resolver =
+                ZoneResolver::with_registry(&registry, "OFFICE")?;
+            let _ = registry.unregister("OFFICE");
+            assert_eq!(resolver.offset().whole_hours(), 3);
+            assert_eq!(
+                ZoneResolver::with_registry(&registry, "OFFICE"),
+                Err(DateTimeError::InvalidTimezone)
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_resolver_out_of_range() -> Result<(), DateTimeError> {
+            let resolver = ZoneResolver::new("JST")?;
+            let last = DateTime::parse("9999-12-31T23:00:00Z")?;
+            assert!(matches!(
+                resolver.convert(&last),
+                Err(DateTimeError::ConversionOverflow { .. })
+            ));
+            Ok(())
+        }
+    }
+}
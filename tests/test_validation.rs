@@ -0,0 +1,122 @@
+// test_validation.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `validation` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::datetime::DateTime;
+    use dtt::validation::{
+        validate_date, validate_time, ValidatedDate,
+    };
+    use dtt::Date;
+
+    mod date_tests {
+        use super::*;
+
+        #[test]
+        fn test_valid_dates() {
+            let date = validate_date(2024, 2, 29).unwrap();
+            assert_eq!(
+                (date.year(), date.month(), date.day()),
+                (2024, 2, 29)
+            );
+            assert_eq!(
+                Date::from(date),
+                Date::from_calendar_date(2024, 2, 29).unwrap()
+            );
+            assert_eq!(date.to_date(), Date::from(date));
+            assert!(validate_date(-9999, 1, 1).is_ok());
+            assert!(validate_date(9999, 12, 31).is_ok());
+        }
+
+        #[test]
+        fn test_day_depends_on_month_and_year() {
+            let errors = validate_date(2023, 2, 29).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].component, "day");
+            assert_eq!(errors[0].maximum, 28);
+            assert!(validate_date(2024, 4, 31).is_err());
+            assert!(validate_date(2000, 2, 29).is_ok());
+            assert!(validate_date(1900, 2, 29).is_err());
+        }
+
+        #[test]
+        fn test_reports_every_component() {
+            let errors = validate_date(10_000, 0, 0).unwrap_err();
+            let names: Vec<&str> =
+                errors.iter().map(|e| e.component).collect();
+            assert_eq!(names, ["year", "month", "day"]);
+        }
+
+        #[test]
+        fn test_validated_date_new_and_display() {
+            let date = ValidatedDate::new(2024, 12, 25).unwrap();
+            assert_eq!(date.to_string(), "2024-12-25");
+            assert_eq!(Ok(date), validate_date(2024, 12, 25));
+            assert!(ValidatedDate::new(2024, 12, 32).is_err());
+        }
+
+        #[test]
+        fn test_agrees_with_string_validators() {
+            for day in 0..=u8::MAX {
+                assert_eq!(
+                    validate_date(2024, 1, day).is_ok(),
+                    DateTime::is_valid_day_u8(day)
+                );
+            }
+            for month in 0..=u8::MAX {
+                assert_eq!(
+                    validate_date(2024, month, 1).is_ok(),
+                    DateTime::is_valid_month_u8(month)
+                );
+            }
+        }
+    }
+
+    mod time_tests {
+        use super::*;
+
+        #[test]
+        fn test_valid_times() {
+            let time = validate_time(23, 59, 59, 999_999_999).unwrap();
+            assert_eq!(time.to_string(), "23:59:59.999999999");
+            assert_eq!(
+                validate_time(0, 0, 0, 0).unwrap().to_string(),
+                "00:00:00"
+            );
+        }
+
+        #[test]
+        fn test_reports_every_component() {
+            let errors =
+                validate_time(24, 60, 60, 1_000_000_000).unwrap_err();
+            let names: Vec<&str> =
+                errors.iter().map(|e| e.component).collect();
+            assert_eq!(
+                names,
+                ["hour", "minute", "second", "nanosecond"]
+            );
+            assert_eq!(
+                errors[0].to_string(),
+                "hour must be in the range 0..=23, got 24"
+            );
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn test_agrees_with_is_valid_time() {
+            for (hour, minute, second) in
+                [(23, 59, 59), (24, 0, 0), (0, 60, 0), (0, 0, 60)]
+            {
+                let text = format!("{hour:02}:{minute:02}:{second:02}");
+                assert_eq!(
+                    validate_time(hour, minute, second, 0).is_ok(),
+                    DateTime::is_valid_time(&text)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// test_validators.rs
+//
+// Copyright © 2025 DateTime (DTT) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unit tests for the `validators` module.
+
+#[cfg(test)]
+mod tests {
+    use dtt::validators::*;
+
+    #[test]
+    fn test_date_components() {
+        assert!(is_valid_year("2024"));
+        assert!(is_valid_year("-9999"));
+        assert!(!is_valid_year("10000"));
+        assert!(is_valid_month("1") && is_valid_month("12"));
+        assert!(!is_valid_month("0") && !is_valid_month("13"));
+        assert!(is_valid_day("31"));
+        assert!(!is_valid_day("0") && !is_valid_day("32"));
+        assert!(is_valid_ordinal("1") && is_valid_ordinal("366"));
+        assert!(!is_valid_ordinal("0") && !is_valid_ordinal("367"));
+        assert!(is_valid_iso_week("1") && is_valid_iso_week("53"));
+        assert!(!is_valid_iso_week("54"));
+    }
+
+    #[test]
+    fn test_time_components() {
+        assert!(is_valid_hour("23") && !is_valid_hour("24"));
+        assert!(is_valid_minute("59") && !is_valid_minute("60"));
+        assert!(is_valid_second("59") && !is_valid_second("60"));
+        assert!(is_valid_microsecond("999999"));
+        assert!(!is_valid_microsecond("1000000"));
+        assert!(is_valid_nanosecond("999999999"));
+        assert!(!is_valid_nanosecond("1000000000"));
+        assert!(is_valid_time("23:59:59"));
+        assert!(!is_valid_time("23:59:60"));
+        assert!(!is_valid_time("23:59:59:00"));
+    }
+
+    #[test]
+    fn test_offsets() {
+        for offset in ["Z", "+00:00", "-14:00", "+0530", "UTC-7"] {
+            assert!(is_valid_offset(offset), "{offset}");
+        }
+        for offset in ["", "z", "+14:01", "+05:3", "0530", "+5:60"] {
+            assert!(!is_valid_offset(offset), "{offset}");
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_numeric_input() {
+        for validate in [is_valid_year, is_valid_day, is_valid_hour] {
+            assert!(!validate(""));
+            assert!(!validate("ten"));
+            assert!(!validate("1.5"));
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_checks_agree() {
+        use dtt::datetime::DateTime;
+
+        for input in ["2024", "-9999", "99999", "0", "12", "13", "abc"]
+        {
+            assert_eq!(
+                DateTime::is_valid_year(input),
+                is_valid_year(input),
+                "{input}"
+            );
+            assert_eq!(
+                DateTime::is_valid_month(input),
+                is_valid_month(input),
+                "{input}"
+            );
+        }
+        assert!(!DateTime::is_valid_year("99999"));
+    }
+}